@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use common_utils::pii;
 use masking::{Secret, StrongSecret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url;
 use utoipa::ToSchema;
 
@@ -44,6 +47,10 @@ pub struct CreateMerchantAccount {
     #[schema(max_length = 255, example = "xkkdf909012sdjki2dkh5sdf")]
     pub parent_merchant_id: Option<String>,
 
+    /// The identifier of the organization the merchant belongs to. Sub-merchants belong to the organization of their parent merchant.
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
+
     /// A boolean value to indicate if payment response hash needs to be enabled
     #[schema(default = false, example = true)]
     pub enable_payment_response_hash: Option<bool>,
@@ -66,6 +73,10 @@ pub struct CreateMerchantAccount {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// Payment method filters applied to all the connectors of the merchant, keyed by payment method type. Filters configured on a connector take precedence over these.
+    #[schema(value_type = Option<Object>, example = json!({ "credit": { "accepted_currencies": { "type": "enable_only", "list": ["USD", "EUR"] }, "card_networks": ["Visa"] } }))]
+    pub pm_filters: Option<PaymentMethodFilters>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -91,8 +102,8 @@ pub struct MerchantAccountResponse {
     pub enable_payment_response_hash: bool,
 
     /// Refers to the Parent Merchant ID if the merchant being created is a sub-merchant
-    #[schema(max_length = 255, example = "xkkdf909012sdjki2dkh5sdf")]
-    pub payment_response_hash_key: Option<String>,
+    #[schema(value_type = Option<String>, max_length = 255, example = "xkkdf909012sdjki2dkh5sdf")]
+    pub payment_response_hash_key: Option<Secret<String>>,
 
     /// A boolean value to indicate if redirect to merchant with http post needs to be enabled
     #[schema(default = false, example = true)]
@@ -104,7 +115,7 @@ pub struct MerchantAccountResponse {
 
     /// Webhook related details
     #[schema(value_type = Option<WebhookDetails>)]
-    pub webhook_details: Option<serde_json::Value>,
+    pub webhook_details: Option<Secret<serde_json::Value>>,
 
     /// The routing algorithm to be used to process the incoming request from merchant to outgoing payment processor or payment method. The default is 'Custom'
     #[schema(value_type = Option<RoutingAlgorithm>, max_length = 255, example = "custom")]
@@ -118,6 +129,10 @@ pub struct MerchantAccountResponse {
     #[schema(max_length = 255, example = "xkkdf909012sdjki2dkh5sdf")]
     pub parent_merchant_id: Option<String>,
 
+    /// The identifier of the organization the merchant belongs to
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
+
     /// API key that will be used for server side API access
     #[schema(example = "AH3423bkjbkjdsfbkj")]
     pub publishable_key: Option<String>,
@@ -129,6 +144,10 @@ pub struct MerchantAccountResponse {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// Payment method filters applied to all the connectors of the merchant, keyed by payment method type. Filters configured on a connector take precedence over these.
+    #[schema(value_type = Option<Object>, example = json!({ "credit": { "accepted_currencies": { "type": "enable_only", "list": ["USD", "EUR"] }, "card_networks": ["Visa"] } }))]
+    pub pm_filters: Option<PaymentMethodFilters>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -209,6 +228,74 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The format of the outgoing webhooks. Use `stripe` to receive Stripe `Event` objects, so
+    /// that existing Stripe webhook handlers can be used without changes
+    #[schema(value_type = Option<OutgoingWebhookFormat>, example = "stripe")]
+    pub webhook_format: Option<api_enums::OutgoingWebhookFormat>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrganizationRequest {
+    /// Name of the organization
+    #[schema(max_length = 255, example = "NewAge Retail Group")]
+    pub org_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CreateOrganizationResponse {
+    /// The identifier for the organization
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub org_id: String,
+
+    /// Name of the organization
+    #[schema(max_length = 255, example = "NewAge Retail Group")]
+    pub org_name: Option<String>,
+
+    /// The plaintext API key of the organization, which can be used to access the APIs on behalf of any merchant of the organization. The merchant is selected with the `x-merchant-id` header. Ensure you store the API key securely as you will not be able to see it again.
+    #[schema(value_type = String, max_length = 128)]
+    pub api_key: StrongSecret<String>,
+
+    /// The time at which the organization was created
+    #[schema(example = "2023-03-17T07:12:43.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the organization was last updated
+    #[schema(example = "2023-03-17T07:12:43.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    /// The identifier for the organization
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub org_id: String,
+
+    /// Name of the organization
+    #[schema(max_length = 255, example = "NewAge Retail Group")]
+    pub org_name: Option<String>,
+
+    /// The first few characters of the plaintext API key of the organization to help you identify it
+    #[schema(value_type = String, max_length = 64)]
+    pub prefix: StrongSecret<String>,
+
+    /// The time at which the organization was created
+    #[schema(example = "2023-03-17T07:12:43.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the organization was last updated
+    #[schema(example = "2023-03-17T07:12:43.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct OrganizationId {
+    pub org_id: String,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -254,6 +341,9 @@ pub struct PaymentConnectorCreate {
     /// A boolean value to indicate if the connector is disabled. By default, its value is false.
     #[schema(default = false, example = false)]
     pub disabled: Option<bool>,
+    /// A boolean value to indicate if the sub-merchants of the merchant can use the connector account, when they do not have an account of their own for the connector. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub shared_with_sub_merchants: Option<bool>,
     /// Refers to the Parent Merchant ID if the merchant being created is a sub-merchant
     #[schema(example = json!([
         {
@@ -285,6 +375,9 @@ pub struct PaymentConnectorCreate {
         }
     ]))]
     pub payment_methods_enabled: Option<Vec<PaymentMethodsEnabled>>,
+    /// Payment method filters for the connector, keyed by payment method type. These take precedence over the filters configured on the merchant account.
+    #[schema(value_type = Option<Object>, example = json!({ "credit": { "accepted_countries": { "type": "disable_only", "list": ["FR", "DE"] }, "minimum_amount": 100 } }))]
+    pub pm_filters: Option<PaymentMethodFilters>,
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
@@ -303,6 +396,32 @@ pub struct PaymentMethodsEnabled {
     pub payment_method_types: Option<Vec<payment_methods::RequestPaymentMethodTypes>>,
 }
 
+/// Payment method filters keyed by the payment method type they apply to
+pub type PaymentMethodFilters = HashMap<api_enums::PaymentMethodType, PaymentMethodFilter>;
+
+/// Restrictions on the payments that a payment method type can be used for
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodFilter {
+    /// List of currencies accepted or rejected for the payment method type
+    pub accepted_currencies: Option<AcceptedCurrencies>,
+
+    /// List of countries accepted or rejected for the payment method type
+    pub accepted_countries: Option<AcceptedCountries>,
+
+    /// Minimum amount, in the lowest denomination of the currency, accepted for the payment method type
+    #[schema(example = 100)]
+    pub minimum_amount: Option<i64>,
+
+    /// Maximum amount, in the lowest denomination of the currency, accepted for the payment method type
+    #[schema(example = 1000000)]
+    pub maximum_amount: Option<i64>,
+
+    /// Card networks accepted for the payment method type, applicable for cards only
+    #[schema(value_type = Option<Vec<CardNetwork>>, example = json!(["Visa", "Mastercard"]))]
+    pub card_networks: Option<Vec<api_enums::CardNetwork>>,
+}
+
 /// List of enabled and disabled currencies, empty in case all currencies are enabled
 #[derive(Eq, PartialEq, Hash, Debug, Clone, serde::Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -349,9 +468,105 @@ pub struct ToggleKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKeyRotationResponse {
+    /// The identifier of the process tracker task re-wrapping the data keys of merchants
+    #[schema(example = "MERCHANT_KEY_ROTATION_WORKFLOW_MERCHANT_KEY_ROTATION_2_1678693473")]
+    pub task_id: String,
+    /// The identifier of the master key the data keys are re-wrapped with
+    #[schema(example = "2")]
+    pub master_key_identifier: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVRequest {
     /// Status of KV for the specific merchant
     #[schema(example = true)]
     pub kv_enabled: bool,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorHealthResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Health of each of the connectors configured for the merchant
+    pub connectors: Vec<ConnectorHealth>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorHealth {
+    /// Name of the Connector
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+    /// Unique ID of the connector
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+    /// State of the circuit breaker for the connector
+    #[schema(value_type = CircuitState, example = "closed")]
+    pub circuit_state: api_enums::CircuitState,
+    /// Number of calls made to the connector in the current window
+    #[schema(example = 120)]
+    pub total_calls: u64,
+    /// Number of failed calls made to the connector in the current window
+    #[schema(example = 3)]
+    pub failed_calls: u64,
+    /// Number of calls in the current window that exceeded the slow call threshold
+    #[schema(example = 0)]
+    pub slow_calls: u64,
+    /// Percentage of failed calls in the current window
+    #[schema(example = 2)]
+    pub error_rate: u64,
+    /// Average latency of calls in the current window (in milliseconds)
+    #[schema(example = 450)]
+    pub average_latency: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorCallLogResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The identifier for the payment
+    #[schema(max_length = 255, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// Calls made to connectors for the payment, in the order in which they were made
+    pub calls: Vec<ConnectorCallLog>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorCallLog {
+    /// The identifier for the payment attempt
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+    /// Name of the Connector
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The flow for which the call was made
+    #[schema(example = "Authorize")]
+    pub flow: String,
+    /// HTTP method of the request
+    #[schema(example = "POST")]
+    pub method: String,
+    /// URL to which the request was sent
+    #[schema(example = "https://api.stripe.com/v1/payment_intents")]
+    pub url: String,
+    /// Headers sent to the connector, with the values of sensitive headers masked
+    #[schema(value_type = Object)]
+    pub request_headers: serde_json::Value,
+    /// Body sent to the connector, with sensitive fields masked
+    pub request_body: Option<String>,
+    /// Body returned by the connector, with sensitive fields masked
+    pub response_body: Option<String>,
+    /// HTTP status code returned by the connector
+    #[schema(example = 200)]
+    pub status_code: Option<i16>,
+    /// Error encountered while communicating with the connector
+    pub error_message: Option<String>,
+    /// Time taken by the connector to respond (in milliseconds)
+    #[schema(example = 450)]
+    pub latency: i64,
+    /// The timestamp at which the call was made
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
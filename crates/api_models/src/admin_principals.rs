@@ -0,0 +1,95 @@
+use masking::StrongSecret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The request body for creating an admin principal.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateAdminPrincipalRequest {
+    /// A unique name for the admin principal, recorded in the audit log for the actions performed
+    /// by it.
+    #[schema(max_length = 64, example = "support-team")]
+    pub name: String,
+
+    /// A description to provide more context about the admin principal.
+    #[schema(max_length = 256, example = "Key used by the support team")]
+    pub description: Option<String>,
+
+    /// The permissions granted to the admin principal on the admin APIs.
+    #[schema(value_type = Vec<Permission>, example = json!(["merchant_accounts:read", "connectors:read"]))]
+    pub permissions: Vec<api_enums::Permission>,
+}
+
+/// The response body for creating an admin principal.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateAdminPrincipalResponse {
+    /// The identifier for the admin principal.
+    #[schema(max_length = 64, example = "adm_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub principal_id: String,
+
+    /// The unique name for the admin principal.
+    #[schema(max_length = 64, example = "support-team")]
+    pub name: String,
+
+    /// The description to provide more context about the admin principal.
+    #[schema(max_length = 256, example = "Key used by the support team")]
+    pub description: Option<String>,
+
+    /// The plaintext admin API Key of the admin principal. Ensure you store the API Key securely
+    /// as you will not be able to see it again.
+    #[schema(value_type = String, max_length = 128)]
+    pub api_key: StrongSecret<String>,
+
+    /// The permissions granted to the admin principal on the admin APIs.
+    #[schema(value_type = Vec<Permission>, example = json!(["merchant_accounts:read", "connectors:read"]))]
+    pub permissions: Vec<api_enums::Permission>,
+
+    /// The time at which the admin principal was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for retrieving an admin principal.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminPrincipalResponse {
+    /// The identifier for the admin principal.
+    #[schema(max_length = 64, example = "adm_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub principal_id: String,
+
+    /// The unique name for the admin principal.
+    #[schema(max_length = 64, example = "support-team")]
+    pub name: String,
+
+    /// The description to provide more context about the admin principal.
+    #[schema(max_length = 256, example = "Key used by the support team")]
+    pub description: Option<String>,
+
+    /// The first few characters of the plaintext admin API Key to help you identify it.
+    #[schema(value_type = String, max_length = 64)]
+    pub prefix: StrongSecret<String>,
+
+    /// The permissions granted to the admin principal on the admin APIs.
+    #[schema(value_type = Vec<Permission>, example = json!(["merchant_accounts:read", "connectors:read"]))]
+    pub permissions: Vec<api_enums::Permission>,
+
+    /// The time at which the admin principal was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for revoking an admin principal.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevokeAdminPrincipalResponse {
+    /// The identifier for the admin principal.
+    #[schema(max_length = 64, example = "adm_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub principal_id: String,
+
+    /// Indicates whether the admin principal was revoked or not.
+    #[schema(example = "true")]
+    pub revoked: bool,
+}
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +25,11 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key. API Keys without permissions have full access to
+    /// the merchant account.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["payments:read", "refunds:read"]))]
+    pub permissions: Option<Vec<api_enums::Permission>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +67,11 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key, absent if the API Key has full access to the
+    /// merchant account.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["payments:read", "refunds:read"]))]
+    pub permissions: Option<Vec<api_enums::Permission>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +114,11 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key, absent if the API Key has full access to the
+    /// merchant account.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["payments:read", "refunds:read"]))]
+    pub permissions: Option<Vec<api_enums::Permission>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -129,6 +146,10 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// The permissions granted to the API Key, replacing the permissions granted earlier.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["payments:read", "refunds:read"]))]
+    pub permissions: Option<Vec<api_enums::Permission>>,
 }
 
 /// The response body for revoking an API Key.
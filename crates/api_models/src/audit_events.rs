@@ -0,0 +1,96 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The constraints that are applicable when listing audit events.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditEventListConstraints {
+    /// Only list the events of the merchant account.
+    pub merchant_id: Option<String>,
+
+    /// Only list the events of the kind of resource.
+    pub resource_type: Option<api_enums::AuditResourceType>,
+
+    /// Only list the events of the resource, such as a merchant connector ID or an API key ID.
+    pub resource_id: Option<String>,
+
+    /// Only list the events of the actor, such as an admin principal ID.
+    pub actor_id: Option<String>,
+
+    /// Only list the events recorded at or after this time.
+    #[serde(
+        default,
+        rename = "created.gte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_gte: Option<PrimitiveDateTime>,
+
+    /// Only list the events recorded at or before this time.
+    #[serde(
+        default,
+        rename = "created.lte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_lte: Option<PrimitiveDateTime>,
+
+    /// The maximum number of audit events to include in the response.
+    pub limit: Option<i64>,
+
+    /// The number of audit events to skip when retrieving the list of audit events.
+    pub skip: Option<i64>,
+}
+
+/// A change made through the admin APIs, recorded in the audit log.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AuditEventResponse {
+    /// The identifier for the audit event.
+    #[schema(max_length = 64, example = "aud_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub event_id: String,
+
+    /// The kind of principal which made the change.
+    #[schema(value_type = AuditActorType)]
+    pub actor_type: api_enums::AuditActorType,
+
+    /// The identifier for the principal which made the change.
+    #[schema(max_length = 64, example = "adm_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub actor_id: String,
+
+    /// The kind of change.
+    #[schema(value_type = AuditAction)]
+    pub action: api_enums::AuditAction,
+
+    /// The kind of resource which was changed.
+    #[schema(value_type = AuditResourceType)]
+    pub resource_type: api_enums::AuditResourceType,
+
+    /// The identifier for the resource which was changed.
+    #[schema(max_length = 255, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub resource_id: String,
+
+    /// The identifier for the merchant account the resource belongs to.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: Option<String>,
+
+    /// The fields of the resource which were changed, as they were before the change. Secrets are
+    /// masked.
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<serde_json::Value>,
+
+    /// The fields of the resource which were changed, as they are after the change. Secrets are
+    /// masked.
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<serde_json::Value>,
+
+    /// The identifier for the request which made the change.
+    #[schema(example = "0186f9c4-1b8a-7ba3-9a6c-3b1f7f1f0b7d")]
+    pub request_id: Option<String>,
+
+    /// The time at which the change was made.
+    #[schema(example = "2023-03-22T06:45:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
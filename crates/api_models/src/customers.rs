@@ -91,6 +91,9 @@ pub struct CustomerResponse {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
+    /// The identifier of the payment method which is used by default for the customer
+    #[schema(max_length = 64, example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize)]
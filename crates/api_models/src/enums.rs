@@ -264,10 +264,37 @@ pub enum Currency {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentFailed,
+    PaymentProcessing,
+    ActionRequired,
+    PaymentCancelled,
     RefundSucceeded,
     RefundFailed,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookFormat {
+    /// Outgoing webhooks are sent as `OutgoingWebhook` objects
+    #[default]
+    Hyperswitch,
+    /// Outgoing webhooks are sent as Stripe `Event` objects
+    Stripe,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -407,6 +434,11 @@ pub enum PaymentMethodType {
     GooglePay,
     ApplePay,
     Paypal,
+    Ach,
+    Sepa,
+    Bacs,
+    Becs,
+    Multibanco,
 }
 
 #[derive(
@@ -432,6 +464,8 @@ pub enum PaymentMethod {
     PayLater,
     Wallet,
     BankRedirect,
+    BankTransfer,
+    BankDebit,
 }
 
 #[derive(
@@ -501,6 +535,248 @@ pub enum RoutingAlgorithm {
     Custom,
 }
 
+/// The status of the payment link, which indicates whether it can be used to pay
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    /// The payment link can be used by the customer to pay
+    #[default]
+    Active,
+    /// The payment has succeeded through the payment link
+    Completed,
+    /// The payment link has expired and can no longer be used to pay
+    Expired,
+}
+
+/// A permission granted to an API key or an admin principal, in the `resource:action` format.
+/// The `merchant_accounts`, `organizations`, `connectors`, `api_keys`, `configs`,
+/// `admin_principals` and `audit_events` permissions apply to the admin APIs and can only be
+/// granted to admin principals, the other permissions can only be granted to API keys.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+pub enum Permission {
+    /// Retrieve and list payments
+    #[serde(rename = "payments:read")]
+    #[strum(serialize = "payments:read")]
+    PaymentsRead,
+    /// Create, update, confirm, capture and cancel payments
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+    /// Retrieve and list refunds
+    #[serde(rename = "refunds:read")]
+    #[strum(serialize = "refunds:read")]
+    RefundsRead,
+    /// Create and update refunds
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+    /// Retrieve and list customers
+    #[serde(rename = "customers:read")]
+    #[strum(serialize = "customers:read")]
+    CustomersRead,
+    /// Create, update and delete customers
+    #[serde(rename = "customers:write")]
+    #[strum(serialize = "customers:write")]
+    CustomersWrite,
+    /// Retrieve and list mandates
+    #[serde(rename = "mandates:read")]
+    #[strum(serialize = "mandates:read")]
+    MandatesRead,
+    /// Revoke mandates
+    #[serde(rename = "mandates:write")]
+    #[strum(serialize = "mandates:write")]
+    MandatesWrite,
+    /// Retrieve and list saved payment methods
+    #[serde(rename = "payment_methods:read")]
+    #[strum(serialize = "payment_methods:read")]
+    PaymentMethodsRead,
+    /// Create, update and delete saved payment methods
+    #[serde(rename = "payment_methods:write")]
+    #[strum(serialize = "payment_methods:write")]
+    PaymentMethodsWrite,
+    /// Detokenize data stored in the vault
+    #[serde(rename = "vault:read")]
+    #[strum(serialize = "vault:read")]
+    VaultRead,
+    /// Tokenize data and delete tokens in the vault
+    #[serde(rename = "vault:write")]
+    #[strum(serialize = "vault:write")]
+    VaultWrite,
+    /// Retrieve and list merchant accounts
+    #[serde(rename = "merchant_accounts:read")]
+    #[strum(serialize = "merchant_accounts:read")]
+    MerchantAccountsRead,
+    /// Create, update and delete merchant accounts
+    #[serde(rename = "merchant_accounts:write")]
+    #[strum(serialize = "merchant_accounts:write")]
+    MerchantAccountsWrite,
+    /// Retrieve and list organizations
+    #[serde(rename = "organizations:read")]
+    #[strum(serialize = "organizations:read")]
+    OrganizationsRead,
+    /// Create, update and delete organizations
+    #[serde(rename = "organizations:write")]
+    #[strum(serialize = "organizations:write")]
+    OrganizationsWrite,
+    /// Retrieve and list merchant connector accounts
+    #[serde(rename = "connectors:read")]
+    #[strum(serialize = "connectors:read")]
+    ConnectorsRead,
+    /// Create, update and delete merchant connector accounts
+    #[serde(rename = "connectors:write")]
+    #[strum(serialize = "connectors:write")]
+    ConnectorsWrite,
+    /// Retrieve and list API keys
+    #[serde(rename = "api_keys:read")]
+    #[strum(serialize = "api_keys:read")]
+    ApiKeysRead,
+    /// Create, update and delete API keys
+    #[serde(rename = "api_keys:write")]
+    #[strum(serialize = "api_keys:write")]
+    ApiKeysWrite,
+    /// Retrieve and list configs
+    #[serde(rename = "configs:read")]
+    #[strum(serialize = "configs:read")]
+    ConfigsRead,
+    /// Create, update and delete configs
+    #[serde(rename = "configs:write")]
+    #[strum(serialize = "configs:write")]
+    ConfigsWrite,
+    /// Retrieve and list admin principals
+    #[serde(rename = "admin_principals:read")]
+    #[strum(serialize = "admin_principals:read")]
+    AdminPrincipalsRead,
+    /// Create, update and delete admin principals
+    #[serde(rename = "admin_principals:write")]
+    #[strum(serialize = "admin_principals:write")]
+    AdminPrincipalsWrite,
+    /// List the changes recorded in the audit log
+    #[serde(rename = "audit_events:read")]
+    #[strum(serialize = "audit_events:read")]
+    AuditEventsRead,
+}
+
+impl Permission {
+    /// Whether the permission applies to the admin APIs.
+    pub fn is_admin_permission(self) -> bool {
+        matches!(
+            self,
+            Self::MerchantAccountsRead
+                | Self::MerchantAccountsWrite
+                | Self::OrganizationsRead
+                | Self::OrganizationsWrite
+                | Self::ConnectorsRead
+                | Self::ConnectorsWrite
+                | Self::ApiKeysRead
+                | Self::ApiKeysWrite
+                | Self::ConfigsRead
+                | Self::ConfigsWrite
+                | Self::AdminPrincipalsRead
+                | Self::AdminPrincipalsWrite
+                | Self::AuditEventsRead
+        )
+    }
+}
+
+/// The kind of principal which made a change recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    /// The `admin_api_key` from the configuration
+    AdminApiKey,
+    /// An admin principal, identified by its principal ID
+    AdminPrincipal,
+}
+
+/// The kind of change recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// The kind of resource changed by an event recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditResourceType {
+    /// A merchant account, including its key-value storage setting
+    MerchantAccount,
+    /// A payment connector of a merchant account, including its credentials
+    MerchantConnectorAccount,
+    /// An API key of a merchant account
+    ApiKey,
+    /// A config entry
+    Config,
+}
+
 /// The status of the mandate, which indicates whether it can be used to initiate a payment
 #[derive(
     Clone,
@@ -526,6 +802,30 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The state of the circuit breaker for a connector. Calls to the connector are rejected while the circuit is open
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CircuitState {
+    #[default]
+    Closed,
+    Open,
+    HalfOpen,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -754,3 +1054,28 @@ impl From<AttemptStatus> for IntentStatus {
         }
     }
 }
+
+/// Result of the fraud check of a payment, before it is authorized
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckDecision {
+    /// The payment is authorized as requested
+    Accept,
+    /// The payment is authorized, but held for manual capture until it is reviewed
+    Review,
+    /// The payment is not authorized
+    Decline,
+}
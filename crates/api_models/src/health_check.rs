@@ -0,0 +1,42 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// The component is working as expected
+    Healthy,
+    /// The component is reachable, but is lagging behind
+    Degraded,
+    /// The component is unreachable or returned an error
+    Unhealthy,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct ComponentHealth {
+    /// The status of the component
+    pub status: HealthStatus,
+    /// Time taken to check the component, in milliseconds
+    pub latency: u64,
+    /// Number of entries waiting to be processed, for components which process streams
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lag: Option<usize>,
+    /// The reason the component is not healthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct DeepHealthCheckResponse {
+    /// The overall status of the application, which is unhealthy if any of the components
+    /// required to serve requests is unhealthy
+    pub status: HealthStatus,
+    pub master_database: ComponentHealth,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replica_database: Option<ComponentHealth>,
+    pub redis: ComponentHealth,
+    pub locker: ComponentHealth,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets_manager: Option<ComponentHealth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drainer: Option<ComponentHealth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduler: Option<ComponentHealth>,
+}
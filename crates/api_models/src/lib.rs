@@ -1,6 +1,8 @@
 #![forbid(unsafe_code)]
 pub mod admin;
+pub mod admin_principals;
 pub mod api_keys;
+pub mod audit_events;
 pub mod bank_accounts;
 pub mod cards;
 pub mod customers;
@@ -8,9 +10,11 @@ pub mod disputes;
 pub mod enums;
 pub mod errors;
 pub mod files;
+pub mod health_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod vault;
 pub mod webhooks;
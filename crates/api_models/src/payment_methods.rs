@@ -180,7 +180,7 @@ pub struct ResponsePaymentMethodTypes {
     pub bank_names: Option<Vec<BankCodeResponse>>,
 }
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
 pub struct ResponsePaymentMethodsEnabled {
     pub payment_method: api_enums::PaymentMethod,
     pub payment_method_types: Vec<ResponsePaymentMethodTypes>,
@@ -514,12 +514,49 @@ pub struct CustomerPaymentMethod {
     #[schema(value_type = Option<PrimitiveDateTime>,example = "2023-01-18T11:04:09.922Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub created: Option<time::PrimitiveDateTime>,
+
+    /// Indicates whether the payment method is the default payment method of the customer
+    #[schema(example = true)]
+    pub default_payment_method_set: bool,
+
+    ///  A timestamp (ISO 8601 code) that determines when the payment method was last used for a successful payment
+    #[schema(value_type = Option<PrimitiveDateTime>,example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DefaultPaymentMethod {
+    /// The identifier of the customer
+    pub customer_id: String,
+    /// The identifier of the payment method to be used by default for the customer
+    pub payment_method_id: String,
 }
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CustomerDefaultPaymentMethodResponse {
+    /// The unique identifier of the customer.
+    #[schema(example = "cus_meowerunwiuwiwqw")]
+    pub customer_id: String,
+
+    /// The unique identifier of the default payment method of the customer
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    pub default_payment_method_id: Option<String>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct PaymentMethodId {
     pub payment_method_id: String,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentMethodAttachRequest {
+    /// The identifier of the payment method to be attached
+    pub payment_method_id: String,
+    /// The identifier of the customer to attach the payment method to
+    pub customer_id: String,
+}
+
 //------------------------------------------------TokenizeService------------------------------------------------
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct TokenizePayloadEncrypted {
@@ -590,3 +627,23 @@ pub struct TokenizedWalletValue1 {
 pub struct TokenizedWalletValue2 {
     pub customer_id: Option<String>,
 }
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedBankDebitValue1 {
+    pub data: payments::BankDebitData,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedBankDebitValue2 {
+    pub customer_id: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedBankTransferValue1 {
+    pub data: payments::BankTransferData,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedBankTransferValue2 {
+    pub customer_id: Option<String>,
+}
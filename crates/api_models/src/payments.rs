@@ -196,6 +196,43 @@ pub struct PaymentsRequest {
     /// Payment Method Type
     #[schema(value_type = Option<PaymentMethodType>, example = "google_pay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// Create a shareable payment link, which opens a checkout page hosted by Hyperswitch for the customer to complete the payment
+    pub payment_link: Option<PaymentLinkRequest>,
+}
+
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkRequest {
+    /// Time in seconds after which the payment link expires. Defaults to the expiry configured for the server
+    #[schema(example = 86400)]
+    pub expires_in: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentLinkResponse {
+    /// The identifier for the payment link
+    #[schema(example = "plink_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_link_id: String,
+    /// The identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// The URL of the checkout page, which can be shared with the customer
+    #[schema(
+        example = "https://sandbox.hyperswitch.io/payment_link/pay/plink_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub link_to_pay: String,
+    /// The status of the payment link
+    #[schema(value_type = PaymentLinkStatus, example = "active")]
+    pub status: api_enums::PaymentLinkStatus,
+    /// The time at which the payment link was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time after which the payment link can no longer be used to pay
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -384,6 +421,14 @@ pub struct Card {
     pub card_issuer: Option<String>,
     #[schema(value_type = Option<CardNetwork>, example = "Visa")]
     pub card_network: Option<api_enums::CardNetwork>,
+    /// The funding type of the card, determined from the card's BIN. Ignored in requests.
+    #[serde(skip_deserializing)]
+    #[schema(read_only, example = "credit")]
+    pub card_type: Option<String>,
+    /// The country of the card's issuer, determined from the card's BIN. Ignored in requests.
+    #[serde(skip_deserializing)]
+    #[schema(read_only, example = "US")]
+    pub card_issuing_country: Option<String>,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -422,6 +467,8 @@ pub enum PaymentMethodData {
     Wallet(WalletData),
     PayLater(PayLaterData),
     BankRedirect(BankRedirectData),
+    BankDebit(BankDebitData),
+    BankTransfer(BankTransferData),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -430,12 +477,16 @@ pub enum AdditionalPaymentData {
     Card {
         card_issuer: Option<String>,
         card_network: Option<String>,
+        card_type: Option<String>,
+        card_issuing_country: Option<String>,
     },
     BankRedirect {
         bank_name: Option<api_enums::BankNames>,
     },
     Wallet {},
     PayLater {},
+    BankDebit {},
+    BankTransfer {},
 }
 
 impl From<&PaymentMethodData> for AdditionalPaymentData {
@@ -447,6 +498,8 @@ impl From<&PaymentMethodData> for AdditionalPaymentData {
                     .card_network
                     .as_ref()
                     .map(|card_network| card_network.to_string()),
+                card_type: card_data.card_type.to_owned(),
+                card_issuing_country: card_data.card_issuing_country.to_owned(),
             },
             PaymentMethodData::BankRedirect(bank_redirect_data) => match bank_redirect_data {
                 BankRedirectData::Eps { bank_name, .. } => Self::BankRedirect {
@@ -459,6 +512,8 @@ impl From<&PaymentMethodData> for AdditionalPaymentData {
             },
             PaymentMethodData::Wallet(_) => Self::Wallet {},
             PaymentMethodData::PayLater(_) => Self::PayLater {},
+            PaymentMethodData::BankDebit(_) => Self::BankDebit {},
+            PaymentMethodData::BankTransfer(_) => Self::BankTransfer {},
         }
     }
 }
@@ -490,6 +545,97 @@ pub enum BankRedirectData {
     },
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankDebitData {
+    /// Payment Method data for Ach bank debit
+    AchBankDebit {
+        /// Billing details for bank debit
+        billing_details: BankDebitBilling,
+        /// Account number for ach bank debit payment
+        #[schema(value_type = String, example = "000123456789")]
+        account_number: Secret<String>,
+        /// Routing number for ach bank debit payment
+        #[schema(value_type = String, example = "110000000")]
+        routing_number: Secret<String>,
+    },
+    /// Payment Method data for Sepa bank debit
+    SepaBankDebit {
+        /// Billing details for bank debit
+        billing_details: BankDebitBilling,
+        /// International bank account number (iban) for SEPA
+        #[schema(value_type = String, example = "DE89370400440532013000")]
+        iban: Secret<String>,
+    },
+    /// Payment Method data for Becs bank debit
+    BecsBankDebit {
+        /// Billing details for bank debit
+        billing_details: BankDebitBilling,
+        /// Account number for Becs payment method
+        #[schema(value_type = String, example = "000123456")]
+        account_number: Secret<String>,
+        /// Bank-State-Branch (bsb) number
+        #[schema(value_type = String, example = "000000")]
+        bsb_number: Secret<String>,
+    },
+    /// Payment Method data for Bacs bank debit
+    BacsBankDebit {
+        /// Billing details for bank debit
+        billing_details: BankDebitBilling,
+        /// Account number for Bacs payment method
+        #[schema(value_type = String, example = "00012345")]
+        account_number: Secret<String>,
+        /// Sort code for Bacs payment method
+        #[schema(value_type = String, example = "108800")]
+        sort_code: Secret<String>,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankDebitBilling {
+    /// The billing name for bank debits
+    #[schema(value_type = String, example = "John Doe")]
+    pub name: Secret<String>,
+    /// The billing email for bank debits
+    #[schema(value_type = String, example = "example@example.com")]
+    pub email: Secret<String, pii::Email>,
+    /// The billing address for bank debits
+    pub address: Option<AddressDetails>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankTransferData {
+    /// Payment Method data for Ach credit transfer
+    AchBankTransfer {
+        /// Billing details for bank transfer
+        billing_details: BankTransferBilling,
+    },
+    /// Payment Method data for Sepa credit transfer
+    SepaBankTransfer {
+        /// Billing details for bank transfer
+        billing_details: BankTransferBilling,
+        /// The two-letter ISO country code of the bank account to be shown to the customer
+        #[schema(example = "DE")]
+        country: String,
+    },
+    /// Payment Method data for Multibanco
+    Multibanco {
+        /// Billing details for bank transfer
+        billing_details: BankTransferBilling,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankTransferBilling {
+    /// The billing name for bank transfers
+    #[schema(value_type = Option<String>, example = "John Doe")]
+    pub name: Option<Secret<String>>,
+    /// The billing email for bank transfers
+    #[schema(value_type = String, example = "example@example.com")]
+    pub email: Secret<String, pii::Email>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct SofortBilling {
     /// The country associated with the billing
@@ -596,6 +742,8 @@ pub enum PaymentMethodDataResponse {
     PayLater(PayLaterData),
     Paypal,
     BankRedirect(BankRedirectData),
+    #[serde(rename = "bank_debit")]
+    BankDebit,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -749,6 +897,7 @@ pub enum NextActionType {
     DisplayQrCode,
     InvokeSdkClient,
     TriggerApi,
+    DisplayBankTransferInformation,
 }
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
 pub struct NextAction {
@@ -758,6 +907,46 @@ pub struct NextAction {
     /// Contains the url for redirection flow
     #[schema(example = "https://router.juspay.io/redirect/fakushdfjlksdfasklhdfj")]
     pub redirect_to_url: Option<String>,
+    /// Contains the details the customer needs to complete a bank transfer
+    pub bank_transfer_instructions: Option<BankTransferInstructions>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BankTransferInstructions {
+    /// Name of the holder of the account to which the amount has to be transferred
+    #[schema(example = "Acme Payments Ltd")]
+    pub account_holder_name: Option<String>,
+    /// IBAN of the account to which the amount has to be transferred
+    #[schema(example = "NL13TEST0123456789")]
+    pub iban: Option<String>,
+    /// BIC of the bank to which the amount has to be transferred
+    #[schema(example = "TESTNL01")]
+    pub bic: Option<String>,
+    /// Number of the account to which the amount has to be transferred
+    pub account_number: Option<String>,
+    /// Routing number of the bank to which the amount has to be transferred
+    #[schema(example = "110000000")]
+    pub routing_number: Option<String>,
+    /// Name of the bank to which the amount has to be transferred
+    pub bank_name: Option<String>,
+    /// Entity code of the receiver, required for Multibanco payments
+    #[schema(example = "12101")]
+    pub entity: Option<String>,
+    /// Reference which the customer has to quote when making the transfer
+    #[schema(example = "PRNMWGNCFZ76")]
+    pub reference: Option<String>,
+    /// The amount to be transferred, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+    /// The three-letter ISO currency code of the amount to be transferred
+    #[schema(example = "EUR")]
+    pub currency: Option<String>,
+    /// Time after which the transfer instructions are no longer valid and the payment expires
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// Url of the page, hosted by the connector, which has the instructions for the transfer
+    pub instructions_url: Option<String>,
 }
 
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
@@ -882,6 +1071,12 @@ pub struct PaymentsResponse {
     /// Payment Method Type
     #[schema(value_type = Option<PaymentMethodType>, example = "gpay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The payment link created for the payment, if requested
+    pub payment_link: Option<PaymentLinkResponse>,
+    /// Result of the fraud check of the payment. Payments held for review are authorized with a
+    /// manual capture, to be captured or cancelled once they are reviewed
+    #[schema(value_type = Option<FraudCheckDecision>, example = "accept")]
+    pub fraud_check_decision: Option<api_enums::FraudCheckDecision>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema)]
@@ -1049,6 +1244,8 @@ impl From<PaymentMethodData> for PaymentMethodDataResponse {
             PaymentMethodData::BankRedirect(bank_redirect_data) => {
                 Self::BankRedirect(bank_redirect_data)
             }
+            PaymentMethodData::BankDebit(_) => Self::BankDebit,
+            PaymentMethodData::BankTransfer(_) => Self::BankTransfer,
         }
     }
 }
@@ -1190,6 +1387,59 @@ pub struct GpaySessionTokenData {
     pub data: GpayMetaData,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplepaySessionTokenData {
+    #[serde(rename = "apple_pay")]
+    pub data: ApplePayMetadata,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplePayMetadata {
+    pub payment_request_data: PaymentRequestMetadata,
+    pub session_token_data: SessionTokenInfo,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentRequestMetadata {
+    pub supported_networks: Vec<String>,
+    pub merchant_capabilities: Vec<String>,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SessionTokenInfo {
+    pub merchant_identifier: String,
+    pub display_name: String,
+    pub initiative: String,
+    pub initiative_context: String,
+}
+
+/// The Apple Pay merchant identity certificate, which is configured in the connector account
+/// details of the merchant connector account since it is a secret
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ApplePayCertificateDetails {
+    #[serde(rename = "apple_pay")]
+    pub data: ApplePayCertificate,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ApplePayCertificate {
+    /// Base64 encoded merchant identity certificate issued by Apple
+    pub certificate: Secret<String>,
+    /// Base64 encoded private key of the merchant identity certificate
+    pub certificate_keys: Secret<String>,
+}
+
+/// The request sent to Apple Pay to validate the merchant and start a payment session
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplepaySessionRequest {
+    pub merchant_identifier: String,
+    pub display_name: String,
+    pub initiative: String,
+    pub initiative_context: String,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
 #[serde(tag = "wallet_name")]
 #[serde(rename_all = "snake_case")]
@@ -1229,6 +1479,10 @@ pub struct KlarnaSessionTokenResponse {
 pub struct PaypalSessionTokenResponse {
     /// The session token for PayPal
     pub session_token: String,
+    /// The id of the PayPal order created by the connector, to be approved by the customer
+    pub order_id: String,
+    /// The connector which created the session token, to be used by the SDK to complete the order
+    pub connector: String,
 }
 
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
@@ -123,17 +123,26 @@ pub struct RefundListRequest {
         with = "custom_serde::iso8601::option"
     )]
     pub created_gte: Option<PrimitiveDateTime>,
+    /// A cursor for use in pagination, fetch the next list after some object
+    pub starting_after: Option<String>,
+    /// A cursor for use in pagination, fetch the previous list before some object
+    pub ending_before: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct RefundListResponse {
     /// The list of refund response
     pub data: Vec<RefundResponse>,
+    /// Whether there are more refunds matching the constraints than the ones in the list
+    pub has_more: bool,
 }
 
 /// The status for refunds
-#[derive(Debug, Eq, Clone, PartialEq, Default, Deserialize, Serialize, ToSchema)]
+#[derive(
+    Debug, Eq, Clone, PartialEq, Default, Deserialize, Serialize, ToSchema, strum::Display,
+)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum RefundStatus {
     Succeeded,
     Failed,
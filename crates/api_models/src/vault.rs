@@ -0,0 +1,65 @@
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for storing data in the vault in exchange for a token.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TokenizeRequest {
+    /// The data to be stored in the vault
+    #[schema(value_type = String, example = "4242424242424242")]
+    pub value1: Secret<String>,
+
+    /// Additional data to be stored along with `value1`
+    #[schema(value_type = Option<String>)]
+    pub value2: Option<Secret<String>>,
+
+    /// Time after which the token expires, in seconds. Defaults to the token lifetime configured
+    /// for the vault.
+    #[schema(example = 900)]
+    pub ttl: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TokenizeResponse {
+    /// The token which can be exchanged for the stored data until it expires
+    #[schema(example = "tok_mbabizu24mvu3mela5njyhpit4")]
+    pub token: String,
+
+    /// The time after which the token can no longer be exchanged for the stored data
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+/// The request body for retrieving the data stored in the vault against a token.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DetokenizeRequest {
+    /// The token returned while storing the data
+    #[schema(example = "tok_mbabizu24mvu3mela5njyhpit4")]
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DetokenizeResponse {
+    /// The data stored in the vault
+    #[schema(value_type = String, example = "4242424242424242")]
+    pub value1: Secret<String>,
+
+    /// Additional data stored along with `value1`
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value2: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeleteTokenResponse {
+    /// The token which was deleted
+    #[schema(example = "tok_mbabizu24mvu3mela5njyhpit4")]
+    pub token: String,
+
+    /// Whether the token was deleted
+    pub deleted: bool,
+}
@@ -4,11 +4,15 @@ use time::PrimitiveDateTime;
 
 use crate::{enums as api_enums, payments, refunds};
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, strum::Display)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     PaymentIntentFailure,
     PaymentIntentSuccess,
+    PaymentIntentProcessing,
+    PaymentIntentExpired,
+    PaymentActionRequired,
     RefundFailure,
     RefundSuccess,
     EndpointVerification,
@@ -26,6 +30,9 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
         match evt {
             IncomingWebhookEvent::PaymentIntentFailure => Self::Payment,
             IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentProcessing => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentExpired => Self::Payment,
+            IncomingWebhookEvent::PaymentActionRequired => Self::Payment,
             IncomingWebhookEvent::RefundSuccess => Self::Refund,
             IncomingWebhookEvent::RefundFailure => Self::Refund,
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
@@ -216,6 +216,38 @@ impl DecodeMessage for GcmAes256 {
     }
 }
 
+impl GcmAes256 {
+    /// Encrypts the message with a freshly generated nonce, returning the nonce followed by the
+    /// ciphertext and the authentication tag
+    pub fn encrypt(secret: &[u8], msg: &[u8]) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let nonce = generate_cryptographically_secure_random_bytes::<{ aead::NONCE_LEN }>();
+        let (mut encoded_message, mut auth_tag) = Self {
+            nonce: nonce.to_vec(),
+        }
+        .encode_message(secret, msg)?;
+
+        let mut output = nonce.to_vec();
+        output.append(&mut encoded_message);
+        output.append(&mut auth_tag);
+        Ok(output)
+    }
+
+    /// Decrypts data produced by [`GcmAes256::encrypt`]
+    pub fn decrypt(secret: &[u8], data: &[u8]) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        if data.len() < aead::NONCE_LEN {
+            return Err(errors::CryptoError::DecodingFailed)
+                .into_report()
+                .attach_printable("Encrypted data is shorter than the nonce");
+        }
+        let (nonce, msg) = data.split_at(aead::NONCE_LEN);
+
+        Self {
+            nonce: nonce.to_vec(),
+        }
+        .decode_message(secret, msg)
+    }
+}
+
 /// Secure Hash Algorithm 512
 #[derive(Debug)]
 pub struct Sha512;
@@ -425,6 +457,29 @@ mod crypto_tests {
         assert!(err_decoded.is_err());
     }
 
+    #[test]
+    fn test_gcm_aes_256_encrypt_decrypt() {
+        let right_secret =
+            hex::decode("000102030405060708090a0b0c0d0e0f000102030405060708090a0b0c0d0e0f")
+                .expect("Secret decoding");
+        let wrong_secret =
+            hex::decode("000102030405060708090a0b0c0d0e0f000102030405060708090a0b0c0d0e0e")
+                .expect("Secret decoding");
+        let message = r#"{"type":"PAYMENT"}"#.as_bytes();
+
+        let encrypted = super::GcmAes256::encrypt(&right_secret, message).expect("Encrypted data");
+        let encrypted_again =
+            super::GcmAes256::encrypt(&right_secret, message).expect("Encrypted data");
+
+        assert_ne!(encrypted, encrypted_again);
+        assert_eq!(
+            super::GcmAes256::decrypt(&right_secret, &encrypted).expect("Decrypted data"),
+            message
+        );
+        assert!(super::GcmAes256::decrypt(&wrong_secret, &encrypted).is_err());
+        assert!(super::GcmAes256::decrypt(&right_secret, &encrypted[..4]).is_err());
+    }
+
     #[test]
     fn test_md5_digest() {
         let message = "abcdefghijklmnopqrstuvwxyz".as_bytes();
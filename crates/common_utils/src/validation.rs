@@ -44,6 +44,191 @@ pub fn validate_email(email: &str) -> CustomResult<(), ValidationError> {
     Ok(())
 }
 
+/// Card networks that can be identified from the issuer identification number (IIN) of a card.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CardNetwork {
+    /// Visa
+    Visa,
+    /// Mastercard
+    Mastercard,
+    /// American Express
+    AmericanExpress,
+    /// Diners Club
+    DinersClub,
+    /// Discover
+    Discover,
+    /// JCB
+    JCB,
+    /// UnionPay
+    UnionPay,
+    /// Maestro
+    Maestro,
+}
+
+/// IIN ranges of the card networks, as `(network, first IIN, last IIN)`.
+/// The ranges are checked in order, so more specific ranges must precede broader ones.
+const CARD_IIN_RANGES: [(CardNetwork, u32, u32); 17] = [
+    (CardNetwork::AmericanExpress, 34, 34),
+    (CardNetwork::AmericanExpress, 37, 37),
+    (CardNetwork::DinersClub, 300, 305),
+    (CardNetwork::DinersClub, 36, 36),
+    (CardNetwork::DinersClub, 38, 39),
+    (CardNetwork::JCB, 3528, 3589),
+    (CardNetwork::Visa, 4, 4),
+    (CardNetwork::Mastercard, 51, 55),
+    (CardNetwork::Mastercard, 2221, 2720),
+    (CardNetwork::Discover, 6011, 6011),
+    (CardNetwork::Discover, 622126, 622925),
+    (CardNetwork::Discover, 644, 649),
+    (CardNetwork::Discover, 65, 65),
+    (CardNetwork::UnionPay, 62, 62),
+    (CardNetwork::Maestro, 50, 50),
+    (CardNetwork::Maestro, 56, 58),
+    (CardNetwork::Maestro, 6, 6),
+];
+
+const CARD_NUMBER_MIN_LENGTH: usize = 12;
+const CARD_NUMBER_MAX_LENGTH: usize = 19;
+
+fn card_number_digits(card_number: &str) -> Option<Vec<u32>> {
+    card_number
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .map(|character| character.to_digit(10))
+        .collect()
+}
+
+fn is_luhn_valid(digits: &[u32]) -> bool {
+    let checksum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| match index % 2 {
+            0 => digit,
+            _ if digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum();
+
+    checksum % 10 == 0
+}
+
+/// Validates the length and the Luhn checksum of a card number.
+/// Whitespace in the card number is ignored.
+pub fn validate_card_number(card_number: &str) -> CustomResult<(), ValidationError> {
+    let digits = card_number_digits(card_number).ok_or_else(|| {
+        report!(ValidationError::InvalidValue {
+            message: "Card number must contain only digits".into()
+        })
+    })?;
+
+    if !(CARD_NUMBER_MIN_LENGTH..=CARD_NUMBER_MAX_LENGTH).contains(&digits.len()) {
+        return Err(report!(ValidationError::InvalidValue {
+            message: format!(
+                "Card number must have {CARD_NUMBER_MIN_LENGTH} to {CARD_NUMBER_MAX_LENGTH} digits"
+            )
+        }));
+    }
+
+    if !is_luhn_valid(&digits) {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "Card number failed the Luhn check".into()
+        }));
+    }
+
+    Ok(())
+}
+
+/// Identifies the card network from the IIN ranges of the card number.
+/// Returns `None` if the card number does not fall in any of the known ranges.
+pub fn get_card_network(card_number: &str) -> Option<CardNetwork> {
+    let digits = card_number_digits(card_number)?;
+
+    CARD_IIN_RANGES
+        .iter()
+        .find(|(_, start, end)| {
+            let iin_length = start.to_string().len();
+            digits.len() >= iin_length
+                && (*start..=*end).contains(
+                    &digits
+                        .iter()
+                        .take(iin_length)
+                        .fold(0, |iin, digit| iin * 10 + digit),
+                )
+        })
+        .map(|(network, _, _)| *network)
+}
+
+/// Validates the expiry month of a card, which must be between 1 and 12.
+pub fn validate_card_expiry_month(expiry_month: &str) -> CustomResult<u8, ValidationError> {
+    expiry_month
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))
+        .ok_or_else(|| {
+            report!(ValidationError::InvalidValue {
+                message: "Card expiry month must be between 1 and 12".into()
+            })
+        })
+}
+
+/// Validates that a card has not expired. The expiry year may be provided with 2 or 4 digits.
+/// A card is valid until the end of its expiry month.
+pub fn validate_card_expiry(
+    expiry_month: &str,
+    expiry_year: &str,
+) -> CustomResult<(), ValidationError> {
+    let month = validate_card_expiry_month(expiry_month)?;
+    let expiry_year = expiry_year.trim();
+    let year = expiry_year
+        .parse::<i32>()
+        .ok()
+        .and_then(|year| match expiry_year.len() {
+            2 => Some(2000 + year),
+            4 => Some(year),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            report!(ValidationError::InvalidValue {
+                message: "Card expiry year must be a 2 or 4 digit year".into()
+            })
+        })?;
+
+    let now = crate::date_time::now();
+    let current_month = u8::from(now.month());
+    if (year, month) < (now.year(), current_month) {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "Card has expired".into()
+        }));
+    }
+
+    Ok(())
+}
+
+/// Validates the length of a card's CVC, which is 4 digits for American Express cards and
+/// 3 digits for other networks. Both lengths are accepted if the network is unknown.
+pub fn validate_card_cvc(
+    cvc: &str,
+    card_network: Option<CardNetwork>,
+) -> CustomResult<(), ValidationError> {
+    let expected_lengths: &[usize] = match card_network {
+        Some(CardNetwork::AmericanExpress) => &[4],
+        Some(_) => &[3],
+        None => &[3, 4],
+    };
+
+    if !cvc.chars().all(|character| character.is_ascii_digit())
+        || !expected_lengths.contains(&cvc.len())
+    {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "Invalid card CVC".into()
+        }));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{faker::internet::en::SafeEmail, Fake};
@@ -79,6 +264,57 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_card_number() {
+        assert!(validate_card_number("4242424242424242").is_ok());
+        assert!(validate_card_number("4242 4242 4242 4242").is_ok());
+        assert!(validate_card_number("378282246310005").is_ok());
+        assert!(validate_card_number("4242424242424241").is_err());
+        assert!(validate_card_number("4242abcd42424242").is_err());
+        assert!(validate_card_number("42424242").is_err());
+        assert!(validate_card_number("").is_err());
+    }
+
+    #[test]
+    fn test_get_card_network() {
+        let test_cases = [
+            ("4242424242424242", Some(CardNetwork::Visa)),
+            ("5555555555554444", Some(CardNetwork::Mastercard)),
+            ("2223003122003222", Some(CardNetwork::Mastercard)),
+            ("378282246310005", Some(CardNetwork::AmericanExpress)),
+            ("6011111111111117", Some(CardNetwork::Discover)),
+            ("3566002020360505", Some(CardNetwork::JCB)),
+            ("30569309025904", Some(CardNetwork::DinersClub)),
+            ("6200000000000005", Some(CardNetwork::UnionPay)),
+            ("6759649826438453", Some(CardNetwork::Maestro)),
+            ("9999999999999995", None),
+        ];
+
+        for (card_number, card_network) in test_cases {
+            assert_eq!(get_card_network(card_number), card_network, "{card_number}");
+        }
+    }
+
+    #[test]
+    fn test_validate_card_expiry() {
+        assert!(validate_card_expiry("12", "2099").is_ok());
+        assert!(validate_card_expiry("01", "99").is_ok());
+        assert!(validate_card_expiry("12", "2020").is_err());
+        assert!(validate_card_expiry("13", "2099").is_err());
+        assert!(validate_card_expiry("00", "2099").is_err());
+        assert!(validate_card_expiry("12", "209").is_err());
+    }
+
+    #[test]
+    fn test_validate_card_cvc() {
+        assert!(validate_card_cvc("123", Some(CardNetwork::Visa)).is_ok());
+        assert!(validate_card_cvc("1234", Some(CardNetwork::AmericanExpress)).is_ok());
+        assert!(validate_card_cvc("1234", Some(CardNetwork::Visa)).is_err());
+        assert!(validate_card_cvc("123", Some(CardNetwork::AmericanExpress)).is_err());
+        assert!(validate_card_cvc("1234", None).is_ok());
+        assert!(validate_card_cvc("12a", None).is_err());
+    }
+
     proptest::proptest! {
         /// Example of unit test
         #[test]
@@ -1,5 +1,6 @@
 use drainer::{errors, errors::DrainerResult, logger::logger, services, settings, start_drainer};
 use error_stack::ResultExt;
+use external_services::secrets_management;
 
 #[tokio::main]
 async fn main() -> DrainerResult<()> {
@@ -13,6 +14,16 @@ async fn main() -> DrainerResult<()> {
     conf.validate()
         .expect("Failed to validate drainer configuration");
 
+    #[allow(clippy::expect_used)]
+    let secrets_manager = secrets_management::get_secrets_manager(&conf.secrets_management)
+        .await
+        .expect("Failed to create the secrets manager");
+    #[allow(clippy::expect_used)]
+    let conf = conf
+        .decrypt_secrets(&*secrets_manager)
+        .await
+        .expect("Failed to decrypt the secrets in the configuration");
+
     let store = services::Store::new(&conf, false).await;
     let store = std::sync::Arc::new(store);
 
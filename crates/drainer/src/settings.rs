@@ -1,7 +1,10 @@
 use std::path::PathBuf;
 
-use common_utils::ext_traits::ConfigExt;
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
 use config::{Environment, File};
+use external_services::secrets_management::{
+    self, types::SecretsManagementSettings, SecretManagementInterface, SecretsManagementError,
+};
 use redis_interface as redis;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use router_env::{env, logger};
@@ -25,6 +28,7 @@ pub struct Settings {
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
+    pub secrets_management: SecretsManagementSettings,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -160,7 +164,29 @@ impl Settings {
             errors::DrainerError::ConfigParsingError("invalid Redis configuration".into())
         })?;
         self.drainer.validate()?;
+        self.secrets_management.validate().map_err(|error| {
+            println!("{error}");
+            errors::DrainerError::ConfigParsingError(
+                "invalid secrets management configuration".into(),
+            )
+        })?;
 
         Ok(())
     }
+
+    /// Replaces the database and Redis passwords with their plaintext values, when they are
+    /// stored encrypted by the secrets manager.
+    pub async fn decrypt_secrets(
+        mut self,
+        secrets_manager: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let mut secrets = vec![&mut self.master_database.password];
+        if let Some(password) = self.redis.password.as_mut() {
+            secrets.push(password);
+        }
+
+        secrets_management::decrypt_secrets(secrets_manager, secrets).await?;
+
+        Ok(self)
+    }
 }
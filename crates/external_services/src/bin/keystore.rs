@@ -0,0 +1,99 @@
+//! Manages the keyring file of the local keystore, and encrypts the secrets of the configuration
+//! with its keys.
+//!
+//! The passphrase of the keyring file is read from the `KEYSTORE_PASSPHRASE` environment variable
+//! and the secret to encrypt from the standard input, so neither ends up in the shell history.
+//!
+//! ```text
+//! keystore init --keyring-path keyring.json --key-id 1
+//! keystore add-key --keyring-path keyring.json --key-id 2
+//! keystore encrypt --keyring-path keyring.json --key-id 2 < database_password.txt
+//! ```
+
+use std::io::Read;
+
+use common_utils::errors::CustomResult;
+use error_stack::{report, IntoReport, ResultExt};
+use external_services::secrets_management::{
+    local_keystore::{KeyringFile, LocalKeystore},
+    SecretsManagementError,
+};
+
+const PASSPHRASE_ENV: &str = "KEYSTORE_PASSPHRASE";
+
+#[derive(clap::Parser)]
+#[command(about = "Manages the keyring file of the local keystore")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Create a keyring file holding a newly generated key
+    Init(KeyArgs),
+    /// Add a newly generated key to the keyring file
+    AddKey(KeyArgs),
+    /// Encrypt the secret read from the standard input with a key of the keyring
+    Encrypt(KeyArgs),
+}
+
+#[derive(clap::Args)]
+struct KeyArgs {
+    /// Path to the keyring file
+    #[arg(long)]
+    keyring_path: String,
+    /// ID of the key in the keyring
+    #[arg(long)]
+    key_id: String,
+}
+
+fn invalid(message: &str) -> error_stack::Report<SecretsManagementError> {
+    report!(SecretsManagementError::InvalidConfiguration(message.into()))
+}
+
+fn main() -> CustomResult<(), SecretsManagementError> {
+    let cli = <Cli as clap::Parser>::parse();
+    let passphrase = std::env::var(PASSPHRASE_ENV)
+        .ok()
+        .filter(|passphrase| !passphrase.is_empty())
+        .ok_or_else(|| invalid("the `KEYSTORE_PASSPHRASE` environment variable must be set"))?;
+
+    match cli.command {
+        Command::Init(args) => {
+            if std::path::Path::new(&args.keyring_path).exists() {
+                return Err(invalid("the keyring file already exists"));
+            }
+
+            let mut keystore = LocalKeystore::default();
+            keystore.generate_key(&args.key_id);
+            keystore.seal(&passphrase)?.write(&args.keyring_path)
+        }
+        Command::AddKey(args) => {
+            let mut keystore =
+                LocalKeystore::open(&KeyringFile::read(&args.keyring_path)?, &passphrase)?;
+            if keystore.contains_key(&args.key_id) {
+                return Err(invalid("the keyring already holds a key with this ID"));
+            }
+
+            keystore.generate_key(&args.key_id);
+            keystore.seal(&passphrase)?.write(&args.keyring_path)
+        }
+        Command::Encrypt(args) => {
+            let keystore =
+                LocalKeystore::open(&KeyringFile::read(&args.keyring_path)?, &passphrase)?;
+
+            let mut secret = String::new();
+            std::io::stdin()
+                .read_to_string(&mut secret)
+                .into_report()
+                .change_context(SecretsManagementError::InvalidConfiguration(
+                    "failed to read the secret from the standard input".into(),
+                ))?;
+            let secret = secret.trim_end_matches(|c| c == '\n' || c == '\r');
+
+            println!("{}", keystore.encrypt_secret(&args.key_id, secret)?);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Interactions with external services which are shared by the applications of the workspace,
+//! such as the secrets managers the secrets in the configuration are encrypted with.
+#![forbid(unsafe_code)]
+
+pub mod secrets_management;
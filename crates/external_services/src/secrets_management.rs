@@ -0,0 +1,123 @@
+//! Secrets management.
+//!
+//! The secrets in the configuration of the applications (admin API key, JWE keys, database and
+//! Redis passwords, ...) can be stored encrypted by one of the supported secrets managers. They are
+//! decrypted once when the application starts, so the rest of the application only ever sees
+//! plaintext values.
+
+#[cfg(feature = "kms")]
+pub mod aws_kms;
+pub mod hashicorp_vault;
+pub mod local_keystore;
+pub mod types;
+
+use std::sync::Arc;
+
+use common_utils::errors::CustomResult;
+#[cfg(not(feature = "kms"))]
+use error_stack::report;
+
+use self::types::{SecretsManagementSettings, SecretsManagerKind};
+
+pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
+    base64::engine::general_purpose::STANDARD;
+
+/// Timeout of the requests made to the secrets managers, in seconds
+pub(crate) const REQUEST_TIME_OUT: u64 = 30;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SecretsManagementError {
+    #[error("Failed to decrypt the secret")]
+    DecryptionFailed,
+    #[error("Failed to reach the secrets manager")]
+    ConnectionFailed,
+    #[error("The secrets manager returned an unexpected response")]
+    UnexpectedResponse,
+    #[error("Invalid secrets manager configuration: {0}")]
+    InvalidConfiguration(String),
+}
+
+#[async_trait::async_trait]
+pub trait SecretManagementInterface: Send + Sync {
+    /// Decrypts a secret which was encrypted by the secrets manager.
+    async fn get_secret(&self, input: String) -> CustomResult<String, SecretsManagementError>;
+
+    /// Checks that the secrets manager is reachable.
+    async fn health_check(&self) -> CustomResult<(), SecretsManagementError> {
+        Ok(())
+    }
+}
+
+/// Secrets are stored in plaintext in the configuration.
+pub struct NoEncryption;
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for NoEncryption {
+    async fn get_secret(&self, input: String) -> CustomResult<String, SecretsManagementError> {
+        Ok(input)
+    }
+}
+
+pub async fn get_secrets_manager(
+    settings: &SecretsManagementSettings,
+) -> CustomResult<Arc<dyn SecretManagementInterface>, SecretsManagementError> {
+    match settings.secrets_manager {
+        SecretsManagerKind::NoEncryption => Ok(Arc::new(NoEncryption)),
+        #[cfg(feature = "kms")]
+        SecretsManagerKind::AwsKms => Ok(Arc::new(aws_kms::AwsKms::new(&settings.aws_kms).await)),
+        #[cfg(not(feature = "kms"))]
+        SecretsManagerKind::AwsKms => Err(report!(SecretsManagementError::InvalidConfiguration(
+            "the application was built without the `kms` feature".into()
+        ))),
+        SecretsManagerKind::HashicorpVault => Ok(Arc::new(hashicorp_vault::HashiCorpVault::new(
+            &settings.hashicorp_vault,
+        )?)),
+        SecretsManagerKind::LocalKeystore => Ok(Arc::new(
+            local_keystore::LocalKeystore::from_settings(&settings.local_keystore)?,
+        )),
+    }
+}
+
+/// Replaces each of the secrets with its plaintext value. Secrets which are not configured are
+/// left empty.
+pub async fn decrypt_secrets(
+    secrets_manager: &dyn SecretManagementInterface,
+    secrets: Vec<&mut String>,
+) -> CustomResult<(), SecretsManagementError> {
+    for secret in secrets {
+        if secret.is_empty() {
+            continue;
+        }
+        *secret = secrets_manager.get_secret(std::mem::take(secret)).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    struct Reverse;
+
+    #[async_trait::async_trait]
+    impl SecretManagementInterface for Reverse {
+        async fn get_secret(&self, input: String) -> CustomResult<String, SecretsManagementError> {
+            Ok(input.chars().rev().collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_secrets() {
+        let mut password = "drowssap".to_string();
+        let mut empty = String::new();
+        decrypt_secrets(&Reverse, vec![&mut password, &mut empty])
+            .await
+            .unwrap();
+
+        assert_eq!(password, "password");
+        assert_eq!(empty, "");
+    }
+}
@@ -0,0 +1,69 @@
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_kms::{types::Blob, Client, Region};
+use base64::Engine;
+use common_utils::errors::CustomResult;
+use error_stack::{report, IntoReport, ResultExt};
+
+use super::{
+    types::AwsKmsSettings, SecretManagementInterface, SecretsManagementError, BASE64_ENGINE,
+};
+
+pub struct AwsKms {
+    client: Client,
+    key_id: String,
+}
+
+impl AwsKms {
+    pub async fn new(settings: &AwsKmsSettings) -> Self {
+        let region_provider = RegionProviderChain::first_try(Region::new(settings.region.clone()));
+        let shared_config = aws_config::from_env().region(region_provider).load().await;
+        Self {
+            client: Client::new(&shared_config),
+            key_id: settings.key_id.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for AwsKms {
+    // This expects a base64 encoded input, the values are set via the AWS CLI which already does
+    // the encoding.
+    async fn get_secret(&self, input: String) -> CustomResult<String, SecretsManagementError> {
+        let data = BASE64_ENGINE
+            .decode(input)
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Error decoding from base64")?;
+        let response = self
+            .client
+            .decrypt()
+            .key_id(&self.key_id)
+            .ciphertext_blob(Blob::new(data))
+            .send()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Error decrypting kms encrypted data")?;
+
+        match response.plaintext() {
+            Some(plaintext) => String::from_utf8(plaintext.as_ref().to_vec())
+                .into_report()
+                .change_context(SecretsManagementError::DecryptionFailed)
+                .attach_printable("Could not convert to UTF-8"),
+            None => Err(report!(SecretsManagementError::DecryptionFailed)
+                .attach_printable("Missing plaintext in response")),
+        }
+    }
+
+    async fn health_check(&self) -> CustomResult<(), SecretsManagementError> {
+        self.client
+            .describe_key()
+            .key_id(&self.key_id)
+            .send()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::ConnectionFailed)
+            .attach_printable("Error describing kms key")
+            .map(|_| ())
+    }
+}
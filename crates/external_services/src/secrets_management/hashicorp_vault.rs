@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use base64::Engine;
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+
+use super::{
+    types::HashiCorpVaultSettings, SecretManagementInterface, SecretsManagementError,
+    BASE64_ENGINE, REQUEST_TIME_OUT,
+};
+
+const VAULT_TOKEN_HEADER: &str = "X-Vault-Token";
+
+/// Secrets encrypted with the transit secrets engine of HashiCorp Vault, in the
+/// `vault:v<version>:<ciphertext>` format returned by the engine.
+pub struct HashiCorpVault {
+    client: reqwest::Client,
+    settings: HashiCorpVaultSettings,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct TransitDecryptRequest<'a> {
+    ciphertext: &'a str,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TransitDecryptResponse {
+    data: TransitDecryptResponseData,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TransitDecryptResponseData {
+    /// Base64 encoded plaintext
+    plaintext: String,
+}
+
+impl HashiCorpVault {
+    pub fn new(settings: &HashiCorpVaultSettings) -> CustomResult<Self, SecretsManagementError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIME_OUT))
+            .build()
+            .into_report()
+            .change_context(SecretsManagementError::InvalidConfiguration(
+                "failed to build the HashiCorp Vault client".into(),
+            ))?;
+
+        Ok(Self {
+            client,
+            settings: settings.clone(),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}/v1/{path}", self.settings.url.trim_end_matches('/'))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for HashiCorpVault {
+    async fn get_secret(&self, input: String) -> CustomResult<String, SecretsManagementError> {
+        let url = self.endpoint(&format!(
+            "{}/decrypt/{}",
+            self.settings.mount_path, self.settings.transit_key
+        ));
+        let response = self
+            .client
+            .post(url)
+            .header(VAULT_TOKEN_HEADER, &self.settings.token)
+            .json(&TransitDecryptRequest { ciphertext: &input })
+            .send()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::ConnectionFailed)?
+            .error_for_status()
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Vault failed to decrypt the secret")?
+            .json::<TransitDecryptResponse>()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::UnexpectedResponse)?;
+
+        let plaintext = BASE64_ENGINE
+            .decode(response.data.plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::UnexpectedResponse)
+            .attach_printable("Error decoding plaintext from base64")?;
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Could not convert to UTF-8")
+    }
+
+    async fn health_check(&self) -> CustomResult<(), SecretsManagementError> {
+        self.client
+            .get(self.endpoint("sys/health"))
+            .send()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::ConnectionFailed)?
+            .error_for_status()
+            .into_report()
+            .change_context(SecretsManagementError::ConnectionFailed)
+            .attach_printable("Vault is sealed or not initialized")
+            .map(|_| ())
+    }
+}
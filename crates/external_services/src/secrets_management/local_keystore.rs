@@ -0,0 +1,282 @@
+//! Keystore for deployments where no external secrets manager is available.
+//!
+//! The keys are held in a keyring file, which is encrypted with a key derived from a passphrase.
+//! Secrets are encrypted with one of the keys of the keyring and stored as
+//! `<key_id>:<base64 ciphertext>`, so keys can be added to the keyring without re-encrypting the
+//! existing secrets.
+
+use std::{collections::HashMap, num::NonZeroU32};
+
+use base64::Engine;
+use common_utils::{
+    crypto::{self, GcmAes256},
+    errors::CustomResult,
+};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use ring::pbkdf2;
+
+use super::{
+    types::LocalKeystoreSettings, SecretManagementInterface, SecretsManagementError, BASE64_ENGINE,
+};
+
+const PBKDF2_ITERATIONS: u32 = 100_000;
+const SALT_LENGTH: usize = 16;
+const KEY_LENGTH: usize = 32;
+
+/// Contents of the keyring file.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct KeyringFile {
+    /// Hex encoded salt used to derive the key from the passphrase
+    pub salt: String,
+    /// Base64 encoded keyring, encrypted with the key derived from the passphrase
+    pub keyring: String,
+}
+
+impl KeyringFile {
+    pub fn read(path: &str) -> CustomResult<Self, SecretsManagementError> {
+        let keyring_file = std::fs::read_to_string(path)
+            .into_report()
+            .change_context(SecretsManagementError::InvalidConfiguration(
+                "failed to read the keyring file".into(),
+            ))
+            .attach_printable_lazy(|| format!("Keyring path: {path}"))?;
+
+        serde_json::from_str(&keyring_file)
+            .into_report()
+            .change_context(SecretsManagementError::InvalidConfiguration(
+                "failed to parse the keyring file".into(),
+            ))
+    }
+
+    pub fn write(&self, path: &str) -> CustomResult<(), SecretsManagementError> {
+        let keyring_file = serde_json::to_string_pretty(self)
+            .into_report()
+            .change_context(SecretsManagementError::InvalidConfiguration(
+                "failed to serialize the keyring file".into(),
+            ))?;
+
+        std::fs::write(path, keyring_file)
+            .into_report()
+            .change_context(SecretsManagementError::InvalidConfiguration(
+                "failed to write the keyring file".into(),
+            ))
+            .attach_printable_lazy(|| format!("Keyring path: {path}"))
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Keyring {
+    /// Hex encoded keys, by key ID
+    keys: HashMap<String, String>,
+}
+
+#[derive(Default)]
+pub struct LocalKeystore {
+    keys: HashMap<String, Secret<Vec<u8>>>,
+}
+
+impl LocalKeystore {
+    pub fn from_settings(
+        settings: &LocalKeystoreSettings,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let keyring_file = KeyringFile::read(&settings.keyring_path)?;
+
+        Self::open(&keyring_file, &settings.passphrase)
+    }
+
+    /// Decrypts the keyring with the passphrase.
+    pub fn open(
+        keyring_file: &KeyringFile,
+        passphrase: &str,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let salt = hex::decode(&keyring_file.salt)
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Keyring salt is not hex encoded")?;
+        let encrypted_keyring = BASE64_ENGINE
+            .decode(&keyring_file.keyring)
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Error decoding keyring from base64")?;
+        let keyring = GcmAes256::decrypt(&derive_key(passphrase, &salt)?, &encrypted_keyring)
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Failed to decrypt the keyring, the passphrase may be incorrect")?;
+        let keyring: Keyring = serde_json::from_slice(&keyring)
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Failed to deserialize the keyring")?;
+
+        let keys = keyring
+            .keys
+            .into_iter()
+            .map(|(key_id, key)| {
+                hex::decode(key)
+                    .into_report()
+                    .change_context(SecretsManagementError::DecryptionFailed)
+                    .attach_printable_lazy(|| format!("Key {key_id} is not hex encoded"))
+                    .map(|key| (key_id, Secret::new(key)))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { keys })
+    }
+
+    /// Encrypts the keyring with the passphrase, for writing the keyring file.
+    pub fn seal(&self, passphrase: &str) -> CustomResult<KeyringFile, SecretsManagementError> {
+        let keyring = Keyring {
+            keys: self
+                .keys
+                .iter()
+                .map(|(key_id, key)| (key_id.clone(), hex::encode(key.peek())))
+                .collect(),
+        };
+        let keyring = serde_json::to_vec(&keyring).into_report().change_context(
+            SecretsManagementError::InvalidConfiguration("failed to serialize the keyring".into()),
+        )?;
+        let salt = crypto::generate_cryptographically_secure_random_bytes::<SALT_LENGTH>();
+        let encrypted_keyring = GcmAes256::encrypt(&derive_key(passphrase, &salt)?, &keyring)
+            .change_context(SecretsManagementError::InvalidConfiguration(
+                "failed to encrypt the keyring".into(),
+            ))?;
+
+        Ok(KeyringFile {
+            salt: hex::encode(salt),
+            keyring: BASE64_ENGINE.encode(encrypted_keyring),
+        })
+    }
+
+    pub fn contains_key(&self, key_id: &str) -> bool {
+        self.keys.contains_key(key_id)
+    }
+
+    /// Adds a newly generated key to the keyring, replacing any key with the same ID.
+    pub fn generate_key(&mut self, key_id: &str) {
+        self.keys.insert(
+            key_id.to_owned(),
+            Secret::new(
+                crypto::generate_cryptographically_secure_random_bytes::<KEY_LENGTH>().to_vec(),
+            ),
+        );
+    }
+
+    /// Encrypts a secret with a key of the keyring, in the format expected by `get_secret`.
+    pub fn encrypt_secret(
+        &self,
+        key_id: &str,
+        plaintext: &str,
+    ) -> CustomResult<String, SecretsManagementError> {
+        let encrypted = GcmAes256::encrypt(self.get_key(key_id)?.peek(), plaintext.as_bytes())
+            .change_context(SecretsManagementError::InvalidConfiguration(
+                "failed to encrypt the secret".into(),
+            ))?;
+
+        Ok(format!("{key_id}:{}", BASE64_ENGINE.encode(encrypted)))
+    }
+
+    fn get_key(&self, key_id: &str) -> CustomResult<&Secret<Vec<u8>>, SecretsManagementError> {
+        self.keys.get(key_id).ok_or_else(|| {
+            report!(SecretsManagementError::DecryptionFailed)
+                .attach_printable(format!("Key {key_id} is not present in the keyring"))
+        })
+    }
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+) -> CustomResult<[u8; KEY_LENGTH], SecretsManagementError> {
+    let iterations = NonZeroU32::new(PBKDF2_ITERATIONS).ok_or_else(|| {
+        report!(SecretsManagementError::InvalidConfiguration(
+            "PBKDF2 iterations must not be zero".into()
+        ))
+    })?;
+    let mut key = [0; KEY_LENGTH];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    Ok(key)
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for LocalKeystore {
+    async fn get_secret(&self, input: String) -> CustomResult<String, SecretsManagementError> {
+        let (key_id, encoded) = input.split_once(':').ok_or_else(|| {
+            report!(SecretsManagementError::DecryptionFailed)
+                .attach_printable("Secret is not in the `<key_id>:<ciphertext>` format")
+        })?;
+        let encrypted = BASE64_ENGINE
+            .decode(encoded)
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Error decoding secret from base64")?;
+        let plaintext = GcmAes256::decrypt(self.get_key(key_id)?.peek(), &encrypted)
+            .change_context(SecretsManagementError::DecryptionFailed)?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Could not convert to UTF-8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_keystore() {
+        let mut keystore = LocalKeystore::default();
+        keystore.generate_key("1");
+        let keyring_file = keystore.seal("passphrase").unwrap();
+
+        let keystore = LocalKeystore::open(&keyring_file, "passphrase").unwrap();
+        let secret = keystore.encrypt_secret("1", "admin_api_key").unwrap();
+        assert!(secret.starts_with("1:"));
+        assert_eq!(keystore.get_secret(secret).await.unwrap(), "admin_api_key");
+
+        assert!(keystore.encrypt_secret("2", "admin_api_key").is_err());
+        assert!(keystore
+            .get_secret("2:YWRtaW5fYXBpX2tleQ==".to_string())
+            .await
+            .is_err());
+        assert!(LocalKeystore::open(&keyring_file, "wrong passphrase").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_keyring_file() {
+        let mut keystore = LocalKeystore::default();
+        keystore.generate_key("1");
+        let secret = keystore.encrypt_secret("1", "database_password").unwrap();
+
+        let keyring_path = std::env::temp_dir().join(format!(
+            "keyring_{}.json",
+            hex::encode(crypto::generate_cryptographically_secure_random_bytes::<8>())
+        ));
+        let keyring_path = keyring_path.to_str().unwrap();
+        keystore
+            .seal("passphrase")
+            .unwrap()
+            .write(keyring_path)
+            .unwrap();
+
+        let keystore = LocalKeystore::from_settings(&LocalKeystoreSettings {
+            keyring_path: keyring_path.to_string(),
+            passphrase: "passphrase".to_string(),
+        })
+        .unwrap();
+        std::fs::remove_file(keyring_path).unwrap();
+
+        assert!(keystore.contains_key("1"));
+        assert_eq!(
+            keystore.get_secret(secret).await.unwrap(),
+            "database_password"
+        );
+    }
+}
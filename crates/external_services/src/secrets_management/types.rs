@@ -0,0 +1,118 @@
+use common_utils::errors::CustomResult;
+use error_stack::IntoReport;
+use serde::Deserialize;
+
+use super::SecretsManagementError;
+
+/// Secrets in the configuration can be stored encrypted by a secrets manager, in which case they
+/// are decrypted when the application starts.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SecretsManagementSettings {
+    pub secrets_manager: SecretsManagerKind,
+    pub aws_kms: AwsKmsSettings,
+    pub hashicorp_vault: HashiCorpVaultSettings,
+    pub local_keystore: LocalKeystoreSettings,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretsManagerKind {
+    /// Secrets are stored in plaintext in the configuration
+    #[default]
+    NoEncryption,
+    /// Secrets are encrypted with an AWS KMS key, requires the `kms` feature
+    AwsKms,
+    /// Secrets are encrypted with a HashiCorp Vault transit key
+    HashicorpVault,
+    /// Secrets are encrypted with a key from a local keyring file
+    LocalKeystore,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AwsKmsSettings {
+    pub key_id: String,
+    pub region: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HashiCorpVaultSettings {
+    pub url: String,
+    pub token: String,
+    /// Path at which the transit secrets engine is mounted
+    pub mount_path: String,
+    /// Name of the transit key the secrets are encrypted with
+    pub transit_key: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LocalKeystoreSettings {
+    /// Path to the keyring file, which holds the keys encrypted with the passphrase
+    pub keyring_path: String,
+    pub passphrase: String,
+}
+
+impl Default for HashiCorpVaultSettings {
+    fn default() -> Self {
+        Self {
+            url: "http://127.0.0.1:8200".into(),
+            token: String::new(),
+            mount_path: "transit".into(),
+            transit_key: String::new(),
+        }
+    }
+}
+
+impl SecretsManagementSettings {
+    pub fn validate(&self) -> CustomResult<(), SecretsManagementError> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        let invalid = |message: &str| {
+            Err(SecretsManagementError::InvalidConfiguration(message.into())).into_report()
+        };
+
+        match self.secrets_manager {
+            SecretsManagerKind::NoEncryption => Ok(()),
+            SecretsManagerKind::AwsKms => {
+                when(!cfg!(feature = "kms"), || {
+                    invalid("AWS KMS secrets manager requires the `kms` feature to be enabled")
+                })?;
+
+                when(self.aws_kms.key_id.is_default_or_empty(), || {
+                    invalid("AWS KMS key ID must not be empty")
+                })?;
+
+                when(self.aws_kms.region.is_default_or_empty(), || {
+                    invalid("AWS KMS region must not be empty")
+                })
+            }
+            SecretsManagerKind::HashicorpVault => {
+                when(self.hashicorp_vault.url.is_default_or_empty(), || {
+                    invalid("HashiCorp Vault URL must not be empty")
+                })?;
+
+                when(self.hashicorp_vault.token.is_default_or_empty(), || {
+                    invalid("HashiCorp Vault token must not be empty")
+                })?;
+
+                when(
+                    self.hashicorp_vault.transit_key.is_default_or_empty(),
+                    || invalid("HashiCorp Vault transit key must not be empty"),
+                )
+            }
+            SecretsManagerKind::LocalKeystore => {
+                when(
+                    self.local_keystore.keyring_path.is_default_or_empty(),
+                    || invalid("local keystore keyring path must not be empty"),
+                )?;
+
+                when(self.local_keystore.passphrase.is_default_or_empty(), || {
+                    invalid("local keystore passphrase must not be empty")
+                })
+            }
+        }
+    }
+}
@@ -42,7 +42,9 @@ mod vec;
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(feature = "serde")]
-pub use crate::serde::{Deserialize, SerializableSecret, Serialize};
+pub use crate::serde::{
+    with_masked_serialization, with_secret_collection, Deserialize, SerializableSecret, Serialize,
+};
 
 /// This module should be included with asterisk.
 ///
@@ -2,10 +2,97 @@
 //! Serde-related.
 //!
 
+use std::cell::{Cell, RefCell};
+
 pub use serde::{de, ser, Deserialize, Serialize};
 
 use crate::{PeekInterface, Secret, Strategy, StrongSecret, ZeroizableSecret};
 
+thread_local! {
+    static MASKED_SERIALIZATION: Cell<bool> = Cell::new(false);
+    static COLLECTED_SECRETS: RefCell<Option<Vec<String>>> = RefCell::new(None);
+}
+
+/// Run the provided closure with masked serialization enabled on the current thread.
+///
+/// While the closure runs, [`Secret`] and [`StrongSecret`] values are serialized as their masked
+/// representation (as formatted by their masking [`Strategy`]) instead of their inner value.
+/// This allows obtaining a representation of a serialized payload that is safe to be logged or
+/// persisted, while preserving the structure of the payload.
+pub fn with_masked_serialization<R>(f: impl FnOnce() -> R) -> R {
+    struct ResetGuard(bool);
+
+    impl Drop for ResetGuard {
+        fn drop(&mut self) {
+            MASKED_SERIALIZATION.with(|masked| masked.set(self.0));
+        }
+    }
+
+    let _guard = ResetGuard(MASKED_SERIALIZATION.with(|masked| masked.replace(true)));
+    f()
+}
+
+fn is_serialization_masked() -> bool {
+    MASKED_SERIALIZATION.with(Cell::get)
+}
+
+/// Run the provided closure, collecting the values of the [`Secret`] and [`StrongSecret`] values
+/// serialized on the current thread while it runs.
+///
+/// Secrets are serialized as usual, and their values are returned as the strings and numbers they
+/// consist of. This allows masking the secrets in a payload which has already been serialized,
+/// without serializing it a second time.
+pub fn with_secret_collection<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    struct RestoreGuard(Option<Vec<String>>);
+
+    impl Drop for RestoreGuard {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            COLLECTED_SECRETS.with(|collected| *collected.borrow_mut() = previous);
+        }
+    }
+
+    let _guard =
+        RestoreGuard(COLLECTED_SECRETS.with(|collected| collected.replace(Some(Vec::new()))));
+    let result = f();
+    let secrets = COLLECTED_SECRETS
+        .with(|collected| collected.borrow_mut().take())
+        .unwrap_or_default();
+    (result, secrets)
+}
+
+fn collect_secret<T: Serialize + ?Sized>(value: &T) {
+    fn collect_leaves(value: serde_json::Value, leaves: &mut Vec<String>) {
+        match value {
+            serde_json::Value::String(value) if !value.is_empty() => leaves.push(value),
+            serde_json::Value::Number(value) => leaves.push(value.to_string()),
+            serde_json::Value::Array(values) => values
+                .into_iter()
+                .for_each(|value| collect_leaves(value, leaves)),
+            serde_json::Value::Object(values) => values
+                .into_iter()
+                .for_each(|(_, value)| collect_leaves(value, leaves)),
+            _ => {}
+        }
+    }
+
+    if COLLECTED_SECRETS.with(|collected| collected.borrow().is_none()) {
+        return;
+    }
+
+    // The value is converted before the collected secrets are borrowed, since it may contain
+    // secrets itself
+    if let Ok(value) = serde_json::to_value(value) {
+        let mut leaves = Vec::new();
+        collect_leaves(value, &mut leaves);
+        COLLECTED_SECRETS.with(|collected| {
+            if let Some(secrets) = collected.borrow_mut().as_mut() {
+                secrets.extend(leaves);
+            }
+        });
+    }
+}
+
 /// Marker trait for secret types which can be [`Serialize`]-d by [`serde`].
 ///
 /// When the `serde` feature of this crate is enabled and types are marked with
@@ -46,6 +133,11 @@ where
     where
         S: ser::Serializer,
     {
+        if is_serialization_masked() {
+            return serializer.collect_str(&format_args!("{self:?}"));
+        }
+
+        collect_secret(self.peek());
         self.peek().serialize(serializer)
     }
 }
@@ -72,6 +164,11 @@ where
     where
         S: serde::Serializer,
     {
+        if is_serialization_masked() {
+            return serializer.collect_str(&format_args!("{self:?}"));
+        }
+
+        collect_secret(self.peek());
         self.peek().serialize(serializer)
     }
 }
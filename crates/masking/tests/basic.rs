@@ -144,3 +144,153 @@ fn for_string() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
+
+#[test]
+fn masked_serialization() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use pii::Secret;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct Composite {
+        secret_number: Secret<String>,
+        not_secret: String,
+    }
+
+    // construct
+
+    let composite = Composite {
+        secret_number: Secret::<String>::new("abc".to_string()),
+        not_secret: "not secret".to_string(),
+    };
+
+    // serialize masked
+
+    let got = pii::with_masked_serialization(|| serde_json::to_string(&composite)).unwrap();
+    let exp = "{\"secret_number\":\"*** alloc::string::String ***\",\"not_secret\":\"not secret\"}";
+    assert_eq!(got, exp);
+
+    // serialize after masked serialization
+
+    let got = serde_json::to_string(&composite).unwrap();
+    let exp = "{\"secret_number\":\"abc\",\"not_secret\":\"not secret\"}";
+    assert_eq!(got, exp);
+
+    // end
+
+    Ok(())
+}
+
+#[test]
+fn nested_masked_serialization() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use pii::Secret;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct Inner {
+        secret_number: Secret<String>,
+    }
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct Outer {
+        inner: Inner,
+        secret_details: Secret<serde_json::Value>,
+    }
+
+    // construct
+
+    let outer = Outer {
+        inner: Inner {
+            secret_number: Secret::<String>::new("abc".to_string()),
+        },
+        secret_details: Secret::new(serde_json::json!({ "key": "value" })),
+    };
+
+    // serialize masked, with another masked serialization nested in the closure
+
+    let (inner, got) = pii::with_masked_serialization(|| {
+        let inner = pii::with_masked_serialization(|| serde_json::to_string(&outer.inner));
+        (inner, serde_json::to_string(&outer))
+    });
+    let exp = "{\"secret_number\":\"*** alloc::string::String ***\"}";
+    assert_eq!(inner.unwrap(), exp);
+    let exp = "{\"inner\":{\"secret_number\":\"*** alloc::string::String ***\"},\"secret_details\":\"*** serde_json::value::Value ***\"}";
+    assert_eq!(got.unwrap(), exp);
+
+    // serialize after masked serialization
+
+    let got = serde_json::to_string(&outer).unwrap();
+    let exp = "{\"inner\":{\"secret_number\":\"abc\"},\"secret_details\":{\"key\":\"value\"}}";
+    assert_eq!(got, exp);
+
+    // end
+
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::panic)]
+fn masked_serialization_is_reset_after_panic(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use pii::Secret;
+
+    let secret = Secret::<String>::new("abc".to_string());
+
+    // panic while serialization is masked
+
+    let result = std::panic::catch_unwind(|| {
+        pii::with_masked_serialization(|| {
+            let _ = serde_json::to_string(&secret);
+            panic!("panic during masked serialization")
+        })
+    });
+    assert!(result.is_err());
+
+    // serialize after the panic
+
+    let got = serde_json::to_string(&secret).unwrap();
+    assert_eq!(got, "\"abc\"");
+
+    // end
+
+    Ok(())
+}
+
+#[test]
+fn secret_collection() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use pii::Secret;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct Composite {
+        secret_number: Secret<String>,
+        secret_details: Secret<serde_json::Value>,
+        not_secret: String,
+    }
+
+    // construct
+
+    let composite = Composite {
+        secret_number: Secret::<String>::new("abc".to_string()),
+        secret_details: Secret::new(serde_json::json!({ "name": "def", "pin": 1234 })),
+        not_secret: "not secret".to_string(),
+    };
+
+    // serialize collecting secrets
+
+    let (got, secrets) = pii::with_secret_collection(|| serde_json::to_string(&composite));
+    let exp = "{\"secret_number\":\"abc\",\"secret_details\":{\"name\":\"def\",\"pin\":1234},\"not_secret\":\"not secret\"}";
+    assert_eq!(got.unwrap(), exp);
+    let mut secrets = secrets;
+    secrets.sort();
+    assert_eq!(secrets, vec!["1234", "abc", "def"]);
+
+    // nothing is collected outside of the closure
+
+    let (_, secrets) = pii::with_secret_collection(|| ());
+    serde_json::to_string(&composite).unwrap();
+    assert!(secrets.is_empty());
+
+    // end
+
+    Ok(())
+}
@@ -15,7 +15,7 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{ClientLike, HashesInterface, KeysInterface, LuaInterface, StreamsInterface},
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
@@ -29,7 +29,31 @@ use crate::{
     types::{HsetnxReply, MsetnxReply, RedisEntryId, SetnxReply},
 };
 
+/// Increments a key and sets its expiry in a single step, so that a key is never left without an
+/// expiry and concurrent increments each observe a distinct value.
+const INCREMENT_KEY_SCRIPT: &str = r"
+local value = redis.call('INCRBY', KEYS[1], ARGV[1])
+redis.call('EXPIRE', KEYS[1], ARGV[2])
+return value
+";
+
+/// Increments a field of a hash and sets the expiry of the hash in a single step.
+const INCREMENT_HASH_FIELD_SCRIPT: &str = r"
+local value = redis.call('HINCRBY', KEYS[1], ARGV[1], ARGV[2])
+redis.call('EXPIRE', KEYS[1], ARGV[3])
+return value
+";
+
 impl super::RedisConnectionPool {
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn ping(&self) -> CustomResult<(), errors::RedisError> {
+        self.pool
+            .ping()
+            .await
+            .into_report()
+            .change_context(errors::RedisError::PingFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key<V>(&self, key: &str, value: V) -> CustomResult<(), errors::RedisError>
     where
@@ -171,6 +195,32 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    /// Sets the key along with its expiry in a single `SET NX EX` command, so that the key is
+    /// never left without an expiry.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_key_if_not_exist_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<SetnxReply, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug,
+        V::Error: Into<fred::error::RedisError>,
+    {
+        self.pool
+            .set(
+                key,
+                value,
+                Some(Expiration::EX(seconds)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -184,6 +234,20 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(
+        &self,
+        key: &str,
+        increment: i64,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .eval(INCREMENT_KEY_SCRIPT, key, vec![increment, seconds])
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementKeyFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expire_at(
         &self,
@@ -370,6 +434,41 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_hash_fields<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .hgetall(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+        increment: i64,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .eval(
+                INCREMENT_HASH_FIELD_SCRIPT,
+                key,
+                vec![
+                    RedisValue::from(field),
+                    RedisValue::from(increment),
+                    RedisValue::from(seconds),
+                ],
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -92,6 +92,7 @@ impl RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::RedisConnectionError)?;
 
+        config.password = conf.password.clone();
         if !conf.use_legacy_version {
             config.version = fred::types::RespVersion::RESP3;
         }
@@ -138,6 +139,15 @@ impl RedisConnectionPool {
             };
         }
     }
+
+    /// Stream of the keys published on the channels the connection is subscribed to. Every call
+    /// returns a new stream, which receives all the messages published after it was created.
+    pub fn on_message_keys(&self) -> impl futures::Stream<Item = String> {
+        self.subscriber
+            .on_message()
+            .filter_map(|(_, key)| futures::future::ready(key.as_string()))
+    }
+
     pub async fn on_error(&self) {
         self.pool
             .on_error()
@@ -157,7 +167,6 @@ impl RedisConnectionPool {
 pub trait PubSubInterface {
     async fn subscribe(&self, channel: &str) -> CustomResult<usize, errors::RedisError>;
     async fn publish(&self, channel: &str, key: &str) -> CustomResult<usize, errors::RedisError>;
-    async fn on_message(&self) -> CustomResult<(), errors::RedisError>;
 }
 
 #[async_trait::async_trait]
@@ -178,17 +187,6 @@ impl PubSubInterface for RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::SubscribeError)
     }
-    #[inline]
-    async fn on_message(&self) -> CustomResult<(), errors::RedisError> {
-        let mut message = self.subscriber.on_message();
-        while let Some((_, key)) = message.next().await {
-            let key = key
-                .as_string()
-                .ok_or::<errors::RedisError>(errors::RedisError::DeleteFailed)?;
-            self.delete_key(&key).await?;
-        }
-        Ok(())
-    }
 }
 
 struct RedisConfig {
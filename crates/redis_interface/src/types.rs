@@ -13,6 +13,8 @@ use crate::errors;
 pub struct RedisSettings {
     pub host: String,
     pub port: u16,
+    /// Password used to authenticate with the Redis server, if it requires authentication
+    pub password: Option<String>,
     pub cluster_enabled: bool,
     pub cluster_urls: Vec<String>,
     pub use_legacy_version: bool,
@@ -53,6 +55,7 @@ impl Default for RedisSettings {
         Self {
             host: "127.0.0.1".to_string(),
             port: 6379,
+            password: None,
             cluster_enabled: false,
             cluster_urls: vec![],
             use_legacy_version: false,
@@ -38,9 +38,16 @@ async fn start_scheduler(
 ) -> CustomResult<(), errors::ProcessTrackerError> {
     use std::str::FromStr;
 
+    let scheduler_settings = state
+        .conf
+        .scheduler
+        .clone()
+        .ok_or(errors::ProcessTrackerError::ConfigurationError)?;
+
+    // The loop interval is only used until the first run, as it can be overridden at runtime
     let options = scheduler::SchedulerOptions {
         looper_interval: scheduler::Milliseconds {
-            milliseconds: 5_000,
+            milliseconds: scheduler_settings.loop_interval,
         },
         db_name: "".to_string(),
         cache_name: "".to_string(),
@@ -66,11 +73,6 @@ async fn start_scheduler(
     let flow = scheduler::SchedulerFlow::from_str(&flow)
         .expect("Unable to parse SchedulerFlow from environment variable");
 
-    let scheduler_settings = state
-        .conf
-        .scheduler
-        .clone()
-        .ok_or(errors::ProcessTrackerError::ConfigurationError)?;
     scheduler::start_process_tracker(state, Arc::new(options), flow, Arc::new(scheduler_settings))
         .await
 }
@@ -1,8 +1,10 @@
 pub mod app;
 pub mod customers;
 pub mod payment_intents;
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
+pub mod webhooks;
 use actix_web::{web, Scope};
 pub mod errors;
 
@@ -18,6 +20,7 @@ impl StripeApis {
             .service(app::SetupIntents::server(state.clone()))
             .service(app::PaymentIntents::server(state.clone()))
             .service(app::Refunds::server(state.clone()))
+            .service(app::PaymentMethods::server(state.clone()))
             .service(app::Customers::server(state))
     }
 }
@@ -1,6 +1,6 @@
 use actix_web::{web, Scope};
 
-use super::{customers::*, payment_intents::*, refunds::*, setup_intents::*};
+use super::{customers::*, payment_intents::*, payment_methods::*, refunds::*, setup_intents::*};
 use crate::routes;
 
 pub struct PaymentIntents;
@@ -27,6 +27,7 @@ impl SetupIntents {
             .service(setup_intents_retrieve)
             .service(setup_intents_update)
             .service(setup_intents_confirm)
+            .service(setup_intents_cancel)
     }
 }
 
@@ -34,14 +35,30 @@ pub struct Refunds;
 
 impl Refunds {
     pub fn server(config: routes::AppState) -> Scope {
-        web::scope("/refunds")
-            .app_data(web::Data::new(config))
+        let mut route = web::scope("/refunds").app_data(web::Data::new(config));
+
+        #[cfg(feature = "olap")]
+        {
+            route = route.service(refund_list);
+        }
+        route
             .service(refund_create)
             .service(refund_retrieve)
             .service(refund_update)
     }
 }
 
+pub struct PaymentMethods;
+
+impl PaymentMethods {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/payment_methods")
+            .app_data(web::Data::new(config))
+            .service(payment_method_attach)
+            .service(payment_method_detach)
+    }
+}
+
 pub struct Customers;
 
 impl Customers {
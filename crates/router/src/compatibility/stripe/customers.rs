@@ -46,6 +46,7 @@ pub async fn customer_create(
             customers::create_customer(&*state.store, merchant_account, req)
         },
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
@@ -78,6 +79,7 @@ pub async fn customer_retrieve(
             customers::retrieve_customer(&*state.store, merchant_account, req)
         },
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersRead,
     )
     .await
 }
@@ -119,6 +121,7 @@ pub async fn customer_update(
             customers::update_customer(&*state.store, merchant_account, req)
         },
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
@@ -149,6 +152,7 @@ pub async fn customer_delete(
         payload,
         customers::delete_customer,
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
@@ -177,6 +181,7 @@ pub async fn list_customer_payment_method_api(
         customer_id.as_ref(),
         cards::list_customer_payment_method,
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentMethodsRead,
     )
     .await
 }
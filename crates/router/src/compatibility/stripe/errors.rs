@@ -49,6 +49,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::CardError, code = "invalid_card_type", message = "Card data is invalid")]
     InvalidCardType,
 
+    #[error(error_type = StripeErrorType::CardError, code = "card_declined", message = "Your card was declined.")]
+    CardDeclined,
+
     #[error(error_type = StripeErrorType::ApiError, code = "refund_failed", message = "refund has failed")]
     RefundFailed, // stripe error code
 
@@ -180,6 +183,12 @@ pub enum StripeErrorCode {
 
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "The connector provided in the request is incorrect or not available")]
     IncorrectConnectorNameGiven,
+
+    #[error(error_type = StripeErrorType::ApiError, code = "processor_unavailable", message = "{connector} is currently unavailable. Retry after some time")]
+    ConnectorUnavailable { connector: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly. Retry after {retry_after} seconds")]
+    RateLimit { retry_after: u64 },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -205,7 +214,6 @@ pub enum StripeErrorCode {
         BillingInvalidMandate,
         BitcoinUpgradeRequired,
         CardDeclineRateLimitExceeded,
-        CardDeclined,
         CardholderPhoneNumberRequired,
         ChargeAlreadyCaptured,
         ChargeAlreadyRefunded,
@@ -289,7 +297,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -377,6 +384,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::PaymentCaptureFailed { data } => {
                 Self::PaymentIntentPaymentAttemptFailed { data }
             }
+            errors::ApiErrorResponse::FraudCheckDeclined { .. } => Self::CardDeclined,
             errors::ApiErrorResponse::InvalidCardData { data } => Self::InvalidCardType, // Maybe it is better to de generalize this router error
             errors::ApiErrorResponse::CardExpired { data } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector } => Self::RefundFailed,
@@ -457,6 +465,12 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::DuplicatePayment { payment_id }
             }
             errors::ApiErrorResponse::NotSupported { .. } => Self::InternalServerError,
+            errors::ApiErrorResponse::ConnectorUnavailable { connector } => {
+                Self::ConnectorUnavailable { connector }
+            }
+            errors::ApiErrorResponse::TooManyRequests { retry_after } => {
+                Self::RateLimit { retry_after }
+            }
         }
     }
 }
@@ -475,6 +489,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentIntentPaymentAttemptFailed { .. }
             | Self::ExpiredCard
             | Self::InvalidCardType
+            | Self::CardDeclined
             | Self::DuplicateRefundRequest
             | Self::RefundNotFound
             | Self::CustomerNotFound
@@ -508,7 +523,10 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::InternalServerError
             | Self::MandateActive
             | Self::CustomerRedacted => StatusCode::INTERNAL_SERVER_ERROR,
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ReturnUrlUnavailable | Self::ConnectorUnavailable { .. } => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
@@ -518,10 +536,14 @@ impl actix_web::ResponseError for StripeErrorCode {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response
             .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .insert_header((header::VIA, "Juspay_Router"))
-            .body(self.to_string())
+            .insert_header((header::VIA, "Juspay_Router"));
+        if let Self::RateLimit { retry_after } = self {
+            response.insert_header((header::RETRY_AFTER, retry_after.to_string()));
+        }
+        response.body(self.to_string())
     }
 }
 
@@ -9,7 +9,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::payments,
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, rate_limit},
     types::api::{self as api_types},
 };
 
@@ -57,7 +57,8 @@ pub async fn payment_intents_create(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::PaymentsCreate),
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -106,6 +107,7 @@ pub async fn payment_intents_retrieve(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsRead,
     )
     .await
 }
@@ -165,6 +167,7 @@ pub async fn payment_intents_update(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -226,6 +229,7 @@ pub async fn payment_intents_confirm(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -276,6 +280,7 @@ pub async fn payment_intents_capture(
             )
         },
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -331,6 +336,7 @@ pub async fn payment_intents_cancel(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -360,10 +366,11 @@ pub async fn payment_intent_list(
         state.get_ref(),
         &req,
         payload,
-        |state, merchant_account, req| {
-            payments::list_payments(&*state.store, merchant_account, req)
+        |state, merchant_accounts, req| {
+            payments::list_payments(&*state.store, merchant_accounts, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::PaymentsList),
+        auth::Permission::PaymentsRead,
     )
     .await
 }
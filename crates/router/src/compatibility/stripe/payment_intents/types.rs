@@ -82,6 +82,8 @@ impl From<StripeCard> for payments::Card {
             card_cvc: card.cvc,
             card_issuer: None,
             card_network: None,
+            card_type: None,
+            card_issuing_country: None,
         }
     }
 }
@@ -410,7 +412,7 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
 }
 
 #[inline]
-fn from_timestamp_to_datetime(
+pub(crate) fn from_timestamp_to_datetime(
     time: Option<i64>,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ApiErrorResponse> {
     if let Some(time) = time {
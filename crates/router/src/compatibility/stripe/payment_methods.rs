@@ -0,0 +1,87 @@
+pub mod types;
+
+use actix_web::{post, web, HttpRequest, HttpResponse};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::payment_methods::cards,
+    routes,
+    services::{api, authentication as auth},
+    types::api::payment_methods as payment_method_types,
+};
+
+#[instrument(skip_all)]
+#[post("/{payment_method_id}/attach")]
+pub async fn payment_method_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload: types::StripeAttachPaymentMethodRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let attach_req = payment_method_types::PaymentMethodAttachRequest {
+        payment_method_id: path.into_inner(),
+        customer_id: payload.customer,
+    };
+
+    wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+    >(
+        state.get_ref(),
+        &req,
+        attach_req,
+        cards::attach_payment_method,
+        &auth::ApiKeyAuth,
+        auth::Permission::PaymentMethodsWrite,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+#[post("/{payment_method_id}/detach")]
+pub async fn payment_method_detach(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+    >(
+        state.get_ref(),
+        &req,
+        payload,
+        cards::detach_payment_method,
+        &auth::ApiKeyAuth,
+        auth::Permission::PaymentMethodsWrite,
+    )
+    .await
+}
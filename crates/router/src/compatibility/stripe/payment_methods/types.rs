@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::customers::types::CardDetails,
+    types::api::{self, enums as api_enums},
+};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StripeAttachPaymentMethodRequest {
+    pub customer: String,
+}
+
+#[derive(Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub payment_method_type: api_enums::PaymentMethod,
+    pub customer: Option<String>,
+    pub card: Option<CardDetails>,
+    pub created: Option<i64>,
+    pub metadata: serde_json::Value,
+    pub livemode: bool,
+}
+
+impl From<api::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: api::PaymentMethodResponse) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            payment_method_type: item.payment_method,
+            customer: item.customer_id,
+            card: item.card.map(From::from),
+            created: item.created.map(|t| t.assume_utc().unix_timestamp()),
+            metadata: item.metadata.unwrap_or_else(|| serde_json::json!({})),
+            livemode: false,
+        }
+    }
+}
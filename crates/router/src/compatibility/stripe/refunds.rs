@@ -7,7 +7,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::refunds,
     routes,
-    services::authentication as auth,
+    services::{authentication as auth, rate_limit},
     types::api::refunds as refund_types,
 };
 
@@ -35,7 +35,8 @@ pub async fn refund_create(
         &req,
         create_refund_req,
         refunds::refund_create_core,
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsWrite,
     )
     .await
 }
@@ -69,7 +70,8 @@ pub async fn refund_retrieve(
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsRead,
     )
     .await
 }
@@ -102,7 +104,41 @@ pub async fn refund_update(
         |state, merchant_account, req| {
             refunds::refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsWrite,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+#[get("")]
+#[cfg(feature = "olap")]
+pub async fn refund_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    payload: web::Query<types::StripeRefundListConstraints>,
+) -> HttpResponse {
+    let payload = match refund_types::RefundListRequest::try_from(payload.into_inner()) {
+        Ok(p) => p,
+        Err(err) => return crate::services::api::log_and_return_error_response(err),
+    };
+
+    wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeRefundListResponse,
+        errors::StripeErrorCode,
+    >(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_accounts, req| refunds::refund_list(&*state.store, merchant_accounts, req),
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsRead,
     )
     .await
 }
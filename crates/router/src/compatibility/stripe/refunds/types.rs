@@ -2,7 +2,10 @@ use std::{convert::From, default::Default};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{core::errors, types::api::refunds};
+use crate::{
+    compatibility::stripe::payment_intents::types::from_timestamp_to_datetime, core::errors,
+    types::api::refunds,
+};
 
 #[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct StripeCreateRefundRequest {
@@ -82,3 +85,106 @@ impl TryFrom<refunds::RefundResponse> for StripeCreateRefundResponse {
         })
     }
 }
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StripeRefundListConstraints {
+    pub payment_intent: Option<String>,
+    pub limit: Option<i64>,
+    pub created: Option<i64>,
+    #[serde(rename = "created[lt]")]
+    pub created_lt: Option<i64>,
+    #[serde(rename = "created[gt]")]
+    pub created_gt: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+}
+
+impl TryFrom<StripeRefundListConstraints> for refunds::RefundListRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeRefundListConstraints) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_id: item.payment_intent,
+            limit: item.limit,
+            created: from_timestamp_to_datetime(item.created)?,
+            created_lt: from_timestamp_to_datetime(item.created_lt)?,
+            created_gt: from_timestamp_to_datetime(item.created_gt)?,
+            created_lte: from_timestamp_to_datetime(item.created_lte)?,
+            created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            starting_after: item.starting_after,
+            ending_before: item.ending_before,
+        })
+    }
+}
+
+#[derive(Serialize)]
+pub struct StripeRefundListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeCreateRefundResponse>,
+}
+
+impl TryFrom<refunds::RefundListResponse> for StripeRefundListResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(res: refunds::RefundListResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            object: "list",
+            url: "/v1/refunds",
+            has_more: res.has_more,
+            data: res
+                .data
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_refund_list_constraints_with_cursors() {
+        let constraints: StripeRefundListConstraints = serde_urlencoded::from_str(
+            "payment_intent=pay_1&limit=2&created[gte]=1672531200&starting_after=ref_2",
+        )
+        .unwrap();
+        let request = refunds::RefundListRequest::try_from(constraints).unwrap();
+
+        assert_eq!(request.payment_id.as_deref(), Some("pay_1"));
+        assert_eq!(request.limit, Some(2));
+        assert_eq!(
+            request.created_gte,
+            Some(time::macros::datetime!(2023-01-01 00:00))
+        );
+        assert_eq!(request.starting_after.as_deref(), Some("ref_2"));
+        assert_eq!(request.ending_before, None);
+
+        let constraints: StripeRefundListConstraints =
+            serde_urlencoded::from_str("ending_before=ref_1").unwrap();
+        let request = refunds::RefundListRequest::try_from(constraints).unwrap();
+        assert_eq!(request.ending_before.as_deref(), Some("ref_1"));
+
+        assert!(serde_urlencoded::from_str::<StripeRefundListConstraints>("expand=data").is_err());
+    }
+
+    #[test]
+    fn test_refund_list_response_has_more() {
+        let response = StripeRefundListResponse::try_from(refunds::RefundListResponse {
+            data: vec![],
+            has_more: true,
+        })
+        .unwrap();
+
+        assert!(response.has_more);
+        assert_eq!(response.object, "list");
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::payments,
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, rate_limit},
     types::api as api_types,
 };
 
@@ -54,7 +54,8 @@ pub async fn setup_intents_create(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::PaymentsCreate),
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -103,6 +104,7 @@ pub async fn setup_intents_retrieve(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsRead,
     )
     .await
 }
@@ -159,6 +161,7 @@ pub async fn setup_intents_update(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -216,6 +219,63 @@ pub async fn setup_intents_confirm(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsWrite,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+#[post("/{setup_id}/cancel")]
+pub async fn setup_intents_cancel(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let setup_id = path.into_inner();
+    let stripe_payload: types::StripePaymentCancelRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let mut payload: payment_types::PaymentsCancelRequest = stripe_payload.into();
+    payload.payment_id = setup_id;
+
+    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
+
+    wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeSetupIntentResponse,
+        errors::StripeErrorCode,
+    >(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<api_types::Void, api_types::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account,
+                payments::PaymentCancel,
+                req,
+                auth_flow,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &*auth_type,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -77,6 +77,8 @@ impl From<StripeCard> for payments::Card {
             card_cvc: card.cvc,
             card_issuer: None,
             card_network: None,
+            card_type: None,
+            card_issuing_country: None,
         }
     }
 }
@@ -0,0 +1,141 @@
+use api_models::{enums as api_enums, webhooks as api};
+use serde::Serialize;
+
+use super::{
+    payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeCreateRefundResponse,
+};
+use crate::core::errors;
+
+/// An outgoing webhook in the shape of a Stripe `Event` object, for merchants who have opted to
+/// receive webhooks in the Stripe format.
+#[derive(Serialize)]
+pub struct StripeOutgoingWebhook {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stripe_event_type: &'static str,
+    pub created: i64,
+    pub livemode: bool,
+    pub data: StripeWebhookObject,
+}
+
+#[derive(Serialize)]
+pub struct StripeWebhookObject {
+    pub object: StripeWebhookContent,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum StripeWebhookContent {
+    PaymentIntent(StripePaymentIntentResponse),
+    Refund(StripeCreateRefundResponse),
+}
+
+fn get_stripe_event_type(event_type: api_enums::EventType) -> &'static str {
+    match event_type {
+        api_enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
+        api_enums::EventType::PaymentFailed => "payment_intent.payment_failed",
+        api_enums::EventType::PaymentProcessing => "payment_intent.processing",
+        api_enums::EventType::ActionRequired => "payment_intent.requires_action",
+        api_enums::EventType::PaymentCancelled => "payment_intent.canceled",
+        api_enums::EventType::RefundSucceeded | api_enums::EventType::RefundFailed => {
+            "charge.refund.updated"
+        }
+    }
+}
+
+impl TryFrom<api::OutgoingWebhookContent> for StripeWebhookContent {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(content: api::OutgoingWebhookContent) -> Result<Self, Self::Error> {
+        Ok(match content {
+            api::OutgoingWebhookContent::PaymentDetails(payment) => {
+                Self::PaymentIntent(payment.into())
+            }
+            api::OutgoingWebhookContent::RefundDetails(refund) => Self::Refund(refund.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<api::OutgoingWebhook> for StripeOutgoingWebhook {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(webhook: api::OutgoingWebhook) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: webhook.event_id,
+            object: "event",
+            stripe_event_type: get_stripe_event_type(webhook.event_type),
+            created: webhook.timestamp.assume_utc().unix_timestamp(),
+            livemode: false,
+            data: StripeWebhookObject {
+                object: webhook.content.try_into()?,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use api_models::{payments, refunds};
+
+    use super::*;
+
+    fn outgoing_webhook(
+        event_type: api_enums::EventType,
+        content: api::OutgoingWebhookContent,
+    ) -> serde_json::Value {
+        let webhook = api::OutgoingWebhook {
+            merchant_id: "merchant".to_string(),
+            event_id: "evt_1".to_string(),
+            event_type,
+            content,
+            timestamp: time::macros::datetime!(2023-01-01 00:00),
+        };
+        let webhook = StripeOutgoingWebhook::try_from(webhook).unwrap();
+        serde_json::to_value(webhook).unwrap()
+    }
+
+    #[test]
+    fn test_payment_webhook_in_stripe_format() {
+        let webhook = outgoing_webhook(
+            api_enums::EventType::PaymentCancelled,
+            api::OutgoingWebhookContent::PaymentDetails(payments::PaymentsResponse {
+                payment_id: Some("pay_1".to_string()),
+                ..payments::PaymentsResponse::default()
+            }),
+        );
+
+        assert_eq!(webhook["id"], "evt_1");
+        assert_eq!(webhook["object"], "event");
+        assert_eq!(webhook["type"], "payment_intent.canceled");
+        assert_eq!(webhook["created"], 1672531200);
+        assert_eq!(webhook["data"]["object"]["object"], "payment_intent");
+        assert_eq!(webhook["data"]["object"]["id"], "pay_1");
+    }
+
+    #[test]
+    fn test_refund_webhook_in_stripe_format() {
+        let webhook = outgoing_webhook(
+            api_enums::EventType::RefundSucceeded,
+            api::OutgoingWebhookContent::RefundDetails(refunds::RefundResponse {
+                refund_id: "ref_1".to_string(),
+                payment_id: "pay_1".to_string(),
+                amount: 100,
+                currency: "USD".to_string(),
+                reason: None,
+                status: refunds::RefundStatus::Succeeded,
+                metadata: None,
+                error_message: None,
+                error_code: None,
+                created_at: None,
+                updated_at: None,
+            }),
+        );
+
+        assert_eq!(webhook["type"], "charge.refund.updated");
+        assert_eq!(webhook["data"]["object"]["id"], "ref_1");
+        assert_eq!(webhook["data"]["object"]["payment_intent"], "pay_1");
+        assert_eq!(webhook["data"]["object"]["currency"], "usd");
+        assert_eq!(webhook["data"]["object"]["status"], "succeeded");
+    }
+}
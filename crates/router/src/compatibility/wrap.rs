@@ -18,6 +18,7 @@ pub async fn compatibility_api_wrap<'a, 'b, A, U, T, Q, F, Fut, S, E>(
     payload: T,
     func: F,
     api_authentication: &dyn auth::AuthenticateAndFetch<U, A>,
+    permission: auth::Permission,
 ) -> HttpResponse
 where
     F: Fn(&'b A, U, T) -> Fut,
@@ -29,8 +30,15 @@ where
     T: std::fmt::Debug,
     A: AppStateInfo,
 {
-    let resp: common_utils::errors::CustomResult<_, E> =
-        api::server_wrap_util(state, request, payload, func, api_authentication).await;
+    let resp: common_utils::errors::CustomResult<_, E> = api::server_wrap_util(
+        state,
+        request,
+        payload,
+        func,
+        api_authentication,
+        permission,
+    )
+    .await;
     match resp {
         Ok(api::ApplicationResponse::Json(router_resp)) => {
             let pg_resp = S::try_from(router_resp);
@@ -71,6 +79,11 @@ where
         Ok(api::ApplicationResponse::Form(form_data)) => api::build_redirection_form(&form_data)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(api::ApplicationResponse::PaymentLinkForm(payment_link_data)) => {
+            api::build_payment_link_html(&payment_link_data)
+                .respond_to(request)
+                .map_into_boxed_body()
+        }
         Err(error) => {
             logger::error!(api_response_error=?error);
             api::log_and_return_error_response(error)
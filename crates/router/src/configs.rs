@@ -1,3 +1,4 @@
 mod defaults;
+pub mod dynamic;
 pub mod settings;
 mod validations;
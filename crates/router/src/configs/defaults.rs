@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 impl Default for super::settings::Server {
     fn default() -> Self {
         Self {
@@ -30,6 +32,8 @@ impl Default for super::settings::Secrets {
         Self {
             jwt_secret: "secret".into(),
             admin_api_key: "test_admin".into(),
+            card_fingerprint_hash_key: "card_fingerprint_hash_key".into(),
+            velocity_hash_key: "velocity_hash_key".into(),
         }
     }
 }
@@ -83,6 +87,7 @@ impl Default for super::settings::SchedulerSettings {
     fn default() -> Self {
         Self {
             stream: "SCHEDULER_STREAM".into(),
+            loop_interval: 5000,
             producer: super::settings::ProducerSettings::default(),
             consumer: super::settings::ConsumerSettings::default(),
         }
@@ -122,3 +127,128 @@ impl Default for super::settings::DrainerSettings {
         }
     }
 }
+
+impl Default for super::settings::CircuitBreakerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_size: 60,
+            min_calls: 20,
+            error_rate_threshold: 50,
+            slow_call_threshold: 10000,
+            slow_call_rate_threshold: 80,
+            open_duration: 30,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window: 60,
+            default: super::settings::RateLimits::default(),
+            merchants: HashMap::new(),
+            api_keys: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::RateLimits {
+    fn default() -> Self {
+        Self {
+            payments_create: 1000,
+            payments_list: 100,
+            refunds: 500,
+        }
+    }
+}
+
+impl Default for super::settings::FraudCheckSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            connector: super::settings::FrmConnectorKind::default(),
+            velocity_rules: super::settings::VelocityRulesSettings::default(),
+        }
+    }
+}
+
+impl Default for super::settings::VelocityRulesSettings {
+    fn default() -> Self {
+        Self {
+            window: 3600,
+            card_fingerprint: super::settings::VelocityThresholds {
+                review_after: 3,
+                decline_after: 5,
+            },
+            email: super::settings::VelocityThresholds {
+                review_after: 5,
+                decline_after: 10,
+            },
+            ip_address: super::settings::VelocityThresholds {
+                review_after: 10,
+                decline_after: 20,
+            },
+        }
+    }
+}
+
+impl Default for super::settings::VelocityThresholds {
+    fn default() -> Self {
+        Self {
+            review_after: 5,
+            decline_after: 10,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentLinkSettings {
+    fn default() -> Self {
+        Self {
+            default_expiry: 86400,
+            max_expiry: 7776000,
+        }
+    }
+}
+
+impl Default for super::settings::NetworkTokenizationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token_service_provider: super::settings::TokenServiceProviderKind::default(),
+            lifecycle_event_secret: String::new(),
+            supported_connectors: None,
+        }
+    }
+}
+
+impl Default for super::settings::HealthCheckSettings {
+    fn default() -> Self {
+        Self {
+            component_timeout: 3000,
+            drainer_lag_threshold: 10000,
+            scheduler_lag_threshold: 10000,
+        }
+    }
+}
+
+impl Default for super::settings::VaultSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token_ttl: 900,
+            max_token_ttl: 86400,
+            token_cleanup_interval: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::CacheSettings {
+    fn default() -> Self {
+        Self {
+            max_capacity: 10_000,
+            ttl: 300,
+        }
+    }
+}
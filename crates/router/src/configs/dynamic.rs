@@ -0,0 +1,388 @@
+//! Settings which can be changed without restarting the application.
+//!
+//! The values loaded from the configuration file can be overridden by storing the overrides in the
+//! `configs` table, under the key of the section being overridden. Whenever an override is
+//! updated, all the instances are notified via Redis pub/sub and reload the overrides.
+
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+
+use futures::StreamExt;
+
+use super::settings::{
+    BankRedirectConfig, ConnectorFilters, ConnectorParams, Connectors, RateLimitSettings,
+    SchedulerSettings, Settings, WebhooksSettings,
+};
+use crate::{
+    async_spawn,
+    core::errors::{ApplicationError, ApplicationResult},
+    db::StorageInterface,
+    logger,
+};
+
+const DYNAMIC_CONFIG_KEY_PREFIX: &str = "dynamic_config_";
+
+/// Sections of the settings which can be overridden at runtime.
+///
+/// The override of each section is stored as JSON, in the same format as the section in the
+/// configuration file:
+/// - `connectors`: parameters by connector name, replacing the parameters of those connectors
+/// - `pm_filters`: payment method filters by connector name, replacing the filters of those
+///   connectors
+/// - `bank_config`: banks by payment method type, replacing the banks of those payment methods
+/// - `webhooks`, `scheduler` and `rate_limit`: replace the complete section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum DynamicConfigSection {
+    Connectors,
+    PmFilters,
+    BankConfig,
+    Webhooks,
+    Scheduler,
+    RateLimit,
+}
+
+impl DynamicConfigSection {
+    const ALL: [Self; 6] = [
+        Self::Connectors,
+        Self::PmFilters,
+        Self::BankConfig,
+        Self::Webhooks,
+        Self::Scheduler,
+        Self::RateLimit,
+    ];
+
+    /// Key of the override of the section in the `configs` table.
+    pub fn config_key(self) -> String {
+        format!("{DYNAMIC_CONFIG_KEY_PREFIX}{self}")
+    }
+
+    pub fn from_config_key(key: &str) -> Option<Self> {
+        key.strip_prefix(DYNAMIC_CONFIG_KEY_PREFIX)
+            .and_then(|section| Self::from_str(section).ok())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DynamicSettings {
+    pub connectors: Connectors,
+    pub pm_filters: ConnectorFilters,
+    pub bank_config: BankRedirectConfig,
+    pub webhooks: WebhooksSettings,
+    pub scheduler: Option<SchedulerSettings>,
+    pub rate_limit: RateLimitSettings,
+}
+
+impl DynamicSettings {
+    fn from_settings(settings: &Settings) -> Self {
+        Self {
+            connectors: settings.connectors.clone(),
+            pm_filters: settings.pm_filters.clone(),
+            bank_config: settings.bank_config.clone(),
+            webhooks: settings.webhooks.clone(),
+            scheduler: settings.scheduler.clone(),
+            rate_limit: settings.rate_limit.clone(),
+        }
+    }
+
+    /// Applies the override of a section. The settings are left unchanged if the override is
+    /// invalid.
+    pub fn apply_override(
+        &mut self,
+        section: DynamicConfigSection,
+        value: &str,
+    ) -> ApplicationResult<()> {
+        let mut settings = self.clone();
+
+        match section {
+            DynamicConfigSection::Connectors => {
+                let overrides: HashMap<String, ConnectorParams> = parse_override(section, value)?;
+                for (connector_name, params) in overrides {
+                    *settings
+                        .connectors
+                        .get_connector_params_mut(&connector_name)
+                        .ok_or_else(|| {
+                            ApplicationError::InvalidConfigurationValueError(format!(
+                                "unknown connector {connector_name}"
+                            ))
+                        })? = params;
+                }
+                settings.connectors.validate()?;
+            }
+            DynamicConfigSection::PmFilters => {
+                let overrides: ConnectorFilters = parse_override(section, value)?;
+                overrides.validate()?;
+                settings.pm_filters.0.extend(overrides.0);
+            }
+            DynamicConfigSection::BankConfig => {
+                validate_bank_names(section, value)?;
+                let overrides: BankRedirectConfig = parse_override(section, value)?;
+                overrides.validate(&settings.connectors)?;
+                settings.bank_config.0.extend(overrides.0);
+            }
+            DynamicConfigSection::Webhooks => {
+                // Unknown fields are rejected while parsing, as no value of the section is invalid
+                settings.webhooks = parse_override(section, value)?;
+            }
+            DynamicConfigSection::Scheduler => {
+                let scheduler: SchedulerSettings = parse_override(section, value)?;
+                scheduler.validate()?;
+                settings.scheduler = Some(scheduler);
+            }
+            DynamicConfigSection::RateLimit => {
+                let rate_limit: RateLimitSettings = parse_override(section, value)?;
+                rate_limit.validate()?;
+                settings.rate_limit = rate_limit;
+            }
+        }
+
+        *self = settings;
+        Ok(())
+    }
+}
+
+fn parse_override<T: serde::de::DeserializeOwned>(
+    section: DynamicConfigSection,
+    value: &str,
+) -> ApplicationResult<T> {
+    serde_json::from_str(value).map_err(|error| {
+        ApplicationError::InvalidConfigurationValueError(format!(
+            "invalid override of {section}: {error}"
+        ))
+    })
+}
+
+/// Unknown bank names are skipped when the bank configuration is deserialized, so the override is
+/// checked for them beforehand instead of the banks being dropped silently.
+fn validate_bank_names(section: DynamicConfigSection, value: &str) -> ApplicationResult<()> {
+    #[derive(serde::Deserialize)]
+    struct Banks {
+        banks: String,
+    }
+
+    let overrides: HashMap<String, HashMap<String, Banks>> = parse_override(section, value)?;
+    let bank_names = overrides
+        .values()
+        .flat_map(HashMap::values)
+        .flat_map(|banks| banks.banks.trim().split(','));
+    for bank_name in bank_names {
+        if api_models::enums::BankNames::from_str(bank_name).is_err() {
+            return Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "invalid override of {section}: unknown bank {bank_name}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+pub struct DynamicConfig {
+    /// Settings loaded from the configuration file, on top of which the overrides are applied
+    base: DynamicSettings,
+    current: RwLock<Arc<DynamicSettings>>,
+}
+
+impl DynamicConfig {
+    pub fn new(settings: &Settings) -> Self {
+        let base = DynamicSettings::from_settings(settings);
+        Self {
+            current: RwLock::new(Arc::new(base.clone())),
+            base,
+        }
+    }
+
+    /// Snapshot of the current settings, which is not affected by later refreshes.
+    pub fn get(&self) -> Arc<DynamicSettings> {
+        match self.current.read() {
+            Ok(current) => Arc::clone(&current),
+            Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+        }
+    }
+
+    /// Checks that the override of a section can be applied to the current settings.
+    pub fn validate_override(
+        &self,
+        section: DynamicConfigSection,
+        value: &str,
+    ) -> ApplicationResult<()> {
+        DynamicSettings::clone(&self.get()).apply_override(section, value)
+    }
+
+    /// Reloads the overrides from the `configs` table. Overrides which cannot be fetched or are
+    /// invalid are skipped, so the settings from the configuration file are used for them.
+    pub async fn refresh(&self, db: &dyn StorageInterface) {
+        let mut settings = self.base.clone();
+
+        for section in DynamicConfigSection::ALL {
+            match db.find_config_by_key(&section.config_key()).await {
+                Ok(config) => {
+                    if let Err(error) = settings.apply_override(section, &config.config) {
+                        logger::error!(%section, %error, "Skipping invalid dynamic config override");
+                    }
+                }
+                Err(error) if error.current_context().is_db_not_found() => {}
+                Err(error) => {
+                    logger::error!(%section, ?error, "Failed to fetch dynamic config override");
+                }
+            }
+        }
+
+        let settings = Arc::new(settings);
+        match self.current.write() {
+            Ok(mut current) => *current = settings,
+            Err(poisoned) => *poisoned.into_inner() = settings,
+        }
+    }
+}
+
+/// Loads the overrides and keeps reloading them whenever an update to any of them is published.
+pub async fn start_refresh_listener(
+    dynamic_config: Arc<DynamicConfig>,
+    store: Box<dyn StorageInterface>,
+    redis_conn: Arc<redis_interface::RedisConnectionPool>,
+) {
+    // Subscribe before loading the overrides, so that no update is missed in between
+    let mut updated_keys = redis_conn.on_message_keys();
+    dynamic_config.refresh(&*store).await;
+
+    async_spawn!({
+        while let Some(key) = updated_keys.next().await {
+            if let Some(section) = DynamicConfigSection::from_config_key(&key) {
+                logger::info!(%section, "Reloading dynamic config overrides");
+                dynamic_config.refresh(&*store).await;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_dynamic_config_section_keys() {
+        let key = DynamicConfigSection::PmFilters.config_key();
+        assert_eq!(key, "dynamic_config_pm_filters");
+        assert_eq!(
+            DynamicConfigSection::from_config_key(&key),
+            Some(DynamicConfigSection::PmFilters)
+        );
+        assert_eq!(DynamicConfigSection::from_config_key("pm_filters"), None);
+        assert_eq!(
+            DynamicConfigSection::from_config_key("dynamic_config_locker"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_apply_override() {
+        let mut settings = DynamicSettings::from_settings(&Settings::default());
+
+        settings
+            .apply_override(
+                DynamicConfigSection::PmFilters,
+                r#"{"stripe": {"klarna": {"country": "US,GB", "currency": "USD,GBP"}}}"#,
+            )
+            .unwrap();
+        let filters = settings.pm_filters.0.get("stripe").unwrap();
+        let filter = filters.0.values().next().unwrap();
+        assert_eq!(filter.country.as_ref().unwrap().len(), 2);
+
+        settings
+            .apply_override(
+                DynamicConfigSection::Webhooks,
+                r#"{"outgoing_enabled": true}"#,
+            )
+            .unwrap();
+        assert!(settings.webhooks.outgoing_enabled);
+
+        // Invalid overrides leave the settings unchanged
+        assert!(settings
+            .apply_override(
+                DynamicConfigSection::PmFilters,
+                r#"{"stripe": {"klarna": {"country": "united states"}}}"#,
+            )
+            .is_err());
+        assert!(settings
+            .apply_override(DynamicConfigSection::Webhooks, "not json")
+            .is_err());
+        assert!(settings
+            .apply_override(
+                DynamicConfigSection::Connectors,
+                r#"{"unknown": {"base_url": "https://example.com"}}"#,
+            )
+            .is_err());
+        assert!(settings.webhooks.outgoing_enabled);
+        assert_eq!(
+            settings
+                .pm_filters
+                .0
+                .get("stripe")
+                .and_then(|filters| filters.0.values().next())
+                .and_then(|filter| filter.country.as_ref())
+                .map(|countries| countries.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_override_validation() {
+        let mut settings = DynamicSettings::from_settings(&Settings::default());
+
+        settings
+            .apply_override(
+                DynamicConfigSection::BankConfig,
+                r#"{"eps": {"stripe": {"banks": "bank_austria,dolomitenbank"}}}"#,
+            )
+            .unwrap();
+        assert_eq!(
+            settings
+                .bank_config
+                .0
+                .get(&api_models::enums::PaymentMethodType::Eps)
+                .and_then(|connector_banks| connector_banks.0.get("stripe"))
+                .map(|banks| banks.banks.len()),
+            Some(2)
+        );
+
+        // Unknown banks are rejected instead of being dropped, as are unknown connectors
+        for value in [
+            r#"{"eps": {"stripe": {"banks": "bank_austria,unknown_bank"}}}"#,
+            r#"{"eps": {"unknown": {"banks": "bank_austria"}}}"#,
+            r#"{"eps": {"stripe": {"banks": ""}}}"#,
+        ] {
+            assert!(settings
+                .apply_override(DynamicConfigSection::BankConfig, value)
+                .is_err());
+        }
+
+        assert!(settings
+            .apply_override(
+                DynamicConfigSection::Webhooks,
+                r#"{"outgoing_enable": true}"#,
+            )
+            .is_err());
+
+        settings
+            .apply_override(
+                DynamicConfigSection::Scheduler,
+                r#"{"loop_interval": 1000}"#,
+            )
+            .unwrap();
+        assert!(settings
+            .apply_override(DynamicConfigSection::Scheduler, r#"{"loop_interval": 0}"#)
+            .is_err());
+        assert_eq!(
+            settings
+                .scheduler
+                .as_ref()
+                .map(|scheduler| scheduler.loop_interval),
+            Some(1000)
+        );
+    }
+}
@@ -6,6 +6,7 @@ use std::{
 
 use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
+use external_services::secrets_management::types::SecretsManagementSettings;
 use redis_interface::RedisSettings;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::{Deserialize, Deserializer};
@@ -57,6 +58,16 @@ pub struct Settings {
     pub webhooks: WebhooksSettings,
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
+    pub circuit_breaker: CircuitBreakerSettings,
+    pub rate_limit: RateLimitSettings,
+    pub fraud_check: FraudCheckSettings,
+    pub connector_call_log: ConnectorCallLogSettings,
+    pub health_check: HealthCheckSettings,
+    pub payment_link: PaymentLinkSettings,
+    pub network_tokenization: NetworkTokenizationSettings,
+    pub secrets_management: SecretsManagementSettings,
+    pub vault: VaultSettings,
+    pub cache: CacheSettings,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -151,6 +162,8 @@ where
 pub struct Secrets {
     pub jwt_secret: String,
     pub admin_api_key: String,
+    pub card_fingerprint_hash_key: String,
+    pub velocity_hash_key: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -177,16 +190,17 @@ pub struct EphemeralConfig {
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Jwekey {
-    #[cfg(feature = "kms")]
-    pub aws_key_id: String,
-    #[cfg(feature = "kms")]
-    pub aws_region: String,
     pub locker_key_identifier1: String,
     pub locker_key_identifier2: String,
     pub locker_encryption_key1: String,
     pub locker_encryption_key2: String,
     pub locker_decryption_key1: String,
     pub locker_decryption_key2: String,
+    pub master_key_identifier1: String,
+    pub master_key_identifier2: String,
+    pub master_encryption_key1: String,
+    pub master_encryption_key2: String,
+    pub active_master_key_identifier: String,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -220,51 +234,95 @@ pub struct Database {
 }
 
 #[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(test, derive(serde::Serialize))]
 #[serde(default)]
 pub struct SupportedConnectors {
     pub wallets: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
-#[serde(default)]
-pub struct Connectors {
-    pub aci: ConnectorParams,
-    pub adyen: ConnectorParams,
-    pub airwallex: ConnectorParams,
-    pub applepay: ConnectorParams,
-    pub authorizedotnet: ConnectorParams,
-    pub bambora: ConnectorParams,
-    pub bluesnap: ConnectorParams,
-    pub braintree: ConnectorParams,
-    pub checkout: ConnectorParams,
-    pub cybersource: ConnectorParams,
-    pub dlocal: ConnectorParams,
-    pub fiserv: ConnectorParams,
-    pub globalpay: ConnectorParams,
-    pub klarna: ConnectorParams,
-    pub multisafepay: ConnectorParams,
-    pub nuvei: ConnectorParams,
-    pub payu: ConnectorParams,
-    pub rapyd: ConnectorParams,
-    pub shift4: ConnectorParams,
-    pub stripe: ConnectorParams,
-    pub worldline: ConnectorParams,
-    pub worldpay: ConnectorParams,
-
-    // Keep this field separate from the remaining fields
-    pub supported: SupportedConnectors,
+/// Defines the `Connectors` settings with the parameters of each listed connector, along with the
+/// lookup of the parameters of a connector by its name.
+macro_rules! connectors {
+    ($($connector:ident),+ $(,)?) => {
+        #[derive(Debug, Deserialize, Clone, Default)]
+        #[cfg_attr(test, derive(serde::Serialize))]
+        #[serde(default)]
+        pub struct Connectors {
+            $(pub $connector: ConnectorParams,)+
+
+            // Keep this field separate from the remaining fields
+            pub supported: SupportedConnectors,
+        }
+
+        impl Connectors {
+            pub fn get_connector_params(&self, connector_name: &str) -> Option<&ConnectorParams> {
+                match connector_name {
+                    $(stringify!($connector) => Some(&self.$connector),)+
+                    _ => None,
+                }
+            }
+
+            pub fn get_connector_params_mut(
+                &mut self,
+                connector_name: &str,
+            ) -> Option<&mut ConnectorParams> {
+                match connector_name {
+                    $(stringify!($connector) => Some(&mut self.$connector),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+connectors! {
+    aci,
+    adyen,
+    airwallex,
+    applepay,
+    authorizedotnet,
+    bambora,
+    bluesnap,
+    braintree,
+    checkout,
+    cybersource,
+    dlocal,
+    fiserv,
+    globalpay,
+    klarna,
+    multisafepay,
+    nuvei,
+    payu,
+    rapyd,
+    shift4,
+    stripe,
+    worldline,
+    worldpay,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
+#[cfg_attr(test, derive(serde::Serialize))]
 #[serde(default)]
 pub struct ConnectorParams {
     pub base_url: String,
+    /// Timeout for establishing a connection with the connector (in seconds)
+    pub connect_timeout: Option<u64>,
+    /// Timeout for the complete request, including reading the response (in seconds)
+    pub read_timeout: Option<u64>,
+    /// Maximum number of idle connections kept open to the connector
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Use HTTP/2 for requests to the connector without negotiating the protocol
+    pub http2_prior_knowledge: bool,
+    /// PEM encoded CA certificates to be trusted for the connector, instead of the system roots
+    pub ca_bundle: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
     pub stream: String,
+    /// Time between two runs of the producer and consumer loops, in milliseconds
+    pub loop_interval: u64,
     pub producer: ProducerSettings,
     pub consumer: ConsumerSettings,
 }
@@ -298,12 +356,163 @@ pub struct DrainerSettings {
     pub loop_interval: u32,     // in milliseconds
 }
 
+/// Unknown fields are rejected, so that a misspelt field in a runtime override is not silently
+/// replaced by its default value.
 #[derive(Debug, Clone, Default, Deserialize)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ConnectorCallLogSettings {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HealthCheckSettings {
+    /// Time after which a component is considered unhealthy, in milliseconds
+    pub component_timeout: u64,
+    pub drainer_lag_threshold: usize,
+    pub scheduler_lag_threshold: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PaymentLinkSettings {
+    /// Time after which a payment link expires if not specified in the request, in seconds
+    pub default_expiry: u32,
+    /// Maximum time for which a payment link can be valid, in seconds
+    pub max_expiry: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NetworkTokenizationSettings {
+    /// Whether cards saved for customers are tokenized with the card networks
+    pub enabled: bool,
+    pub token_service_provider: TokenServiceProviderKind,
+    /// Secret used to verify the token lifecycle events sent by the token service provider
+    pub lifecycle_event_secret: String,
+    /// Connectors which accept network tokens in place of the card number
+    #[serde(deserialize_with = "string_set_deser")]
+    pub supported_connectors: Option<HashSet<String>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenServiceProviderKind {
+    #[default]
+    Mock,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct VaultSettings {
+    /// Whether cards and tokens are stored in the built-in vault instead of the external locker
+    pub enabled: bool,
+    /// Time after which a token expires if not specified while creating it, in seconds
+    pub token_ttl: u32,
+    /// Maximum time for which a token can be valid, in seconds
+    pub max_token_ttl: u32,
+    /// Time between two runs of the scheduler task which deletes the expired tokens, in seconds
+    pub token_cleanup_interval: u32,
+}
+
+/// In-process cache of merchant accounts, connector accounts and configs, in front of the cache
+/// in Redis.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CacheSettings {
+    /// Maximum number of entries held in the cache, zero disables the cache
+    pub max_capacity: usize,
+    /// Time after which an entry is evicted from the cache, in seconds
+    pub ttl: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CircuitBreakerSettings {
+    pub enabled: bool,
+    pub window_size: i64,              // in seconds
+    pub min_calls: u64,                // minimum calls in a window before the circuit can open
+    pub error_rate_threshold: u64,     // in percent
+    pub slow_call_threshold: u64,      // in milliseconds
+    pub slow_call_rate_threshold: u64, // in percent
+    pub open_duration: i64,            // in seconds
+}
+
+/// Limits on the number of requests made by merchants in each route group, which are enforced per
+/// merchant account and per API key. The limits of the `default` section apply to the merchant
+/// accounts and the API keys which do not have their own limits in the `merchants` and `api_keys`
+/// sections respectively.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    pub window: i64, // in seconds
+    pub default: RateLimits,
+    /// Limits by merchant ID
+    pub merchants: HashMap<String, RateLimitOverrides>,
+    /// Limits by API key ID
+    pub api_keys: HashMap<String, RateLimitOverrides>,
+}
+
+/// Maximum number of requests in a window, by route group.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimits {
+    pub payments_create: u64,
+    pub payments_list: u64,
+    pub refunds: u64,
+}
+
+/// Limits which replace the default limits of some of the route groups.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RateLimitOverrides {
+    pub payments_create: Option<u64>,
+    pub payments_list: Option<u64>,
+    pub refunds: Option<u64>,
+}
+
+/// Fraud check of payments before they are sent to the payment connector.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FraudCheckSettings {
+    pub enabled: bool,
+    /// Fraud and risk management connector which checks the payments
+    pub connector: FrmConnectorKind,
+    pub velocity_rules: VelocityRulesSettings,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrmConnectorKind {
+    #[default]
+    VelocityRules,
+}
+
+/// Limits on the number of payments of a merchant made with the same card, email or IP address
+/// within a window, above which payments are held for review or declined.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct VelocityRulesSettings {
+    pub window: i64, // in seconds
+    pub card_fingerprint: VelocityThresholds,
+    pub email: VelocityThresholds,
+    pub ip_address: VelocityThresholds,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct VelocityThresholds {
+    pub review_after: u64,
+    pub decline_after: u64,
+}
+
 impl Settings {
     pub fn new() -> ApplicationResult<Self> {
         Self::with_config_path(None)
@@ -370,6 +579,7 @@ impl Settings {
         self.secrets.validate()?;
         self.locker.validate()?;
         self.connectors.validate()?;
+        self.pm_filters.validate()?;
 
         self.scheduler
             .as_ref()
@@ -378,7 +588,101 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.jwekey.validate()?;
+        self.secrets_management.validate().map_err(|error| {
+            println!("{error}");
+            ApplicationError::InvalidConfigurationValueError(
+                "secrets management configuration".into(),
+            )
+        })?;
+        self.vault.validate()?;
+        self.cache.validate()?;
+        self.circuit_breaker.validate()?;
+        self.network_tokenization.validate()?;
+        self.rate_limit.validate()?;
+        self.fraud_check.validate()?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_get_connector_params_covers_all_connectors() {
+        // Every field of `Connectors` other than `supported` holds the parameters of a connector
+        let connector_names = serde_json::to_value(Connectors::default())
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .keys()
+            .filter(|field| *field != "supported")
+            .cloned()
+            .collect::<Vec<_>>();
+        let connectors: Connectors = serde_json::from_value(serde_json::Value::Object(
+            connector_names
+                .iter()
+                .map(|name| (name.clone(), serde_json::json!({ "base_url": name })))
+                .collect(),
+        ))
+        .unwrap();
+
+        for connector_name in &connector_names {
+            let connector_params = connectors.get_connector_params(connector_name);
+            assert!(
+                connector_params.is_some(),
+                "{connector_name} is missing from `Connectors::get_connector_params`"
+            );
+            assert_eq!(&connector_params.unwrap().base_url, connector_name);
+        }
+    }
+
+    #[test]
+    fn test_get_connector_params_mut_covers_all_connectors() {
+        let mut connectors = Connectors::default();
+        let connector_names = serde_json::to_value(&connectors)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .keys()
+            .filter(|field| *field != "supported")
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for connector_name in &connector_names {
+            let connector_params = connectors.get_connector_params_mut(connector_name);
+            assert!(
+                connector_params.is_some(),
+                "{connector_name} is missing from `Connectors::get_connector_params_mut`"
+            );
+            connector_params.unwrap().base_url = connector_name.clone();
+        }
+
+        // Each name must refer to the parameters of its own connector
+        for connector_name in &connector_names {
+            assert_eq!(
+                &connectors
+                    .get_connector_params(connector_name)
+                    .unwrap()
+                    .base_url,
+                connector_name
+            );
+        }
+    }
+
+    #[test]
+    fn test_network_tokenization_requires_lifecycle_event_secret() {
+        let mut settings = NetworkTokenizationSettings::default();
+        assert!(settings.lifecycle_event_secret.is_empty());
+        assert!(settings.validate().is_ok());
+
+        settings.enabled = true;
+        assert!(settings.validate().is_err());
+
+        settings.lifecycle_event_secret = "lifecycle_event_secret".to_string();
+        assert!(settings.validate().is_ok());
+    }
+}
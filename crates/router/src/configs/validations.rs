@@ -16,6 +16,18 @@ impl super::settings::Secrets {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "admin API key must not be empty".into(),
             ))
+        })?;
+
+        when(self.card_fingerprint_hash_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card fingerprint hash key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.velocity_hash_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "velocity hash key must not be empty".into(),
+            ))
         })
     }
 }
@@ -43,21 +55,53 @@ impl super::settings::Locker {
 
 impl super::settings::Jwekey {
     pub fn validate(&self) -> Result<(), ApplicationError> {
-        #[cfg(feature = "kms")]
-        common_utils::fp_utils::when(self.aws_key_id.is_default_or_empty(), || {
+        common_utils::fp_utils::when(self.master_encryption_key1.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "AWS key ID must not be empty when KMS feature is enabled".into(),
+                "master encryption key must not be empty".into(),
             ))
         })?;
 
-        #[cfg(feature = "kms")]
-        common_utils::fp_utils::when(self.aws_region.is_default_or_empty(), || {
+        common_utils::fp_utils::when(
+            self.active_master_key_identifier != self.master_key_identifier1
+                && self.active_master_key_identifier != self.master_key_identifier2,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "active master key identifier must match one of the master key identifiers"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::VaultSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.token_ttl == 0 || self.token_ttl > self.max_token_ttl,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "vault token TTL must be between 1 second and the maximum token TTL".into(),
+                ))
+            },
+        )?;
+
+        when(self.token_cleanup_interval == 0, || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "AWS region must not be empty when KMS feature is enabled".into(),
+                "vault token cleanup interval must be greater than zero".into(),
             ))
-        })?;
+        })
+    }
+}
 
-        Ok(())
+impl super::settings::CacheSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.max_capacity > 0 && self.ttl == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cache TTL must be greater than zero when the cache is enabled".into(),
+            ))
+        })
     }
 }
 
@@ -134,11 +178,88 @@ impl super::settings::Connectors {
 
 impl super::settings::ConnectorParams {
     pub fn validate(&self) -> Result<(), ApplicationError> {
-        common_utils::fp_utils::when(self.base_url.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "connector base URL must not be empty".into(),
             ))
-        })
+        })?;
+
+        when(
+            self.connect_timeout == Some(0) || self.read_timeout == Some(0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector timeouts must be greater than zero".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.ca_bundle
+                .as_ref()
+                .map_or(false, |ca_bundle| ca_bundle.trim().is_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector CA bundle must not be empty when specified".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorFilters {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        let is_invalid_country = |country: &String| {
+            country.len() != 2 || !country.chars().all(|c| c.is_ascii_uppercase())
+        };
+
+        for (connector, filters) in &self.0 {
+            common_utils::fp_utils::when(
+                filters
+                    .0
+                    .values()
+                    .filter_map(|filter| filter.country.as_ref())
+                    .flatten()
+                    .any(is_invalid_country),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "payment method filters of {connector} must only contain ISO 3166-1 \
+                         alpha-2 country codes"
+                    )))
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl super::settings::BankRedirectConfig {
+    pub fn validate(
+        &self,
+        connectors: &super::settings::Connectors,
+    ) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        for (payment_method_type, connector_banks) in &self.0 {
+            for (connector, banks) in &connector_banks.0 {
+                when(connectors.get_connector_params(connector).is_none(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "banks of {payment_method_type} are configured for unknown connector \
+                         {connector}"
+                    )))
+                })?;
+
+                when(banks.banks.is_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "banks of {payment_method_type} for {connector} must not be empty"
+                    )))
+                })?;
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -152,6 +273,12 @@ impl super::settings::SchedulerSettings {
             ))
         })?;
 
+        when(self.loop_interval == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduler loop interval must be greater than zero".into(),
+            ))
+        })?;
+
         when(self.consumer.consumer_group.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "scheduler consumer group must not be empty".into(),
@@ -184,3 +311,115 @@ impl super::settings::DrainerSettings {
         })
     }
 }
+
+impl super::settings::CircuitBreakerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker window size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.open_duration <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker open duration must be greater than zero".into(),
+            ))
+        })?;
+
+        when(
+            self.error_rate_threshold > 100 || self.slow_call_rate_threshold > 100,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker rate thresholds must not exceed 100 percent".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::NetworkTokenizationSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            self.enabled && self.lifecycle_event_secret.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "network token lifecycle event secret must not be empty when network \
+                     tokenization is enabled"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit window must be greater than zero".into(),
+            ))
+        })?;
+
+        let default_limits = [
+            self.default.payments_create,
+            self.default.payments_list,
+            self.default.refunds,
+        ];
+        let overridden_limits = self
+            .merchants
+            .values()
+            .chain(self.api_keys.values())
+            .flat_map(|overrides| {
+                [
+                    overrides.payments_create,
+                    overrides.payments_list,
+                    overrides.refunds,
+                ]
+            })
+            .flatten();
+
+        default_limits
+            .into_iter()
+            .chain(overridden_limits)
+            .try_for_each(|limit| {
+                when(limit == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "rate limits must be greater than zero".into(),
+                    ))
+                })
+            })
+    }
+}
+
+impl super::settings::FraudCheckSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        let rules = &self.velocity_rules;
+        when(rules.window <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "fraud check velocity window must be greater than zero".into(),
+            ))
+        })?;
+
+        [rules.card_fingerprint, rules.email, rules.ip_address]
+            .into_iter()
+            .try_for_each(|thresholds| {
+                when(thresholds.review_after == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "fraud check velocity thresholds must be greater than zero".into(),
+                    ))
+                })?;
+
+                when(thresholds.review_after > thresholds.decline_after, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "fraud check review threshold must not exceed the decline threshold".into(),
+                    ))
+                })
+            })
+    }
+}
@@ -111,6 +111,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AciPaymentsRequest {
             api::PaymentMethodData::PayLater(_) => PaymentDetails::Klarna,
             api::PaymentMethodData::Wallet(_) => PaymentDetails::Wallet,
             api::PaymentMethodData::BankRedirect(_) => PaymentDetails::BankRedirect,
+            api::PaymentMethodData::BankDebit(_) | api::PaymentMethodData::BankTransfer(_) => Err(
+                errors::ConnectorError::NotImplemented("Payment method".to_string()),
+            )?,
         };
 
         let auth = AciAuthType::try_from(&item.connector_auth_type)?;
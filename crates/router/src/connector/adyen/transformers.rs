@@ -1,10 +1,11 @@
 use api_models::webhooks::IncomingWebhookEvent;
+use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::RouterData,
+    connector::utils::{self, CardData, RouterData},
     consts,
     core::errors,
     pii::{self, Email, Secret},
@@ -97,6 +98,16 @@ pub struct AdyenPaymentRequest<'a> {
     delivery_address: Option<Address>,
     country_code: Option<String>,
     line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpi_data: Option<AdyenMpiData>,
+}
+
+/// Cryptogram of the network token sent in place of the card number
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdyenMpiData {
+    token_authentication_verification_value: Secret<String>,
+    eci: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -118,6 +129,9 @@ pub enum AdyenStatus {
     Refused,
     Cancelled,
     RedirectShopper,
+    Received,
+    PresentToShopper,
+    Pending,
 }
 
 impl From<AdyenStatus> for storage_enums::AttemptStatus {
@@ -126,7 +140,10 @@ impl From<AdyenStatus> for storage_enums::AttemptStatus {
             AdyenStatus::Authorised => Self::Charged,
             AdyenStatus::Refused => Self::Failure,
             AdyenStatus::Cancelled => Self::Voided,
-            AdyenStatus::RedirectShopper => Self::AuthenticationPending,
+            AdyenStatus::RedirectShopper | AdyenStatus::PresentToShopper => {
+                Self::AuthenticationPending
+            }
+            AdyenStatus::Received | AdyenStatus::Pending => Self::Pending,
         }
     }
 }
@@ -166,6 +183,7 @@ pub struct AdyenThreeDS {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum AdyenPaymentResponse {
+    AdyenPresentToShopperResponse(AdyenPresentToShopperResponse),
     AdyenResponse(AdyenResponse),
     AdyenRedirectResponse(AdyenRedirectionResponse),
 }
@@ -201,6 +219,74 @@ pub struct AdyenRedirectionAction {
     data: Option<std::collections::HashMap<String, String>>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPresentToShopperResponse {
+    psp_reference: String,
+    result_code: AdyenStatus,
+    action: AdyenPresentToShopperAction,
+    refusal_reason: Option<String>,
+    refusal_reason_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AdyenPresentToShopperAction {
+    Voucher(AdyenTransferInstructions),
+    BankTransfer(AdyenTransferInstructions),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenTransferInstructions {
+    beneficiary: Option<String>,
+    iban: Option<String>,
+    bic: Option<String>,
+    account_number: Option<String>,
+    entity: Option<String>,
+    reference: Option<String>,
+    total_amount: Option<Amount>,
+    expires_at: Option<String>,
+    download_url: Option<String>,
+}
+
+impl From<AdyenTransferInstructions> for api_models::payments::BankTransferInstructions {
+    fn from(instructions: AdyenTransferInstructions) -> Self {
+        Self {
+            account_holder_name: instructions.beneficiary,
+            iban: instructions.iban,
+            bic: instructions.bic,
+            account_number: instructions.account_number,
+            routing_number: None,
+            bank_name: None,
+            entity: instructions.entity,
+            reference: instructions.reference,
+            amount: instructions
+                .total_amount
+                .as_ref()
+                .map(|amount| amount.value),
+            currency: instructions.total_amount.map(|amount| amount.currency),
+            expires_at: instructions
+                .expires_at
+                .as_deref()
+                .and_then(parse_expiry_time),
+            instructions_url: instructions.download_url,
+        }
+    }
+}
+
+/// Adyen sends the expiry time either with or without an offset, the latter being treated as UTC
+fn parse_expiry_time(expires_at: &str) -> Option<time::PrimitiveDateTime> {
+    let format = time::format_description::well_known::Iso8601::DEFAULT;
+    time::OffsetDateTime::parse(expires_at, &format)
+        .map(|date_time| {
+            let utc_date_time = date_time.to_offset(time::UtcOffset::UTC);
+            time::PrimitiveDateTime::new(utc_date_time.date(), utc_date_time.time())
+        })
+        .or_else(|_| time::PrimitiveDateTime::parse(expires_at, &format))
+        .ok()
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Amount {
     currency: String,
@@ -211,6 +297,7 @@ pub struct Amount {
 #[serde(tag = "type")]
 pub enum AdyenPaymentMethod<'a> {
     AdyenCard(AdyenCard),
+    NetworkToken(AdyenNetworkToken),
     AdyenPaypal(AdyenPaypal),
     Gpay(AdyenGPay),
     ApplePay(AdyenApplePay),
@@ -221,6 +308,47 @@ pub enum AdyenPaymentMethod<'a> {
     Ideal(BankRedirectionWithIssuer<'a>),
     Giropay(BankRedirectionPMData),
     Sofort(BankRedirectionPMData),
+    AchDirectDebit(Box<AdyenAchDirectDebitData>),
+    SepaDirectDebit(Box<AdyenSepaDirectDebitData>),
+    BacsDirectDebit(Box<AdyenBacsDirectDebitData>),
+    SepaBankTransfer(BankTransferPMData),
+    Multibanco(BankTransferPMData),
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAchDirectDebitData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    bank_account_number: Secret<String>,
+    bank_location_id: Secret<String>,
+    owner_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdyenSepaDirectDebitData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    #[serde(rename = "sepa.ownerName")]
+    owner_name: Secret<String>,
+    #[serde(rename = "sepa.ibanNumber")]
+    iban_number: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBacsDirectDebitData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    bank_account_number: Secret<String>,
+    bank_location_id: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BankTransferPMData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -247,6 +375,40 @@ pub struct AdyenCard {
     cvc: Option<Secret<String>>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNetworkToken {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    brand: CardBrand,
+    number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CardBrand {
+    Visa,
+    #[serde(rename = "mc")]
+    MasterCard,
+    Amex,
+    Maestro,
+    Discover,
+}
+
+impl From<utils::CardIssuer> for CardBrand {
+    fn from(issuer: utils::CardIssuer) -> Self {
+        match issuer {
+            utils::CardIssuer::Visa => Self::Visa,
+            utils::CardIssuer::Master => Self::MasterCard,
+            utils::CardIssuer::AmericanExpress => Self::Amex,
+            utils::CardIssuer::Maestro => Self::Maestro,
+            utils::CardIssuer::Discover => Self::Discover,
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenCancelRequest {
@@ -337,6 +499,16 @@ pub enum PaymentType {
     Giropay,
     #[serde(rename = "directEbanking")]
     Sofort,
+    Ach,
+    #[serde(rename = "sepadirectdebit")]
+    SepaDirectDebit,
+    #[serde(rename = "directdebit_GB")]
+    BacsDirectDebit,
+    #[serde(rename = "bankTransfer_IBAN")]
+    SepaBankTransfer,
+    Multibanco,
+    #[serde(rename = "networkToken")]
+    NetworkToken,
 }
 
 pub struct AdyenTestBankNames<'a>(&'a str);
@@ -430,6 +602,10 @@ impl<'a> TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest<'a
             storage_models::enums::PaymentMethod::BankRedirect => {
                 get_bank_redirect_specific_payment_data(item)
             }
+            storage_models::enums::PaymentMethod::BankDebit
+            | storage_models::enums::PaymentMethod::BankTransfer => {
+                get_bank_payment_specific_payment_data(item)
+            }
         }
     }
 }
@@ -519,6 +695,21 @@ fn get_line_items(item: &types::PaymentsAuthorizeRouterData) -> Vec<LineItem> {
     vec![line_item]
 }
 
+fn get_mpi_data(item: &types::PaymentsAuthorizeRouterData) -> Option<AdyenMpiData> {
+    item.request
+        .network_token
+        .as_ref()
+        .and_then(|network_token| {
+            network_token
+                .cryptogram
+                .clone()
+                .map(|cryptogram| AdyenMpiData {
+                    token_authentication_verification_value: cryptogram,
+                    eci: network_token.eci.clone(),
+                })
+        })
+}
+
 fn get_telephone_number(item: &types::PaymentsAuthorizeRouterData) -> Option<Secret<String>> {
     let phone = item
         .address
@@ -560,6 +751,16 @@ fn get_payment_method_data<'a>(
     item: &types::PaymentsAuthorizeRouterData,
 ) -> Result<AdyenPaymentMethod<'a>, error_stack::Report<errors::ConnectorError>> {
     match item.request.payment_method_data {
+        api::PaymentMethodData::Card(ref card) if item.request.network_token.is_some() => {
+            let network_token = AdyenNetworkToken {
+                payment_type: PaymentType::NetworkToken,
+                brand: card.get_card_issuer()?.into(),
+                number: card.card_number.clone(),
+                expiry_month: card.card_exp_month.clone(),
+                expiry_year: card.card_exp_year.clone(),
+            };
+            Ok(AdyenPaymentMethod::NetworkToken(network_token))
+        }
         api::PaymentMethodData::Card(ref card) => {
             let adyen_card = AdyenCard {
                 payment_type: PaymentType::Scheme,
@@ -646,6 +847,76 @@ fn get_payment_method_data<'a>(
                 }
             }
         }
+        api_models::payments::PaymentMethodData::BankDebit(ref bank_debit_data) => {
+            match bank_debit_data {
+                api_models::payments::BankDebitData::AchBankDebit {
+                    billing_details,
+                    account_number,
+                    routing_number,
+                } => Ok(AdyenPaymentMethod::AchDirectDebit(Box::new(
+                    AdyenAchDirectDebitData {
+                        payment_type: PaymentType::Ach,
+                        bank_account_number: account_number.clone(),
+                        bank_location_id: routing_number.clone(),
+                        owner_name: billing_details.name.clone(),
+                    },
+                ))),
+                api_models::payments::BankDebitData::SepaBankDebit {
+                    billing_details,
+                    iban,
+                } => Ok(AdyenPaymentMethod::SepaDirectDebit(Box::new(
+                    AdyenSepaDirectDebitData {
+                        payment_type: PaymentType::SepaDirectDebit,
+                        owner_name: billing_details.name.clone(),
+                        iban_number: iban.clone(),
+                    },
+                ))),
+                api_models::payments::BankDebitData::BacsBankDebit {
+                    billing_details,
+                    account_number,
+                    sort_code,
+                } => Ok(AdyenPaymentMethod::BacsDirectDebit(Box::new(
+                    AdyenBacsDirectDebitData {
+                        payment_type: PaymentType::BacsDirectDebit,
+                        bank_account_number: account_number.clone(),
+                        bank_location_id: sort_code.clone(),
+                        holder_name: billing_details.name.clone(),
+                    },
+                ))),
+                // Adyen does not offer BECS direct debits
+                api_models::payments::BankDebitData::BecsBankDebit { .. } => {
+                    Err(errors::ConnectorError::NotSupported {
+                        payment_method: api_enums::PaymentMethodType::Becs.to_string(),
+                        connector: "Adyen",
+                        payment_experience: api_enums::PaymentMethod::BankDebit.to_string(),
+                    }
+                    .into())
+                }
+            }
+        }
+        api_models::payments::PaymentMethodData::BankTransfer(ref bank_transfer_data) => {
+            match bank_transfer_data {
+                api_models::payments::BankTransferData::SepaBankTransfer { .. } => {
+                    Ok(AdyenPaymentMethod::SepaBankTransfer(BankTransferPMData {
+                        payment_type: PaymentType::SepaBankTransfer,
+                    }))
+                }
+                api_models::payments::BankTransferData::Multibanco { .. } => {
+                    Ok(AdyenPaymentMethod::Multibanco(BankTransferPMData {
+                        payment_type: PaymentType::Multibanco,
+                    }))
+                }
+                // Adyen does not offer ACH credit transfers
+                api_models::payments::BankTransferData::AchBankTransfer { .. } => {
+                    Err(errors::ConnectorError::NotSupported {
+                        payment_method: api_enums::PaymentMethodType::Ach.to_string(),
+                        connector: "Adyen",
+                        payment_experience: api_enums::PaymentMethod::BankTransfer.to_string(),
+                    }
+                    .into())
+                }
+            }
+        }
     }
 }
 
@@ -660,6 +931,7 @@ fn get_card_specific_payment_data<'a>(
     let additional_data = get_additional_data(item);
     let return_url = item.get_return_url()?;
     let payment_method = get_payment_method_data(item)?;
+    let mpi_data = get_mpi_data(item);
     Ok(AdyenPaymentRequest {
         amount,
         merchant_account: auth_type.merchant_account,
@@ -678,6 +950,7 @@ fn get_card_specific_payment_data<'a>(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        mpi_data,
     })
 }
 
@@ -733,6 +1006,117 @@ fn get_bank_redirect_specific_payment_data<'a>(
         delivery_address: None,
         country_code: country,
         line_items: None,
+        mpi_data: None,
+    })
+}
+
+fn get_bank_payment_billing_details(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> (
+    Option<Secret<String>>,
+    Option<Secret<String, Email>>,
+    Option<&api_models::payments::AddressDetails>,
+) {
+    match item.request.payment_method_data {
+        api_models::payments::PaymentMethodData::BankDebit(
+            api_models::payments::BankDebitData::AchBankDebit {
+                ref billing_details,
+                ..
+            }
+            | api_models::payments::BankDebitData::SepaBankDebit {
+                ref billing_details,
+                ..
+            }
+            | api_models::payments::BankDebitData::BecsBankDebit {
+                ref billing_details,
+                ..
+            }
+            | api_models::payments::BankDebitData::BacsBankDebit {
+                ref billing_details,
+                ..
+            },
+        ) => (
+            Some(billing_details.name.clone()),
+            Some(billing_details.email.clone()),
+            billing_details.address.as_ref(),
+        ),
+        api_models::payments::PaymentMethodData::BankTransfer(
+            api_models::payments::BankTransferData::AchBankTransfer {
+                ref billing_details,
+            }
+            | api_models::payments::BankTransferData::SepaBankTransfer {
+                ref billing_details,
+                ..
+            }
+            | api_models::payments::BankTransferData::Multibanco {
+                ref billing_details,
+            },
+        ) => (
+            billing_details.name.clone(),
+            Some(billing_details.email.clone()),
+            None,
+        ),
+        _ => (None, None, None),
+    }
+}
+
+fn get_bank_payment_specific_payment_data<'a>(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<AdyenPaymentRequest<'a>, error_stack::Report<errors::ConnectorError>> {
+    let amount = get_amount_data(item);
+    let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+    let shopper_interaction = AdyenShopperInteraction::from(item);
+    let recurring_processing_model = get_recurring_processing_model(item);
+    let browser_info = get_browser_info(item);
+    let additional_data = get_additional_data(item);
+    let return_url = item.get_return_url()?;
+    let payment_method = get_payment_method_data(item)?;
+    let (name, shopper_email, address) = get_bank_payment_billing_details(item);
+    let shopper_name = name.map(|name| {
+        let name = name.peek();
+        let (first_name, last_name) = name
+            .split_once(' ')
+            .map_or((name.as_str(), None), |(first_name, last_name)| {
+                (first_name, Some(last_name))
+            });
+        ShopperName {
+            first_name: Some(Secret::new(first_name.to_string())),
+            last_name: last_name.map(|last_name| Secret::new(last_name.to_string())),
+        }
+    });
+    let billing_address = address
+        .map(|address| Address {
+            city: address.city.clone(),
+            country: address.country.clone(),
+            house_number_or_name: address.line1.clone(),
+            postal_code: address.zip.clone(),
+            state_or_province: address.state.clone(),
+            street: address.line2.clone(),
+        })
+        .or_else(|| get_address_info(item.address.billing.as_ref()));
+    let country_code = billing_address
+        .as_ref()
+        .and_then(|address| address.country.clone());
+
+    Ok(AdyenPaymentRequest {
+        amount,
+        merchant_account: auth_type.merchant_account,
+        payment_method,
+        reference: item.payment_id.to_string(),
+        return_url,
+        shopper_interaction,
+        recurring_processing_model,
+        browser_info,
+        additional_data,
+        telephone_number: None,
+        shopper_name,
+        shopper_email: shopper_email.or_else(|| item.request.email.clone()),
+        shopper_locale: None,
+        billing_address,
+        delivery_address: None,
+        country_code,
+        line_items: None,
+        mpi_data: None,
     })
 }
 
@@ -765,6 +1149,7 @@ fn get_wallet_specific_payment_data<'a>(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        mpi_data: None,
     })
 }
 
@@ -804,6 +1189,7 @@ fn get_paylater_specific_payment_data<'a>(
         delivery_address,
         country_code,
         line_items,
+        mpi_data: None,
     })
 }
 
@@ -894,6 +1280,53 @@ pub fn get_adyen_response(
     Ok((status, error, payments_response_data))
 }
 
+pub fn get_present_to_shopper_response(
+    response: AdyenPresentToShopperResponse,
+    status_code: u16,
+) -> errors::CustomResult<
+    (
+        storage_enums::AttemptStatus,
+        Option<types::ErrorResponse>,
+        types::PaymentsResponseData,
+    ),
+    errors::ConnectorError,
+> {
+    let status = response.result_code.into();
+
+    let error = if response.refusal_reason.is_some() || response.refusal_reason_code.is_some() {
+        Some(types::ErrorResponse {
+            code: response
+                .refusal_reason_code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .refusal_reason
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+            status_code,
+        })
+    } else {
+        None
+    };
+
+    let instructions = match response.action {
+        AdyenPresentToShopperAction::Voucher(instructions)
+        | AdyenPresentToShopperAction::BankTransfer(instructions) => instructions,
+    };
+    let connector_metadata = serde_json::to_value(types::BankTransferNextStepsData {
+        bank_transfer_instructions: instructions.into(),
+    })
+    .into_report()
+    .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+
+    let payments_response_data = types::PaymentsResponseData::TransactionResponse {
+        resource_id: types::ResponseId::ConnectorTransactionId(response.psp_reference),
+        redirection_data: None,
+        mandate_reference: None,
+        connector_metadata: Some(connector_metadata),
+    };
+    Ok((status, error, payments_response_data))
+}
+
 pub fn get_redirection_response(
     response: AdyenRedirectionResponse,
     status_code: u16,
@@ -964,6 +1397,9 @@ impl<F, Req>
         let item = items.0;
         let is_manual_capture = items.1;
         let (status, error, payment_response_data) = match item.response {
+            AdyenPaymentResponse::AdyenPresentToShopperResponse(response) => {
+                get_present_to_shopper_response(response, item.http_code)?
+            }
             AdyenPaymentResponse::AdyenResponse(response) => {
                 get_adyen_response(response, is_manual_capture, item.http_code)?
             }
@@ -1168,12 +1604,16 @@ pub enum WebhookEventCode {
     Refund,
     CancelOrRefund,
     RefundFailed,
+    Pending,
+    OfferClosed,
 }
 
 impl From<WebhookEventCode> for IncomingWebhookEvent {
     fn from(code: WebhookEventCode) -> Self {
         match code {
             WebhookEventCode::Authorisation => Self::PaymentIntentSuccess,
+            WebhookEventCode::Pending => Self::PaymentIntentProcessing,
+            WebhookEventCode::OfferClosed => Self::PaymentIntentExpired,
             WebhookEventCode::Refund => Self::RefundSuccess,
             WebhookEventCode::CancelOrRefund => Self::RefundSuccess,
             WebhookEventCode::RefundFailed => Self::RefundFailure,
@@ -1208,19 +1648,101 @@ pub struct AdyenIncomingWebhook {
 
 impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
     fn from(notif: AdyenNotificationRequestItemWH) -> Self {
+        let (result_code, refusal_reason) = match (&notif.event_code, notif.success.as_str()) {
+            // The offer of an asynchronous payment is closed once it expires, without the
+            // customer having completed the payment
+            (WebhookEventCode::OfferClosed, "true") => (
+                AdyenStatus::Cancelled,
+                Some("The payment expired before it was completed".to_string()),
+            ),
+            (WebhookEventCode::Pending, _) => (AdyenStatus::Pending, None),
+            (_, "true") => (AdyenStatus::Authorised, None),
+            _ => (AdyenStatus::Refused, None),
+        };
         Self {
             psp_reference: notif.psp_reference,
             merchant_reference: notif.merchant_reference,
-            result_code: match notif.success.as_str() {
-                "true" => AdyenStatus::Authorised,
-                _ => AdyenStatus::Refused,
-            },
+            result_code,
             amount: Some(Amount {
                 value: notif.amount.value,
                 currency: notif.amount.currency,
             }),
-            refusal_reason: None,
+            refusal_reason,
             refusal_reason_code: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_expiry_time_with_offset_is_converted_to_utc() {
+        assert_eq!(
+            parse_expiry_time("2023-03-08T12:30:00+01:00"),
+            Some(datetime!(2023-03-08 11:30:00))
+        );
+    }
+
+    #[test]
+    fn test_parse_expiry_time_without_offset_is_treated_as_utc() {
+        assert_eq!(
+            parse_expiry_time("2023-03-08T12:30:00"),
+            Some(datetime!(2023-03-08 12:30:00))
+        );
+    }
+
+    #[test]
+    fn test_parse_expiry_time_rejects_invalid_time() {
+        assert_eq!(parse_expiry_time("next tuesday"), None);
+    }
+
+    #[test]
+    fn test_transfer_instructions_to_bank_transfer_instructions() {
+        let instructions: AdyenTransferInstructions = serde_json::from_value(serde_json::json!({
+            "beneficiary": "Adyen",
+            "iban": "NL13TEST0123456789",
+            "bic": "TESTNL01",
+            "reference": "9912345678",
+            "totalAmount": { "currency": "EUR", "value": 6540 },
+            "expiresAt": "2023-03-15T10:00:00Z",
+            "downloadUrl": "https://test.adyen.com/instructions"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            api_models::payments::BankTransferInstructions::from(instructions),
+            api_models::payments::BankTransferInstructions {
+                account_holder_name: Some("Adyen".to_string()),
+                iban: Some("NL13TEST0123456789".to_string()),
+                bic: Some("TESTNL01".to_string()),
+                reference: Some("9912345678".to_string()),
+                amount: Some(6540),
+                currency: Some("EUR".to_string()),
+                expires_at: Some(datetime!(2023-03-15 10:00:00)),
+                instructions_url: Some("https://test.adyen.com/instructions".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_webhook_event_code_to_incoming_webhook_event() {
+        assert_eq!(
+            IncomingWebhookEvent::from(WebhookEventCode::Pending),
+            IncomingWebhookEvent::PaymentIntentProcessing
+        );
+        assert_eq!(
+            IncomingWebhookEvent::from(WebhookEventCode::OfferClosed),
+            IncomingWebhookEvent::PaymentIntentExpired
+        );
+        assert_eq!(
+            IncomingWebhookEvent::from(WebhookEventCode::Authorisation),
+            IncomingWebhookEvent::PaymentIntentSuccess
+        );
+    }
+}
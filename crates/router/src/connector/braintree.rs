@@ -2,7 +2,7 @@ mod transformers;
 
 use std::fmt::Debug;
 
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 
 use self::transformers as braintree;
 use crate::{
@@ -12,7 +12,7 @@ use crate::{
         errors::{self, CustomResult},
         payments,
     },
-    headers, services,
+    headers, routes, services,
     types::{
         self,
         api::{self, ConnectorCommon},
@@ -65,20 +65,20 @@ impl
 
 impl
     services::ConnectorIntegration<
-        api::Session,
+        api::CreateClientToken,
         types::PaymentsSessionData,
         types::PaymentsResponseData,
     > for Braintree
 {
     fn get_headers(
         &self,
-        req: &types::PaymentsSessionRouterData,
+        req: &types::ClientTokenRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
         let mut headers = vec![
             (
                 headers::CONTENT_TYPE.to_string(),
-                types::PaymentsSessionType::get_content_type(self).to_string(),
+                types::ClientTokenType::get_content_type(self).to_string(),
             ),
             (headers::X_ROUTER.to_string(), "test".to_string()),
             (headers::X_API_VERSION.to_string(), "6".to_string()),
@@ -93,6 +93,159 @@ impl
         "application/json"
     }
 
+    fn get_url(
+        &self,
+        req: &types::ClientTokenRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let auth_type = braintree::BraintreeAuthType::try_from(&req.connector_auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(format!(
+            "{}merchants/{}/client_token",
+            self.base_url(connectors),
+            auth_type.merchant_id,
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ClientTokenRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ClientTokenType::get_url(self, req, connectors)?)
+                .headers(types::ClientTokenType::get_headers(self, req, connectors)?)
+                .body(types::ClientTokenType::get_request_body(self, req)?)
+                .build(),
+        );
+
+        Ok(request)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: braintree::ErrorResponse = res
+            .response
+            .parse_struct("Error Response")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: response.api_error_response.message,
+            reason: None,
+        })
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ClientTokenRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let braintree_session_request =
+            utils::Encode::<braintree::BraintreeSessionRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        Ok(Some(braintree_session_request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ClientTokenRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::ClientTokenRouterData, errors::ConnectorError> {
+        let response: braintree::BraintreeSessionTokenResponse = res
+            .response
+            .parse_struct("braintree SessionTokenResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl
+    services::ConnectorIntegration<
+        api::Session,
+        types::PaymentsSessionData,
+        types::PaymentsResponseData,
+    > for Braintree
+{
+    /// The PayPal order is created with the authorization fingerprint of a client token, which is
+    /// created before the order
+    async fn execute_pretasks(
+        &self,
+        router_data: &mut types::PaymentsSessionRouterData,
+        app_state: &routes::AppState,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        if router_data.request.client_token.is_some() {
+            return Ok(());
+        }
+
+        let integ: Box<
+            &(dyn services::ConnectorIntegration<
+                api::CreateClientToken,
+                types::PaymentsSessionData,
+                types::PaymentsResponseData,
+            > + Send
+                  + Sync
+                  + 'static),
+        > = Box::new(&Self);
+        let client_token_router_data: types::ClientTokenRouterData =
+            payments::access_token::router_data_type_conversion(
+                router_data.clone(),
+                router_data.request.clone(),
+                Err(types::ErrorResponse::default()),
+            );
+        let resp = services::execute_connector_processing_step(
+            app_state,
+            integ,
+            &client_token_router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await?;
+
+        match resp.response {
+            Ok(types::PaymentsResponseData::SessionTokenResponse { session_token }) => {
+                router_data.request.client_token = Some(session_token);
+                Ok(())
+            }
+            Ok(_) => Err(errors::ConnectorError::ResponseHandlingFailed).into_report(),
+            Err(error) => Err(errors::ConnectorError::ProcessingStepFailed(None))
+                .into_report()
+                .attach_printable(format!(
+                    "Failed to create the client token on braintree: {}",
+                    error.message
+                )),
+        }
+    }
+
+    fn get_headers(
+        &self,
+        _req: &types::PaymentsSessionRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // The client API is authorized by the fingerprint of the client token in the body
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PaymentsSessionType::get_content_type(self).to_string(),
+            ),
+            (headers::ACCEPT.to_string(), "application/json".to_string()),
+        ])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
     fn get_url(
         &self,
         req: &types::PaymentsSessionRouterData,
@@ -101,7 +254,7 @@ impl
         let auth_type = braintree::BraintreeAuthType::try_from(&req.connector_auth_type)
             .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
         Ok(format!(
-            "{}/merchants/{}/client_token",
+            "{}merchants/{}/client_api/v1/paypal_hermes/create_payment_resource",
             self.base_url(connectors),
             auth_type.merchant_id,
         ))
@@ -130,15 +283,15 @@ impl
         &self,
         res: types::Response,
     ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
-        let response: braintree::ErrorResponse = res
+        let response: braintree::BraintreeClientApiErrorResponse = res
             .response
-            .parse_struct("Error Response")
+            .parse_struct("braintree ClientApiErrorResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
         Ok(types::ErrorResponse {
             status_code: res.status_code,
             code: consts::NO_ERROR_CODE.to_string(),
-            message: response.api_error_response.message,
+            message: response.error.message,
             reason: None,
         })
     }
@@ -147,11 +300,11 @@ impl
         &self,
         req: &types::PaymentsSessionRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
-        let braintree_session_request =
-            utils::Encode::<braintree::BraintreeSessionRequest>::convert_and_encode(req)
+        let braintree_order_request =
+            utils::Encode::<braintree::BraintreePaypalOrderRequest>::convert_and_encode(req)
                 .change_context(errors::ConnectorError::RequestEncodingFailed)?;
 
-        Ok(Some(braintree_session_request))
+        Ok(Some(braintree_order_request))
     }
 
     fn handle_response(
@@ -159,9 +312,9 @@ impl
         data: &types::PaymentsSessionRouterData,
         res: types::Response,
     ) -> CustomResult<types::PaymentsSessionRouterData, errors::ConnectorError> {
-        let response: braintree::BraintreeSessionTokenResponse = res
+        let response: braintree::BraintreePaypalOrderResponse = res
             .response
-            .parse_struct("braintree SessionTokenResponse")
+            .parse_struct("braintree PaypalOrderResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         types::RouterData::try_from(types::ResponseRouterData {
             response,
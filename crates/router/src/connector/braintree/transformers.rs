@@ -1,5 +1,6 @@
 use api_models::payments;
 use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +9,7 @@ use crate::{
     consts,
     core::errors,
     types::{self, api, storage::enums},
+    utils::ByteSliceExt,
 };
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -33,9 +35,9 @@ pub struct BraintreeSessionRequest {
     client_token: BraintreeApiVersion,
 }
 
-impl TryFrom<&types::PaymentsSessionRouterData> for BraintreeSessionRequest {
+impl TryFrom<&types::ClientTokenRouterData> for BraintreeSessionRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(_item: &types::PaymentsSessionRouterData) -> Result<Self, Self::Error> {
+    fn try_from(_item: &types::ClientTokenRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             client_token: BraintreeApiVersion {
                 version: "2".to_string(),
@@ -241,11 +243,122 @@ impl<F, T>
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                session_token: item.response.client_token.value,
+            }),
+            ..item.data
+        })
+    }
+}
+
+/// Request to create a PayPal order, which Braintree calls a payment resource
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct BraintreePaypalOrderRequest {
+    authorization_fingerprint: Secret<String>,
+    amount: String,
+    currency_iso_code: String,
+    return_url: String,
+    cancel_url: String,
+    offer_paypal_credit: bool,
+}
+
+impl TryFrom<&types::PaymentsSessionRouterData> for BraintreePaypalOrderRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsSessionRouterData) -> Result<Self, Self::Error> {
+        let client_token = item.request.client_token.as_deref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "client_token",
+            },
+        )?;
+        // The customer returns to the SDK in the PayPal popup, after approving or cancelling the
+        // order
+        let return_url = item
+            .return_url
+            .clone()
+            .or_else(|| item.router_return_url.clone())
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "return_url",
+            })?;
+
+        Ok(Self {
+            authorization_fingerprint: get_authorization_fingerprint(client_token)?,
+            amount: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            currency_iso_code: item.request.currency.to_string(),
+            cancel_url: return_url.clone(),
+            return_url,
+            offer_paypal_credit: false,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BraintreeClientTokenData {
+    authorization_fingerprint: Secret<String>,
+}
+
+/// The client token is a base64 encoded JSON object, which has the fingerprint that authorizes the
+/// requests to the client API
+pub fn get_authorization_fingerprint(
+    client_token: &str,
+) -> Result<Secret<String>, error_stack::Report<errors::ConnectorError>> {
+    let client_token = consts::BASE64_ENGINE
+        .decode(client_token)
+        .into_report()
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+        .attach_printable("Failed to decode the braintree client token")?;
+    let client_token_data: BraintreeClientTokenData = client_token
+        .parse_struct("BraintreeClientTokenData")
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+    Ok(client_token_data.authorization_fingerprint)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BraintreePaypalOrderResponse {
+    payment_resource: PaypalPaymentResource,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaypalPaymentResource {
+    payment_token: String,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            BraintreePaypalOrderResponse,
+            types::PaymentsSessionData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsSessionData, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            BraintreePaypalOrderResponse,
+            types::PaymentsSessionData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let session_token = item.data.request.client_token.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "client_token",
+            },
+        )?;
+
         Ok(Self {
             response: Ok(types::PaymentsResponseData::SessionResponse {
                 session_token: types::api::SessionToken::Paypal(Box::new(
                     payments::PaypalSessionTokenResponse {
-                        session_token: item.response.client_token.value,
+                        session_token,
+                        order_id: item.response.payment_resource.payment_token,
+                        connector: item.data.connector.clone(),
                     },
                 )),
             }),
@@ -292,6 +405,11 @@ pub struct ApiErrorResponse {
     pub message: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BraintreeClientApiErrorResponse {
+    pub error: ApiErrorResponse,
+}
+
 #[derive(Default, Debug, Clone, Serialize)]
 pub struct BraintreeRefundRequest {
     transaction: Amount,
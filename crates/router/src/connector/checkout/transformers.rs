@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
+    connector::utils::{self, CardData},
     core::errors,
     pii, services,
     types::{self, api, storage::enums, transformers::ForeignFrom},
@@ -16,10 +17,51 @@ pub struct CardSource {
     pub expiry_year: Option<pii::Secret<String>>,
 }
 
+/// Network token sent in place of the card number
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
+    pub token: pii::Secret<String, pii::CardNumber>,
+    pub expiry_month: pii::Secret<String>,
+    pub expiry_year: pii::Secret<String>,
+    pub token_type: NetworkTokenType,
+    pub cryptogram: Option<pii::Secret<String>>,
+    pub eci: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkTokenType {
+    /// Visa Token Service
+    Vts,
+    /// Mastercard Digital Enablement Service
+    Mdes,
+}
+
+impl TryFrom<utils::CardIssuer> for NetworkTokenType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(issuer: utils::CardIssuer) -> Result<Self, Self::Error> {
+        match issuer {
+            utils::CardIssuer::Visa => Ok(Self::Vts),
+            utils::CardIssuer::Master | utils::CardIssuer::Maestro => Ok(Self::Mdes),
+            utils::CardIssuer::AmericanExpress | utils::CardIssuer::Discover => {
+                Err(errors::ConnectorError::NotSupported {
+                    payment_method: format!("{issuer} network token"),
+                    connector: "checkout",
+                    payment_experience: api_models::enums::PaymentMethod::Card.to_string(),
+                }
+                .into())
+            }
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum Source {
     Card(CardSource),
+    NetworkToken(NetworkTokenSource),
 }
 
 pub struct CheckoutAuthType {
@@ -72,7 +114,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             api::PaymentMethodData::Card(ref ccard) => Some(ccard),
             api::PaymentMethodData::Wallet(_)
             | api::PaymentMethodData::PayLater(_)
-            | api::PaymentMethodData::BankRedirect(_) => None,
+            | api::PaymentMethodData::BankRedirect(_)
+            | api::PaymentMethodData::BankDebit(_)
+            | api::PaymentMethodData::BankTransfer(_) => None,
         };
 
         let three_ds = match item.auth_type {
@@ -102,12 +146,23 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             Some(enums::CaptureMethod::Automatic)
         );
 
-        let source_var = Source::Card(CardSource {
-            source_type: Some("card".to_owned()),
-            number: ccard.map(|x| x.card_number.clone()),
-            expiry_month: ccard.map(|x| x.card_exp_month.clone()),
-            expiry_year: ccard.map(|x| x.card_exp_year.clone()),
-        });
+        let source_var = match (ccard, item.request.network_token.as_ref()) {
+            (Some(ccard), Some(network_token)) => Source::NetworkToken(NetworkTokenSource {
+                source_type: "network_token".to_owned(),
+                token: ccard.card_number.clone(),
+                expiry_month: ccard.card_exp_month.clone(),
+                expiry_year: ccard.card_exp_year.clone(),
+                token_type: ccard.get_card_issuer()?.try_into()?,
+                cryptogram: network_token.cryptogram.clone(),
+                eci: network_token.eci.clone(),
+            }),
+            _ => Source::Card(CardSource {
+                source_type: Some("card".to_owned()),
+                number: ccard.map(|x| x.card_number.clone()),
+                expiry_month: ccard.map(|x| x.card_exp_month.clone()),
+                expiry_year: ccard.map(|x| x.card_exp_year.clone()),
+            }),
+        };
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
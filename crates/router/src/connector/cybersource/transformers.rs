@@ -17,6 +17,8 @@ pub struct CybersourcePaymentsRequest {
     processing_information: ProcessingInformation,
     payment_information: PaymentInformation,
     order_information: OrderInformationWithBill,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consumer_authentication_information: Option<ConsumerAuthenticationInformation>,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -33,9 +35,24 @@ pub struct CaptureOptions {
     total_capture_count: u32,
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct PaymentInformation {
-    card: Card,
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PaymentInformation {
+    Card {
+        card: Card,
+    },
+    #[serde(rename_all = "camelCase")]
+    NetworkToken {
+        tokenized_card: TokenizedCard,
+    },
+}
+
+impl Default for PaymentInformation {
+    fn default() -> Self {
+        Self::Card {
+            card: Card::default(),
+        }
+    }
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -47,6 +64,30 @@ pub struct Card {
     security_code: Secret<String>,
 }
 
+/// Network token sent in place of the card number
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenizedCard {
+    number: Secret<String, pii::CardNumber>,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    cryptogram: Option<Secret<String>>,
+    transaction_type: TokenizedCardTransactionType,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub enum TokenizedCardTransactionType {
+    /// Token of a card stored on file
+    #[serde(rename = "3")]
+    StoredCredentials,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsumerAuthenticationInformation {
+    eci_raw: String,
+}
+
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderInformationWithBill {
@@ -129,14 +170,36 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
                     bill_to,
                 };
 
-                let payment_information = PaymentInformation {
-                    card: Card {
-                        number: ccard.card_number,
-                        expiration_month: ccard.card_exp_month,
-                        expiration_year: ccard.card_exp_year,
-                        security_code: ccard.card_cvc,
-                    },
-                };
+                let (payment_information, consumer_authentication_information) =
+                    match item.request.network_token.as_ref() {
+                        Some(network_token) => (
+                            PaymentInformation::NetworkToken {
+                                tokenized_card: TokenizedCard {
+                                    number: ccard.card_number,
+                                    expiration_month: ccard.card_exp_month,
+                                    expiration_year: ccard.card_exp_year,
+                                    cryptogram: network_token.cryptogram.clone(),
+                                    transaction_type:
+                                        TokenizedCardTransactionType::StoredCredentials,
+                                },
+                            },
+                            network_token
+                                .eci
+                                .clone()
+                                .map(|eci_raw| ConsumerAuthenticationInformation { eci_raw }),
+                        ),
+                        None => (
+                            PaymentInformation::Card {
+                                card: Card {
+                                    number: ccard.card_number,
+                                    expiration_month: ccard.card_exp_month,
+                                    expiration_year: ccard.card_exp_year,
+                                    security_code: ccard.card_cvc,
+                                },
+                            },
+                            None,
+                        ),
+                    };
 
                 let processing_information = ProcessingInformation {
                     capture: matches!(
@@ -150,6 +213,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
                     processing_information,
                     payment_information,
                     order_information,
+                    consumer_authentication_information,
                 })
             }
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
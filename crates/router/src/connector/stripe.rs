@@ -16,7 +16,7 @@ use crate::{
         payments,
     },
     db::StorageInterface,
-    headers, services,
+    headers, routes, services,
     types::{
         self,
         api::{self, ConnectorCommon},
@@ -301,6 +301,109 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::ConnectorCustomerType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", self.base_url(connectors), "v1/customers"))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req = stripe::CustomerRequest::try_from(req)?;
+        let stripe_req = utils::Encode::<stripe::CustomerRequest>::encode(&req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ConnectorCustomerType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::ConnectorCustomerType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::ConnectorCustomerType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ConnectorCustomerRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::ConnectorCustomerRouterData, errors::ConnectorError> {
+        let response: stripe::StripeCustomerResponse = res
+            .response
+            .parse_struct("StripeCustomerResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
 impl
     services::ConnectorIntegration<
         api::Authorize,
@@ -308,6 +411,61 @@ impl
         types::PaymentsResponseData,
     > for Stripe
 {
+    /// Bank transfers which are funded from the balance of the customer require a customer on
+    /// Stripe, which is created before the payment is authorized unless one is already stored for
+    /// the customer
+    async fn execute_pretasks(
+        &self,
+        router_data: &mut types::PaymentsAuthorizeRouterData,
+        app_state: &routes::AppState,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let customer_data = match stripe::get_connector_customer_data(&router_data.request) {
+            Some(customer_data) if router_data.request.connector_customer.is_none() => {
+                customer_data
+            }
+            _ => return Ok(()),
+        };
+
+        let integ: Box<
+            &(dyn services::ConnectorIntegration<
+                api::CreateConnectorCustomer,
+                types::ConnectorCustomerData,
+                types::PaymentsResponseData,
+            > + Send
+                  + Sync
+                  + 'static),
+        > = Box::new(&Self);
+        let customer_router_data: types::ConnectorCustomerRouterData =
+            payments::access_token::router_data_type_conversion(
+                router_data.clone(),
+                customer_data,
+                Err(types::ErrorResponse::default()),
+            );
+        let resp = services::execute_connector_processing_step(
+            app_state,
+            integ,
+            &customer_router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await?;
+
+        match resp.response {
+            Ok(types::PaymentsResponseData::ConnectorCustomerResponse {
+                connector_customer_id,
+            }) => {
+                router_data.request.connector_customer = Some(connector_customer_id);
+                Ok(())
+            }
+            Ok(_) => Err(errors::ConnectorError::ResponseHandlingFailed).into_report(),
+            Err(error) => Err(errors::ConnectorError::ProcessingStepFailed(None))
+                .into_report()
+                .attach_printable(format!(
+                    "Failed to create the customer on stripe: {}",
+                    error.message
+                )),
+        }
+    }
+
     fn get_headers(
         &self,
         req: &types::PaymentsAuthorizeRouterData,
@@ -953,6 +1111,8 @@ impl api::IncomingWebhook for Stripe {
         Ok(match details.event_type.as_str() {
             "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
             "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            "payment_intent.processing" => api::IncomingWebhookEvent::PaymentIntentProcessing,
+            "payment_intent.requires_action" => api::IncomingWebhookEvent::PaymentActionRequired,
             _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
         })
     }
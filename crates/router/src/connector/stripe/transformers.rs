@@ -1,7 +1,10 @@
 use std::str::FromStr;
 
 use api_models::{self, enums as api_enums, payments};
-use common_utils::{fp_utils, pii::Email};
+use common_utils::{
+    fp_utils,
+    pii::{Email, IpAddress},
+};
 use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
 use serde::{Deserialize, Serialize};
@@ -89,6 +92,20 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    #[serde(flatten)]
+    pub mandate_data: Option<StripeMandateRequest>,
+    pub customer: Option<String>,
+    #[serde(flatten)]
+    pub network_token: Option<StripeNetworkTokenData>,
+}
+
+/// Cryptogram of the network token sent in place of the card number
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeNetworkTokenData {
+    #[serde(rename = "payment_method_options[card][network_token][cryptogram]")]
+    pub cryptogram: Secret<String>,
+    #[serde(rename = "payment_method_options[card][network_token][electronic_commerce_indicator]")]
+    pub electronic_commerce_indicator: Option<String>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -104,6 +121,10 @@ pub struct SetupIntentRequest {
     pub off_session: Option<bool>,
     #[serde(flatten)]
     pub payment_data: StripePaymentMethodData,
+    #[serde(flatten)]
+    pub billing: StripeBillingAddress,
+    #[serde(flatten)]
+    pub mandate_data: Option<StripeMandateRequest>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -189,6 +210,99 @@ pub struct StripeBankRedirectData {
     pub bank_specific_data: Option<BankSpecificData>,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum BankDebitData {
+    Ach {
+        #[serde(rename = "payment_method_data[us_bank_account][account_holder_type]")]
+        account_holder_type: String,
+        #[serde(rename = "payment_method_data[us_bank_account][account_number]")]
+        account_number: Secret<String>,
+        #[serde(rename = "payment_method_data[us_bank_account][routing_number]")]
+        routing_number: Secret<String>,
+    },
+    Sepa {
+        #[serde(rename = "payment_method_data[sepa_debit][iban]")]
+        iban: Secret<String>,
+    },
+    Becs {
+        #[serde(rename = "payment_method_data[au_becs_debit][account_number]")]
+        account_number: Secret<String>,
+        #[serde(rename = "payment_method_data[au_becs_debit][bsb_number]")]
+        bsb_number: Secret<String>,
+    },
+    Bacs {
+        #[serde(rename = "payment_method_data[bacs_debit][account_number]")]
+        account_number: Secret<String>,
+        #[serde(rename = "payment_method_data[bacs_debit][sort_code]")]
+        sort_code: Secret<String>,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeBankDebitData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(flatten)]
+    pub bank_specific_data: BankDebitData,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeBankTransferData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(flatten)]
+    pub customer_balance_options: Option<StripeCustomerBalanceOptions>,
+}
+
+/// ACH and SEPA credit transfers are received by Stripe into the balance of the customer, from
+/// which the payment is funded.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeCustomerBalanceOptions {
+    #[serde(rename = "payment_method_options[customer_balance][funding_type]")]
+    pub funding_type: StripeFundingType,
+    #[serde(rename = "payment_method_options[customer_balance][bank_transfer][type]")]
+    pub bank_transfer_type: StripeBankTransferType,
+    #[serde(
+        rename = "payment_method_options[customer_balance][bank_transfer][eu_bank_transfer][country]"
+    )]
+    pub eu_bank_transfer_country: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeFundingType {
+    BankTransfer,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeBankTransferType {
+    UsBankTransfer,
+    EuBankTransfer,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeMandateRequest {
+    #[serde(rename = "mandate_data[customer_acceptance][type]")]
+    pub mandate_type: StripeMandateType,
+    #[serde(rename = "mandate_data[customer_acceptance][online][ip_address]")]
+    pub ip_address: Option<Secret<String, IpAddress>>,
+    #[serde(rename = "mandate_data[customer_acceptance][online][user_agent]")]
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeMandateType {
+    Online,
+    Offline,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum StripePaymentMethodData {
@@ -196,6 +310,8 @@ pub enum StripePaymentMethodData {
     PayLater(StripePayLaterData),
     Wallet,
     BankRedirect(StripeBankRedirectData),
+    BankDebit(StripeBankDebitData),
+    BankTransfer(StripeBankTransferData),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize, Clone)]
@@ -209,6 +325,16 @@ pub enum StripePaymentMethodType {
     Giropay,
     Ideal,
     Sofort,
+    #[serde(rename = "us_bank_account")]
+    Ach,
+    #[serde(rename = "sepa_debit")]
+    Sepa,
+    #[serde(rename = "au_becs_debit")]
+    Becs,
+    #[serde(rename = "bacs_debit")]
+    Bacs,
+    CustomerBalance,
+    Multibanco,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize, Clone)]
@@ -480,6 +606,185 @@ fn get_bank_specific_data(
     }
 }
 
+fn get_bank_debit_data(
+    bank_debit_data: &payments::BankDebitData,
+) -> (StripePaymentMethodType, BankDebitData, StripeBillingAddress) {
+    match bank_debit_data {
+        payments::BankDebitData::AchBankDebit {
+            billing_details,
+            account_number,
+            routing_number,
+        } => (
+            StripePaymentMethodType::Ach,
+            BankDebitData::Ach {
+                account_holder_type: "individual".to_string(),
+                account_number: account_number.to_owned(),
+                routing_number: routing_number.to_owned(),
+            },
+            StripeBillingAddress::from(billing_details),
+        ),
+        payments::BankDebitData::SepaBankDebit {
+            billing_details,
+            iban,
+        } => (
+            StripePaymentMethodType::Sepa,
+            BankDebitData::Sepa {
+                iban: iban.to_owned(),
+            },
+            StripeBillingAddress::from(billing_details),
+        ),
+        payments::BankDebitData::BecsBankDebit {
+            billing_details,
+            account_number,
+            bsb_number,
+        } => (
+            StripePaymentMethodType::Becs,
+            BankDebitData::Becs {
+                account_number: account_number.to_owned(),
+                bsb_number: bsb_number.to_owned(),
+            },
+            StripeBillingAddress::from(billing_details),
+        ),
+        payments::BankDebitData::BacsBankDebit {
+            billing_details,
+            account_number,
+            sort_code,
+        } => (
+            StripePaymentMethodType::Bacs,
+            BankDebitData::Bacs {
+                account_number: account_number.to_owned(),
+                sort_code: sort_code.to_owned(),
+            },
+            StripeBillingAddress::from(billing_details),
+        ),
+    }
+}
+
+fn get_bank_transfer_data(
+    bank_transfer_data: &payments::BankTransferData,
+) -> (
+    StripePaymentMethodType,
+    Option<StripeCustomerBalanceOptions>,
+    StripeBillingAddress,
+) {
+    match bank_transfer_data {
+        payments::BankTransferData::AchBankTransfer { billing_details } => (
+            StripePaymentMethodType::CustomerBalance,
+            Some(StripeCustomerBalanceOptions {
+                funding_type: StripeFundingType::BankTransfer,
+                bank_transfer_type: StripeBankTransferType::UsBankTransfer,
+                eu_bank_transfer_country: None,
+            }),
+            StripeBillingAddress::from(billing_details),
+        ),
+        payments::BankTransferData::SepaBankTransfer {
+            billing_details,
+            country,
+        } => (
+            StripePaymentMethodType::CustomerBalance,
+            Some(StripeCustomerBalanceOptions {
+                funding_type: StripeFundingType::BankTransfer,
+                bank_transfer_type: StripeBankTransferType::EuBankTransfer,
+                eu_bank_transfer_country: Some(country.to_owned()),
+            }),
+            StripeBillingAddress::from(billing_details),
+        ),
+        payments::BankTransferData::Multibanco { billing_details } => (
+            StripePaymentMethodType::Multibanco,
+            None,
+            StripeBillingAddress::from(billing_details),
+        ),
+    }
+}
+
+/// Details of the customer to be created on Stripe for a payment, which is required for the bank
+/// transfers that are funded from the balance of the customer.
+pub fn get_connector_customer_data(
+    request: &types::PaymentsAuthorizeData,
+) -> Option<types::ConnectorCustomerData> {
+    match &request.payment_method_data {
+        payments::PaymentMethodData::BankTransfer(
+            payments::BankTransferData::AchBankTransfer { billing_details }
+            | payments::BankTransferData::SepaBankTransfer {
+                billing_details, ..
+            },
+        ) => Some(types::ConnectorCustomerData {
+            email: Some(billing_details.email.to_owned()),
+            name: billing_details.name.to_owned(),
+        }),
+        _ => None,
+    }
+}
+
+impl From<&payments::BankTransferBilling> for StripeBillingAddress {
+    fn from(billing_details: &payments::BankTransferBilling) -> Self {
+        Self {
+            email: Some(billing_details.email.to_owned()),
+            name: billing_details.name.to_owned(),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&payments::BankDebitBilling> for StripeBillingAddress {
+    fn from(billing_details: &payments::BankDebitBilling) -> Self {
+        let address = billing_details.address.as_ref();
+        Self {
+            email: Some(billing_details.email.to_owned()),
+            country: address.and_then(|address| address.country.to_owned()),
+            name: Some(billing_details.name.to_owned()),
+            city: address.and_then(|address| address.city.to_owned()),
+            address_line1: address.and_then(|address| address.line1.to_owned()),
+            address_line2: address.and_then(|address| address.line2.to_owned()),
+            zip_code: address.and_then(|address| address.zip.to_owned()),
+            state: address.and_then(|address| address.state.to_owned()),
+        }
+    }
+}
+
+/// Bank debits are only accepted by Stripe along with the customer's acceptance of the mandate,
+/// which is taken from the mandate data of the payment or, failing that, from the browser
+/// information of the customer.
+fn get_bank_debit_mandate_data(
+    setup_mandate_details: Option<&payments::MandateData>,
+    browser_info: Option<&types::BrowserInformation>,
+) -> Result<StripeMandateRequest, errors::ConnectorError> {
+    match setup_mandate_details.map(|mandate_data| &mandate_data.customer_acceptance) {
+        Some(customer_acceptance) => match customer_acceptance.acceptance_type {
+            payments::AcceptanceType::Online => {
+                let online = customer_acceptance.online.as_ref().ok_or(
+                    errors::ConnectorError::MissingRequiredField {
+                        field_name: "mandate_data.customer_acceptance.online",
+                    },
+                )?;
+                Ok(StripeMandateRequest {
+                    mandate_type: StripeMandateType::Online,
+                    ip_address: Some(online.ip_address.to_owned()),
+                    user_agent: Some(online.user_agent.to_owned()),
+                })
+            }
+            payments::AcceptanceType::Offline => Ok(StripeMandateRequest {
+                mandate_type: StripeMandateType::Offline,
+                ip_address: None,
+                user_agent: None,
+            }),
+        },
+        None => browser_info
+            .and_then(|browser_info| {
+                browser_info
+                    .ip_address
+                    .map(|ip_address| StripeMandateRequest {
+                        mandate_type: StripeMandateType::Online,
+                        ip_address: Some(Secret::new(ip_address.to_string())),
+                        user_agent: Some(browser_info.user_agent.to_owned()),
+                    })
+            })
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "mandate_data.customer_acceptance",
+            }),
+    }
+}
+
 fn create_stripe_payment_method(
     pm_type: Option<&enums::PaymentMethodType>,
     experience: Option<&enums::PaymentExperience>,
@@ -552,6 +857,31 @@ fn create_stripe_payment_method(
                 billing_address,
             ))
         }
+        payments::PaymentMethodData::BankDebit(bank_debit_data) => {
+            let (pm_type, bank_debit_data, billing_address) = get_bank_debit_data(bank_debit_data);
+            Ok((
+                StripePaymentMethodData::BankDebit(StripeBankDebitData {
+                    payment_method_types: pm_type.clone(),
+                    payment_method_data_type: pm_type.clone(),
+                    bank_specific_data: bank_debit_data,
+                }),
+                pm_type,
+                billing_address,
+            ))
+        }
+        payments::PaymentMethodData::BankTransfer(bank_transfer_data) => {
+            let (pm_type, customer_balance_options, billing_address) =
+                get_bank_transfer_data(bank_transfer_data);
+            Ok((
+                StripePaymentMethodData::BankTransfer(StripeBankTransferData {
+                    payment_method_types: pm_type.clone(),
+                    payment_method_data_type: pm_type.clone(),
+                    customer_balance_options,
+                }),
+                pm_type,
+                billing_address,
+            ))
+        }
         _ => Err(errors::ConnectorError::NotImplemented(
             "stripe does not support this payment method".to_string(),
         )),
@@ -595,6 +925,14 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             None => StripeShippingAddress::default(),
         };
 
+        let mandate_data = match item.request.payment_method_data {
+            payments::PaymentMethodData::BankDebit(_) => Some(get_bank_debit_mandate_data(
+                item.request.setup_mandate_details.as_ref(),
+                item.request.browser_info.as_ref(),
+            )?),
+            _ => None,
+        };
+
         let (payment_data, mandate, billing_address) = {
             match item
                 .request
@@ -616,6 +954,16 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                         &payment_method_type,
                     )?;
 
+                    fp_utils::when(
+                        payment_method_type == StripePaymentMethodType::CustomerBalance
+                            && item.request.connector_customer.is_none(),
+                        || {
+                            Err(errors::ConnectorError::MissingRequiredField {
+                                field_name: "connector_customer",
+                            })
+                        },
+                    )?;
+
                     (Some(payment_method_data), None, billing_address)
                 }
                 Some(mandate_id) => (None, Some(mandate_id), StripeBillingAddress::default()),
@@ -641,6 +989,21 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
             payment_data,
             mandate,
+            mandate_data,
+            customer: item.request.connector_customer.clone(),
+            network_token: item
+                .request
+                .network_token
+                .as_ref()
+                .and_then(|network_token| {
+                    network_token
+                        .cryptogram
+                        .clone()
+                        .map(|cryptogram| StripeNetworkTokenData {
+                            cryptogram,
+                            electronic_commerce_indicator: network_token.eci.clone(),
+                        })
+                }),
         })
     }
 }
@@ -652,13 +1015,33 @@ impl TryFrom<&types::VerifyRouterData> for SetupIntentRequest {
         let metadata_txn_id = format!("{}_{}_{}", item.merchant_id, item.payment_id, "1");
         let metadata_txn_uuid = Uuid::new_v4().to_string();
 
-        //Only cards supported for mandates
-        let pm_type = StripePaymentMethodType::Card;
-        let payment_data = StripePaymentMethodData::try_from((
-            item.request.payment_method_data.clone(),
-            item.auth_type,
-            pm_type,
-        ))?;
+        let (payment_data, billing, mandate_data) = match &item.request.payment_method_data {
+            // Bank debits are set up along with the customer's acceptance of the mandate
+            api::PaymentMethodData::BankDebit(bank_debit_data) => {
+                let (pm_type, bank_debit_data, billing_address) =
+                    get_bank_debit_data(bank_debit_data);
+                let mandate_data =
+                    get_bank_debit_mandate_data(item.request.setup_mandate_details.as_ref(), None)?;
+                (
+                    StripePaymentMethodData::BankDebit(StripeBankDebitData {
+                        payment_method_types: pm_type.clone(),
+                        payment_method_data_type: pm_type,
+                        bank_specific_data: bank_debit_data,
+                    }),
+                    billing_address,
+                    Some(mandate_data),
+                )
+            }
+            payment_method_data => {
+                let pm_type = StripePaymentMethodType::Card;
+                let payment_data = StripePaymentMethodData::try_from((
+                    payment_method_data.clone(),
+                    item.auth_type,
+                    pm_type,
+                ))?;
+                (payment_data, StripeBillingAddress::default(), None)
+            }
+        };
 
         Ok(Self {
             confirm: true,
@@ -668,6 +1051,46 @@ impl TryFrom<&types::VerifyRouterData> for SetupIntentRequest {
             payment_data,
             off_session: item.request.off_session,
             usage: item.request.setup_future_usage,
+            billing,
+            mandate_data,
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct CustomerRequest {
+    pub email: Option<Secret<String, Email>>,
+    pub name: Option<Secret<String>>,
+}
+
+impl TryFrom<&types::ConnectorCustomerRouterData> for CustomerRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorCustomerRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            email: item.request.email.to_owned(),
+            name: item.request.name.to_owned(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeCustomerResponse {
+    pub id: String,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, StripeCustomerResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, StripeCustomerResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::ConnectorCustomerResponse {
+                connector_customer_id: item.response.id,
+            }),
+            ..item.data
         })
     }
 }
@@ -794,12 +1217,19 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PaymentIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data =
-            item.response
-                .next_action
-                .map(|StripeNextActionResponse::RedirectToUrl(response)| {
-                    services::RedirectForm::from((response.url, services::Method::Get))
-                });
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_url)
+            .map(|url| services::RedirectForm::from((url, services::Method::Get)));
+        let connector_metadata = item
+            .response
+            .next_action
+            .as_ref()
+            .map(StripeNextActionResponse::get_connector_metadata)
+            .transpose()?
+            .flatten();
 
         let mandate_reference =
             item.response
@@ -821,7 +1251,7 @@ impl<F, T>
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data,
                 mandate_reference,
-                connector_metadata: None,
+                connector_metadata,
             }),
             amount_captured: Some(item.response.amount_received),
             ..item.data
@@ -842,11 +1272,19 @@ impl<F, T>
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                services::RedirectForm::from((response.url.clone(), services::Method::Get))
-            },
-        );
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_url)
+            .map(|url| services::RedirectForm::from((url, services::Method::Get)));
+        let connector_metadata = item
+            .response
+            .next_action
+            .as_ref()
+            .map(StripeNextActionResponse::get_connector_metadata)
+            .transpose()?
+            .flatten();
 
         let mandate_reference =
             item.response
@@ -862,7 +1300,13 @@ impl<F, T>
                     | StripePaymentMethodOptions::Eps {}
                     | StripePaymentMethodOptions::Giropay {}
                     | StripePaymentMethodOptions::Ideal {}
-                    | StripePaymentMethodOptions::Sofort {} => None,
+                    | StripePaymentMethodOptions::Sofort {}
+                    | StripePaymentMethodOptions::UsBankAccount {}
+                    | StripePaymentMethodOptions::SepaDebit {}
+                    | StripePaymentMethodOptions::AuBecsDebit {}
+                    | StripePaymentMethodOptions::BacsDebit {}
+                    | StripePaymentMethodOptions::CustomerBalance {}
+                    | StripePaymentMethodOptions::Multibanco {} => None,
                 });
 
         let error_res =
@@ -881,7 +1325,7 @@ impl<F, T>
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
                 redirection_data,
                 mandate_reference,
-                connector_metadata: None,
+                connector_metadata,
             }),
             Err,
         );
@@ -903,12 +1347,12 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, SetupIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data =
-            item.response
-                .next_action
-                .map(|StripeNextActionResponse::RedirectToUrl(response)| {
-                    services::RedirectForm::from((response.url, services::Method::Get))
-                });
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_url)
+            .map(|url| services::RedirectForm::from((url, services::Method::Get)));
 
         let mandate_reference =
             item.response
@@ -937,6 +1381,39 @@ impl<F, T>
 #[serde(rename_all = "snake_case", remote = "Self")]
 pub enum StripeNextActionResponse {
     RedirectToUrl(StripeRedirectToUrlResponse),
+    VerifyWithMicrodeposits(StripeVerifyWithMicroDepositsResponse),
+    DisplayBankTransferInstructions(StripeBankTransferInstructions),
+    MultibancoDisplayDetails(StripeMultibancoDisplayDetails),
+}
+
+impl StripeNextActionResponse {
+    fn get_url(&self) -> Option<Url> {
+        match self {
+            Self::RedirectToUrl(redirect_to_url) => Some(redirect_to_url.url.to_owned()),
+            Self::VerifyWithMicrodeposits(verify_with_microdeposits) => {
+                Some(verify_with_microdeposits.hosted_verification_url.to_owned())
+            }
+            Self::DisplayBankTransferInstructions(_) | Self::MultibancoDisplayDetails(_) => None,
+        }
+    }
+
+    /// Connector metadata of the payment attempt, with the instructions of the transfer which the
+    /// customer has to make for bank transfer and Multibanco payments
+    fn get_connector_metadata(
+        &self,
+    ) -> Result<Option<serde_json::Value>, error_stack::Report<errors::ConnectorError>> {
+        let bank_transfer_instructions = match self {
+            Self::DisplayBankTransferInstructions(instructions) => instructions.to_owned().into(),
+            Self::MultibancoDisplayDetails(details) => details.to_owned().into(),
+            Self::RedirectToUrl(_) | Self::VerifyWithMicrodeposits(_) => return Ok(None),
+        };
+        serde_json::to_value(types::BankTransferNextStepsData {
+            bank_transfer_instructions,
+        })
+        .map(Some)
+        .into_report()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
 }
 
 // This impl is required because Stripe's response is of the below format, which is externally
@@ -965,6 +1442,98 @@ pub struct StripeRedirectToUrlResponse {
     url: Url,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeVerifyWithMicroDepositsResponse {
+    hosted_verification_url: Url,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeBankTransferInstructions {
+    amount_remaining: Option<i64>,
+    currency: Option<String>,
+    #[serde(default)]
+    financial_addresses: Vec<StripeFinancialAddress>,
+    hosted_instructions_url: Option<String>,
+    reference: Option<String>,
+}
+
+// Account of the customer balance to which the customer transfers the amount, an ABA account for
+// transfers in the US or an IBAN account for transfers in the EU
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeFinancialAddress {
+    aba: Option<StripeAbaAccount>,
+    iban: Option<StripeIbanAccount>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeAbaAccount {
+    account_number: String,
+    bank_name: Option<String>,
+    routing_number: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeIbanAccount {
+    account_holder_name: Option<String>,
+    bic: Option<String>,
+    iban: String,
+}
+
+impl From<StripeBankTransferInstructions> for payments::BankTransferInstructions {
+    fn from(instructions: StripeBankTransferInstructions) -> Self {
+        let aba = instructions
+            .financial_addresses
+            .iter()
+            .find_map(|address| address.aba.to_owned());
+        let iban = instructions
+            .financial_addresses
+            .into_iter()
+            .find_map(|address| address.iban);
+        Self {
+            account_holder_name: iban
+                .as_ref()
+                .and_then(|iban| iban.account_holder_name.to_owned()),
+            iban: iban.as_ref().map(|iban| iban.iban.to_owned()),
+            bic: iban.and_then(|iban| iban.bic),
+            account_number: aba.as_ref().map(|aba| aba.account_number.to_owned()),
+            routing_number: aba.as_ref().map(|aba| aba.routing_number.to_owned()),
+            bank_name: aba.and_then(|aba| aba.bank_name),
+            reference: instructions.reference,
+            amount: instructions.amount_remaining,
+            currency: instructions
+                .currency
+                .map(|currency| currency.to_uppercase()),
+            instructions_url: instructions.hosted_instructions_url,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeMultibancoDisplayDetails {
+    entity: Option<String>,
+    reference: Option<String>,
+    expires_at: Option<i64>,
+    hosted_voucher_url: Option<String>,
+}
+
+impl From<StripeMultibancoDisplayDetails> for payments::BankTransferInstructions {
+    fn from(details: StripeMultibancoDisplayDetails) -> Self {
+        Self {
+            entity: details.entity,
+            reference: details.reference,
+            expires_at: details
+                .expires_at
+                .and_then(|expires_at| time::OffsetDateTime::from_unix_timestamp(expires_at).ok())
+                .map(|expires_at| {
+                    time::PrimitiveDateTime::new(expires_at.date(), expires_at.time())
+                }),
+            instructions_url: details.hosted_voucher_url,
+            ..Self::default()
+        }
+    }
+}
+
 // REFUND :
 // Type definition for Stripe RefundRequest
 
@@ -1107,6 +1676,16 @@ pub struct StripeBillingAddress {
     pub country: Option<String>,
     #[serde(rename = "payment_method_data[billing_details][name]")]
     pub name: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][city]")]
+    pub city: Option<String>,
+    #[serde(rename = "payment_method_data[billing_details][address][line1]")]
+    pub address_line1: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][line2]")]
+    pub address_line2: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][postal_code]")]
+    pub zip_code: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][state]")]
+    pub state: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, Eq, PartialEq)]
@@ -1165,6 +1744,12 @@ pub enum StripePaymentMethodOptions {
     Giropay {},
     Ideal {},
     Sofort {},
+    UsBankAccount {},
+    SepaDebit {},
+    AuBecsDebit {},
+    BacsDebit {},
+    CustomerBalance {},
+    Multibanco {},
 }
 // #[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
 // pub struct Card
@@ -1300,6 +1885,331 @@ impl
                 }))
             }
             api::PaymentMethodData::Wallet(_) => Ok(Self::Wallet),
+            api::PaymentMethodData::BankDebit(ref bank_debit_data) => {
+                let (pm_type, bank_debit_data, _) = get_bank_debit_data(bank_debit_data);
+                Ok(Self::BankDebit(StripeBankDebitData {
+                    payment_method_types: pm_type.clone(),
+                    payment_method_data_type: pm_type,
+                    bank_specific_data: bank_debit_data,
+                }))
+            }
+            // Bank transfers are pushed by the customer for each payment, so there is no payment
+            // method which can be set up for future payments
+            api::PaymentMethodData::BankTransfer(_) => Err(errors::ConnectorError::NotSupported {
+                payment_method: api_enums::PaymentMethod::BankTransfer.to_string(),
+                connector: "Stripe",
+                payment_experience: "mandate".to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn bank_transfer_billing() -> payments::BankTransferBilling {
+        payments::BankTransferBilling {
+            name: Some(Secret::new("John Doe".to_string())),
+            email: Secret::new("john@example.com".to_string()),
+        }
+    }
+
+    fn mandate_data(customer_acceptance: payments::CustomerAcceptance) -> payments::MandateData {
+        payments::MandateData {
+            customer_acceptance,
+            mandate_type: payments::MandateType::MultiUse(None),
         }
     }
+
+    fn browser_info(ip_address: Option<std::net::IpAddr>) -> types::BrowserInformation {
+        types::BrowserInformation {
+            color_depth: 24,
+            java_enabled: false,
+            java_script_enabled: true,
+            language: "en-US".to_string(),
+            screen_height: 1080,
+            screen_width: 1920,
+            time_zone: 0,
+            ip_address,
+            accept_header: "text/html".to_string(),
+            user_agent: "Mozilla/5.0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sepa_bank_transfer_is_funded_from_eu_customer_balance() {
+        let (payment_method_type, customer_balance_options, billing_address) =
+            get_bank_transfer_data(&payments::BankTransferData::SepaBankTransfer {
+                billing_details: bank_transfer_billing(),
+                country: "DE".to_string(),
+            });
+
+        assert_eq!(
+            payment_method_type,
+            StripePaymentMethodType::CustomerBalance
+        );
+        assert_eq!(
+            customer_balance_options,
+            Some(StripeCustomerBalanceOptions {
+                funding_type: StripeFundingType::BankTransfer,
+                bank_transfer_type: StripeBankTransferType::EuBankTransfer,
+                eu_bank_transfer_country: Some("DE".to_string()),
+            })
+        );
+        assert_eq!(
+            billing_address.name,
+            Some(Secret::new("John Doe".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_bank_transfer_request_encodes_customer_balance_options() {
+        let (payment_method_type, customer_balance_options, _) =
+            get_bank_transfer_data(&payments::BankTransferData::AchBankTransfer {
+                billing_details: bank_transfer_billing(),
+            });
+        let request = StripeBankTransferData {
+            payment_method_types: payment_method_type.clone(),
+            payment_method_data_type: payment_method_type,
+            customer_balance_options,
+        };
+
+        let encoded = serde_urlencoded::to_string(&request).unwrap();
+        let fields: Vec<(String, String)> = serde_urlencoded::from_str(&encoded).unwrap();
+
+        assert_eq!(
+            fields,
+            vec![
+                (
+                    "payment_method_types[]".to_string(),
+                    "customer_balance".to_string()
+                ),
+                (
+                    "payment_method_data[type]".to_string(),
+                    "customer_balance".to_string()
+                ),
+                (
+                    "payment_method_options[customer_balance][funding_type]".to_string(),
+                    "bank_transfer".to_string()
+                ),
+                (
+                    "payment_method_options[customer_balance][bank_transfer][type]".to_string(),
+                    "us_bank_transfer".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multibanco_bank_transfer_has_no_customer_balance_options() {
+        let (payment_method_type, customer_balance_options, _) =
+            get_bank_transfer_data(&payments::BankTransferData::Multibanco {
+                billing_details: bank_transfer_billing(),
+            });
+
+        assert_eq!(payment_method_type, StripePaymentMethodType::Multibanco);
+        assert_eq!(customer_balance_options, None);
+    }
+
+    #[test]
+    fn test_bank_debit_mandate_from_online_customer_acceptance() {
+        let mandate_data = mandate_data(payments::CustomerAcceptance {
+            acceptance_type: payments::AcceptanceType::Online,
+            accepted_at: None,
+            online: Some(payments::OnlineMandate {
+                ip_address: Secret::new("127.0.0.1".to_string()),
+                user_agent: "Mozilla/5.0".to_string(),
+            }),
+        });
+
+        let mandate = get_bank_debit_mandate_data(Some(&mandate_data), None).unwrap();
+
+        assert_eq!(
+            mandate,
+            StripeMandateRequest {
+                mandate_type: StripeMandateType::Online,
+                ip_address: Some(Secret::new("127.0.0.1".to_string())),
+                user_agent: Some("Mozilla/5.0".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_bank_debit_mandate_requires_online_details() {
+        let mandate_data = mandate_data(payments::CustomerAcceptance {
+            acceptance_type: payments::AcceptanceType::Online,
+            accepted_at: None,
+            online: None,
+        });
+
+        let result = get_bank_debit_mandate_data(Some(&mandate_data), None);
+
+        assert!(matches!(
+            result,
+            Err(errors::ConnectorError::MissingRequiredField {
+                field_name: "mandate_data.customer_acceptance.online"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_bank_debit_mandate_from_offline_customer_acceptance() {
+        let mandate_data = mandate_data(payments::CustomerAcceptance {
+            acceptance_type: payments::AcceptanceType::Offline,
+            accepted_at: None,
+            online: None,
+        });
+
+        let mandate = get_bank_debit_mandate_data(Some(&mandate_data), None).unwrap();
+
+        assert_eq!(mandate.mandate_type, StripeMandateType::Offline);
+        assert_eq!(mandate.ip_address, None);
+        assert_eq!(mandate.user_agent, None);
+    }
+
+    #[test]
+    fn test_bank_debit_mandate_falls_back_to_browser_info() {
+        let browser_info = browser_info(Some(std::net::IpAddr::from([127, 0, 0, 1])));
+
+        let mandate = get_bank_debit_mandate_data(None, Some(&browser_info)).unwrap();
+
+        assert_eq!(
+            mandate,
+            StripeMandateRequest {
+                mandate_type: StripeMandateType::Online,
+                ip_address: Some(Secret::new("127.0.0.1".to_string())),
+                user_agent: Some("Mozilla/5.0".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_bank_debit_mandate_requires_customer_acceptance_or_ip_address() {
+        let browser_info = browser_info(None);
+
+        assert!(matches!(
+            get_bank_debit_mandate_data(None, Some(&browser_info)),
+            Err(errors::ConnectorError::MissingRequiredField {
+                field_name: "mandate_data.customer_acceptance"
+            })
+        ));
+        assert!(matches!(
+            get_bank_debit_mandate_data(None, None),
+            Err(errors::ConnectorError::MissingRequiredField {
+                field_name: "mandate_data.customer_acceptance"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_customer_request_encoding() {
+        let request = CustomerRequest {
+            email: Some(Secret::new("john@example.com".to_string())),
+            name: Some(Secret::new("John Doe".to_string())),
+        };
+
+        let encoded = serde_urlencoded::to_string(&request).unwrap();
+
+        assert_eq!(encoded, "email=john%40example.com&name=John+Doe");
+    }
+
+    #[test]
+    fn test_customer_response_deserialization() {
+        let response: StripeCustomerResponse =
+            serde_json::from_str(r#"{"id":"cus_123","object":"customer","email":null}"#).unwrap();
+
+        assert_eq!(response.id, "cus_123");
+    }
+
+    #[test]
+    fn test_eu_bank_transfer_instructions() {
+        let next_action: StripeNextActionResponse = serde_json::from_value(serde_json::json!({
+            "type": "display_bank_transfer_instructions",
+            "display_bank_transfer_instructions": {
+                "amount_remaining": 6540,
+                "currency": "eur",
+                "financial_addresses": [{
+                    "type": "iban",
+                    "iban": {
+                        "account_holder_name": "Stripe Technology Europe Limited",
+                        "bic": "SXPYDEHH",
+                        "country": "DE",
+                        "iban": "DE00000000000000000001"
+                    }
+                }],
+                "hosted_instructions_url": "https://payments.stripe.com/instructions",
+                "reference": "RYB5RPMP3ZHU",
+                "type": "eu_bank_transfer"
+            }
+        }))
+        .unwrap();
+
+        let connector_metadata = next_action.get_connector_metadata().unwrap().unwrap();
+        let next_steps: types::BankTransferNextStepsData =
+            serde_json::from_value(connector_metadata).unwrap();
+
+        assert_eq!(
+            next_steps.bank_transfer_instructions,
+            payments::BankTransferInstructions {
+                account_holder_name: Some("Stripe Technology Europe Limited".to_string()),
+                iban: Some("DE00000000000000000001".to_string()),
+                bic: Some("SXPYDEHH".to_string()),
+                reference: Some("RYB5RPMP3ZHU".to_string()),
+                amount: Some(6540),
+                currency: Some("EUR".to_string()),
+                instructions_url: Some("https://payments.stripe.com/instructions".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(next_action.get_url(), None);
+    }
+
+    #[test]
+    fn test_us_bank_transfer_instructions() {
+        let instructions: StripeBankTransferInstructions =
+            serde_json::from_value(serde_json::json!({
+                "amount_remaining": 6540,
+                "currency": "usd",
+                "financial_addresses": [{
+                    "type": "aba",
+                    "aba": {
+                        "account_number": "0000000001",
+                        "bank_name": "Test Bank",
+                        "routing_number": "110000000"
+                    }
+                }],
+                "reference": "RYB5RPMP3ZHU"
+            }))
+            .unwrap();
+
+        let instructions = payments::BankTransferInstructions::from(instructions);
+
+        assert_eq!(instructions.account_number, Some("0000000001".to_string()));
+        assert_eq!(instructions.routing_number, Some("110000000".to_string()));
+        assert_eq!(instructions.bank_name, Some("Test Bank".to_string()));
+        assert_eq!(instructions.iban, None);
+        assert_eq!(instructions.currency, Some("USD".to_string()));
+    }
+
+    #[test]
+    fn test_multibanco_display_details() {
+        let details: StripeMultibancoDisplayDetails = serde_json::from_value(serde_json::json!({
+            "entity": "12345",
+            "reference": "123456789",
+            "expires_at": 1678881600,
+            "hosted_voucher_url": "https://payments.stripe.com/multibanco"
+        }))
+        .unwrap();
+
+        let instructions = payments::BankTransferInstructions::from(details);
+
+        assert_eq!(instructions.entity, Some("12345".to_string()));
+        assert_eq!(instructions.reference, Some("123456789".to_string()));
+        assert_eq!(
+            instructions.expires_at,
+            Some(time::macros::datetime!(2023-03-15 12:00:00))
+        );
+    }
 }
@@ -23,4 +23,12 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+/// Prefix of the API keys of organizations, which distinguishes them from merchant API keys
+pub(crate) const ORGANIZATION_API_KEY_PREFIX: &str = "org_";
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
+
+/// Lengths of the issuer identification numbers looked up in the BIN table
+pub(crate) const CARD_IIN_LENGTHS: [usize; 2] = [8, 6];
+
+/// Number of data keys re-wrapped by each run of the merchant key rotation task
+pub(crate) const MERCHANT_KEY_ROTATION_BATCH_SIZE: i64 = 100;
@@ -1,9 +1,15 @@
 pub mod admin;
+pub mod admin_principals;
 pub mod api_keys;
+pub mod audit_events;
+pub mod card_vault;
 pub mod configs;
 pub mod customers;
 pub mod errors;
+pub mod fraud_check;
+pub mod health_check;
 pub mod mandate;
+pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
 pub mod refunds;
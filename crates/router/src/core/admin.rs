@@ -1,14 +1,25 @@
+use api_models::enums as api_enums;
 use common_utils::ext_traits::ValueExt;
-use error_stack::{report, FutureExt, ResultExt};
+use error_stack::{report, FutureExt, IntoReport, ResultExt};
+use masking::StrongSecret;
 use storage_models::{enums, merchant_account};
 use uuid::Uuid;
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    core::{
+        api_keys::PlaintextApiKey,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
     db::StorageInterface,
     pii::Secret,
-    services::api as service_api,
+    routes::AppState,
+    services::{
+        api as service_api,
+        audit::{AuditContext, AuditResource},
+        circuit_breaker,
+        envelope_encryption::Decryptable,
+    },
     types::{
         self, api,
         storage::{self, MerchantAccount},
@@ -26,6 +37,76 @@ pub fn create_merchant_api_key() -> String {
     )
 }
 
+fn encode_pm_filters(
+    pm_filters: Option<&api::PaymentMethodFilters>,
+) -> RouterResult<Option<serde_json::Value>> {
+    pm_filters
+        .map(|pm_filters| {
+            for (payment_method_type, filter) in pm_filters {
+                validate_pm_filter(payment_method_type, filter)?;
+            }
+            utils::Encode::<api::PaymentMethodFilters>::encode_to_value(pm_filters)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while encoding to serde_json::Value, PaymentMethodFilters",
+                )
+        })
+        .transpose()
+}
+
+pub fn decode_pm_filters(
+    pm_filters: Option<serde_json::Value>,
+) -> RouterResult<Option<api::PaymentMethodFilters>> {
+    pm_filters
+        .map(|pm_filters| {
+            pm_filters
+                .parse_value("PaymentMethodFilters")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .transpose()
+}
+
+fn validate_pm_filter(
+    payment_method_type: &api_enums::PaymentMethodType,
+    filter: &api::PaymentMethodFilter,
+) -> RouterResult<()> {
+    let accept_types = [
+        filter
+            .accepted_currencies
+            .as_ref()
+            .map(|currencies| currencies.accept_type.as_str()),
+        filter
+            .accepted_countries
+            .as_ref()
+            .map(|countries| countries.accept_type.as_str()),
+    ];
+    if accept_types
+        .into_iter()
+        .flatten()
+        .any(|accept_type| accept_type != "enable_only" && accept_type != "disable_only")
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "pm_filters.{payment_method_type}: type must be either `enable_only` or `disable_only`"
+            ),
+        }))?;
+    }
+
+    if let (Some(minimum_amount), Some(maximum_amount)) =
+        (filter.minimum_amount, filter.maximum_amount)
+    {
+        if minimum_amount > maximum_amount {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "pm_filters.{payment_method_type}: minimum_amount must not exceed maximum_amount"
+                ),
+            }))?;
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn create_merchant_account(
     db: &dyn StorageInterface,
     req: api::CreateMerchantAccount,
@@ -58,6 +139,13 @@ pub async fn create_merchant_account(
             .attach_printable("Invalid routing algorithm given")?;
     }
 
+    let pm_filters = encode_pm_filters(req.pm_filters.as_ref())?;
+
+    let parent_merchant =
+        get_parent_merchant(db, req.sub_merchants_enabled, req.parent_merchant_id).await?;
+    let organization_id =
+        get_organization_id(db, req.organization_id, parent_merchant.as_ref()).await?;
+
     let merchant_account = storage::MerchantAccountNew {
         merchant_id: req.merchant_id,
         merchant_name: req.merchant_name,
@@ -67,18 +155,15 @@ pub async fn create_merchant_account(
         webhook_details,
         routing_algorithm: req.routing_algorithm,
         sub_merchants_enabled: req.sub_merchants_enabled,
-        parent_merchant_id: get_parent_merchant(
-            db,
-            req.sub_merchants_enabled,
-            req.parent_merchant_id,
-        )
-        .await?,
+        parent_merchant_id: parent_merchant.map(|parent_merchant| parent_merchant.merchant_id),
         enable_payment_response_hash: req.enable_payment_response_hash,
         payment_response_hash_key: req.payment_response_hash_key,
         redirect_to_merchant_with_http_post: req.redirect_to_merchant_with_http_post,
         publishable_key,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        pm_filters,
+        organization_id,
     };
 
     let merchant_account = db
@@ -89,7 +174,7 @@ pub async fn create_merchant_account(
         })?;
 
     Ok(service_api::ApplicationResponse::Json(
-        merchant_account.foreign_into(),
+        merchant_account.foreign_try_into()?,
     ))
 }
 
@@ -105,7 +190,7 @@ pub async fn get_merchant_account(
         })?;
 
     Ok(service_api::ApplicationResponse::Json(
-        merchant_account.foreign_into(),
+        merchant_account.foreign_try_into()?,
     ))
 }
 
@@ -113,6 +198,7 @@ pub async fn merchant_account_update(
     db: &dyn StorageInterface,
     merchant_id: &String,
     req: api::CreateMerchantAccount,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::MerchantAccountResponse> {
     if &req.merchant_id != merchant_id {
         Err(report!(errors::ValidationError::IncorrectValueProvided {
@@ -136,6 +222,19 @@ pub async fn merchant_account_update(
             .attach_printable("Invalid routing algorithm given")?;
     }
 
+    let merchant_account: api::MerchantAccountResponse = db
+        .find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?
+        .foreign_try_into()?;
+
+    let parent_merchant =
+        get_parent_merchant(db, req.sub_merchants_enabled, req.parent_merchant_id).await?;
+    let organization_id =
+        get_organization_id(db, req.organization_id, parent_merchant.as_ref()).await?;
+
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
         merchant_name: req.merchant_name,
 
@@ -157,32 +256,37 @@ pub async fn merchant_account_update(
 
         routing_algorithm: req.routing_algorithm,
         sub_merchants_enabled: req.sub_merchants_enabled,
-
-        parent_merchant_id: get_parent_merchant(
-            db,
-            req.sub_merchants_enabled,
-            req.parent_merchant_id,
-        )
-        .await?,
+        parent_merchant_id: parent_merchant.map(|parent_merchant| parent_merchant.merchant_id),
+        organization_id,
         enable_payment_response_hash: req.enable_payment_response_hash,
         payment_response_hash_key: req.payment_response_hash_key,
         redirect_to_merchant_with_http_post: req.redirect_to_merchant_with_http_post,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        pm_filters: encode_pm_filters(req.pm_filters.as_ref())?,
         api_key: None,
         publishable_key: None,
     };
 
-    let response = db
+    let response: api::MerchantAccountResponse = db
         .update_specific_fields_in_merchant(merchant_id, updated_merchant_account)
         .await
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
-        })?;
+        })?
+        .foreign_try_into()?;
 
-    Ok(service_api::ApplicationResponse::Json(
-        response.foreign_into(),
-    ))
+    audit_context
+        .record(
+            db,
+            enums::AuditAction::Update,
+            AuditResource::merchant_account(merchant_id),
+            Some(&merchant_account),
+            Some(&response),
+        )
+        .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn merchant_account_delete(
@@ -206,7 +310,7 @@ async fn get_parent_merchant(
     db: &dyn StorageInterface,
     sub_merchants_enabled: Option<bool>,
     parent_merchant: Option<String>,
-) -> RouterResult<Option<String>> {
+) -> RouterResult<Option<MerchantAccount>> {
     Ok(match sub_merchants_enabled {
         Some(true) => {
             Some(
@@ -222,13 +326,40 @@ async fn get_parent_merchant(
                     errors::ApiErrorResponse::InvalidDataValue { field_name: "parent_merchant_id" }
                 ))?
                 .await?
-                .merchant_id
             )
         }
         _ => None,
     })
 }
 
+/// Sub-merchants always belong to the organization of their parent merchant.
+async fn get_organization_id(
+    db: &dyn StorageInterface,
+    organization_id: Option<String>,
+    parent_merchant: Option<&MerchantAccount>,
+) -> RouterResult<Option<String>> {
+    match (parent_merchant, organization_id) {
+        (Some(parent_merchant), Some(organization_id))
+            if parent_merchant.organization_id.as_ref() != Some(&organization_id) =>
+        {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "organization_id must be the organization of the parent merchant"
+                    .to_string(),
+            }))
+        }
+        (Some(parent_merchant), _) => Ok(parent_merchant.organization_id.clone()),
+        (None, Some(organization_id)) => db
+            .find_organization_by_org_id(&organization_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "organization_id",
+            })
+            .attach_printable_lazy(|| format!("Organization {organization_id} does not exist"))
+            .map(|organization| Some(organization.org_id)),
+        (None, None) => Ok(None),
+    }
+}
+
 async fn validate_merchant_id<S: Into<String>>(
     db: &dyn StorageInterface,
     merchant_id: S,
@@ -240,6 +371,108 @@ async fn validate_merchant_id<S: Into<String>>(
         })
 }
 
+// Organization API - Organizations group merchant accounts, which can be accessed with the API key
+//                     of the organization
+
+pub async fn create_organization(
+    db: &dyn StorageInterface,
+    req: api::OrganizationRequest,
+) -> RouterResponse<api::CreateOrganizationResponse> {
+    let hash_key = PlaintextApiKey::new_hash_key();
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let organization = storage::OrganizationNew {
+        org_id: utils::generate_id(consts::ID_LENGTH, "org"),
+        org_name: req.org_name,
+        hash_key: Secret::from(hex::encode(hash_key)),
+        hashed_api_key: plaintext_api_key.keyed_hash(&hash_key).into(),
+        prefix: plaintext_api_key.prefix(),
+    };
+
+    let organization = db
+        .insert_organization(organization)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert organization")?;
+
+    // The organization ID starts with the organization API key prefix, which routes the API key to
+    // the organization when authenticating
+    Ok(service_api::ApplicationResponse::Json(
+        api::CreateOrganizationResponse {
+            api_key: StrongSecret::from(format!(
+                "{}-{}",
+                organization.org_id,
+                plaintext_api_key.peek()
+            )),
+            org_id: organization.org_id,
+            org_name: organization.org_name,
+            created_at: organization.created_at,
+            modified_at: organization.modified_at,
+        },
+    ))
+}
+
+async fn find_organization(
+    db: &dyn StorageInterface,
+    org_id: &str,
+) -> RouterResult<storage::Organization> {
+    db.find_organization_by_org_id(org_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound))
+}
+
+pub async fn get_organization(
+    db: &dyn StorageInterface,
+    req: api::OrganizationId,
+) -> RouterResponse<api::OrganizationResponse> {
+    let organization = find_organization(db, &req.org_id).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        organization.foreign_into(),
+    ))
+}
+
+pub async fn update_organization(
+    db: &dyn StorageInterface,
+    org_id: &str,
+    req: api::OrganizationRequest,
+) -> RouterResponse<api::OrganizationResponse> {
+    let organization = db
+        .update_organization_by_org_id(
+            org_id,
+            storage::OrganizationUpdate::Update {
+                org_name: req.org_name,
+            },
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        organization.foreign_into(),
+    ))
+}
+
+pub async fn list_organization_merchant_accounts(
+    db: &dyn StorageInterface,
+    req: api::OrganizationId,
+) -> RouterResponse<Vec<api::MerchantAccountResponse>> {
+    let organization = find_organization(db, &req.org_id).await?;
+
+    let merchant_accounts = db
+        .list_merchant_accounts_by_organization_id(&organization.org_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant accounts of the organization")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        merchant_accounts
+            .into_iter()
+            .map(ForeignTryInto::foreign_try_into)
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
 // Payment Connector API -  Every merchant and connector can have an instance of (merchant <> connector)
 //                          with unique merchant_connector_id for Create Operation
 
@@ -247,6 +480,7 @@ pub async fn create_payment_connector(
     store: &dyn StorageInterface,
     req: api::PaymentConnectorCreate,
     merchant_id: &String,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::PaymentConnectorCreate> {
     let _merchant_account = store
         .find_merchant_account_by_merchant_id(merchant_id)
@@ -292,16 +526,30 @@ pub async fn create_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        pm_filters: encode_pm_filters(req.pm_filters.as_ref())?,
+        shared_with_sub_merchants: req.shared_with_sub_merchants,
     };
 
     let mca = store
         .insert_merchant_connector_account(merchant_connector_account)
         .await
+        .map(Decryptable::into_encrypted)
         .map_err(|error| {
             error.to_duplicate_response(errors::ApiErrorResponse::DuplicateMerchantConnectorAccount)
         })?;
 
-    response.merchant_connector_id = Some(mca.merchant_connector_id);
+    response.merchant_connector_id = Some(mca.merchant_connector_id.clone());
+
+    audit_context
+        .record(
+            store,
+            enums::AuditAction::Create,
+            AuditResource::merchant_connector_account(merchant_id, &mca.merchant_connector_id),
+            None,
+            Some(&response),
+        )
+        .await;
+
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
@@ -323,6 +571,7 @@ pub async fn retrieve_payment_connector(
             &merchant_connector_id,
         )
         .await
+        .and_then(Decryptable::decrypt)
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
@@ -347,6 +596,7 @@ pub async fn list_payment_connectors(
     let merchant_connector_accounts = store
         .find_merchant_connector_account_by_merchant_id_and_disabled_list(&merchant_id, true)
         .await
+        .and_then(Decryptable::decrypt_all)
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
@@ -360,11 +610,96 @@ pub async fn list_payment_connectors(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+pub async fn get_payment_connectors_health(
+    state: &AppState,
+    merchant_id: String,
+) -> RouterResponse<api::ConnectorHealthResponse> {
+    let db = &*state.store;
+    db.find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|err| {
+            err.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(&merchant_id, true)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+
+    let mut connectors = Vec::with_capacity(merchant_connector_accounts.len());
+    for mca in merchant_connector_accounts
+        .into_iter()
+        .map(Decryptable::into_encrypted)
+    {
+        let (circuit_state, stats) =
+            circuit_breaker::get_connector_health(state, &merchant_id, &mca.connector_name).await;
+
+        connectors.push(api::ConnectorHealth {
+            connector_name: mca.connector_name,
+            merchant_connector_id: mca.merchant_connector_id,
+            circuit_state: circuit_state.foreign_into(),
+            total_calls: stats.total_calls,
+            failed_calls: stats.failed_calls,
+            slow_calls: stats.slow_calls,
+            error_rate: stats.error_rate(),
+            average_latency: stats.average_latency(),
+        });
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::ConnectorHealthResponse {
+            merchant_id,
+            connectors,
+        },
+    ))
+}
+
+pub async fn get_connector_call_logs(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+    payment_id: String,
+) -> RouterResponse<api::ConnectorCallLogResponse> {
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|err| {
+            err.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_id,
+        &merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let calls = db
+        .find_connector_call_logs_by_merchant_id_payment_id(&merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve connector call logs")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::ConnectorCallLogResponse {
+            merchant_id,
+            payment_id,
+            calls,
+        },
+    ))
+}
+
 pub async fn update_payment_connector(
     db: &dyn StorageInterface,
     merchant_id: &str,
     merchant_connector_id: &str,
     req: api::PaymentConnectorCreate,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::PaymentConnectorCreate> {
     let _merchant_account = db
         .find_merchant_account_by_merchant_id(merchant_id)
@@ -379,10 +714,13 @@ pub async fn update_payment_connector(
             merchant_connector_id,
         )
         .await
+        .and_then(Decryptable::decrypt)
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
 
+    let payment_connector_before: api::PaymentConnectorCreate = mca.clone().foreign_try_into()?;
+
     let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
         pm_enabled
             .iter()
@@ -402,11 +740,14 @@ pub async fn update_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        pm_filters: encode_pm_filters(req.pm_filters.as_ref())?,
+        shared_with_sub_merchants: req.shared_with_sub_merchants,
     };
 
     let updated_mca = db
         .update_merchant_connector_account(mca, payment_connector)
         .await
+        .and_then(Decryptable::decrypt)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| {
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
@@ -430,9 +771,22 @@ pub async fn update_payment_connector(
         connector_account_details: Some(Secret::new(updated_mca.connector_account_details)),
         test_mode: updated_mca.test_mode,
         disabled: updated_mca.disabled,
+        shared_with_sub_merchants: Some(updated_mca.shared_with_sub_merchants),
         payment_methods_enabled: updated_pm_enabled,
+        pm_filters: decode_pm_filters(updated_mca.pm_filters)?,
         metadata: updated_mca.metadata,
     };
+
+    audit_context
+        .record(
+            db,
+            enums::AuditAction::Update,
+            AuditResource::merchant_connector_account(merchant_id, merchant_connector_id),
+            Some(&payment_connector_before),
+            Some(&response),
+        )
+        .await;
+
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
@@ -440,6 +794,7 @@ pub async fn delete_payment_connector(
     db: &dyn StorageInterface,
     merchant_id: String,
     merchant_connector_id: String,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::DeleteMcaResponse> {
     let _merchant_account = db
         .find_merchant_account_by_merchant_id(&merchant_id)
@@ -448,6 +803,18 @@ pub async fn delete_payment_connector(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
         })?;
 
+    let payment_connector: api::PaymentConnectorCreate = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_id,
+            &merchant_connector_id,
+        )
+        .await
+        .and_then(Decryptable::decrypt)
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?
+        .foreign_try_into()?;
+
     let is_deleted = db
         .delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
             &merchant_id,
@@ -457,6 +824,17 @@ pub async fn delete_payment_connector(
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
+
+    audit_context
+        .record(
+            db,
+            enums::AuditAction::Delete,
+            AuditResource::merchant_connector_account(&merchant_id, &merchant_connector_id),
+            Some(&payment_connector),
+            None,
+        )
+        .await;
+
     let response = api::DeleteMcaResponse {
         merchant_id,
         merchant_connector_id,
@@ -469,6 +847,7 @@ pub async fn kv_for_merchant(
     db: &dyn StorageInterface,
     merchant_id: String,
     enable: bool,
+    audit_context: AuditContext,
 ) -> RouterResponse<api_models::admin::ToggleKVResponse> {
     // check if the merchant account exists
     let merchant_account = db
@@ -477,6 +856,13 @@ pub async fn kv_for_merchant(
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
         })?;
+    let kv_status_before = api_models::admin::ToggleKVResponse {
+        merchant_id: merchant_account.merchant_id.clone(),
+        kv_enabled: matches!(
+            merchant_account.storage_scheme,
+            enums::MerchantStorageScheme::RedisKv
+        ),
+    };
 
     let updated_merchant_account = match (enable, merchant_account.storage_scheme) {
         (true, enums::MerchantStorageScheme::RedisKv)
@@ -509,13 +895,22 @@ pub async fn kv_for_merchant(
         updated_merchant_account.storage_scheme,
         enums::MerchantStorageScheme::RedisKv
     );
+    let response = api_models::admin::ToggleKVResponse {
+        merchant_id: updated_merchant_account.merchant_id,
+        kv_enabled: kv_status,
+    };
 
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::ToggleKVResponse {
-            merchant_id: updated_merchant_account.merchant_id,
-            kv_enabled: kv_status,
-        },
-    ))
+    audit_context
+        .record(
+            db,
+            enums::AuditAction::Update,
+            AuditResource::merchant_account(&merchant_id),
+            Some(&kv_status_before),
+            Some(&response),
+        )
+        .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn check_merchant_account_kv_status(
@@ -542,3 +937,50 @@ pub async fn check_merchant_account_kv_status(
         },
     ))
 }
+
+pub async fn schedule_merchant_key_rotation(
+    state: &AppState,
+) -> RouterResponse<api_models::admin::MerchantKeyRotationResponse> {
+    let master_key_identifier = state.conf.jwekey.active_master_key_identifier.clone();
+    let runner = "MERCHANT_KEY_ROTATION_WORKFLOW";
+    let task = "MERCHANT_KEY_ROTATION";
+    let current_time = common_utils::date_time::now();
+    let tracking_data = serde_json::to_value(storage::MerchantKeyRotationTrackingData {
+        master_key_identifier: master_key_identifier.clone(),
+    })
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to convert merchant key rotation tracking data into value")?;
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: format!(
+            "{runner}_{task}_{master_key_identifier}_{}",
+            current_time.assume_utc().unix_timestamp()
+        ),
+        name: Some(String::from(task)),
+        tag: vec![String::from("KEY_ROTATION")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(current_time),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    let process = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting merchant key rotation task in process_tracker")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::MerchantKeyRotationResponse {
+            task_id: process.id,
+            master_key_identifier,
+        },
+    ))
+}
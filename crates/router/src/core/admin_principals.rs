@@ -0,0 +1,105 @@
+use common_utils::date_time;
+use error_stack::ResultExt;
+use masking::{Secret, StrongSecret};
+use router_env::{instrument, tracing};
+
+use super::api_keys::{self, PlaintextApiKey};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{api::admin_principals as admin_principals_api, storage, transformers::ForeignInto},
+    utils,
+};
+
+#[instrument(skip_all)]
+pub async fn create_admin_principal(
+    store: &dyn StorageInterface,
+    req: admin_principals_api::CreateAdminPrincipalRequest,
+) -> RouterResponse<admin_principals_api::CreateAdminPrincipalResponse> {
+    api_keys::validate_permissions(&req.permissions, true)?;
+
+    let hash_key = PlaintextApiKey::new_hash_key();
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let admin_principal = storage::AdminPrincipalNew {
+        principal_id: utils::generate_id(consts::ID_LENGTH, "adm"),
+        name: req.name,
+        description: req.description,
+        hash_key: Secret::from(hex::encode(hash_key)),
+        hashed_api_key: plaintext_api_key.keyed_hash(&hash_key).into(),
+        prefix: plaintext_api_key.prefix(),
+        permissions: req
+            .permissions
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+        created_at: date_time::now(),
+    };
+
+    let admin_principal = store
+        .insert_admin_principal(admin_principal)
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::InvalidRequestData {
+                message: "An admin principal with the name already exists".to_string(),
+            })
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        admin_principals_api::CreateAdminPrincipalResponse {
+            api_key: StrongSecret::from(format!(
+                "{}-{}",
+                admin_principal.principal_id,
+                plaintext_api_key.peek()
+            )),
+            principal_id: admin_principal.principal_id,
+            name: admin_principal.name,
+            description: admin_principal.description,
+            permissions: admin_principal
+                .permissions
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+            created: admin_principal.created_at,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_admin_principals(
+    store: &dyn StorageInterface,
+) -> RouterResponse<Vec<admin_principals_api::AdminPrincipalResponse>> {
+    let admin_principals = store
+        .list_admin_principals()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list admin principals")?;
+
+    Ok(ApplicationResponse::Json(
+        admin_principals
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn revoke_admin_principal(
+    store: &dyn StorageInterface,
+    principal_id: String,
+) -> RouterResponse<admin_principals_api::RevokeAdminPrincipalResponse> {
+    let revoked = store
+        .revoke_admin_principal(&principal_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        admin_principals_api::RevokeAdminPrincipalResponse {
+            principal_id,
+            revoked,
+        },
+    ))
+}
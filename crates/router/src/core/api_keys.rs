@@ -5,10 +5,18 @@ use router_env::{instrument, tracing};
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
     db::StorageInterface,
-    services::ApplicationResponse,
-    types::{api, storage, transformers::ForeignInto},
+    services::{
+        audit::{AuditContext, AuditResource},
+        ApplicationResponse,
+    },
+    types::{
+        api,
+        api::enums as api_enums,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
     utils,
 };
 
@@ -91,6 +99,63 @@ impl PlaintextApiKey {
             Err(errors::ApiKeyError::HashVerificationFailed).into_report()
         })
     }
+
+    /// Verifies the plaintext API key against a stored hash, given the hex encoded hash key the
+    /// hash was computed with.
+    pub fn verify_stored_hash(
+        &self,
+        hash_key: &Secret<String>,
+        stored_api_key: &storage::HashedApiKey,
+    ) -> CustomResult<(), errors::ApiKeyError> {
+        let hash_key: [u8; Self::HASH_KEY_LEN] = hex::decode(hash_key.peek())
+            .ok()
+            .and_then(|hash_key| hash_key.try_into().ok())
+            .ok_or(errors::ApiKeyError::FailedToReadHashFromHex)
+            .into_report()
+            .attach_printable("Invalid hash key")?;
+
+        self.verify_hash(&hash_key, &HashedApiKey(stored_api_key.as_str().to_owned()))
+    }
+}
+
+/// API keys are issued in the `<key_id>-<plaintext key>` format, the key ID identifies the stored
+/// hash the plaintext key is verified against. Admin API keys of admin principals and API keys of
+/// organizations use the same format, with the principal ID or organization ID in place of the key
+/// ID.
+pub fn split_api_key(api_key: &str) -> Option<(&str, PlaintextApiKey)> {
+    api_key.split_once('-').map(|(key_id, plaintext_api_key)| {
+        (key_id, PlaintextApiKey(plaintext_api_key.to_owned().into()))
+    })
+}
+
+/// Admin permissions can only be granted to admin principals, and the other permissions can only
+/// be granted to API keys.
+pub(crate) fn validate_permissions(
+    permissions: &[api_enums::Permission],
+    admin: bool,
+) -> RouterResult<()> {
+    if permissions.is_empty() {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "permissions must not be empty".to_string(),
+        }))?;
+    }
+
+    match permissions
+        .iter()
+        .find(|permission| permission.is_admin_permission() != admin)
+    {
+        Some(permission) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{permission} cannot be granted to {}",
+                if admin {
+                    "admin principals"
+                } else {
+                    "API keys"
+                }
+            ),
+        })),
+        None => Ok(()),
+    }
 }
 
 #[instrument(skip_all)]
@@ -98,7 +163,12 @@ pub async fn create_api_key(
     store: &dyn StorageInterface,
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
+    if let Some(permissions) = &api_key.permissions {
+        validate_permissions(permissions, false)?;
+    }
+
     let hash_key = PlaintextApiKey::new_hash_key();
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -112,6 +182,12 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        permissions: api_key.permissions.map(|permissions| {
+            permissions
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect()
+        }),
     };
 
     let api_key = store
@@ -119,10 +195,19 @@ pub async fn create_api_key(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert new API key")?;
+    let response: api::CreateApiKeyResponse = (api_key, plaintext_api_key).foreign_into();
+
+    audit_context
+        .record(
+            store,
+            storage_enums::AuditAction::Create,
+            AuditResource::api_key(&response.merchant_id, &response.key_id),
+            None,
+            Some(&response),
+        )
+        .await;
 
-    Ok(ApplicationResponse::Json(
-        (api_key, plaintext_api_key).foreign_into(),
-    ))
+    Ok(ApplicationResponse::Json(response))
 }
 
 #[instrument(skip_all)]
@@ -130,6 +215,15 @@ pub async fn retrieve_api_key(
     store: &dyn StorageInterface,
     key_id: &str,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
+    let api_key = find_api_key(store, key_id).await?;
+
+    Ok(ApplicationResponse::Json(api_key))
+}
+
+async fn find_api_key(
+    store: &dyn StorageInterface,
+    key_id: &str,
+) -> RouterResult<api::RetrieveApiKeyResponse> {
     let api_key = store
         .find_api_key_optional(key_id)
         .await
@@ -137,7 +231,7 @@ pub async fn retrieve_api_key(
         .attach_printable("Failed to retrieve new API key")?
         .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
 
-    Ok(ApplicationResponse::Json(api_key.foreign_into()))
+    Ok(api_key.foreign_into())
 }
 
 #[instrument(skip_all)]
@@ -145,25 +239,56 @@ pub async fn update_api_key(
     store: &dyn StorageInterface,
     key_id: &str,
     api_key: api::UpdateApiKeyRequest,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
-    let api_key = store
+    if let Some(permissions) = &api_key.permissions {
+        validate_permissions(permissions, false)?;
+    }
+
+    let api_key_before = find_api_key(store, key_id).await?;
+
+    let response: api::RetrieveApiKeyResponse = store
         .update_api_key(key_id.to_owned(), api_key.foreign_into())
         .await
-        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound))?;
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound))?
+        .foreign_into();
+
+    audit_context
+        .record(
+            store,
+            storage_enums::AuditAction::Update,
+            AuditResource::api_key(&response.merchant_id, key_id),
+            Some(&api_key_before),
+            Some(&response),
+        )
+        .await;
 
-    Ok(ApplicationResponse::Json(api_key.foreign_into()))
+    Ok(ApplicationResponse::Json(response))
 }
 
 #[instrument(skip_all)]
 pub async fn revoke_api_key(
     store: &dyn StorageInterface,
     key_id: &str,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
+    let api_key = find_api_key(store, key_id).await?;
+
     let revoked = store
         .revoke_api_key(key_id)
         .await
         .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound))?;
 
+    audit_context
+        .record(
+            store,
+            storage_enums::AuditAction::Delete,
+            AuditResource::api_key(&api_key.merchant_id, key_id),
+            Some(&api_key),
+            None,
+        )
+        .await;
+
     Ok(ApplicationResponse::Json(api::RevokeApiKeyResponse {
         key_id: key_id.to_owned(),
         revoked,
@@ -216,4 +341,41 @@ mod tests {
             .verify_hash(&hash_key, &hashed_api_key)
             .unwrap();
     }
+
+    #[test]
+    fn test_split_and_verify_stored_hash() {
+        let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+        let hash_key = PlaintextApiKey::new_hash_key();
+        let hashed_api_key: storage::HashedApiKey = plaintext_api_key.keyed_hash(&hash_key).into();
+        let hash_key = Secret::new(hex::encode(hash_key));
+
+        let api_key = format!(
+            "dev_5hEEqkgJUyuxgSKGArHA4mWSnX-{}",
+            plaintext_api_key.peek()
+        );
+        let (key_id, parsed_api_key) = split_api_key(&api_key).unwrap();
+        assert_eq!(key_id, "dev_5hEEqkgJUyuxgSKGArHA4mWSnX");
+        parsed_api_key
+            .verify_stored_hash(&hash_key, &hashed_api_key)
+            .unwrap();
+
+        let (_, wrong_api_key) = split_api_key("dev_5hEEqkgJUyuxgSKGArHA4mWSnX-wrong").unwrap();
+        assert!(wrong_api_key
+            .verify_stored_hash(&hash_key, &hashed_api_key)
+            .is_err());
+        assert!(split_api_key("dev_5hEEqkgJUyuxgSKGArHA4mWSnX").is_none());
+    }
+
+    #[test]
+    fn test_validate_permissions() {
+        let permissions = [
+            api_enums::Permission::PaymentsRead,
+            api_enums::Permission::RefundsRead,
+        ];
+        assert!(validate_permissions(&permissions, false).is_ok());
+        assert!(validate_permissions(&permissions, true).is_err());
+        assert!(validate_permissions(&[api_enums::Permission::ConnectorsWrite], true).is_ok());
+        assert!(validate_permissions(&[api_enums::Permission::ConnectorsWrite], false).is_err());
+        assert!(validate_permissions(&[], false).is_err());
+    }
 }
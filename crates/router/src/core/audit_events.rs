@@ -0,0 +1,43 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{api::audit_events as audit_events_api, transformers::ForeignInto},
+};
+
+/// The number of audit events listed when no limit is provided.
+const DEFAULT_LIST_LIMIT: i64 = 10;
+
+/// The maximum number of audit events which can be listed at once.
+const MAX_LIST_LIMIT: i64 = 100;
+
+#[instrument(skip_all)]
+pub async fn list_audit_events(
+    store: &dyn StorageInterface,
+    constraints: audit_events_api::AuditEventListConstraints,
+) -> RouterResponse<Vec<audit_events_api::AuditEventResponse>> {
+    let limit = constraints.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+    if !(1..=MAX_LIST_LIMIT).contains(&limit) {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {MAX_LIST_LIMIT}"),
+        }))?;
+    }
+
+    let audit_events = store
+        .filter_audit_events_by_constraints(
+            &constraints,
+            constraints.resource_type.map(ForeignInto::foreign_into),
+            limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list audit events")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(ApplicationResponse::Json(audit_events))
+}
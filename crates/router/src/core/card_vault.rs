@@ -0,0 +1,723 @@
+//! Built-in vault, which stores cards and tokenized data in the separate `vault` schema, encrypted
+//! with the data key of the merchant, in place of the external locker. Every access to the data in
+//! the vault is recorded in the access audit trail.
+
+use common_utils::{consts, generate_id, generate_id_with_default_len};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::VaultSettings,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::transformers as payment_methods,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api::{self, vault as vault_api},
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
+    },
+};
+
+const VAULT_TOKEN_CLEANUP_RUNNER: &str = "VAULT_TOKEN_CLEANUP_WORKFLOW";
+const VAULT_TOKEN_CLEANUP_TASK: &str = "VAULT_TOKEN_CLEANUP";
+
+async fn record_access(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    resource_type: storage_enums::VaultResourceType,
+    resource_id: &str,
+    action: storage_enums::VaultAction,
+) -> CustomResult<(), errors::StorageError> {
+    db.insert_vault_access_event(storage::VaultAccessEventNew {
+        merchant_id: merchant_id.to_owned(),
+        resource_type,
+        resource_id: resource_id.to_owned(),
+        action,
+    })
+    .await
+    .attach_printable("Failed to record access to the vault")
+    .map(|_| ())
+}
+
+fn get_add_card_response(card: storage::VaultCard) -> payment_methods::AddCardResponse {
+    payment_methods::AddCardResponse {
+        external_id: card.card_id.clone(),
+        card_id: card.card_id,
+        card_fingerprint: card.card_fingerprint.clone().into(),
+        card_global_fingerprint: card.card_fingerprint.into(),
+        merchant_id: Some(card.merchant_id),
+        card_number: Some(card.card_number.expose().into()),
+        card_exp_year: Some(card.card_exp_year),
+        card_exp_month: Some(card.card_exp_month),
+        name_on_card: card.name_on_card,
+        nickname: card.nickname,
+        customer_id: Some(card.customer_id),
+        duplicate: Some(false),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn store_card(
+    state: &AppState,
+    merchant_id: &str,
+    customer_id: &str,
+    card: &api::CardDetail,
+    card_fingerprint: &str,
+) -> CustomResult<payment_methods::AddCardResponse, errors::StorageError> {
+    let db = &*state.store;
+    let card = db
+        .insert_vault_card(storage::VaultCardNew {
+            card_id: generate_id(consts::ID_LENGTH, "card"),
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+            card_number: Secret::new(card.card_number.peek().to_owned()),
+            card_exp_month: card.card_exp_month.clone(),
+            card_exp_year: card.card_exp_year.clone(),
+            name_on_card: card.card_holder_name.clone(),
+            nickname: None,
+            card_fingerprint: card_fingerprint.to_owned(),
+        })
+        .await?;
+
+    record_access(
+        db,
+        merchant_id,
+        storage_enums::VaultResourceType::Card,
+        &card.card_id,
+        storage_enums::VaultAction::Store,
+    )
+    .await?;
+
+    Ok(get_add_card_response(card))
+}
+
+#[instrument(skip_all)]
+pub async fn get_card(
+    state: &AppState,
+    merchant_id: &str,
+    card_id: &str,
+) -> CustomResult<payment_methods::GetCardResponse, errors::StorageError> {
+    let db = &*state.store;
+    let card = db
+        .find_vault_card_by_merchant_id_card_id(merchant_id, card_id)
+        .await?;
+
+    record_access(
+        db,
+        merchant_id,
+        storage_enums::VaultResourceType::Card,
+        card_id,
+        storage_enums::VaultAction::Retrieve,
+    )
+    .await?;
+
+    Ok(payment_methods::GetCardResponse {
+        card: get_add_card_response(card),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(
+    state: &AppState,
+    merchant_id: &str,
+    card_id: &str,
+) -> CustomResult<payment_methods::DeleteCardResponse, errors::StorageError> {
+    let db = &*state.store;
+    let card = db
+        .delete_vault_card_by_merchant_id_card_id(merchant_id, card_id)
+        .await?;
+
+    record_access(
+        db,
+        merchant_id,
+        storage_enums::VaultResourceType::Card,
+        card_id,
+        storage_enums::VaultAction::Delete,
+    )
+    .await?;
+
+    Ok(payment_methods::DeleteCardResponse {
+        external_id: Some(card.card_id.clone()),
+        card_id: Some(card.card_id),
+        card_isin: None,
+        status: "SUCCESS".to_string(),
+    })
+}
+
+/// Stores the values against the token, replacing the values stored earlier if the merchant
+/// already has the token. A new token is generated if none is provided.
+#[instrument(skip_all)]
+pub async fn store_token(
+    state: &AppState,
+    merchant_id: &str,
+    token: Option<String>,
+    value1: String,
+    value2: String,
+    ttl: u32,
+) -> CustomResult<storage::VaultToken, errors::StorageError> {
+    let db = &*state.store;
+    let expires_at =
+        common_utils::date_time::now().saturating_add(time::Duration::seconds(i64::from(ttl)));
+    let vault_token = match token {
+        Some(token) => match db
+            .find_vault_token_by_merchant_id_token(merchant_id, &token)
+            .await
+        {
+            Ok(_) => {
+                db.update_vault_token_by_token(
+                    merchant_id,
+                    &token,
+                    storage::VaultTokenUpdate::ValueUpdate {
+                        value1: value1.into(),
+                        value2: value2.into(),
+                        expires_at,
+                    },
+                )
+                .await?
+            }
+            // Inserting a token of another merchant fails with a unique violation
+            Err(error) if error.current_context().is_db_not_found() => {
+                insert_token(db, merchant_id, token, value1, value2, expires_at).await?
+            }
+            Err(error) => Err(error)?,
+        },
+        None => {
+            let token = generate_id_with_default_len("token");
+            insert_token(db, merchant_id, token, value1, value2, expires_at).await?
+        }
+    };
+
+    record_access(
+        db,
+        merchant_id,
+        storage_enums::VaultResourceType::Token,
+        &vault_token.token,
+        storage_enums::VaultAction::Store,
+    )
+    .await?;
+
+    Ok(vault_token)
+}
+
+async fn insert_token(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    token: String,
+    value1: String,
+    value2: String,
+    expires_at: time::PrimitiveDateTime,
+) -> CustomResult<storage::VaultToken, errors::StorageError> {
+    db.insert_vault_token(storage::VaultTokenNew {
+        token,
+        merchant_id: merchant_id.to_owned(),
+        value1: value1.into(),
+        value2: value2.into(),
+        expires_at,
+    })
+    .await
+}
+
+/// Expired tokens are treated as not found.
+#[instrument(skip_all)]
+pub async fn get_token(
+    state: &AppState,
+    merchant_id: &str,
+    token: &str,
+) -> CustomResult<storage::VaultToken, errors::StorageError> {
+    let db = &*state.store;
+    let vault_token = db
+        .find_vault_token_by_merchant_id_token(merchant_id, token)
+        .await?;
+
+    record_access(
+        db,
+        merchant_id,
+        storage_enums::VaultResourceType::Token,
+        token,
+        storage_enums::VaultAction::Retrieve,
+    )
+    .await?;
+
+    Ok(vault_token)
+}
+
+#[instrument(skip_all)]
+pub async fn delete_token(
+    state: &AppState,
+    merchant_id: &str,
+    token: &str,
+) -> CustomResult<bool, errors::StorageError> {
+    let db = &*state.store;
+    let deleted = db
+        .delete_vault_token_by_merchant_id_token(merchant_id, token)
+        .await?;
+
+    record_access(
+        db,
+        merchant_id,
+        storage_enums::VaultResourceType::Token,
+        token,
+        storage_enums::VaultAction::Delete,
+    )
+    .await?;
+
+    Ok(deleted)
+}
+
+pub fn get_next_token_cleanup_time(vault_settings: &VaultSettings) -> time::PrimitiveDateTime {
+    common_utils::date_time::now().saturating_add(time::Duration::seconds(i64::from(
+        vault_settings.token_cleanup_interval,
+    )))
+}
+
+/// Schedules the task which periodically deletes the expired tokens, unless it is already
+/// scheduled. A single task is shared by all the scheduler instances.
+#[instrument(skip_all)]
+pub async fn schedule_vault_token_cleanup(
+    state: &AppState,
+) -> Result<(), errors::ProcessTrackerError> {
+    if !state.conf.vault.enabled {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let process_tracker_id = format!("{VAULT_TOKEN_CLEANUP_RUNNER}_{VAULT_TOKEN_CLEANUP_TASK}");
+    match db.find_process_by_id(&process_tracker_id).await? {
+        // The task only finishes if it could not be scheduled again, so it is resumed
+        Some(process) if process.status == storage_enums::ProcessTrackerStatus::Finish => {
+            process.retry(db, common_utils::date_time::now()).await
+        }
+        Some(_) => Ok(()),
+        None => {
+            let current_time = common_utils::date_time::now();
+            let process_tracker_entry = storage::ProcessTrackerNew {
+                id: process_tracker_id,
+                name: Some(String::from(VAULT_TOKEN_CLEANUP_TASK)),
+                tag: vec![String::from("VAULT")],
+                runner: Some(String::from(VAULT_TOKEN_CLEANUP_RUNNER)),
+                retry_count: 0,
+                schedule_time: Some(current_time),
+                rule: String::new(),
+                tracking_data: serde_json::Value::Null,
+                business_status: String::from("Pending"),
+                status: storage_enums::ProcessTrackerStatus::New,
+                event: vec![],
+                created_at: current_time,
+                updated_at: current_time,
+            };
+
+            match db.insert_process(process_tracker_entry).await {
+                // Another scheduler instance scheduled the task in the meantime
+                Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+                result => result.map(|_| ()).map_err(Into::into),
+            }
+        }
+    }
+}
+
+fn validate_vault_enabled(vault_settings: &VaultSettings) -> RouterResult<()> {
+    if vault_settings.enabled {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "The built-in vault is not enabled".to_string(),
+        }))
+    }
+}
+
+/// Fetches a token of the merchant, tokens of other merchants are treated as not found.
+async fn get_merchant_token(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    token: &str,
+) -> RouterResult<storage::VaultToken> {
+    db.find_vault_token_by_merchant_id_token(&merchant_account.merchant_id, token)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound))
+}
+
+#[instrument(skip_all)]
+pub async fn tokenize_data(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: vault_api::TokenizeRequest,
+) -> RouterResponse<vault_api::TokenizeResponse> {
+    let vault_settings = &state.conf.vault;
+    validate_vault_enabled(vault_settings)?;
+
+    let ttl = req.ttl.unwrap_or(vault_settings.token_ttl);
+    if ttl == 0 || ttl > vault_settings.max_token_ttl {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "ttl must be between 1 and {} seconds",
+                vault_settings.max_token_ttl
+            ),
+        }))?;
+    }
+
+    let vault_token = store_token(
+        state,
+        &merchant_account.merchant_id,
+        None,
+        req.value1.expose(),
+        req.value2.map(ExposeInterface::expose).unwrap_or_default(),
+        ttl,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the data in the vault")?;
+
+    Ok(services::ApplicationResponse::Json(
+        vault_api::TokenizeResponse {
+            token: vault_token.token,
+            expires_at: vault_token.expires_at,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn detokenize_data(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: vault_api::DetokenizeRequest,
+) -> RouterResponse<vault_api::DetokenizeResponse> {
+    validate_vault_enabled(&state.conf.vault)?;
+
+    let db = &*state.store;
+    let vault_token = get_merchant_token(db, &merchant_account, &req.token).await?;
+    record_access(
+        db,
+        &merchant_account.merchant_id,
+        storage_enums::VaultResourceType::Token,
+        &req.token,
+        storage_enums::VaultAction::Retrieve,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let value2 = vault_token.value2.expose();
+
+    Ok(services::ApplicationResponse::Json(
+        vault_api::DetokenizeResponse {
+            value1: vault_token.value1,
+            value2: (!value2.is_empty()).then(|| value2.into()),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_token_data(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    token: String,
+) -> RouterResponse<vault_api::DeleteTokenResponse> {
+    validate_vault_enabled(&state.conf.vault)?;
+
+    get_merchant_token(&*state.store, &merchant_account, &token).await?;
+    let deleted = delete_token(state, &merchant_account.merchant_id, &token)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        vault_api::DeleteTokenResponse { token, deleted },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{
+        configs::settings::Settings,
+        test_utils::{self, get_json_response},
+    };
+
+    async fn get_state() -> AppState {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.vault.enabled = true;
+        let (state, _) = test_utils::get_state_with_settings(conf).await;
+        state
+    }
+
+    #[actix_rt::test]
+    async fn test_tokenize_detokenize_round_trip() {
+        let state = get_state().await;
+        let merchant_account = test_utils::insert_merchant(&state, "merchant_1").await;
+        let other_merchant_account = test_utils::insert_merchant(&state, "merchant_2").await;
+
+        let tokenized = get_json_response(
+            tokenize_data(
+                &state,
+                merchant_account.clone(),
+                vault_api::TokenizeRequest {
+                    value1: "4242424242424242".to_string().into(),
+                    value2: Some("123".to_string().into()),
+                    ttl: Some(600),
+                },
+            )
+            .await
+            .unwrap(),
+        );
+        assert!(tokenized.token.starts_with("token_"));
+
+        let detokenize = |merchant_account: storage::MerchantAccount| {
+            detokenize_data(
+                &state,
+                merchant_account,
+                vault_api::DetokenizeRequest {
+                    token: tokenized.token.clone(),
+                },
+            )
+        };
+        let detokenized = get_json_response(detokenize(merchant_account.clone()).await.unwrap());
+        assert_eq!(detokenized.value1.peek(), "4242424242424242");
+        assert_eq!(detokenized.value2.unwrap().peek(), "123");
+
+        // Tokens of other merchants are treated as not found
+        let error = detokenize(other_merchant_account).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::ResourceIdNotFound
+        ));
+
+        let access_events = state
+            .store
+            .find_vault_access_events_by_merchant_id_resource_id("merchant_1", &tokenized.token, 10)
+            .await
+            .unwrap();
+        let actions: Vec<_> = access_events.iter().map(|event| event.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                storage_enums::VaultAction::Retrieve,
+                storage_enums::VaultAction::Store
+            ]
+        );
+
+        let deleted = get_json_response(
+            delete_token_data(&state, merchant_account.clone(), tokenized.token.clone())
+                .await
+                .unwrap(),
+        );
+        assert!(deleted.deleted);
+        assert!(detokenize(merchant_account).await.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_tokenize_without_value2() {
+        let state = get_state().await;
+        let merchant_account = test_utils::insert_merchant(&state, "merchant_1").await;
+
+        let tokenized = get_json_response(
+            tokenize_data(
+                &state,
+                merchant_account.clone(),
+                vault_api::TokenizeRequest {
+                    value1: "4242424242424242".to_string().into(),
+                    value2: None,
+                    ttl: None,
+                },
+            )
+            .await
+            .unwrap(),
+        );
+        let token_ttl = (tokenized.expires_at - common_utils::date_time::now()).whole_seconds();
+        assert!((899..=900).contains(&token_ttl));
+
+        let detokenized = get_json_response(
+            detokenize_data(
+                &state,
+                merchant_account,
+                vault_api::DetokenizeRequest {
+                    token: tokenized.token,
+                },
+            )
+            .await
+            .unwrap(),
+        );
+        assert!(detokenized.value2.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn test_invalid_token_ttl() {
+        let state = get_state().await;
+        let merchant_account = test_utils::insert_merchant(&state, "merchant_1").await;
+
+        for ttl in [0, state.conf.vault.max_token_ttl + 1] {
+            let error = tokenize_data(
+                &state,
+                merchant_account.clone(),
+                vault_api::TokenizeRequest {
+                    value1: "4242424242424242".to_string().into(),
+                    value2: None,
+                    ttl: Some(ttl),
+                },
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_expired_tokens() {
+        let state = get_state().await;
+        let merchant_account = test_utils::insert_merchant(&state, "merchant_1").await;
+        let db = &*state.store;
+
+        let expired_token = db
+            .insert_vault_token(storage::VaultTokenNew {
+                token: "token_expired".to_string(),
+                merchant_id: "merchant_1".to_string(),
+                value1: "4242424242424242".to_string().into(),
+                value2: String::new().into(),
+                expires_at: common_utils::date_time::now()
+                    .saturating_sub(time::Duration::seconds(1)),
+            })
+            .await
+            .unwrap();
+        let active_token = store_token(
+            &state,
+            "merchant_1",
+            None,
+            "4242424242424242".to_string(),
+            String::new(),
+            600,
+        )
+        .await
+        .unwrap();
+
+        // Expired tokens are treated as not found, even before they are deleted
+        let error = detokenize_data(
+            &state,
+            merchant_account,
+            vault_api::DetokenizeRequest {
+                token: expired_token.token.clone(),
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::ResourceIdNotFound
+        ));
+
+        // Storing a token does not delete the expired tokens, the scheduler task does
+        assert!(db.delete_expired_vault_tokens().await.unwrap());
+        assert!(!db.delete_expired_vault_tokens().await.unwrap());
+        assert!(db
+            .delete_vault_token_by_merchant_id_token("merchant_1", &expired_token.token)
+            .await
+            .is_err());
+        assert!(get_token(&state, "merchant_1", &active_token.token)
+            .await
+            .is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_cards_of_other_merchants_are_not_found() {
+        let state = get_state().await;
+        let card = store_card(
+            &state,
+            "merchant_1",
+            "customer_1",
+            &api::CardDetail {
+                card_number: "4242424242424242".to_string().into(),
+                card_exp_month: "10".to_string().into(),
+                card_exp_year: "25".to_string().into(),
+                card_holder_name: None,
+            },
+            "card_fingerprint",
+        )
+        .await
+        .unwrap();
+
+        let error = get_card(&state, "merchant_2", &card.card_id)
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+        let error = delete_card(&state, "merchant_2", &card.card_id)
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+
+        let fetched = get_card(&state, "merchant_1", &card.card_id).await.unwrap();
+        assert_eq!(fetched.card.card_number.unwrap().peek(), "4242424242424242");
+        assert!(delete_card(&state, "merchant_1", &card.card_id)
+            .await
+            .is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_tokens_of_other_merchants_are_not_found() {
+        let state = get_state().await;
+        let vault_token = store_token(
+            &state,
+            "merchant_1",
+            None,
+            "4242424242424242".to_string(),
+            String::new(),
+            600,
+        )
+        .await
+        .unwrap();
+
+        let error = get_token(&state, "merchant_2", &vault_token.token)
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+        let error = delete_token(&state, "merchant_2", &vault_token.token)
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+
+        // Storing values against the token of another merchant does not replace its values
+        let error = store_token(
+            &state,
+            "merchant_2",
+            Some(vault_token.token.clone()),
+            "4000000000000002".to_string(),
+            String::new(),
+            600,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.current_context().is_db_unique_violation());
+
+        let fetched = get_token(&state, "merchant_1", &vault_token.token)
+            .await
+            .unwrap();
+        assert_eq!(fetched.value1.peek(), "4242424242424242");
+        assert!(delete_token(&state, "merchant_1", &vault_token.token)
+            .await
+            .unwrap());
+    }
+
+    #[actix_rt::test]
+    async fn test_schedule_vault_token_cleanup() {
+        let state = get_state().await;
+        let process_id = format!("{VAULT_TOKEN_CLEANUP_RUNNER}_{VAULT_TOKEN_CLEANUP_TASK}");
+
+        schedule_vault_token_cleanup(&state).await.unwrap();
+        let process = state
+            .store
+            .find_process_by_id(&process_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.runner.as_deref(), Some(VAULT_TOKEN_CLEANUP_RUNNER));
+
+        // Starting the scheduler again leaves the scheduled task as is
+        schedule_vault_token_cleanup(&state).await.unwrap();
+        assert_eq!(process.status, storage_enums::ProcessTrackerStatus::New);
+    }
+}
@@ -1,8 +1,19 @@
+use error_stack::{report, ResultExt};
+
 use crate::{
-    core::errors::{self, utils::StorageErrorExt, RouterResponse},
+    configs::dynamic::DynamicConfigSection,
+    core::errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
     db::StorageInterface,
-    services::ApplicationResponse,
-    types::{api, transformers::ForeignInto},
+    routes::AppState,
+    services::{
+        audit::{AuditContext, AuditResource},
+        ApplicationResponse,
+    },
+    types::{
+        api,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
 };
 
 pub async fn read_config(store: &dyn StorageInterface, key: &str) -> RouterResponse<api::Config> {
@@ -14,12 +25,92 @@ pub async fn read_config(store: &dyn StorageInterface, key: &str) -> RouterRespo
 }
 
 pub async fn update_config(
-    store: &dyn StorageInterface,
+    state: &AppState,
     config_update: &api::ConfigUpdate,
+    audit_context: AuditContext,
 ) -> RouterResponse<api::Config> {
-    let config = store
+    let store = &*state.store;
+
+    let config_before: Option<api::Config> =
+        match store.find_config_by_key(&config_update.key).await {
+            Ok(config) => Some(config.foreign_into()),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch config"))?,
+        };
+
+    let config: api::Config = match DynamicConfigSection::from_config_key(&config_update.key) {
+        Some(section) => update_dynamic_config(state, section, config_update).await?,
+        None => store
+            .update_config_cached(&config_update.key, config_update.foreign_into())
+            .await
+            .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ConfigNotFound))?,
+    }
+    .foreign_into();
+
+    // Overrides of the settings are created by their first update
+    let action = if config_before.is_some() {
+        enums::AuditAction::Update
+    } else {
+        enums::AuditAction::Create
+    };
+    audit_context
+        .record(
+            store,
+            action,
+            AuditResource::config(&config.key),
+            config_before.as_ref(),
+            Some(&config),
+        )
+        .await;
+
+    Ok(ApplicationResponse::Json(config))
+}
+
+/// Validates the override of a section of the settings, stores it and notifies all the instances
+/// about it. Overrides are created if they don't exist yet.
+async fn update_dynamic_config(
+    state: &AppState,
+    section: DynamicConfigSection,
+    config_update: &api::ConfigUpdate,
+) -> RouterResult<storage::Config> {
+    let store = &*state.store;
+
+    state
+        .dynamic_config
+        .validate_override(section, &config_update.value)
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: error.to_string(),
+            })
+        })?;
+
+    // Updating the cached config notifies the instances about the update
+    match store
         .update_config_cached(&config_update.key, config_update.foreign_into())
         .await
-        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ConfigNotFound))?;
-    Ok(ApplicationResponse::Json(config.foreign_into()))
+    {
+        Err(error) if error.current_context().is_db_not_found() => {
+            let config = store
+                .insert_config(storage::ConfigNew {
+                    key: config_update.key.clone(),
+                    config: config_update.value.clone(),
+                })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert dynamic config override")?;
+
+            store
+                .publish_config_update(&config_update.key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to notify instances about the dynamic config override")?;
+
+            Ok(config)
+        }
+        result => result
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update dynamic config override"),
+    }
 }
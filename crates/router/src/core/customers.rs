@@ -11,7 +11,7 @@ use crate::{
     db::StorageInterface,
     pii::PeekInterface,
     routes::AppState,
-    services,
+    services::{self, envelope_encryption::Decryptable},
     types::{
         api::customers::{self, CustomerRequestExt},
         storage::{self, enums},
@@ -67,6 +67,7 @@ pub async fn create_customer(
         description: customer_data.description,
         phone_country_code: customer_data.phone_country_code,
         metadata: customer_data.metadata,
+        connector_customer: None,
     };
 
     let customer = match db.insert_customer(new_customer).await {
@@ -88,7 +89,10 @@ pub async fn create_customer(
             }
         }
     };
-    let mut customer_response: customers::CustomerResponse = customer.into();
+    let mut customer_response: customers::CustomerResponse = customer
+        .decrypt()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .into();
     customer_response.address = customer_data.address;
 
     Ok(services::ApplicationResponse::Json(customer_response))
@@ -103,6 +107,7 @@ pub async fn retrieve_customer(
     let response = db
         .find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
         .await
+        .and_then(Decryptable::decrypt)
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
 
     Ok(services::ApplicationResponse::Json(response.into()))
@@ -281,6 +286,7 @@ pub async fn update_customer(
             },
         )
         .await
+        .and_then(Decryptable::decrypt)
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
 
     let mut customer_update_response: customers::CustomerResponse = response.into();
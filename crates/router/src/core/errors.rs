@@ -14,7 +14,7 @@ use storage_models::errors as storage_errors;
 
 pub use self::{
     api_error_response::ApiErrorResponse,
-    utils::{ConnectorErrorExt, StorageErrorExt},
+    utils::{ConnectorErrorExt, StorageErrorExt, ValidationErrorExt},
 };
 use crate::services;
 pub type RouterResult<T> = CustomResult<T, ApiErrorResponse>;
@@ -73,6 +73,12 @@ pub enum StorageError {
     DeserializationFailed,
     #[error("Received Error RedisError: {0}")]
     ERedisError(error_stack::Report<RedisError>),
+    #[error("Failed to encrypt data")]
+    EncryptionError,
+    #[error("Failed to decrypt data")]
+    DecryptionError,
+    #[error("Missing required field: {field_name}")]
+    MissingRequiredField { field_name: &'static str },
 }
 
 impl From<error_stack::Report<RedisError>> for StorageError {
@@ -280,6 +286,8 @@ pub enum ConnectorError {
     InvalidDateFormat,
     #[error("Payment Method data / Payment Method Type / Payment Experience Mismatch ")]
     MismatchedPaymentData,
+    #[error("Circuit is open for connector: {connector}")]
+    CircuitOpen { connector: String },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -294,6 +302,8 @@ pub enum VaultError {
     ResponseDeserializationFailed,
     #[error("Failed to create payment method")]
     PaymentMethodCreationFailed,
+    #[error("The card is already saved for the customer")]
+    DuplicatePaymentMethod,
     #[error("The given payment method is currently not supported in vault")]
     PaymentMethodNotSupported,
     #[error("Missing required field: {field_name}")]
@@ -302,6 +312,20 @@ pub enum VaultError {
     UnexpectedResponseError(bytes::Bytes),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum NetworkTokenizationError {
+    #[error("Failed to provision network token with the token service provider")]
+    TokenProvisioningFailed,
+    #[error("Failed to generate cryptogram for the network token")]
+    CryptogramGenerationFailed,
+    #[error("Failed to delete network token with the token service provider")]
+    TokenDeletionFailed,
+    #[error("Failed to verify the signature of the token lifecycle event")]
+    LifecycleEventVerificationFailed,
+    #[error("Failed to deserialize the token lifecycle event")]
+    LifecycleEventDeserializationFailed,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ProcessTrackerError {
     #[error("An unexpected flow was specified")]
@@ -411,6 +435,8 @@ pub enum WebhooksFlowError {
     CallToMerchantFailed,
     #[error("Webhook not received by merchant")]
     NotReceivedByMerchant,
+    #[error("Failed to encode the outgoing webhook")]
+    OutgoingWebhookEncodingFailed,
     #[error("Resource not found")]
     ResourceNotFound,
 }
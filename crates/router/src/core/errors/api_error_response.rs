@@ -83,6 +83,8 @@ pub enum ApiErrorResponse {
     GenericUnauthorized { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_19", message = "{message}")]
     NotSupported { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_20", message = "Too many requests, retry after {retry_after} seconds")]
+    TooManyRequests { retry_after: u64 },
 
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
@@ -106,6 +108,10 @@ pub enum ApiErrorResponse {
     RefundFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_07", message = "Verification failed while processing with connector. Retry operation")]
     VerificationFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "{connector} is currently unavailable. Retry after some time or use another connector")]
+    ConnectorUnavailable { connector: String },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "The payment was declined by the fraud check")]
+    FraudCheckDeclined { payment_id: String },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
@@ -217,6 +223,7 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::RefundFailed { .. }
             | Self::RefundNotPossible { .. }
             | Self::VerificationFailed { .. }
+            | Self::FraudCheckDeclined { .. }
             | Self::PaymentUnexpectedState { .. }
             | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
@@ -245,7 +252,9 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::DuplicatePaymentMethod
             | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
             Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
+            Self::ConnectorUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE, // 503
             Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
+            Self::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS, // 429
             Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,    // 501
         }
     }
@@ -253,10 +262,14 @@ impl actix_web::ResponseError for ApiErrorResponse {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response
             .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .insert_header((header::VIA, "Juspay_Router"))
-            .body(self.to_string())
+            .insert_header((header::VIA, "Juspay_Router"));
+        if let Self::TooManyRequests { retry_after } = self {
+            response.insert_header((header::RETRY_AFTER, retry_after.to_string()));
+        }
+        response.body(self.to_string())
     }
 }
 
@@ -340,6 +353,7 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::GenericUnauthorized { message } => {
                 AER::Unauthorized(ApiError::new("IR", 18, message.to_string(), None))
             }
+            Self::TooManyRequests { retry_after } => AER::TooManyRequests(ApiError::new("IR", 20, format!("Too many requests, retry after {retry_after} seconds"), None), *retry_after),
             Self::ExternalConnectorError {
                 code,
                 message,
@@ -362,6 +376,12 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::VerificationFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 7, "Verification failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::ConnectorUnavailable { connector } => {
+                AER::ConnectorError(ApiError::new("CE", 8, format!("{connector} is currently unavailable. Retry after some time or use another connector"), Some(Extra { connector: Some(connector.clone()), ..Default::default()})), StatusCode::SERVICE_UNAVAILABLE)
+            }
+            Self::FraudCheckDeclined { payment_id } => {
+                AER::BadRequest(ApiError::new("CE", 9, "The payment was declined by the fraud check", Some(Extra { payment_id: Some(payment_id.clone()), ..Default::default()})))
+            }
             Self::InternalServerError => {
                 AER::InternalServerError(ApiError::new("HE", 0, "Something went wrong", None))
             }
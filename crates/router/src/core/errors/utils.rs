@@ -27,6 +27,10 @@ impl StorageErrorExt for error_stack::Report<errors::StorageError> {
             errors::StorageError::CustomerRedacted => {
                 self.change_context(errors::ApiErrorResponse::CustomerRedacted)
             }
+            errors::StorageError::MissingRequiredField { field_name } => {
+                let field_name = *field_name;
+                self.change_context(errors::ApiErrorResponse::MissingRequiredField { field_name })
+            }
             _ => self.change_context(errors::ApiErrorResponse::InternalServerError),
         }
     }
@@ -36,13 +40,36 @@ impl StorageErrorExt for error_stack::Report<errors::StorageError> {
         duplicate_response: errors::ApiErrorResponse,
     ) -> error_stack::Report<errors::ApiErrorResponse> {
         if self.current_context().is_db_unique_violation() {
-            self.change_context(duplicate_response)
-        } else {
-            self.change_context(errors::ApiErrorResponse::InternalServerError)
+            return self.change_context(duplicate_response);
+        }
+        match self.current_context() {
+            errors::StorageError::MissingRequiredField { field_name } => {
+                let field_name = *field_name;
+                self.change_context(errors::ApiErrorResponse::MissingRequiredField { field_name })
+            }
+            _ => self.change_context(errors::ApiErrorResponse::InternalServerError),
         }
     }
 }
 
+pub trait ValidationErrorExt {
+    #[track_caller]
+    fn to_invalid_field_response(
+        self,
+        field_name: &str,
+    ) -> error_stack::Report<errors::ApiErrorResponse>;
+}
+
+impl ValidationErrorExt for error_stack::Report<errors::ValidationError> {
+    fn to_invalid_field_response(
+        self,
+        field_name: &str,
+    ) -> error_stack::Report<errors::ApiErrorResponse> {
+        let message = format!("{field_name}: {}", self.current_context());
+        self.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+    }
+}
+
 pub trait ConnectorErrorExt {
     #[track_caller]
     fn to_refund_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
@@ -69,6 +96,11 @@ impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
                     }
                 }
             }
+            errors::ConnectorError::CircuitOpen { connector } => {
+                let connector = connector.clone();
+                return self
+                    .change_context(errors::ApiErrorResponse::ConnectorUnavailable { connector });
+            }
             _ => None,
         };
         self.change_context(errors::ApiErrorResponse::RefundFailed { data })
@@ -110,6 +142,11 @@ impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
             errors::ConnectorError::NotSupported { payment_method, connector, payment_experience } => {
                 errors::ApiErrorResponse::NotSupported { message: format!("Payment method type {payment_method} is not supported by {connector} through payment experience {payment_experience}") }
             }
+            errors::ConnectorError::CircuitOpen { connector } => {
+                errors::ApiErrorResponse::ConnectorUnavailable {
+                    connector: connector.clone(),
+                }
+            }
             _ => errors::ApiErrorResponse::InternalServerError,
         };
         self.change_context(error)
@@ -129,6 +166,11 @@ impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
                     }
                 }
             }
+            errors::ConnectorError::CircuitOpen { connector } => {
+                let connector = connector.clone();
+                return self
+                    .change_context(errors::ApiErrorResponse::ConnectorUnavailable { connector });
+            }
             _ => None,
         };
         self.change_context(errors::ApiErrorResponse::PaymentAuthorizationFailed { data })
@@ -0,0 +1,442 @@
+//! Fraud check of payments by fraud and risk management (FRM) connectors.
+//!
+//! Payments are checked after the payment method data is resolved and before the payment is sent
+//! to the payment connector, so that the FRM connector sees the same card, customer, browser and
+//! order details that the payment connector is sent. The decision of the FRM connector is stored
+//! on the payment attempt:
+//! - accepted payments are authorized as requested,
+//! - payments held for review are switched to manual capture, so that the funds are only captured
+//!   once the payment is reviewed,
+//! - declined payments are failed without being sent to the payment connector.
+//!
+//! Errors of the FRM connector are logged and the payment is accepted, so that an outage of the
+//! FRM connector does not block payments.
+
+pub mod velocity_rules;
+
+use std::marker::PhantomData;
+
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, opentelemetry::KeyValue, tracing};
+
+use super::payments::PaymentData;
+use crate::{
+    configs::settings::FrmConnectorKind,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    logger,
+    routes::{metrics, AppState},
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+pub trait FrmConnector: Send + Sync {
+    /// Name of the connector, stored on the payment attempts it checks.
+    fn name(&self) -> &'static str;
+
+    /// Checks a payment for fraud, setting the response of the router data to the decision of the
+    /// connector, or to the error returned by the connector.
+    async fn check_fraud(
+        &self,
+        state: &AppState,
+        router_data: types::FraudCheckRouterData,
+    ) -> RouterResult<types::FraudCheckRouterData>;
+}
+
+pub fn get_frm_connector(kind: FrmConnectorKind) -> Box<dyn FrmConnector> {
+    match kind {
+        FrmConnectorKind::VelocityRules => Box::new(velocity_rules::VelocityRules),
+    }
+}
+
+/// Checks a payment which is being confirmed with the configured FRM connector, and applies the
+/// decision to the payment. Returns [`errors::ApiErrorResponse::FraudCheckDeclined`] if the payment
+/// is declined.
+#[instrument(skip_all)]
+pub async fn run_fraud_check<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+    customer: Option<&storage::Customer>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    let frm_connector = get_frm_connector(state.conf.fraud_check.connector);
+    let router_data = construct_fraud_check_router_data(
+        state,
+        merchant_account,
+        payment_data,
+        customer,
+        frm_connector.name(),
+    )?;
+
+    let response = match frm_connector.check_fraud(state, router_data).await {
+        Ok(router_data) => router_data.response,
+        Err(error) => {
+            logger::error!(fraud_check_error=?error);
+            return Ok(());
+        }
+    };
+    let response = match response {
+        Ok(response) => response,
+        Err(error) => {
+            logger::error!(fraud_check_error=?error);
+            return Ok(());
+        }
+    };
+
+    logger::info!(decision=%response.decision, reason=?response.reason, "Fraud check completed");
+    metrics::FRAUD_CHECK_DECISIONS.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            KeyValue::new("frm_connector", frm_connector.name()),
+            KeyValue::new("decision", response.decision.to_string()),
+        ],
+    );
+
+    let (capture_method, status, error_code, error_message) = match response.decision {
+        storage_enums::FraudCheckDecision::Accept => (None, None, None, None),
+        storage_enums::FraudCheckDecision::Review => {
+            let capture_method = match payment_data.payment_attempt.capture_method {
+                Some(
+                    storage_enums::CaptureMethod::Manual
+                    | storage_enums::CaptureMethod::ManualMultiple,
+                ) => None,
+                _ => Some(storage_enums::CaptureMethod::Manual),
+            };
+            (capture_method, None, None, None)
+        }
+        storage_enums::FraudCheckDecision::Decline => (
+            None,
+            Some(storage_enums::AttemptStatus::Failure),
+            Some("fraud_check_declined".to_string()),
+            response.reason.clone(),
+        ),
+    };
+
+    let db = &*state.store;
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_check_decision: response.decision,
+                fraud_check_connector: frm_connector.name().to_string(),
+                fraud_check_reason: response.reason,
+                capture_method,
+                status,
+                error_code,
+                error_message,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    if response.decision == storage_enums::FraudCheckDecision::Decline {
+        payment_data.payment_intent = db
+            .update_payment_intent(
+                payment_data.payment_intent.clone(),
+                storage::PaymentIntentUpdate::PGStatusUpdate {
+                    status: storage_enums::IntentStatus::Failed,
+                },
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        Err(report!(errors::ApiErrorResponse::FraudCheckDeclined {
+            payment_id: payment_data.payment_intent.payment_id.clone(),
+        }))?
+    }
+
+    Ok(())
+}
+
+fn construct_fraud_check_router_data<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    customer: Option<&storage::Customer>,
+    frm_connector_name: &str,
+) -> RouterResult<types::FraudCheckRouterData> {
+    let payment_method = payment_data
+        .payment_attempt
+        .payment_method
+        .get_required_value("payment_method")?;
+
+    let browser_info: Option<types::BrowserInformation> = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| browser_info.parse_value("BrowserInformation"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?;
+
+    let order_details = payment_data
+        .payment_intent
+        .metadata
+        .clone()
+        .and_then(|metadata| {
+            metadata
+                .parse_value::<api_models::payments::Metadata>("metadata")
+                .ok()
+        })
+        .and_then(|metadata| metadata.order_details);
+
+    let card_fingerprint = match &payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => Some(cards::get_card_fingerprint(
+            &state.conf.secrets.card_fingerprint_hash_key,
+            card.card_number.peek(),
+        )?),
+        _ => None,
+    };
+
+    let request = types::FraudCheckData {
+        amount: payment_data.amount.into(),
+        currency: payment_data.currency,
+        payment_method_data: payment_data.payment_method_data.clone(),
+        email: payment_data
+            .email
+            .clone()
+            .or_else(|| customer.and_then(|customer| customer.email.clone())),
+        customer_id: customer
+            .map(|customer| customer.customer_id.clone())
+            .or_else(|| payment_data.payment_intent.customer_id.clone()),
+        browser_info,
+        order_details,
+        card_fingerprint,
+    };
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: frm_connector_name.to_string(),
+        payment_id: payment_data.payment_attempt.payment_id.clone(),
+        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+        status: payment_data.payment_attempt.status,
+        payment_method,
+        connector_auth_type: types::ConnectorAuthType::NoKey,
+        description: payment_data.payment_intent.description.clone(),
+        return_url: payment_data.payment_intent.return_url.clone(),
+        router_return_url: None,
+        payment_method_id: payment_data.payment_attempt.payment_method_id.clone(),
+        address: payment_data.address.clone(),
+        auth_type: payment_data
+            .payment_attempt
+            .authentication_type
+            .unwrap_or_default(),
+        connector_meta_data: None,
+        amount_captured: payment_data.payment_intent.amount_captured,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{
+        configs::settings::{Settings, VelocityThresholds},
+        core::payments::PaymentAddress,
+        test_utils,
+    };
+
+    /// Returns the state of a router which checks payments with the card fingerprint thresholds.
+    async fn get_state(thresholds: VelocityThresholds) -> (AppState, storage::MerchantAccount) {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.fraud_check.enabled = true;
+        conf.fraud_check.velocity_rules.card_fingerprint = thresholds;
+        test_utils::get_state_with_settings(conf).await
+    }
+
+    async fn get_payment_data(state: &AppState) -> PaymentData<api::Authorize> {
+        let storage_scheme = storage_enums::MerchantStorageScheme::PostgresOnly;
+        let payment_intent = state
+            .store
+            .insert_payment_intent(
+                storage::PaymentIntentNew {
+                    payment_id: "pay_1".to_string(),
+                    merchant_id: test_utils::MERCHANT_ID.to_string(),
+                    status: storage_enums::IntentStatus::RequiresConfirmation,
+                    amount: 1000,
+                    currency: Some(storage_enums::Currency::USD),
+                    ..storage::PaymentIntentNew::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        let payment_attempt = state
+            .store
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    payment_id: "pay_1".to_string(),
+                    merchant_id: test_utils::MERCHANT_ID.to_string(),
+                    attempt_id: "pay_1_1".to_string(),
+                    status: storage_enums::AttemptStatus::Pending,
+                    amount: 1000,
+                    currency: Some(storage_enums::Currency::USD),
+                    payment_method: Some(storage_enums::PaymentMethod::Card),
+                    capture_method: Some(storage_enums::CaptureMethod::Automatic),
+                    ..storage::PaymentAttemptNew::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        let current_time = common_utils::date_time::now();
+
+        PaymentData {
+            flow: PhantomData,
+            payment_intent,
+            payment_attempt,
+            connector_response: storage::ConnectorResponse {
+                id: 1,
+                payment_id: "pay_1".to_string(),
+                merchant_id: test_utils::MERCHANT_ID.to_string(),
+                attempt_id: "pay_1_1".to_string(),
+                created_at: current_time,
+                modified_at: current_time,
+                connector_name: None,
+                connector_transaction_id: None,
+                authentication_data: None,
+                encoded_data: None,
+            },
+            amount: api::Amount::from(1000_i64),
+            mandate_id: None,
+            currency: storage_enums::Currency::USD,
+            setup_mandate: None,
+            address: PaymentAddress::default(),
+            token: None,
+            confirm: Some(true),
+            force_sync: None,
+            payment_method_data: Some(api::PaymentMethodData::Card(api::Card {
+                card_number: "4242424242424242".to_string().into(),
+                card_exp_month: "12".to_string().into(),
+                card_exp_year: "99".to_string().into(),
+                card_holder_name: "John Doe".to_string().into(),
+                card_cvc: "123".to_string().into(),
+                card_issuer: None,
+                card_network: None,
+                card_type: None,
+                card_issuing_country: None,
+            })),
+            refunds: Vec::new(),
+            sessions_token: Vec::new(),
+            card_cvc: None,
+            email: None,
+            payment_link: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_payments_held_for_review_are_switched_to_manual_capture() {
+        let (state, merchant_account) = get_state(VelocityThresholds {
+            review_after: 0,
+            decline_after: 5,
+        })
+        .await;
+        let mut payment_data = get_payment_data(&state).await;
+
+        run_fraud_check(
+            &state,
+            &merchant_account,
+            &mut payment_data,
+            None,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap();
+
+        let payment_attempt = &payment_data.payment_attempt;
+        assert_eq!(
+            payment_attempt.fraud_check_decision,
+            Some(storage_enums::FraudCheckDecision::Review)
+        );
+        assert_eq!(
+            payment_attempt.fraud_check_connector.as_deref(),
+            Some("velocity_rules")
+        );
+        assert_eq!(
+            payment_attempt.capture_method,
+            Some(storage_enums::CaptureMethod::Manual)
+        );
+        assert_eq!(
+            payment_attempt.status,
+            storage_enums::AttemptStatus::Pending
+        );
+        assert_eq!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresConfirmation
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_declined_payments_are_failed() {
+        let (state, merchant_account) = get_state(VelocityThresholds {
+            review_after: 0,
+            decline_after: 0,
+        })
+        .await;
+        let mut payment_data = get_payment_data(&state).await;
+
+        let error = run_fraud_check(
+            &state,
+            &merchant_account,
+            &mut payment_data,
+            None,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::FraudCheckDeclined { payment_id } if payment_id == "pay_1"
+        ));
+
+        let payment_attempt = &payment_data.payment_attempt;
+        assert_eq!(
+            payment_attempt.fraud_check_decision,
+            Some(storage_enums::FraudCheckDecision::Decline)
+        );
+        assert_eq!(
+            payment_attempt.status,
+            storage_enums::AttemptStatus::Failure
+        );
+        assert_eq!(
+            payment_attempt.error_code.as_deref(),
+            Some("fraud_check_declined")
+        );
+        assert_eq!(
+            payment_attempt.capture_method,
+            Some(storage_enums::CaptureMethod::Automatic)
+        );
+
+        let payment_intent = state
+            .store
+            .find_payment_intent_by_payment_id_merchant_id(
+                "pay_1",
+                test_utils::MERCHANT_ID,
+                storage_enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_intent.status, storage_enums::IntentStatus::Failed);
+    }
+}
@@ -0,0 +1,308 @@
+//! Rules-based FRM connector which counts the payments of a merchant made with the same card, email
+//! or IP address.
+//!
+//! Payments are counted in fixed windows stored in Redis, by the card fingerprint and by hashes of
+//! the email and IP address, so that the counts are shared by all router instances without storing
+//! the email and IP address in the clear. A payment is declined when any of its counts exceeds the
+//! decline threshold of the dimension, and held for review when any of them exceeds the review
+//! threshold. Errors while updating a count are logged and the dimension is skipped.
+//!
+//! Every checked payment is counted, including the ones which are declined. Declined attempts are
+//! counted on purpose, so that a card, email or IP address which keeps retrying within the window
+//! stays declined instead of being let through once its earlier attempts stop counting.
+
+use common_utils::{
+    crypto::{self, SignMessage},
+    date_time,
+};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+
+use super::FrmConnector;
+use crate::{
+    configs::settings::VelocityThresholds,
+    core::errors::{self, RouterResult},
+    logger,
+    routes::AppState,
+    types::{self, storage::enums as storage_enums},
+};
+
+pub struct VelocityRules;
+
+/// Payment details by which payments are counted.
+#[derive(Clone, Copy, Debug, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum Dimension {
+    CardFingerprint,
+    Email,
+    IpAddress,
+}
+
+#[async_trait::async_trait]
+impl FrmConnector for VelocityRules {
+    fn name(&self) -> &'static str {
+        "velocity_rules"
+    }
+
+    async fn check_fraud(
+        &self,
+        state: &AppState,
+        mut router_data: types::FraudCheckRouterData,
+    ) -> RouterResult<types::FraudCheckRouterData> {
+        let config = &state.conf.fraud_check.velocity_rules;
+        let hash_key = &state.conf.secrets.velocity_hash_key;
+        let request = &router_data.request;
+
+        let email = request
+            .email
+            .as_ref()
+            .map(|email| hash_value(hash_key, email.peek()))
+            .transpose()?;
+        let ip_address = request
+            .browser_info
+            .as_ref()
+            .and_then(|browser_info| browser_info.ip_address)
+            .map(|ip_address| hash_value(hash_key, &ip_address.to_string()))
+            .transpose()?;
+        let dimensions = [
+            (
+                Dimension::CardFingerprint,
+                request.card_fingerprint.clone(),
+                config.card_fingerprint,
+            ),
+            (Dimension::Email, email, config.email),
+            (Dimension::IpAddress, ip_address, config.ip_address),
+        ];
+
+        let window = date_time::now_unix_timestamp() / config.window;
+        let mut decision = storage_enums::FraudCheckDecision::Accept;
+        let mut reason = None;
+        for (dimension, value, thresholds) in dimensions {
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let counter = get_counter(&router_data.merchant_id, dimension, &value);
+            let count = match state
+                .store
+                .increment_velocity_count(&counter, window, config.window)
+                .await
+            {
+                Ok(count) => count,
+                Err(error) => {
+                    logger::error!(fraud_check_error=?error, %dimension);
+                    continue;
+                }
+            };
+
+            let dimension_decision = get_decision(count, thresholds);
+            if is_stricter(dimension_decision, decision) {
+                decision = dimension_decision;
+                reason = Some(format!(
+                    "{count} payments with the same {dimension} within {} seconds",
+                    config.window
+                ));
+            }
+        }
+
+        router_data.response = Ok(types::FraudCheckResponseData { decision, reason });
+        Ok(router_data)
+    }
+}
+
+/// Counters are scoped to the merchant, so that the payments of other merchants made with the same
+/// card, email or IP address are not counted.
+fn get_counter(merchant_id: &str, dimension: Dimension, value: &str) -> String {
+    format!("{merchant_id}_{dimension}_{value}")
+}
+
+fn hash_value(hash_key: &str, value: &str) -> RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(hash_key.as_bytes(), value.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the fraud check velocity value")
+}
+
+/// Decision for a dimension whose count, including the payment being checked, is `count`.
+fn get_decision(count: u64, thresholds: VelocityThresholds) -> storage_enums::FraudCheckDecision {
+    if count > thresholds.decline_after {
+        storage_enums::FraudCheckDecision::Decline
+    } else if count > thresholds.review_after {
+        storage_enums::FraudCheckDecision::Review
+    } else {
+        storage_enums::FraudCheckDecision::Accept
+    }
+}
+
+fn is_stricter(
+    decision: storage_enums::FraudCheckDecision,
+    than: storage_enums::FraudCheckDecision,
+) -> bool {
+    fn severity(decision: storage_enums::FraudCheckDecision) -> u8 {
+        match decision {
+            storage_enums::FraudCheckDecision::Accept => 0,
+            storage_enums::FraudCheckDecision::Review => 1,
+            storage_enums::FraudCheckDecision::Decline => 2,
+        }
+    }
+
+    severity(decision) > severity(than)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use std::marker::PhantomData;
+
+    use super::*;
+    use crate::{configs::settings::Settings, test_utils};
+
+    const THRESHOLDS: VelocityThresholds = VelocityThresholds {
+        review_after: 3,
+        decline_after: 5,
+    };
+
+    #[test]
+    fn test_payments_within_thresholds_are_accepted() {
+        assert_eq!(
+            get_decision(1, THRESHOLDS),
+            storage_enums::FraudCheckDecision::Accept
+        );
+        assert_eq!(
+            get_decision(3, THRESHOLDS),
+            storage_enums::FraudCheckDecision::Accept
+        );
+    }
+
+    #[test]
+    fn test_payments_over_thresholds_are_reviewed_or_declined() {
+        assert_eq!(
+            get_decision(4, THRESHOLDS),
+            storage_enums::FraudCheckDecision::Review
+        );
+        assert_eq!(
+            get_decision(5, THRESHOLDS),
+            storage_enums::FraudCheckDecision::Review
+        );
+        assert_eq!(
+            get_decision(6, THRESHOLDS),
+            storage_enums::FraudCheckDecision::Decline
+        );
+    }
+
+    #[test]
+    fn test_strictest_decision_wins() {
+        use storage_enums::FraudCheckDecision::{Accept, Decline, Review};
+
+        assert!(is_stricter(Review, Accept));
+        assert!(is_stricter(Decline, Review));
+        assert!(!is_stricter(Review, Review));
+        assert!(!is_stricter(Accept, Decline));
+    }
+
+    fn get_router_data(merchant_id: &str, card_fingerprint: &str) -> types::FraudCheckRouterData {
+        types::RouterData {
+            flow: PhantomData,
+            merchant_id: merchant_id.to_string(),
+            connector: "velocity_rules".to_string(),
+            payment_id: "pay_1".to_string(),
+            attempt_id: "pay_1_1".to_string(),
+            status: storage_enums::AttemptStatus::Pending,
+            payment_method: storage_enums::PaymentMethod::Card,
+            connector_auth_type: types::ConnectorAuthType::NoKey,
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            payment_method_id: None,
+            address: Default::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            request: types::FraudCheckData {
+                amount: 1000,
+                currency: storage_enums::Currency::USD,
+                payment_method_data: None,
+                email: None,
+                customer_id: None,
+                browser_info: None,
+                order_details: None,
+                card_fingerprint: Some(card_fingerprint.to_string()),
+            },
+            response: Err(types::ErrorResponse::default()),
+        }
+    }
+
+    #[test]
+    fn test_counters_are_scoped_to_the_merchant() {
+        assert_eq!(
+            get_counter("merchant_1", Dimension::CardFingerprint, "fingerprint"),
+            "merchant_1_card_fingerprint_fingerprint"
+        );
+        assert_eq!(
+            get_counter("merchant_1", Dimension::IpAddress, "ip_address_hash"),
+            "merchant_1_ip_address_ip_address_hash"
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_check_fraud_counts_payments_of_the_merchant() {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.fraud_check.velocity_rules.card_fingerprint = VelocityThresholds {
+            review_after: 1,
+            decline_after: 2,
+        };
+        let window = conf.fraud_check.velocity_rules.window;
+        let (state, _) = test_utils::get_state_with_settings(conf).await;
+
+        let mut decisions = Vec::new();
+        for merchant_id in [
+            "merchant_1",
+            "merchant_1",
+            "merchant_2",
+            "merchant_1",
+            "merchant_1",
+        ] {
+            let response = VelocityRules
+                .check_fraud(&state, get_router_data(merchant_id, "fingerprint"))
+                .await
+                .unwrap()
+                .response
+                .unwrap();
+            decisions.push((response.decision, response.reason));
+        }
+
+        assert_eq!(
+            decisions,
+            [
+                (storage_enums::FraudCheckDecision::Accept, None),
+                (
+                    storage_enums::FraudCheckDecision::Review,
+                    Some(format!(
+                        "2 payments with the same card_fingerprint within {window} seconds"
+                    ))
+                ),
+                // Payments of other merchants are counted separately
+                (storage_enums::FraudCheckDecision::Accept, None),
+                (
+                    storage_enums::FraudCheckDecision::Decline,
+                    Some(format!(
+                        "3 payments with the same card_fingerprint within {window} seconds"
+                    ))
+                ),
+                // Declined payments are counted as well
+                (
+                    storage_enums::FraudCheckDecision::Decline,
+                    Some(format!(
+                        "4 payments with the same card_fingerprint within {window} seconds"
+                    ))
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::{future::Future, time::Duration};
+
+use api_models::health_check::{ComponentHealth, DeepHealthCheckResponse, HealthStatus};
+use error_stack::{IntoReport, ResultExt};
+use external_services::secrets_management::types::SecretsManagerKind;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::AppState,
+    services,
+};
+
+/// Check every component the application depends on. The overall status is unhealthy if any
+/// component required to serve requests is unhealthy, and degraded if any component is lagging.
+#[instrument(skip_all)]
+pub async fn deep_health_check(state: &AppState) -> DeepHealthCheckResponse {
+    let timeout = Duration::from_millis(state.conf.health_check.component_timeout);
+    let db = &*state.store;
+
+    let master_database =
+        async { component_health(run_check(timeout, db.health_check_master_db()).await) };
+
+    let replica_database = async {
+        #[cfg(feature = "olap")]
+        {
+            Some(component_health(
+                run_check(timeout, db.health_check_replica_db()).await,
+            ))
+        }
+        #[cfg(not(feature = "olap"))]
+        {
+            None
+        }
+    };
+
+    let redis = async { component_health(run_check(timeout, db.health_check_redis()).await) };
+
+    let locker = async {
+        if state.conf.vault.enabled {
+            component_health(run_check(timeout, db.health_check_vault()).await)
+        } else if state.conf.locker.mock_locker {
+            component_health(run_check(timeout, db.health_check_locker_mock_up()).await)
+        } else {
+            component_health(run_check(timeout, health_check_locker(state)).await)
+        }
+    };
+
+    let secrets_manager = async {
+        match state.conf.secrets_management.secrets_manager {
+            SecretsManagerKind::NoEncryption => None,
+            _ => Some(component_health(
+                run_check(timeout, state.secrets_manager.health_check()).await,
+            )),
+        }
+    };
+
+    let drainer = async {
+        #[cfg(feature = "kv_store")]
+        {
+            Some(stream_health(
+                run_check(timeout, db.get_drainer_stream_lag()).await,
+                state.conf.health_check.drainer_lag_threshold,
+            ))
+        }
+        #[cfg(not(feature = "kv_store"))]
+        {
+            None
+        }
+    };
+
+    let scheduler = async {
+        match state.conf.scheduler.as_ref() {
+            Some(scheduler) => Some(stream_health(
+                run_check(timeout, db.get_stream_length(&scheduler.stream)).await,
+                state.conf.health_check.scheduler_lag_threshold,
+            )),
+            None => None,
+        }
+    };
+
+    let (master_database, replica_database, redis, locker, secrets_manager, drainer, scheduler) = futures::join!(
+        master_database,
+        replica_database,
+        redis,
+        locker,
+        secrets_manager,
+        drainer,
+        scheduler
+    );
+
+    let required_components = [
+        Some(&master_database),
+        replica_database.as_ref(),
+        Some(&redis),
+        Some(&locker),
+        secrets_manager.as_ref(),
+    ];
+    let lagging_components = [drainer.as_ref(), scheduler.as_ref()];
+
+    let status = if required_components
+        .iter()
+        .flatten()
+        .any(|component| component.status == HealthStatus::Unhealthy)
+    {
+        HealthStatus::Unhealthy
+    } else if required_components
+        .iter()
+        .chain(lagging_components.iter())
+        .flatten()
+        .any(|component| component.status != HealthStatus::Healthy)
+    {
+        HealthStatus::Degraded
+    } else {
+        HealthStatus::Healthy
+    };
+
+    DeepHealthCheckResponse {
+        status,
+        master_database,
+        replica_database,
+        redis,
+        locker,
+        secrets_manager,
+        drainer,
+        scheduler,
+    }
+}
+
+/// Any response from the locker, other than a server error, means that it is reachable. The
+/// request is sent with the client used for the other requests to the locker, so that it goes
+/// through the configured proxy.
+async fn health_check_locker(state: &AppState) -> CustomResult<(), errors::ApiClientError> {
+    let request = services::Request::new(services::Method::Get, &state.conf.locker.host);
+    let response = services::call_connector_api(state, request)
+        .await
+        .attach_printable("Failed to reach the locker")?;
+
+    match response {
+        Err(response) if response.status_code >= 500 => {
+            Err(errors::ApiClientError::UnexpectedServerResponse)
+                .into_report()
+                .attach_printable_lazy(|| {
+                    format!("Locker responded with status {}", response.status_code)
+                })
+        }
+        Ok(_) | Err(_) => Ok(()),
+    }
+}
+
+/// Run a health check within the timeout, returning the time taken along with the outcome.
+async fn run_check<F, T, E>(timeout: Duration, check: F) -> (u64, Result<T, String>)
+where
+    F: Future<Output = CustomResult<T, E>>,
+    E: error_stack::Context,
+{
+    let start = std::time::Instant::now();
+    let result = match tokio::time::timeout(timeout, check).await {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(error)) => {
+            logger::error!(health_check_error=?error);
+            Err(error.current_context().to_string())
+        }
+        Err(_) => Err(format!(
+            "Did not respond within {} milliseconds",
+            timeout.as_millis()
+        )),
+    };
+    let latency = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+    (latency, result)
+}
+
+fn component_health((latency, result): (u64, Result<(), String>)) -> ComponentHealth {
+    match result {
+        Ok(()) => ComponentHealth {
+            status: HealthStatus::Healthy,
+            latency,
+            lag: None,
+            error: None,
+        },
+        Err(error) => ComponentHealth {
+            status: HealthStatus::Unhealthy,
+            latency,
+            lag: None,
+            error: Some(error),
+        },
+    }
+}
+
+fn stream_health(
+    (latency, result): (u64, Result<usize, String>),
+    lag_threshold: usize,
+) -> ComponentHealth {
+    match result {
+        Ok(lag) => ComponentHealth {
+            status: if lag > lag_threshold {
+                HealthStatus::Degraded
+            } else {
+                HealthStatus::Healthy
+            },
+            latency,
+            lag: Some(lag),
+            error: None,
+        },
+        Err(error) => ComponentHealth {
+            status: HealthStatus::Unhealthy,
+            latency,
+            lag: None,
+            error: Some(error),
+        },
+    }
+}
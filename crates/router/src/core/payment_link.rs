@@ -0,0 +1,526 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::PaymentLinkSettings,
+    connector, consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+/// Time in seconds after which the payment link expires, validated against the configured maximum.
+pub fn get_payment_link_expiry(
+    payment_link_settings: &PaymentLinkSettings,
+    payment_link: &api::PaymentLinkRequest,
+) -> RouterResult<u32> {
+    let expires_in = payment_link
+        .expires_in
+        .unwrap_or(payment_link_settings.default_expiry);
+
+    if expires_in == 0 || expires_in > payment_link_settings.max_expiry {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payment_link.expires_in must be between 1 and {} seconds",
+                payment_link_settings.max_expiry
+            ),
+        }))
+    } else {
+        Ok(expires_in)
+    }
+}
+
+/// Create a payment link for a payment which is being created. The link is created before the
+/// payment is stored, so that a payment is never stored without the link that was requested for it.
+#[instrument(skip_all)]
+pub(crate) async fn create_payment_link(
+    state: &AppState,
+    merchant_id: &str,
+    payment_id: &str,
+    payment_link: &api::PaymentLinkRequest,
+) -> RouterResult<storage::PaymentLink> {
+    let expires_in = get_payment_link_expiry(&state.conf.payment_link, payment_link)?;
+
+    let payment_link_id = utils::generate_id(consts::ID_LENGTH, "plink");
+    let payment_link_new = storage::PaymentLinkNew {
+        link_to_pay: format!(
+            "{}/payment_link/pay/{payment_link_id}",
+            state.conf.server.base_url
+        ),
+        payment_link_id,
+        payment_id: payment_id.to_owned(),
+        merchant_id: merchant_id.to_owned(),
+        status: storage_enums::PaymentLinkStatus::Active,
+        expires_at: common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(i64::from(expires_in))),
+    };
+
+    state
+        .store
+        .insert_payment_link(payment_link_new)
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_id.to_owned(),
+            })
+        })
+}
+
+/// Mark the payment link as completed once the payment has succeeded, or as expired once the
+/// expiry time has passed.
+async fn update_payment_link_status(
+    db: &dyn StorageInterface,
+    payment_link: storage::PaymentLink,
+    intent_status: storage_enums::IntentStatus,
+) -> RouterResult<storage::PaymentLink> {
+    let status = match payment_link.status {
+        storage_enums::PaymentLinkStatus::Active
+            if intent_status == storage_enums::IntentStatus::Succeeded =>
+        {
+            storage_enums::PaymentLinkStatus::Completed
+        }
+        storage_enums::PaymentLinkStatus::Active
+            if common_utils::date_time::now() > payment_link.expires_at =>
+        {
+            storage_enums::PaymentLinkStatus::Expired
+        }
+        status => status,
+    };
+
+    if status == payment_link.status {
+        return Ok(payment_link);
+    }
+
+    db.update_payment_link(
+        payment_link,
+        storage::PaymentLinkUpdate::StatusUpdate { status },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the status of the payment link")
+}
+
+/// Find the payment link of a payment which is being confirmed. A payment which was created with a
+/// payment link cannot be confirmed once the link has expired.
+pub(crate) async fn find_payment_link_for_confirm(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<storage::PaymentLink>> {
+    let payment_link = match db
+        .find_payment_link_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await
+    {
+        Ok(payment_link) => payment_link,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the payment link of the payment")?,
+    };
+
+    let payment_link = update_payment_link_status(db, payment_link, payment_intent.status).await?;
+    utils::when(
+        payment_link.status == storage_enums::PaymentLinkStatus::Expired,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment link of the payment has expired".to_string(),
+            }))
+        },
+    )?;
+
+    Ok(Some(payment_link))
+}
+
+async fn find_payment_intent(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<storage::PaymentIntent> {
+    db.find_payment_intent_by_payment_id_merchant_id(
+        payment_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_link(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<api::PaymentLinkResponse> {
+    let db = &*state.store;
+
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        })?;
+    if payment_link.merchant_id != merchant_account.merchant_id {
+        return Err(report!(errors::ApiErrorResponse::ResourceIdNotFound));
+    }
+
+    let payment_intent =
+        find_payment_intent(db, &merchant_account, &payment_link.payment_id).await?;
+    let payment_link = update_payment_link_status(db, payment_link, payment_intent.status).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_link.foreign_into(),
+    ))
+}
+
+/// Render the checkout page of the payment link, listing the payment methods available for the
+/// payment. The page is public, the payment link is only known from its identifier, which cannot be
+/// guessed.
+#[instrument(skip_all)]
+pub async fn initiate_payment_link(
+    state: &AppState,
+    _: (),
+    payment_link_id: String,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        })?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&payment_link.merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        })?;
+    let payment_intent =
+        find_payment_intent(db, &merchant_account, &payment_link.payment_id).await?;
+    let payment_link = update_payment_link_status(db, payment_link, payment_intent.status).await?;
+
+    let client_secret = payment_intent
+        .client_secret
+        .clone()
+        .get_required_value("client_secret")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Client secret not found for the payment")?;
+    let publishable_key = merchant_account
+        .publishable_key
+        .clone()
+        .get_required_value("publishable_key")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Publishable key not found for the merchant")?;
+    let currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let amount = connector::utils::to_currency_base_unit(payment_intent.amount, currency)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // The payment methods are only needed while the customer can still pay using the link
+    let payment_methods = if payment_link.status == storage_enums::PaymentLinkStatus::Active {
+        let list_request = api::ListPaymentMethodRequest {
+            client_secret: Some(client_secret.clone()),
+            ..Default::default()
+        };
+        match cards::list_payment_methods(state, merchant_account.clone(), list_request).await? {
+            services::ApplicationResponse::Json(response) => response.payment_methods,
+            _ => Vec::new(),
+        }
+    } else {
+        Vec::new()
+    };
+
+    Ok(services::ApplicationResponse::PaymentLinkForm(Box::new(
+        services::PaymentLinkFormData {
+            merchant_name: merchant_account
+                .merchant_name
+                .unwrap_or_else(|| merchant_account.merchant_id.clone()),
+            payment_id: payment_intent.payment_id,
+            amount,
+            currency: currency.to_string(),
+            status: payment_link.status.foreign_into(),
+            client_secret,
+            publishable_key,
+            base_url: state.conf.server.base_url.clone(),
+            return_url: payment_intent.return_url,
+            payment_methods,
+        },
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn test_payment_link_expiry() {
+        let settings = PaymentLinkSettings {
+            default_expiry: 900,
+            max_expiry: 3600,
+        };
+
+        let expiry = |expires_in| {
+            get_payment_link_expiry(&settings, &api::PaymentLinkRequest { expires_in })
+        };
+        assert_eq!(expiry(None).unwrap(), 900);
+        assert_eq!(expiry(Some(3600)).unwrap(), 3600);
+        assert!(expiry(Some(0)).is_err());
+        assert!(expiry(Some(3601)).is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_create_payment_link() {
+        let (state, _) = test_utils::get_state().await;
+        let payment_link = create_payment_link(
+            &state,
+            "merchant_1",
+            "pay_1",
+            &api::PaymentLinkRequest {
+                expires_in: Some(600),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(payment_link.payment_link_id.starts_with("plink_"));
+        assert_eq!(
+            payment_link.link_to_pay,
+            format!(
+                "{}/payment_link/pay/{}",
+                state.conf.server.base_url, payment_link.payment_link_id
+            )
+        );
+        assert_eq!(
+            payment_link.status,
+            storage_enums::PaymentLinkStatus::Active
+        );
+        assert!((599..=600)
+            .contains(&(payment_link.expires_at - payment_link.created_at).whole_seconds()));
+
+        let stored = state
+            .store
+            .find_payment_link_by_merchant_id_payment_id("merchant_1", "pay_1")
+            .await
+            .unwrap();
+        assert_eq!(stored.payment_link_id, payment_link.payment_link_id);
+
+        // A payment can only have one payment link
+        let error = create_payment_link(
+            &state,
+            "merchant_1",
+            "pay_1",
+            &api::PaymentLinkRequest::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::DuplicatePayment { .. }
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn test_invalid_payment_link_expiry_is_not_stored() {
+        let (state, _) = test_utils::get_state().await;
+        let result = create_payment_link(
+            &state,
+            "merchant_1",
+            "pay_1",
+            &api::PaymentLinkRequest {
+                expires_in: Some(0),
+            },
+        )
+        .await;
+        assert!(result.is_err());
+
+        assert!(state
+            .store
+            .find_payment_link_by_merchant_id_payment_id("merchant_1", "pay_1")
+            .await
+            .is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_payment_link_status() {
+        let (state, _) = test_utils::get_state().await;
+        let db = &*state.store;
+        let now = common_utils::date_time::now();
+
+        let active = insert_payment_link(db, "pay_1", now + time::Duration::hours(1)).await;
+        let active = update_payment_link_status(
+            db,
+            active,
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+        )
+        .await
+        .unwrap();
+        assert_eq!(active.status, storage_enums::PaymentLinkStatus::Active);
+
+        let completed =
+            update_payment_link_status(db, active, storage_enums::IntentStatus::Succeeded)
+                .await
+                .unwrap();
+        assert_eq!(
+            completed.status,
+            storage_enums::PaymentLinkStatus::Completed
+        );
+
+        let expired = insert_payment_link(db, "pay_2", now - time::Duration::seconds(1)).await;
+        let expired = update_payment_link_status(
+            db,
+            expired,
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+        )
+        .await
+        .unwrap();
+        assert_eq!(expired.status, storage_enums::PaymentLinkStatus::Expired);
+
+        // The status of the payment link is stored, and not changed once it has expired
+        let stored = db
+            .find_payment_link_by_payment_link_id("plink_pay_2")
+            .await
+            .unwrap();
+        assert_eq!(stored.status, storage_enums::PaymentLinkStatus::Expired);
+        let stored = update_payment_link_status(db, stored, storage_enums::IntentStatus::Succeeded)
+            .await
+            .unwrap();
+        assert_eq!(stored.status, storage_enums::PaymentLinkStatus::Expired);
+    }
+
+    async fn insert_payment_intent(
+        db: &dyn StorageInterface,
+        payment_id: &str,
+    ) -> storage::PaymentIntent {
+        db.insert_payment_intent(
+            storage::PaymentIntentNew {
+                payment_id: payment_id.to_string(),
+                merchant_id: "merchant_1".to_string(),
+                status: storage_enums::IntentStatus::RequiresPaymentMethod,
+                amount: 1000,
+                currency: Some(storage_enums::Currency::USD),
+                client_secret: Some(format!("{payment_id}_secret")),
+                ..storage::PaymentIntentNew::default()
+            },
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn insert_payment_link(
+        db: &dyn StorageInterface,
+        payment_id: &str,
+        expires_at: time::PrimitiveDateTime,
+    ) -> storage::PaymentLink {
+        db.insert_payment_link(storage::PaymentLinkNew {
+            payment_link_id: format!("plink_{payment_id}"),
+            payment_id: payment_id.to_string(),
+            merchant_id: "merchant_1".to_string(),
+            link_to_pay: format!("http://localhost:8080/payment_link/pay/plink_{payment_id}"),
+            status: storage_enums::PaymentLinkStatus::Active,
+            expires_at,
+        })
+        .await
+        .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn test_confirm_with_expired_payment_link() {
+        let (state, _) = test_utils::get_state().await;
+        let db = &*state.store;
+        let now = common_utils::date_time::now();
+
+        let payment_intent = insert_payment_intent(db, "pay_1").await;
+        insert_payment_link(db, "pay_1", now + time::Duration::hours(1)).await;
+        let payment_link = find_payment_link_for_confirm(db, &payment_intent)
+            .await
+            .unwrap();
+        assert_eq!(
+            payment_link.map(|payment_link| payment_link.payment_link_id),
+            Some("plink_pay_1".to_string())
+        );
+
+        let payment_intent = insert_payment_intent(db, "pay_2").await;
+        insert_payment_link(db, "pay_2", now - time::Duration::seconds(1)).await;
+        let error = find_payment_link_for_confirm(db, &payment_intent)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+        let stored = db
+            .find_payment_link_by_payment_link_id("plink_pay_2")
+            .await
+            .unwrap();
+        assert_eq!(stored.status, storage_enums::PaymentLinkStatus::Expired);
+
+        // Payments created without a payment link can always be confirmed
+        let payment_intent = insert_payment_intent(db, "pay_3").await;
+        assert!(find_payment_link_for_confirm(db, &payment_intent)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[actix_rt::test]
+    async fn test_initiate_payment_link() {
+        let (state, _) = test_utils::get_state().await;
+        let db = &*state.store;
+        db.insert_merchant(storage::MerchantAccountNew {
+            merchant_id: "merchant_1".to_string(),
+            publishable_key: Some("pk_merchant_1".to_string()),
+            ..storage::MerchantAccountNew::default()
+        })
+        .await
+        .unwrap();
+        insert_payment_intent(db, "pay_1").await;
+        insert_payment_link(
+            db,
+            "pay_1",
+            common_utils::date_time::now() - time::Duration::seconds(1),
+        )
+        .await;
+
+        let response = initiate_payment_link(&state, (), "plink_pay_1".to_string())
+            .await
+            .unwrap();
+        let form = match response {
+            services::ApplicationResponse::PaymentLinkForm(form) => Some(form),
+            _ => None,
+        }
+        .expect("Expected the checkout page of the payment link");
+        assert_eq!(form.payment_id, "pay_1");
+        assert_eq!(form.amount, "10");
+        assert_eq!(form.client_secret, "pay_1_secret");
+        assert_eq!(form.publishable_key, "pk_merchant_1");
+        assert_eq!(form.status, api_models::enums::PaymentLinkStatus::Expired);
+        assert!(form.payment_methods.is_empty());
+
+        // The checkout page cannot be found from the merchant and payment identifiers
+        let error = initiate_payment_link(&state, (), "pay_1".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::ResourceIdNotFound
+        ));
+    }
+}
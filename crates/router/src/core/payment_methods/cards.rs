@@ -10,20 +10,27 @@ use api_models::{
     },
     payments::BankCodeResponse,
 };
-use common_utils::{consts, ext_traits::AsyncExt, generate_id};
+use common_utils::{
+    consts,
+    crypto::{self, SignMessage},
+    ext_traits::AsyncExt,
+    generate_id,
+};
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use crate::{
     configs::settings,
     core::{
+        admin as admin_core, card_vault,
         errors::{self, StorageErrorExt},
-        payment_methods::{transformers as payment_methods, vault},
+        payment_methods::{network_tokenization, transformers as payment_methods, vault},
         payments::helpers,
     },
     db, logger,
     pii::prelude::*,
-    routes, services,
+    routes,
+    services::{self, envelope_encryption::Decryptable},
     types::{
         api::{self, CreatePaymentMethodExt},
         storage::{self, enums},
@@ -39,6 +46,7 @@ pub async fn create_payment_method(
     customer_id: &str,
     payment_method_id: &str,
     merchant_id: &str,
+    card_fingerprint: Option<String>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::StorageError> {
     let response = db
         .insert_payment_method(storage::PaymentMethodNew {
@@ -50,6 +58,7 @@ pub async fn create_payment_method(
             payment_method_issuer: req.payment_method_issuer.clone(),
             scheme: req.card_network.clone(),
             metadata: req.metadata.clone(),
+            card_fingerprint,
             ..storage::PaymentMethodNew::default()
         })
         .await?;
@@ -67,10 +76,48 @@ pub async fn add_payment_method(
     let merchant_id = &merchant_account.merchant_id;
     let customer_id = req.customer_id.clone().get_required_value("customer_id")?;
     match req.card.clone() {
-        Some(card) => add_card(state, req, card, customer_id, merchant_account)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Add Card Failed"),
+        Some(card) => {
+            let card_fingerprint = get_card_fingerprint(
+                &state.conf.secrets.card_fingerprint_hash_key,
+                card.card_number.peek(),
+            )?;
+            let duplicate_payment_method = state
+                .store
+                .find_payment_method_by_merchant_id_customer_id_card_fingerprint(
+                    merchant_id,
+                    &customer_id,
+                    &card_fingerprint,
+                )
+                .await;
+            match duplicate_payment_method {
+                // The card is already saved for the customer, update the saved payment method
+                // instead of saving the card again
+                Ok(pm) => update_duplicate_card(state, req, card, pm, merchant_account).await,
+                Err(error) if error.current_context().is_db_not_found() => {
+                    let response = add_new_card(
+                        state,
+                        req,
+                        card.clone(),
+                        customer_id,
+                        merchant_account,
+                        card_fingerprint,
+                    )
+                    .await?;
+                    network_tokenization::tokenize_saved_card(
+                        state,
+                        &card,
+                        &response.payment_method_id,
+                    )
+                    .await;
+                    Ok(response)
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed while finding a saved card with the same fingerprint",
+                    ),
+            }
+        }
         None => {
             let payment_method_id = generate_id(consts::ID_LENGTH, "pm");
             create_payment_method(
@@ -79,6 +126,7 @@ pub async fn add_payment_method(
                 &customer_id,
                 &payment_method_id,
                 merchant_id,
+                None,
             )
             .await
             .map_err(|error| {
@@ -121,21 +169,26 @@ pub async fn update_customer_payment_method(
         })?;
     if pm.payment_method == enums::PaymentMethod::Card {
         delete_card(state, &pm.merchant_id, &pm.payment_method_id).await?;
+        network_tokenization::delete_network_token(state, &pm).await;
     };
     let new_pm = api::CreatePaymentMethod {
         payment_method: pm.payment_method.foreign_into(),
         payment_method_type: pm.payment_method_type.map(|x| x.foreign_into()),
-        payment_method_issuer: pm.payment_method_issuer,
+        payment_method_issuer: pm.payment_method_issuer.clone(),
         payment_method_issuer_code: pm.payment_method_issuer_code.map(|x| x.foreign_into()),
         card: req.card,
         metadata: req.metadata,
-        customer_id: Some(pm.customer_id),
+        customer_id: Some(pm.customer_id.clone()),
         card_network: req
             .card_network
             .as_ref()
             .map(|card_network| card_network.to_string()),
     };
-    add_payment_method(state, new_pm, &merchant_account).await
+    let response = add_payment_method(state, new_pm, &merchant_account).await?;
+    if let services::ApplicationResponse::Json(ref new_pm) = response {
+        replace_default_payment_method(db, &pm, Some(new_pm.payment_method_id.clone())).await?;
+    }
+    Ok(response)
 }
 
 #[instrument(skip_all)]
@@ -145,57 +198,76 @@ pub async fn add_card(
     card: api::CardDetail,
     customer_id: String,
     merchant_account: &storage::MerchantAccount,
+    card_fingerprint: String,
 ) -> errors::CustomResult<api::PaymentMethodResponse, errors::VaultError> {
     let locker = &state.conf.locker;
     let db = &*state.store;
     let merchant_id = &merchant_account.merchant_id;
-    let locker_id = merchant_account
-        .locker_id
-        .to_owned()
-        .get_required_value("locker_id")
-        .change_context(errors::VaultError::SaveCardFailed)?;
-
-    let request = payment_methods::mk_add_card_request(
-        locker,
-        &card,
-        &customer_id,
-        &req,
-        &locker_id,
-        merchant_id,
-    )?;
-
-    let response = if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
+    let response = if state.conf.vault.enabled {
+        card_vault::store_card(state, merchant_id, &customer_id, &card, &card_fingerprint)
             .await
+            .change_context(errors::VaultError::SaveCardFailed)?
+    } else {
+        let locker_id = merchant_account
+            .locker_id
+            .to_owned()
+            .get_required_value("locker_id")
             .change_context(errors::VaultError::SaveCardFailed)?;
 
-        let response: payment_methods::AddCardResponse = match response {
-            Ok(card) => card
-                .response
-                .parse_struct("AddCardResponse")
-                .change_context(errors::VaultError::ResponseDeserializationFailed),
-            Err(err) => Err(report!(errors::VaultError::UnexpectedResponseError(
-                err.response
-            ))),
-        }?;
-        response
-    } else {
-        let card_id = generate_id(consts::ID_LENGTH, "card");
-        mock_add_card(db, &card_id, &card, None, None, Some(&customer_id)).await?
+        let request = payment_methods::mk_add_card_request(
+            locker,
+            &card,
+            &customer_id,
+            &req,
+            &locker_id,
+            merchant_id,
+        )?;
+
+        if !locker.mock_locker {
+            let response = services::call_connector_api(state, request)
+                .await
+                .change_context(errors::VaultError::SaveCardFailed)?;
+
+            let response: payment_methods::AddCardResponse = match response {
+                Ok(card) => card
+                    .response
+                    .parse_struct("AddCardResponse")
+                    .change_context(errors::VaultError::ResponseDeserializationFailed),
+                Err(err) => Err(report!(errors::VaultError::UnexpectedResponseError(
+                    err.response
+                ))),
+            }?;
+            response
+        } else {
+            let card_id = generate_id(consts::ID_LENGTH, "card");
+            mock_add_card(db, &card_id, &card, None, None, Some(&customer_id)).await?
+        }
     };
 
     if let Some(false) = response.duplicate {
-        create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
-            .await
-            .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+        create_card_payment_method(
+            state,
+            &req,
+            &customer_id,
+            &response.card_id,
+            merchant_id,
+            card_fingerprint,
+        )
+        .await?;
     } else {
         match db.find_payment_method(&response.card_id).await {
             Ok(_) => (),
             Err(err) => {
                 if err.current_context().is_db_not_found() {
-                    create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
-                        .await
-                        .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+                    create_card_payment_method(
+                        state,
+                        &req,
+                        &customer_id,
+                        &response.card_id,
+                        merchant_id,
+                        card_fingerprint,
+                    )
+                    .await?;
                 } else {
                     Err(errors::VaultError::PaymentMethodCreationFailed)?;
                 }
@@ -207,6 +279,158 @@ pub async fn add_card(
     Ok(payment_method_resp)
 }
 
+/// Saves a card which is not saved for the customer yet. If the same card is saved for the
+/// customer concurrently, the unique index on the card fingerprint lets only one of them create a
+/// payment method, and the payment method of that card is returned to the other one.
+async fn add_new_card(
+    state: &routes::AppState,
+    req: api::CreatePaymentMethod,
+    card: api::CardDetail,
+    customer_id: String,
+    merchant_account: &storage::MerchantAccount,
+    card_fingerprint: String,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
+    match add_card(
+        state,
+        req,
+        card,
+        customer_id.clone(),
+        merchant_account,
+        card_fingerprint.clone(),
+    )
+    .await
+    {
+        Ok(payment_method_resp) => Ok(payment_method_resp),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::VaultError::DuplicatePaymentMethod
+            ) =>
+        {
+            let pm = state
+                .store
+                .find_payment_method_by_merchant_id_customer_id_card_fingerprint(
+                    &merchant_account.merchant_id,
+                    &customer_id,
+                    &card_fingerprint,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while finding the saved card with the same fingerprint",
+                )?;
+            get_payment_method_response(state, merchant_account, pm).await
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Add Card Failed")),
+    }
+}
+
+/// Creates the payment method of a card saved in the locker. If the card was saved for the
+/// customer concurrently, the payment method violates the unique index on the card fingerprint, and
+/// the card which was just saved in the locker is deleted since no payment method uses it.
+async fn create_card_payment_method(
+    state: &routes::AppState,
+    req: &api::CreatePaymentMethod,
+    customer_id: &str,
+    card_id: &str,
+    merchant_id: &str,
+    card_fingerprint: String,
+) -> errors::CustomResult<(), errors::VaultError> {
+    match create_payment_method(
+        &*state.store,
+        req,
+        customer_id,
+        card_id,
+        merchant_id,
+        Some(card_fingerprint),
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            if let Err(delete_error) = delete_card(state, merchant_id, card_id).await {
+                logger::error!(duplicate_card_delete_error=?delete_error);
+            }
+            Err(error.change_context(errors::VaultError::DuplicatePaymentMethod))
+        }
+        Err(error) => Err(error.change_context(errors::VaultError::PaymentMethodCreationFailed)),
+    }
+}
+
+/// Updates a payment method whose card is being saved again: the card is saved again in the
+/// locker with its new expiry, and the metadata of the payment method is replaced if provided.
+async fn update_duplicate_card(
+    state: &routes::AppState,
+    req: api::CreatePaymentMethod,
+    card: api::CardDetail,
+    pm: storage::PaymentMethod,
+    merchant_account: &storage::MerchantAccount,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
+    let locker = &state.conf.locker;
+    let db = &*state.store;
+
+    if !locker.mock_locker {
+        let locker_id = merchant_account
+            .locker_id
+            .clone()
+            .get_required_value("locker_id")?;
+        let request = payment_methods::mk_add_card_request(
+            locker,
+            &card,
+            &pm.customer_id,
+            &req,
+            &locker_id,
+            &merchant_account.merchant_id,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Making add card request failed")?;
+        let _: payment_methods::AddCardResponse = services::call_connector_api(state, request)
+            .await
+            .get_response_inner("AddCardResponse")?;
+    } else {
+        mock_delete_card(db, &pm.payment_method_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while deleting card from mock_locker")?;
+        mock_add_card(
+            db,
+            &pm.payment_method_id,
+            &card,
+            None,
+            None,
+            Some(&pm.customer_id),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while saving card to mock_locker")?;
+    }
+
+    let pm = db
+        .update_payment_method(
+            pm,
+            storage::PaymentMethodUpdate::MetadataUpdate {
+                metadata: req.metadata,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the metadata of the payment method")?;
+
+    get_payment_method_response(state, merchant_account, pm).await
+}
+
+/// Computes the fingerprint of a card as a keyed hash of the card number, which identifies the
+/// same card being saved again without storing the card number.
+pub fn get_card_fingerprint(hash_key: &str, card_number: &str) -> errors::RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(hash_key.as_bytes(), card_number.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the card fingerprint")
+}
+
 #[instrument(skip_all)]
 pub async fn mock_add_card(
     db: &dyn db::StorageInterface,
@@ -303,9 +527,17 @@ pub async fn mock_delete_card<'a>(
 #[instrument(skip_all)]
 pub async fn get_card_from_legacy_locker<'a>(
     state: &'a routes::AppState,
+    merchant_id: &'a str,
     locker_id: &'a str,
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::GetCardResponse> {
+    if state.conf.vault.enabled {
+        return card_vault::get_card(state, merchant_id, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching card from vault");
+    }
+
     let locker = &state.conf.locker;
     let request = payment_methods::mk_get_card_request(locker, locker_id, card_id)
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -334,6 +566,13 @@ pub async fn delete_card<'a>(
     merchant_id: &'a str,
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
+    if state.conf.vault.enabled {
+        return card_vault::delete_card(state, merchant_id, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while deleting card from vault");
+    }
+
     let locker = &state.conf.locker;
     let request = payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -370,9 +609,10 @@ pub fn get_banks(
             eligible_connectors: connectors,
         }])
     } else {
+        let dynamic_settings = state.dynamic_config.get();
         let mut bank_code_responses = vec![];
         for connector in &connectors {
-            if let Some(connector_bank_names) = state.conf.bank_config.0.get(&pm_type) {
+            if let Some(connector_bank_names) = dynamic_settings.bank_config.0.get(&pm_type) {
                 if let Some(connector_hash_set) = connector_bank_names.0.get(connector) {
                     bank_names_hm.insert(connector.clone(), connector_hash_set.banks.clone());
                 } else {
@@ -434,7 +674,8 @@ pub async fn list_payment_methods(
     mut req: api::ListPaymentMethodRequest,
 ) -> errors::RouterResponse<api::ListPaymentMethodResponse> {
     let db = &*state.store;
-    let pm_config_mapping = &state.conf.pm_filters;
+    let dynamic_settings = state.dynamic_config.get();
+    let pm_config_mapping = &dynamic_settings.pm_filters;
 
     let payment_intent = helpers::verify_client_secret(
         db,
@@ -479,13 +720,18 @@ pub async fn list_payment_methods(
 
     logger::debug!(mca_before_filtering=?all_mcas);
 
+    let merchant_pm_filters = admin_core::decode_pm_filters(merchant_account.pm_filters.clone())?;
+
     let mut response: Vec<ResponsePaymentMethodIntermediate> = vec![];
-    for mca in all_mcas {
+    // Only the columns which are not encrypted are needed to filter the payment methods
+    for mca in all_mcas.into_iter().map(Decryptable::into_encrypted) {
         let payment_methods = match mca.payment_methods_enabled {
             Some(pm) => pm,
             None => continue,
         };
 
+        let mca_pm_filters = admin_core::decode_pm_filters(mca.pm_filters)?;
+
         filter_payment_methods(
             payment_methods,
             &mut req,
@@ -495,6 +741,8 @@ pub async fn list_payment_methods(
             address.as_ref(),
             mca.connector_name,
             pm_config_mapping,
+            mca_pm_filters.as_ref(),
+            merchant_pm_filters.as_ref(),
         )
         .await?;
     }
@@ -701,6 +949,8 @@ async fn filter_payment_methods(
     address: Option<&storage::Address>,
     connector: String,
     config: &settings::ConnectorFilters,
+    mca_pm_filters: Option<&admin::PaymentMethodFilters>,
+    merchant_pm_filters: Option<&admin::PaymentMethodFilters>,
 ) -> errors::CustomResult<(), errors::ApiErrorResponse> {
     for payment_method in payment_methods.into_iter() {
         let parse_result = serde_json::from_value::<PaymentMethodsEnabled>(payment_method);
@@ -754,16 +1004,32 @@ async fn filter_payment_methods(
                         true
                     };
 
-                    let filter5 = filter_pm_based_on_config(
-                        config,
-                        &connector,
+                    let country = address.and_then(|inner| inner.country.clone());
+                    let currency = payment_attempt
+                        .and_then(|value| value.currency)
+                        .map(|value| value.foreign_into());
+
+                    let filter5 = match get_pm_filter(
+                        mca_pm_filters,
+                        merchant_pm_filters,
                         &payment_method_object.payment_method_type,
-                        &mut payment_method_object.card_networks,
-                        &address.and_then(|inner| inner.country.clone()),
-                        payment_attempt
-                            .and_then(|value| value.currency)
-                            .map(|value| value.foreign_into()),
-                    );
+                    ) {
+                        Some(pm_filter) => filter_pm_based_on_merchant_filter(
+                            pm_filter,
+                            &mut payment_method_object.card_networks,
+                            country.as_ref(),
+                            currency,
+                            payment_intent.map(|pi| pi.amount).or(req.amount),
+                        ),
+                        None => filter_pm_based_on_config(
+                            config,
+                            &connector,
+                            &payment_method_object.payment_method_type,
+                            &mut payment_method_object.card_networks,
+                            &country,
+                            currency,
+                        ),
+                    };
 
                     let connector = connector.clone();
 
@@ -783,6 +1049,113 @@ async fn filter_payment_methods(
     Ok(())
 }
 
+/// Get the payment method filter for the payment method type, the filter configured on the
+/// merchant connector account takes precedence over the one configured on the merchant account.
+pub fn get_pm_filter<'a>(
+    mca_pm_filters: Option<&'a admin::PaymentMethodFilters>,
+    merchant_pm_filters: Option<&'a admin::PaymentMethodFilters>,
+    payment_method_type: &api_enums::PaymentMethodType,
+) -> Option<&'a admin::PaymentMethodFilter> {
+    mca_pm_filters
+        .and_then(|pm_filters| pm_filters.get(payment_method_type))
+        .or_else(|| merchant_pm_filters.and_then(|pm_filters| pm_filters.get(payment_method_type)))
+}
+
+/// Check the attributes of a card payment against a payment method filter, returning the name of
+/// the first attribute that is not accepted by the filter. A card whose network is not known is
+/// not accepted by a filter restricting the card networks.
+pub fn get_pm_filter_violation(
+    pm_filter: &admin::PaymentMethodFilter,
+    country: Option<&String>,
+    currency: Option<api_enums::Currency>,
+    amount: Option<i64>,
+    card_network: Option<&api_enums::CardNetwork>,
+) -> Option<&'static str> {
+    let card_network_check = pm_filter
+        .card_networks
+        .as_ref()
+        .map_or(true, |card_networks| {
+            card_network.map_or(false, |card_network| card_networks.contains(card_network))
+        });
+
+    match get_pm_filter_payment_violation(pm_filter, country, currency, amount) {
+        Some(violation) => Some(violation),
+        None if !card_network_check => Some("card_network"),
+        None => None,
+    }
+}
+
+/// Check the country, currency and amount of a payment against a payment method filter, returning
+/// the name of the first attribute that is not accepted by the filter. A payment whose country is
+/// not known is not accepted by a filter enabling only some countries.
+pub fn get_pm_filter_payment_violation(
+    pm_filter: &admin::PaymentMethodFilter,
+    country: Option<&String>,
+    currency: Option<api_enums::Currency>,
+    amount: Option<i64>,
+) -> Option<&'static str> {
+    let country_check = pm_filter
+        .accepted_countries
+        .as_ref()
+        .map_or(true, |accepted_countries| match country {
+            Some(country) => is_accepted(
+                &accepted_countries.accept_type,
+                accepted_countries.list.as_ref(),
+                country,
+            ),
+            None => accepted_countries.accept_type != "enable_only",
+        });
+    let currency_check = currency.map_or(true, |currency| {
+        pm_filter
+            .accepted_currencies
+            .as_ref()
+            .map_or(true, |accepted_currencies| {
+                is_accepted(
+                    &accepted_currencies.accept_type,
+                    accepted_currencies.list.as_ref(),
+                    &currency,
+                )
+            })
+    });
+    let amount_check = amount.map_or(true, |amount| {
+        pm_filter.minimum_amount.map_or(true, |min| amount >= min)
+            && pm_filter.maximum_amount.map_or(true, |max| amount <= max)
+    });
+
+    if !country_check {
+        Some("country")
+    } else if !currency_check {
+        Some("currency")
+    } else if !amount_check {
+        Some("amount")
+    } else {
+        None
+    }
+}
+
+fn is_accepted<T: PartialEq>(accept_type: &str, list: Option<&Vec<T>>, item: &T) -> bool {
+    if accept_type == "enable_only" {
+        list.map_or(false, |list| list.contains(item))
+    } else {
+        list.map_or(true, |list| !list.contains(item))
+    }
+}
+
+fn filter_pm_based_on_merchant_filter(
+    pm_filter: &admin::PaymentMethodFilter,
+    card_networks: &mut Option<Vec<api_enums::CardNetwork>>,
+    country: Option<&String>,
+    currency: Option<api_enums::Currency>,
+    amount: Option<i64>,
+) -> bool {
+    if let (Some(card_networks), Some(accepted_card_networks)) =
+        (card_networks.as_mut(), pm_filter.card_networks.as_ref())
+    {
+        card_networks.retain(|card_network| accepted_card_networks.contains(card_network));
+    }
+    get_pm_filter_payment_violation(pm_filter, country, currency, amount).is_none()
+}
+
 fn filter_pm_based_on_config<'a>(
     config: &'a crate::configs::settings::ConnectorFilters,
     connector: &'a str,
@@ -1101,6 +1474,15 @@ pub async fn list_customer_payment_method(
             errors::ApiErrorResponse::PaymentMethodNotFound
         ));
     }
+    let default_payment_method_id = db
+        .find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the customer")?
+        .and_then(|customer| customer.into_encrypted().default_payment_method_id);
     let mut customer_pms = Vec::new();
     for pm in resp.into_iter() {
         let payment_token = generate_id(consts::ID_LENGTH, "token");
@@ -1114,6 +1496,8 @@ pub async fn list_customer_payment_method(
             None
         };
         //Need validation for enabled payment method ,querying MCA
+        let default_payment_method_set =
+            default_payment_method_id.as_ref() == Some(&pm.payment_method_id);
         let pma = api::CustomerPaymentMethod {
             payment_token: payment_token.to_string(),
             customer_id: pm.customer_id,
@@ -1129,9 +1513,17 @@ pub async fn list_customer_payment_method(
             installment_payment_enabled: false,
             payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
             created: Some(pm.created_at),
+            default_payment_method_set,
+            last_used_at: pm.last_used_at,
         };
         customer_pms.push(pma);
     }
+    // The default payment method is listed first, followed by the most recently used ones
+    customer_pms.sort_by(|pm1, pm2| {
+        pm2.default_payment_method_set
+            .cmp(&pm1.default_payment_method_set)
+            .then_with(|| pm2.last_used_at.cmp(&pm1.last_used_at))
+    });
 
     let response = api::ListCustomerPaymentMethodsResponse {
         customer_payment_methods: customer_pms,
@@ -1147,7 +1539,8 @@ pub async fn get_lookup_key_from_locker(
     locker_id: &str,
 ) -> errors::RouterResult<api::CardDetailFromLocker> {
     let get_card_resp =
-        get_card_from_legacy_locker(state, locker_id, pm.payment_method_id.as_str()).await?;
+        get_card_from_legacy_locker(state, &pm.merchant_id, locker_id, &pm.payment_method_id)
+            .await?;
     let card_detail = payment_methods::get_card_detail(pm, get_card_resp.card)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Get Card Details Failed")?;
@@ -1220,6 +1613,21 @@ impl BasiliskCardSupport {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Wrapped value2 construction failed when saving card to locker")?;
 
+        if state.conf.vault.enabled {
+            card_vault::store_token(
+                state,
+                &pm.merchant_id,
+                Some(payment_token.to_string()),
+                value1,
+                value2,
+                state.conf.vault.token_ttl,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to save card to vault")?;
+            return Ok(card);
+        }
+
         let db_value = vault::MockTokenizeDBValue { value1, value2 };
 
         let value_string =
@@ -1324,6 +1732,21 @@ impl BasiliskCardSupport {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Wrapped value2 construction failed when saving card to locker")?;
 
+        if state.conf.vault.enabled {
+            card_vault::store_token(
+                state,
+                &pm.merchant_id,
+                Some(payment_token.to_string()),
+                value1,
+                value2,
+                state.conf.vault.token_ttl,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to save card to vault")?;
+            return Ok(card);
+        }
+
         vault::create_tokenize(state, value1, Some(value2), payment_token.to_string()).await?;
         Ok(card)
     }
@@ -1342,10 +1765,24 @@ pub async fn retrieve_payment_method(
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
         })?;
+    get_payment_method_response(state, &merchant_account, pm)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+async fn get_payment_method_response(
+    state: &routes::AppState,
+    merchant_account: &storage::MerchantAccount,
+    pm: storage::PaymentMethod,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
     let card = if pm.payment_method == enums::PaymentMethod::Card {
-        let locker_id = merchant_account.locker_id.get_required_value("locker_id")?;
+        let locker_id = merchant_account
+            .locker_id
+            .clone()
+            .get_required_value("locker_id")?;
         let get_card_resp =
-            get_card_from_legacy_locker(state, &locker_id, &pm.payment_method_id).await?;
+            get_card_from_legacy_locker(state, &pm.merchant_id, &locker_id, &pm.payment_method_id)
+                .await?;
         let card_detail = payment_methods::get_card_detail(&pm, get_card_resp.card)
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed while getting card details from locker")?;
@@ -1353,21 +1790,108 @@ pub async fn retrieve_payment_method(
     } else {
         None
     };
-    Ok(services::ApplicationResponse::Json(
-        api::PaymentMethodResponse {
-            merchant_id: pm.merchant_id,
-            customer_id: Some(pm.customer_id),
-            payment_method_id: pm.payment_method_id,
-            payment_method: pm.payment_method.foreign_into(),
-            payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
-            card,
-            metadata: pm.metadata,
-            created: Some(pm.created_at),
-            recurring_enabled: false,           //[#219]
-            installment_payment_enabled: false, //[#219]
-            payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219],
-        },
-    ))
+    Ok(api::PaymentMethodResponse {
+        merchant_id: pm.merchant_id,
+        customer_id: Some(pm.customer_id),
+        payment_method_id: pm.payment_method_id,
+        payment_method: pm.payment_method.foreign_into(),
+        payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
+        card,
+        metadata: pm.metadata,
+        created: Some(pm.created_at),
+        recurring_enabled: false,           //[#219]
+        installment_payment_enabled: false, //[#219]
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219],
+    })
+}
+
+async fn find_merchant_payment_method(
+    db: &dyn db::StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_method_id: &str,
+) -> errors::RouterResult<storage::PaymentMethod> {
+    db.find_payment_method(payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })
+        .and_then(|pm| {
+            if pm.merchant_id == merchant_account.merchant_id {
+                Ok(pm)
+            } else {
+                Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound))
+            }
+        })
+}
+
+/// Attaches a payment method to a customer of the merchant. Like on Stripe, a payment method which
+/// is already attached to another customer cannot be attached.
+#[instrument(skip_all)]
+pub async fn attach_payment_method(
+    state: &routes::AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentMethodAttachRequest,
+) -> errors::RouterResponse<api::PaymentMethodResponse> {
+    let db = &*state.store;
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
+        .await
+        .map(Decryptable::into_encrypted)
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+    let pm = find_merchant_payment_method(db, &merchant_account, &req.payment_method_id).await?;
+
+    let pm = if pm.customer_id == customer.customer_id {
+        pm
+    } else if !pm.customer_id.is_empty() {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment method is attached to another customer".to_string(),
+        }))?
+    } else {
+        db.update_payment_method(
+            pm,
+            storage::PaymentMethodUpdate::CustomerUpdate {
+                customer_id: customer.customer_id,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to attach the payment method to the customer")?
+    };
+
+    get_payment_method_response(state, &merchant_account, pm)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+/// Detaches a payment method from its customer. The payment method is deleted along with the
+/// card stored in the locker, and cannot be used for payments once detached.
+#[instrument(skip_all)]
+pub async fn detach_payment_method(
+    state: &routes::AppState,
+    merchant_account: storage::MerchantAccount,
+    pm: api::PaymentMethodId,
+) -> errors::RouterResponse<api::PaymentMethodResponse> {
+    let db = &*state.store;
+    let pm = find_merchant_payment_method(db, &merchant_account, &pm.payment_method_id).await?;
+    let mut response = get_payment_method_response(state, &merchant_account, pm.clone()).await?;
+
+    db.delete_payment_method_by_merchant_id_payment_method_id(
+        &merchant_account.merchant_id,
+        &pm.payment_method_id,
+    )
+    .await
+    .map_err(|error| {
+        error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+    })?;
+
+    if pm.payment_method == enums::PaymentMethod::Card {
+        delete_card(state, &pm.merchant_id, &pm.payment_method_id).await?;
+        network_tokenization::delete_network_token(state, &pm).await;
+    }
+    replace_default_payment_method(db, &pm, None).await?;
+
+    response.customer_id = None;
+    Ok(services::ApplicationResponse::Json(response))
 }
 
 #[instrument(skip_all)]
@@ -1376,8 +1900,12 @@ pub async fn delete_payment_method(
     merchant_account: storage::MerchantAccount,
     pm: api::PaymentMethodId,
 ) -> errors::RouterResponse<api::DeletePaymentMethodResponse> {
-    let (_, supplementary_data) =
-        vault::Vault::get_payment_method_data_from_locker(state, &pm.payment_method_id).await?;
+    let (_, supplementary_data) = vault::Vault::get_payment_method_data_from_locker(
+        state,
+        &merchant_account.merchant_id,
+        &pm.payment_method_id,
+    )
+    .await?;
     let payment_method_id = supplementary_data
         .payment_method_id
         .map_or(Err(errors::ApiErrorResponse::PaymentMethodNotFound), Ok)?;
@@ -1399,7 +1927,9 @@ pub async fn delete_payment_method(
         } else {
             print!("Error: Deleting Card From Locker")
         }
+        network_tokenization::delete_network_token(state, &pm).await;
     };
+    replace_default_payment_method(&*state.store, &pm, None).await?;
 
     Ok(services::ApplicationResponse::Json(
         api::DeletePaymentMethodResponse {
@@ -1408,3 +1938,645 @@ pub async fn delete_payment_method(
         },
     ))
 }
+
+/// Sets the payment method to be used by default for the customer. The payment method must be
+/// saved for the same customer.
+#[instrument(skip_all)]
+pub async fn set_default_payment_method(
+    db: &dyn db::StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    req: api::DefaultPaymentMethod,
+) -> errors::RouterResponse<api::CustomerDefaultPaymentMethodResponse> {
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
+        .await
+        .map(Decryptable::into_encrypted)
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+    let pm = find_merchant_payment_method(db, &merchant_account, &req.payment_method_id).await?;
+    if pm.customer_id != customer.customer_id {
+        return Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound));
+    }
+
+    let customer = db
+        .update_customer_by_customer_id_merchant_id(
+            customer.customer_id,
+            customer.merchant_id,
+            storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id: Some(pm.payment_method_id),
+            },
+        )
+        .await
+        .map(Decryptable::into_encrypted)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the default payment method of the customer")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::CustomerDefaultPaymentMethodResponse {
+            customer_id: customer.customer_id,
+            default_payment_method_id: customer.default_payment_method_id,
+        },
+    ))
+}
+
+/// Replaces the default payment method of the customer with the given replacement, if the
+/// payment method being removed is the default one.
+async fn replace_default_payment_method(
+    db: &dyn db::StorageInterface,
+    pm: &storage::PaymentMethod,
+    replacement: Option<String>,
+) -> errors::RouterResult<()> {
+    let customer = db
+        .find_customer_optional_by_customer_id_merchant_id(&pm.customer_id, &pm.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the customer")?
+        .map(Decryptable::into_encrypted);
+
+    match customer {
+        Some(customer)
+            if customer.default_payment_method_id.as_ref() == Some(&pm.payment_method_id) =>
+        {
+            db.update_customer_by_customer_id_merchant_id(
+                customer.customer_id,
+                customer.merchant_id,
+                storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                    default_payment_method_id: replacement,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the default payment method of the customer")?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Records the time at which the payment method was last used for a successful payment.
+#[instrument(skip_all)]
+pub async fn update_last_used_at(
+    db: &dyn db::StorageInterface,
+    payment_method_id: &str,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let pm = db.find_payment_method(payment_method_id).await?;
+    db.update_payment_method(
+        pm,
+        storage::PaymentMethodUpdate::LastUsedUpdate {
+            last_used_at: common_utils::date_time::now(),
+        },
+    )
+    .await
+    .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::test_utils::{self, get_json_response};
+
+    fn pm_filter() -> admin::PaymentMethodFilter {
+        admin::PaymentMethodFilter {
+            accepted_currencies: Some(admin::AcceptedCurrencies {
+                accept_type: "enable_only".to_string(),
+                list: Some(vec![api_enums::Currency::USD]),
+            }),
+            accepted_countries: Some(admin::AcceptedCountries {
+                accept_type: "disable_only".to_string(),
+                list: Some(vec!["DE".to_string()]),
+            }),
+            minimum_amount: Some(100),
+            maximum_amount: Some(10000),
+            card_networks: Some(vec![api_enums::CardNetwork::Visa]),
+        }
+    }
+
+    #[test]
+    fn test_mca_pm_filter_takes_precedence() {
+        let mca_pm_filters = admin::PaymentMethodFilters::from([(
+            api_enums::PaymentMethodType::Credit,
+            pm_filter(),
+        )]);
+        let merchant_pm_filters = admin::PaymentMethodFilters::from([
+            (
+                api_enums::PaymentMethodType::Credit,
+                admin::PaymentMethodFilter::default(),
+            ),
+            (
+                api_enums::PaymentMethodType::Debit,
+                admin::PaymentMethodFilter::default(),
+            ),
+        ]);
+
+        let credit_filter = get_pm_filter(
+            Some(&mca_pm_filters),
+            Some(&merchant_pm_filters),
+            &api_enums::PaymentMethodType::Credit,
+        );
+        assert_eq!(
+            credit_filter.and_then(|filter| filter.minimum_amount),
+            Some(100)
+        );
+
+        let debit_filter = get_pm_filter(
+            Some(&mca_pm_filters),
+            Some(&merchant_pm_filters),
+            &api_enums::PaymentMethodType::Debit,
+        );
+        assert_eq!(debit_filter.and_then(|filter| filter.minimum_amount), None);
+        assert!(debit_filter.is_some());
+
+        assert!(get_pm_filter(None, None, &api_enums::PaymentMethodType::Credit).is_none());
+    }
+
+    #[test]
+    fn test_pm_filter_violation() {
+        let pm_filter = pm_filter();
+        let us = "US".to_string();
+        let de = "DE".to_string();
+        let visa = api_enums::CardNetwork::Visa;
+        let mastercard = api_enums::CardNetwork::Mastercard;
+
+        assert_eq!(
+            get_pm_filter_violation(
+                &pm_filter,
+                Some(&us),
+                Some(api_enums::Currency::USD),
+                Some(1000),
+                Some(&visa)
+            ),
+            None
+        );
+        assert_eq!(
+            get_pm_filter_violation(&pm_filter, None, None, None, Some(&visa)),
+            None
+        );
+        assert_eq!(
+            get_pm_filter_payment_violation(&pm_filter, None, None, None),
+            None
+        );
+        assert_eq!(
+            get_pm_filter_violation(&pm_filter, Some(&de), None, None, None),
+            Some("country")
+        );
+        assert_eq!(
+            get_pm_filter_violation(&pm_filter, None, Some(api_enums::Currency::EUR), None, None),
+            Some("currency")
+        );
+        assert_eq!(
+            get_pm_filter_violation(&pm_filter, None, None, Some(50), None),
+            Some("amount")
+        );
+        assert_eq!(
+            get_pm_filter_violation(&pm_filter, None, None, Some(20000), None),
+            Some("amount")
+        );
+        assert_eq!(
+            get_pm_filter_violation(&pm_filter, None, None, None, Some(&mastercard)),
+            Some("card_network")
+        );
+    }
+
+    #[test]
+    fn test_pm_filter_violation_without_card_network() {
+        let pm_filter = pm_filter();
+
+        assert_eq!(
+            get_pm_filter_violation(&pm_filter, None, Some(api_enums::Currency::USD), None, None),
+            Some("card_network")
+        );
+        assert_eq!(
+            get_pm_filter_violation(
+                &admin::PaymentMethodFilter::default(),
+                None,
+                None,
+                None,
+                None
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pm_filter_violation_without_country() {
+        let us = "US".to_string();
+        let enable_only = admin::PaymentMethodFilter {
+            accepted_countries: Some(admin::AcceptedCountries {
+                accept_type: "enable_only".to_string(),
+                list: Some(vec![us.clone()]),
+            }),
+            ..admin::PaymentMethodFilter::default()
+        };
+
+        assert_eq!(
+            get_pm_filter_payment_violation(&enable_only, None, None, None),
+            Some("country")
+        );
+        assert_eq!(
+            get_pm_filter_payment_violation(&enable_only, Some(&us), None, None),
+            None
+        );
+        // Without a country the payment cannot be in a list of disabled countries
+        assert_eq!(
+            get_pm_filter_payment_violation(&pm_filter(), None, None, None),
+            None
+        );
+    }
+
+    async fn create_payment_method(
+        state: &routes::AppState,
+        merchant_id: &str,
+        payment_method_id: &str,
+        customer_id: &str,
+    ) {
+        state
+            .store
+            .insert_payment_method(storage::PaymentMethodNew {
+                merchant_id: merchant_id.to_string(),
+                payment_method_id: payment_method_id.to_string(),
+                customer_id: customer_id.to_string(),
+                payment_method: enums::PaymentMethod::Wallet,
+                ..storage::PaymentMethodNew::default()
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn get_state() -> (routes::AppState, storage::MerchantAccount) {
+        let (state, merchant_account) =
+            test_utils::get_state_with_merchant(storage::MerchantAccountNew {
+                merchant_id: "merchant".to_string(),
+                locker_id: Some("m0010".to_string()),
+                ..storage::MerchantAccountNew::default()
+            })
+            .await;
+        for customer_id in ["cus_1", "cus_2"] {
+            state
+                .store
+                .insert_customer(storage::CustomerNew {
+                    customer_id: customer_id.to_string(),
+                    merchant_id: "merchant".to_string(),
+                    ..storage::CustomerNew::default()
+                })
+                .await
+                .unwrap();
+        }
+        (state, merchant_account)
+    }
+
+    fn attach_request(
+        payment_method_id: &str,
+        customer_id: &str,
+    ) -> api::PaymentMethodAttachRequest {
+        api::PaymentMethodAttachRequest {
+            payment_method_id: payment_method_id.to_string(),
+            customer_id: customer_id.to_string(),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_attach_payment_method() {
+        let (state, merchant_account) = get_state().await;
+        create_payment_method(&state, "merchant", "pm_1", "").await;
+
+        let response = attach_payment_method(
+            &state,
+            merchant_account.clone(),
+            attach_request("pm_1", "cus_1"),
+        )
+        .await
+        .unwrap();
+        let response = get_json_response(response);
+        assert_eq!(response.customer_id.as_deref(), Some("cus_1"));
+        let pm = state.store.find_payment_method("pm_1").await.unwrap();
+        assert_eq!(pm.customer_id, "cus_1");
+
+        // Attaching again to the same customer is a no-op
+        let response =
+            attach_payment_method(&state, merchant_account, attach_request("pm_1", "cus_1")).await;
+        assert!(response.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_attach_payment_method_of_another_customer() {
+        let (state, merchant_account) = get_state().await;
+        create_payment_method(&state, "merchant", "pm_1", "cus_1").await;
+
+        let error =
+            attach_payment_method(&state, merchant_account, attach_request("pm_1", "cus_2"))
+                .await
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+        let pm = state.store.find_payment_method("pm_1").await.unwrap();
+        assert_eq!(pm.customer_id, "cus_1");
+    }
+
+    #[actix_rt::test]
+    async fn test_attach_payment_method_of_another_merchant() {
+        let (state, merchant_account) = get_state().await;
+        create_payment_method(&state, "other_merchant", "pm_1", "").await;
+
+        let error =
+            attach_payment_method(&state, merchant_account, attach_request("pm_1", "cus_1"))
+                .await
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PaymentMethodNotFound
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn test_detach_payment_method() {
+        let (state, merchant_account) = get_state().await;
+        create_payment_method(&state, "merchant", "pm_1", "cus_1").await;
+
+        let response = detach_payment_method(
+            &state,
+            merchant_account.clone(),
+            api::PaymentMethodId {
+                payment_method_id: "pm_1".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let response = get_json_response(response);
+        assert_eq!(response.payment_method_id, "pm_1");
+        assert_eq!(response.customer_id, None);
+        assert!(state.store.find_payment_method("pm_1").await.is_err());
+
+        let error = detach_payment_method(
+            &state,
+            merchant_account,
+            api::PaymentMethodId {
+                payment_method_id: "pm_1".to_string(),
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PaymentMethodNotFound
+        ));
+    }
+
+    #[test]
+    fn test_get_card_fingerprint() {
+        let fingerprint = get_card_fingerprint("hash_key", "4111111111111111").unwrap();
+
+        assert_eq!(fingerprint.len(), 64);
+        assert_eq!(
+            get_card_fingerprint("hash_key", "4111111111111111").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            get_card_fingerprint("other_hash_key", "4111111111111111").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            get_card_fingerprint("hash_key", "4242424242424242").unwrap(),
+            fingerprint
+        );
+    }
+
+    fn card_payment_method(
+        card_exp_year: &str,
+        metadata: Option<serde_json::Value>,
+    ) -> api::CreatePaymentMethod {
+        api::CreatePaymentMethod {
+            payment_method: api_enums::PaymentMethod::Card,
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: Some(api::CardDetail {
+                card_number: "4111111111111111".to_string().into(),
+                card_exp_month: "10".to_string().into(),
+                card_exp_year: card_exp_year.to_string().into(),
+                card_holder_name: None,
+            }),
+            metadata,
+            customer_id: Some("cus_1".to_string()),
+            card_network: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_add_duplicate_card() {
+        let (state, merchant_account) = get_state().await;
+
+        let response = add_payment_method(
+            &state,
+            card_payment_method("25", Some(serde_json::json!({ "order": "1" }))),
+            &merchant_account,
+        )
+        .await
+        .unwrap();
+        let pm = get_json_response(response);
+
+        // Saving the same card again updates the saved payment method
+        let response = add_payment_method(
+            &state,
+            card_payment_method("27", Some(serde_json::json!({ "order": "2" }))),
+            &merchant_account,
+        )
+        .await
+        .unwrap();
+        let duplicate_pm = get_json_response(response);
+        assert_eq!(duplicate_pm.payment_method_id, pm.payment_method_id);
+        assert_eq!(
+            duplicate_pm.metadata,
+            Some(serde_json::json!({ "order": "2" }))
+        );
+        let card = duplicate_pm.card.unwrap();
+        assert_eq!(card.expiry_year.unwrap().peek(), "27");
+        assert_eq!(card.last4_digits.as_deref(), Some("1111"));
+
+        let pms = state
+            .store
+            .find_payment_method_by_customer_id_merchant_id_list("cus_1", "merchant")
+            .await
+            .unwrap();
+        assert_eq!(pms.len(), 1);
+        assert_eq!(
+            pms[0].card_fingerprint,
+            Some(
+                get_card_fingerprint(
+                    &state.conf.secrets.card_fingerprint_hash_key,
+                    "4111111111111111"
+                )
+                .unwrap()
+            )
+        );
+
+        // The metadata is kept when the card is saved again without metadata
+        let response =
+            add_payment_method(&state, card_payment_method("27", None), &merchant_account)
+                .await
+                .unwrap();
+        assert_eq!(
+            get_json_response(response).metadata,
+            Some(serde_json::json!({ "order": "2" }))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_add_card_saved_concurrently() {
+        let (state, merchant_account) = get_state().await;
+
+        let response =
+            add_payment_method(&state, card_payment_method("25", None), &merchant_account)
+                .await
+                .unwrap();
+        let pm = get_json_response(response);
+
+        // A concurrent request which did not find the card before it was saved
+        let card_fingerprint = get_card_fingerprint(
+            &state.conf.secrets.card_fingerprint_hash_key,
+            "4111111111111111",
+        )
+        .unwrap();
+        let req = card_payment_method("25", None);
+        let card = req.card.clone().unwrap();
+        let response = add_new_card(
+            &state,
+            req,
+            card,
+            "cus_1".to_string(),
+            &merchant_account,
+            card_fingerprint,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.payment_method_id, pm.payment_method_id);
+
+        let pms = state
+            .store
+            .find_payment_method_by_customer_id_merchant_id_list("cus_1", "merchant")
+            .await
+            .unwrap();
+        assert_eq!(pms.len(), 1);
+    }
+
+    #[actix_rt::test]
+    async fn test_set_default_payment_method() {
+        let (state, merchant_account) = get_state().await;
+        let db = &*state.store;
+        create_payment_method(&state, "merchant", "pm_1", "cus_1").await;
+        create_payment_method(&state, "merchant", "pm_2", "cus_1").await;
+        create_payment_method(&state, "merchant", "pm_3", "cus_2").await;
+        let default_payment_method = |payment_method_id: &str| api::DefaultPaymentMethod {
+            customer_id: "cus_1".to_string(),
+            payment_method_id: payment_method_id.to_string(),
+        };
+
+        let response = set_default_payment_method(
+            db,
+            merchant_account.clone(),
+            default_payment_method("pm_1"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_json_response(response)
+                .default_payment_method_id
+                .as_deref(),
+            Some("pm_1")
+        );
+
+        // A payment method of another customer cannot be the default one
+        let error = set_default_payment_method(
+            db,
+            merchant_account.clone(),
+            default_payment_method("pm_3"),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PaymentMethodNotFound
+        ));
+
+        // Replacing a payment method which is not the default one keeps the default one
+        let pm_2 = db.find_payment_method("pm_2").await.unwrap();
+        replace_default_payment_method(db, &pm_2, None)
+            .await
+            .unwrap();
+        let customer = db
+            .find_customer_by_customer_id_merchant_id("cus_1", "merchant")
+            .await
+            .unwrap()
+            .into_encrypted();
+        assert_eq!(customer.default_payment_method_id.as_deref(), Some("pm_1"));
+
+        let pm_1 = db.find_payment_method("pm_1").await.unwrap();
+        replace_default_payment_method(db, &pm_1, Some("pm_2".to_string()))
+            .await
+            .unwrap();
+        let customer = db
+            .find_customer_by_customer_id_merchant_id("cus_1", "merchant")
+            .await
+            .unwrap()
+            .into_encrypted();
+        assert_eq!(customer.default_payment_method_id.as_deref(), Some("pm_2"));
+
+        // Detaching the default payment method leaves the customer without a default one
+        detach_payment_method(
+            &state,
+            merchant_account,
+            api::PaymentMethodId {
+                payment_method_id: "pm_2".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let customer = db
+            .find_customer_by_customer_id_merchant_id("cus_1", "merchant")
+            .await
+            .unwrap()
+            .into_encrypted();
+        assert_eq!(customer.default_payment_method_id, None);
+    }
+
+    #[actix_rt::test]
+    async fn test_list_customer_payment_methods_order() {
+        let (state, merchant_account) = get_state().await;
+        let db = &*state.store;
+        for payment_method_id in ["pm_1", "pm_2", "pm_3", "pm_4"] {
+            create_payment_method(&state, "merchant", payment_method_id, "cus_1").await;
+        }
+
+        update_last_used_at(db, "pm_2").await.unwrap();
+        update_last_used_at(db, "pm_1").await.unwrap();
+        assert!(db
+            .find_payment_method("pm_1")
+            .await
+            .unwrap()
+            .last_used_at
+            .is_some());
+        set_default_payment_method(
+            db,
+            merchant_account.clone(),
+            api::DefaultPaymentMethod {
+                customer_id: "cus_1".to_string(),
+                payment_method_id: "pm_4".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let response = list_customer_payment_method(&state, merchant_account, "cus_1")
+            .await
+            .unwrap();
+        let customer_pms = get_json_response(response).customer_payment_methods;
+        assert_eq!(customer_pms.len(), 4);
+        assert!(customer_pms[0].default_payment_method_set);
+        assert!(customer_pms[1].last_used_at > customer_pms[2].last_used_at);
+        assert!(customer_pms[2].last_used_at.is_some());
+        assert!(customer_pms[3].last_used_at.is_none());
+        assert!(!customer_pms[3].default_payment_method_set);
+    }
+}
@@ -0,0 +1,452 @@
+use actix_web::http::header::HeaderMap;
+use base64::Engine;
+use common_utils::crypto::{self, VerifySignature};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::{NetworkTokenizationSettings, TokenServiceProviderKind},
+    consts,
+    core::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+/// Header containing the hex encoded HMAC-SHA256 signature of the token lifecycle event.
+const LIFECYCLE_EVENT_SIGNATURE_HEADER: &str = "x-network-token-signature";
+
+/// Network token provisioned by the token service provider for a card.
+#[derive(Debug, Clone)]
+pub struct NetworkToken {
+    /// The identifier of the network token with the token service provider
+    pub token_reference_id: String,
+    pub token_number: Secret<String>,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    /// Whether a cryptogram can be generated for payments made using the network token
+    pub cryptogram_capable: bool,
+}
+
+/// Cryptogram generated by the token service provider for a payment made using a network token.
+#[derive(Debug, Clone)]
+pub struct TokenCryptogram {
+    pub cryptogram: Secret<String>,
+    /// Electronic commerce indicator (ECI) to be sent to the connector along with the cryptogram
+    pub eci: Option<String>,
+}
+
+/// Change to a network token sent by the token service provider, when the card is suspended,
+/// deleted or reissued by the issuer.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NetworkTokenLifecycleEvent {
+    pub token_reference_id: String,
+    pub status: storage_enums::NetworkTokenStatus,
+    pub token_exp_month: Option<Secret<String>>,
+    pub token_exp_year: Option<Secret<String>>,
+}
+
+#[async_trait::async_trait]
+pub trait TokenServiceProvider: Send + Sync {
+    async fn provision_token(
+        &self,
+        card: &api::CardDetail,
+    ) -> CustomResult<NetworkToken, errors::NetworkTokenizationError>;
+
+    async fn generate_cryptogram(
+        &self,
+        token_reference_id: &str,
+        amount: i64,
+        currency: storage_enums::Currency,
+    ) -> CustomResult<TokenCryptogram, errors::NetworkTokenizationError>;
+
+    async fn delete_token(
+        &self,
+        token_reference_id: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError>;
+
+    /// Verify the token lifecycle event received from the token service provider, and parse it.
+    fn get_lifecycle_event(
+        &self,
+        headers: &HeaderMap,
+        body: &[u8],
+        secret: &str,
+    ) -> CustomResult<NetworkTokenLifecycleEvent, errors::NetworkTokenizationError>;
+}
+
+pub fn get_token_service_provider(kind: TokenServiceProviderKind) -> Box<dyn TokenServiceProvider> {
+    match kind {
+        TokenServiceProviderKind::Mock => Box::new(MockTokenServiceProvider),
+    }
+}
+
+/// Token service provider which issues network tokens without contacting the card networks, for
+/// use in tests and sandbox environments.
+#[derive(Debug)]
+pub struct MockTokenServiceProvider;
+
+#[async_trait::async_trait]
+impl TokenServiceProvider for MockTokenServiceProvider {
+    async fn provision_token(
+        &self,
+        card: &api::CardDetail,
+    ) -> CustomResult<NetworkToken, errors::NetworkTokenizationError> {
+        // The token retains the BIN of the card, so that it is routed to the same card network
+        let card_bin = card
+            .card_number
+            .peek()
+            .get(..6)
+            .ok_or(errors::NetworkTokenizationError::TokenProvisioningFailed)
+            .into_report()
+            .attach_printable("Card number is too short to be tokenized")?;
+        let token_suffix = uuid::Uuid::new_v4().as_u128() % 10_000_000_000;
+
+        Ok(NetworkToken {
+            token_reference_id: utils::generate_id(consts::ID_LENGTH, "tknref"),
+            token_number: Secret::new(format!("{card_bin}{token_suffix:010}")),
+            token_exp_month: card.card_exp_month.clone(),
+            token_exp_year: card.card_exp_year.clone(),
+            cryptogram_capable: true,
+        })
+    }
+
+    async fn generate_cryptogram(
+        &self,
+        _token_reference_id: &str,
+        _amount: i64,
+        _currency: storage_enums::Currency,
+    ) -> CustomResult<TokenCryptogram, errors::NetworkTokenizationError> {
+        Ok(TokenCryptogram {
+            cryptogram: Secret::new(consts::BASE64_ENGINE.encode(uuid::Uuid::new_v4().as_bytes())),
+            eci: Some("07".to_string()),
+        })
+    }
+
+    async fn delete_token(
+        &self,
+        _token_reference_id: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError> {
+        Ok(())
+    }
+
+    fn get_lifecycle_event(
+        &self,
+        headers: &HeaderMap,
+        body: &[u8],
+        secret: &str,
+    ) -> CustomResult<NetworkTokenLifecycleEvent, errors::NetworkTokenizationError> {
+        let signature = headers
+            .get(LIFECYCLE_EVENT_SIGNATURE_HEADER)
+            .and_then(|signature| signature.to_str().ok())
+            .and_then(|signature| hex::decode(signature).ok())
+            .ok_or(errors::NetworkTokenizationError::LifecycleEventVerificationFailed)
+            .into_report()
+            .attach_printable("Missing or invalid signature of the token lifecycle event")?;
+
+        let verified = crypto::HmacSha256
+            .verify_signature(secret.as_bytes(), &signature, body)
+            .change_context(errors::NetworkTokenizationError::LifecycleEventVerificationFailed)?;
+        if !verified {
+            return Err(report!(
+                errors::NetworkTokenizationError::LifecycleEventVerificationFailed
+            ));
+        }
+
+        serde_json::from_slice(body)
+            .into_report()
+            .change_context(errors::NetworkTokenizationError::LifecycleEventDeserializationFailed)
+    }
+}
+
+/// Provision a network token for the card saved as the payment method, if network tokenization is
+/// enabled. Saving the card does not fail if the token cannot be provisioned, since the card can
+/// still be used for payments without the network token.
+#[instrument(skip_all)]
+pub async fn tokenize_saved_card(
+    state: &AppState,
+    card: &api::CardDetail,
+    payment_method_id: &str,
+) {
+    let settings = &state.conf.network_tokenization;
+    if !settings.enabled {
+        return;
+    }
+
+    if let Err(error) = provision_network_token(state, settings, card, payment_method_id).await {
+        logger::error!(network_token_provisioning_error=?error);
+    }
+}
+
+async fn provision_network_token(
+    state: &AppState,
+    settings: &NetworkTokenizationSettings,
+    card: &api::CardDetail,
+    payment_method_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let pm = db
+        .find_payment_method(payment_method_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the saved card to be tokenized")?;
+
+    // A card which was saved earlier may already have a network token
+    if pm.network_token_reference_id.is_some() {
+        return Ok(());
+    }
+
+    let network_token = get_token_service_provider(settings.token_service_provider)
+        .provision_token(card)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    db.update_payment_method(
+        pm,
+        storage::PaymentMethodUpdate::NetworkTokenUpdate {
+            network_token_reference_id: network_token.token_reference_id,
+            network_token: network_token.token_number,
+            network_token_exp_month: network_token.token_exp_month,
+            network_token_exp_year: network_token.token_exp_year,
+            network_token_cryptogram_capable: network_token.cryptogram_capable,
+            network_token_status: storage_enums::NetworkTokenStatus::Active,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the network token of the saved card")?;
+
+    Ok(())
+}
+
+/// Network token to be sent in place of the card number of the saved card used for the payment,
+/// if the connector supports network tokens and the token is active. The payment falls back to
+/// the card number if the network token cannot be used.
+#[instrument(skip_all)]
+pub async fn get_network_token_data(
+    state: &AppState,
+    connector_name: &str,
+    payment_method_id: Option<&str>,
+    amount: i64,
+    currency: storage_enums::Currency,
+) -> Option<types::NetworkTokenData> {
+    let settings = &state.conf.network_tokenization;
+    let connector_supported = settings
+        .supported_connectors
+        .as_ref()
+        .map_or(false, |connectors| connectors.contains(connector_name));
+    if !settings.enabled || !connector_supported {
+        return None;
+    }
+
+    let payment_method_id = payment_method_id?;
+    find_network_token_data(state, settings, payment_method_id, amount, currency)
+        .await
+        .map_err(|error| logger::error!(network_token_error=?error))
+        .ok()
+        .flatten()
+}
+
+async fn find_network_token_data(
+    state: &AppState,
+    settings: &NetworkTokenizationSettings,
+    payment_method_id: &str,
+    amount: i64,
+    currency: storage_enums::Currency,
+) -> RouterResult<Option<types::NetworkTokenData>> {
+    let pm = match state.store.find_payment_method(payment_method_id).await {
+        Ok(pm) => pm,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while finding the saved card used for the payment")
+        }
+    };
+
+    let (token_reference_id, token_number, token_exp_month, token_exp_year) = match (
+        pm.network_token_reference_id,
+        pm.network_token,
+        pm.network_token_exp_month,
+        pm.network_token_exp_year,
+        pm.network_token_status,
+    ) {
+        (
+            Some(token_reference_id),
+            Some(token_number),
+            Some(token_exp_month),
+            Some(token_exp_year),
+            Some(storage_enums::NetworkTokenStatus::Active),
+        ) => (
+            token_reference_id,
+            token_number,
+            token_exp_month,
+            token_exp_year,
+        ),
+        _ => return Ok(None),
+    };
+
+    let token_cryptogram = if pm.network_token_cryptogram_capable.unwrap_or(false) {
+        Some(
+            get_token_service_provider(settings.token_service_provider)
+                .generate_cryptogram(&token_reference_id, amount, currency)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        )
+    } else {
+        None
+    };
+    let (cryptogram, eci) = match token_cryptogram {
+        Some(token_cryptogram) => (Some(token_cryptogram.cryptogram), token_cryptogram.eci),
+        None => (None, None),
+    };
+
+    Ok(Some(types::NetworkTokenData {
+        token_number,
+        token_exp_month,
+        token_exp_year,
+        cryptogram,
+        eci,
+    }))
+}
+
+/// Send the network token in place of the card number of the saved card.
+pub fn use_network_token(
+    request: &mut types::PaymentsAuthorizeData,
+    network_token: types::NetworkTokenData,
+) {
+    if let api::PaymentMethodData::Card(ref mut card) = request.payment_method_data {
+        card.card_number = Secret::new(network_token.token_number.peek().to_owned());
+        card.card_exp_month = network_token.token_exp_month.clone();
+        card.card_exp_year = network_token.token_exp_year.clone();
+        request.network_token = Some(network_token);
+    }
+}
+
+/// Delete the network token of the saved card with the token service provider, once the card has
+/// been deleted.
+#[instrument(skip_all)]
+pub async fn delete_network_token(state: &AppState, pm: &storage::PaymentMethod) {
+    if let Some(token_reference_id) = pm.network_token_reference_id.as_deref() {
+        if let Err(error) =
+            get_token_service_provider(state.conf.network_tokenization.token_service_provider)
+                .delete_token(token_reference_id)
+                .await
+        {
+            logger::error!(network_token_deletion_error=?error);
+        }
+    }
+}
+
+/// Update the status and expiry of the network token as notified by the token service provider.
+#[instrument(skip_all)]
+pub async fn handle_lifecycle_event(
+    state: &AppState,
+    headers: &HeaderMap,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<()> {
+    let settings = &state.conf.network_tokenization;
+    let event = get_token_service_provider(settings.token_service_provider)
+        .get_lifecycle_event(headers, &body, &settings.lifecycle_event_secret)
+        .map_err(|error| match error.current_context() {
+            errors::NetworkTokenizationError::LifecycleEventVerificationFailed => {
+                error.change_context(errors::ApiErrorResponse::Unauthorized)
+            }
+            _ => error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid token lifecycle event".to_string(),
+            }),
+        })?;
+
+    let db = &*state.store;
+    let pm = db
+        .find_payment_method_by_network_token_reference_id(&event.token_reference_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    db.update_payment_method(
+        pm,
+        storage::PaymentMethodUpdate::NetworkTokenLifecycleUpdate {
+            network_token_status: event.status,
+            network_token_exp_month: event.token_exp_month,
+            network_token_exp_year: event.token_exp_year,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the network token of the saved card")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::http::header::{HeaderName, HeaderValue};
+    use common_utils::crypto::SignMessage;
+
+    use super::*;
+
+    fn get_card() -> api::CardDetail {
+        api::CardDetail {
+            card_number: Secret::new("4111111111111111".to_string()),
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            card_holder_name: None,
+        }
+    }
+
+    fn get_signed_headers(secret: &str, body: &[u8]) -> HeaderMap {
+        let signature = crypto::HmacSha256
+            .sign_message(secret.as_bytes(), body)
+            .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static(LIFECYCLE_EVENT_SIGNATURE_HEADER),
+            HeaderValue::from_str(&hex::encode(signature)).unwrap(),
+        );
+        headers
+    }
+
+    #[actix_rt::test]
+    async fn test_mock_token_provisioning() {
+        let card = get_card();
+        let network_token = MockTokenServiceProvider
+            .provision_token(&card)
+            .await
+            .unwrap();
+
+        let token_number = network_token.token_number.peek();
+        assert_eq!(token_number.len(), 16);
+        assert!(token_number.starts_with("411111"));
+        assert_ne!(token_number, card.card_number.peek());
+        assert!(network_token.cryptogram_capable);
+    }
+
+    #[test]
+    fn test_mock_lifecycle_event_verification() {
+        let secret = "network_token_lifecycle_secret";
+        let body = br#"{"token_reference_id":"tknref_123","status":"suspended"}"#;
+
+        let event = MockTokenServiceProvider
+            .get_lifecycle_event(&get_signed_headers(secret, body), body, secret)
+            .unwrap();
+        assert_eq!(event.token_reference_id, "tknref_123");
+        assert_eq!(event.status, storage_enums::NetworkTokenStatus::Suspended);
+
+        let error = MockTokenServiceProvider
+            .get_lifecycle_event(&get_signed_headers("other_secret", body), body, secret)
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::NetworkTokenizationError::LifecycleEventVerificationFailed
+        ));
+    }
+}
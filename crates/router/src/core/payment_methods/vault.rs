@@ -1,19 +1,22 @@
 use common_utils::generate_id_with_default_len;
 use error_stack::{IntoReport, ResultExt};
-use masking::PeekInterface;
+use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing};
 
 #[cfg(not(feature = "basilisk"))]
 use crate::types::storage;
+#[cfg(feature = "basilisk")]
+use crate::{core::payment_methods::transformers as payment_methods, services, utils::BytesExt};
 use crate::{
-    core::errors::{self, CustomResult, RouterResult},
+    core::{
+        card_vault,
+        errors::{self, CustomResult, RouterResult},
+    },
     logger, routes,
     types::api,
     utils::{self, StringExt},
 };
 #[cfg(feature = "basilisk")]
-use crate::{core::payment_methods::transformers as payment_methods, services, utils::BytesExt};
-#[cfg(feature = "basilisk")]
 const VAULT_SERVICE_NAME: &str = "CARD";
 #[cfg(feature = "basilisk")]
 const VAULT_VERSION: &str = "0";
@@ -87,6 +90,8 @@ impl Vaultable for api::Card {
             card_cvc: value2.card_security_code.unwrap_or_default().into(),
             card_issuer: None,
             card_network: None,
+            card_type: None,
+            card_issuing_country: None,
         };
 
         let supp_data = SupplementaryVaultData {
@@ -142,11 +147,97 @@ impl Vaultable for api::WalletData {
     }
 }
 
+impl Vaultable for api::BankDebitData {
+    fn get_value1(&self, _customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value1 = api::TokenizedBankDebitValue1 {
+            data: self.to_owned(),
+        };
+
+        utils::Encode::<api::TokenizedBankDebitValue1>::encode_to_string_of_json(&value1)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode bank debit data value1")
+    }
+
+    fn get_value2(&self, customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value2 = api::TokenizedBankDebitValue2 { customer_id };
+
+        utils::Encode::<api::TokenizedBankDebitValue2>::encode_to_string_of_json(&value2)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode bank debit data value2")
+    }
+
+    fn from_values(
+        value1: String,
+        value2: String,
+    ) -> CustomResult<(Self, SupplementaryVaultData), errors::VaultError> {
+        let value1: api::TokenizedBankDebitValue1 = value1
+            .parse_struct("TokenizedBankDebitValue1")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into bank debit data value1")?;
+
+        let value2: api::TokenizedBankDebitValue2 = value2
+            .parse_struct("TokenizedBankDebitValue2")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into bank debit data value2")?;
+
+        let supp_data = SupplementaryVaultData {
+            customer_id: value2.customer_id,
+            payment_method_id: None,
+        };
+
+        Ok((value1.data, supp_data))
+    }
+}
+
+impl Vaultable for api::BankTransferData {
+    fn get_value1(&self, _customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value1 = api::TokenizedBankTransferValue1 {
+            data: self.to_owned(),
+        };
+
+        utils::Encode::<api::TokenizedBankTransferValue1>::encode_to_string_of_json(&value1)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode bank transfer data value1")
+    }
+
+    fn get_value2(&self, customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value2 = api::TokenizedBankTransferValue2 { customer_id };
+
+        utils::Encode::<api::TokenizedBankTransferValue2>::encode_to_string_of_json(&value2)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode bank transfer data value2")
+    }
+
+    fn from_values(
+        value1: String,
+        value2: String,
+    ) -> CustomResult<(Self, SupplementaryVaultData), errors::VaultError> {
+        let value1: api::TokenizedBankTransferValue1 = value1
+            .parse_struct("TokenizedBankTransferValue1")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into bank transfer data value1")?;
+
+        let value2: api::TokenizedBankTransferValue2 = value2
+            .parse_struct("TokenizedBankTransferValue2")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into bank transfer data value2")?;
+
+        let supp_data = SupplementaryVaultData {
+            customer_id: value2.customer_id,
+            payment_method_id: None,
+        };
+
+        Ok((value1.data, supp_data))
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
 pub enum VaultPaymentMethod {
     Card(String),
     Wallet(String),
+    BankDebit(String),
+    BankTransfer(String),
 }
 
 impl Vaultable for api::PaymentMethodData {
@@ -154,6 +245,12 @@ impl Vaultable for api::PaymentMethodData {
         let value1 = match self {
             Self::Card(card) => VaultPaymentMethod::Card(card.get_value1(customer_id)?),
             Self::Wallet(wallet) => VaultPaymentMethod::Wallet(wallet.get_value1(customer_id)?),
+            Self::BankDebit(bank_debit) => {
+                VaultPaymentMethod::BankDebit(bank_debit.get_value1(customer_id)?)
+            }
+            Self::BankTransfer(bank_transfer) => {
+                VaultPaymentMethod::BankTransfer(bank_transfer.get_value1(customer_id)?)
+            }
             _ => Err(errors::VaultError::PaymentMethodNotSupported)
                 .into_report()
                 .attach_printable("Payment method not supported")?,
@@ -168,6 +265,12 @@ impl Vaultable for api::PaymentMethodData {
         let value2 = match self {
             Self::Card(card) => VaultPaymentMethod::Card(card.get_value2(customer_id)?),
             Self::Wallet(wallet) => VaultPaymentMethod::Wallet(wallet.get_value2(customer_id)?),
+            Self::BankDebit(bank_debit) => {
+                VaultPaymentMethod::BankDebit(bank_debit.get_value2(customer_id)?)
+            }
+            Self::BankTransfer(bank_transfer) => {
+                VaultPaymentMethod::BankTransfer(bank_transfer.get_value2(customer_id)?)
+            }
             _ => Err(errors::VaultError::PaymentMethodNotSupported)
                 .into_report()
                 .attach_printable("Payment method not supported")?,
@@ -201,6 +304,18 @@ impl Vaultable for api::PaymentMethodData {
                 let (wallet, supp_data) = api::WalletData::from_values(mvalue1, mvalue2)?;
                 Ok((Self::Wallet(wallet), supp_data))
             }
+            (VaultPaymentMethod::BankDebit(mvalue1), VaultPaymentMethod::BankDebit(mvalue2)) => {
+                let (bank_debit, supp_data) = api::BankDebitData::from_values(mvalue1, mvalue2)?;
+                Ok((Self::BankDebit(bank_debit), supp_data))
+            }
+            (
+                VaultPaymentMethod::BankTransfer(mvalue1),
+                VaultPaymentMethod::BankTransfer(mvalue2),
+            ) => {
+                let (bank_transfer, supp_data) =
+                    api::BankTransferData::from_values(mvalue1, mvalue2)?;
+                Ok((Self::BankTransfer(bank_transfer), supp_data))
+            }
             _ => Err(errors::VaultError::PaymentMethodNotSupported)
                 .into_report()
                 .attach_printable("Payment method not supported"),
@@ -221,8 +336,14 @@ impl Vault {
     #[instrument(skip_all)]
     pub async fn get_payment_method_data_from_locker(
         state: &routes::AppState,
+        merchant_id: &str,
         lookup_key: &str,
     ) -> RouterResult<(Option<api::PaymentMethodData>, SupplementaryVaultData)> {
+        if state.conf.vault.enabled {
+            return get_payment_method_data_from_built_in_vault(state, merchant_id, lookup_key)
+                .await;
+        }
+
         let config = state
             .store
             .find_config_by_key(lookup_key)
@@ -247,6 +368,7 @@ impl Vault {
     #[instrument(skip_all)]
     pub async fn store_payment_method_data_in_locker(
         state: &routes::AppState,
+        merchant_id: &str,
         token_id: Option<String>,
         payment_method: &api::PaymentMethodData,
         customer_id: Option<String>,
@@ -261,6 +383,17 @@ impl Vault {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error getting Value12 for locker")?;
 
+        if state.conf.vault.enabled {
+            return store_payment_method_data_in_built_in_vault(
+                state,
+                merchant_id,
+                token_id,
+                value1,
+                value2,
+            )
+            .await;
+        }
+
         let lookup_key = token_id.unwrap_or_else(|| generate_id_with_default_len("token"));
 
         let db_value = MockTokenizeDBValue { value1, value2 };
@@ -302,8 +435,14 @@ impl Vault {
     #[instrument(skip_all)]
     pub async fn delete_locker_payment_method_by_lookup_key(
         state: &routes::AppState,
+        merchant_id: &str,
         lookup_key: &Option<String>,
     ) {
+        if state.conf.vault.enabled {
+            return delete_payment_method_data_from_built_in_vault(state, merchant_id, lookup_key)
+                .await;
+        }
+
         let db = &*state.store;
         if let Some(id) = lookup_key {
             match db.delete_config_by_key(id).await {
@@ -319,8 +458,14 @@ impl Vault {
     #[instrument(skip_all)]
     pub async fn get_payment_method_data_from_locker(
         state: &routes::AppState,
+        merchant_id: &str,
         lookup_key: &str,
     ) -> RouterResult<(Option<api::PaymentMethodData>, SupplementaryVaultData)> {
+        if state.conf.vault.enabled {
+            return get_payment_method_data_from_built_in_vault(state, merchant_id, lookup_key)
+                .await;
+        }
+
         let de_tokenize = get_tokenized_data(state, lookup_key, true).await?;
         let (payment_method, customer_id) =
             api::PaymentMethodData::from_values(de_tokenize.value1, de_tokenize.value2)
@@ -333,6 +478,7 @@ impl Vault {
     #[instrument(skip_all)]
     pub async fn store_payment_method_data_in_locker(
         state: &routes::AppState,
+        merchant_id: &str,
         token_id: Option<String>,
         payment_method: &api::PaymentMethodData,
         customer_id: Option<String>,
@@ -347,6 +493,17 @@ impl Vault {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error getting Value12 for locker")?;
 
+        if state.conf.vault.enabled {
+            return store_payment_method_data_in_built_in_vault(
+                state,
+                merchant_id,
+                token_id,
+                value1,
+                value2,
+            )
+            .await;
+        }
+
         let lookup_key = token_id.unwrap_or_else(|| generate_id_with_default_len("token"));
 
         create_tokenize(state, value1, Some(value2), lookup_key).await
@@ -355,8 +512,14 @@ impl Vault {
     #[instrument(skip_all)]
     pub async fn delete_locker_payment_method_by_lookup_key(
         state: &routes::AppState,
+        merchant_id: &str,
         lookup_key: &Option<String>,
     ) {
+        if state.conf.vault.enabled {
+            return delete_payment_method_data_from_built_in_vault(state, merchant_id, lookup_key)
+                .await;
+        }
+
         if let Some(lookup_key) = lookup_key {
             let delete_resp = delete_tokenized_data(state, lookup_key).await;
             match delete_resp {
@@ -373,6 +536,61 @@ impl Vault {
     }
 }
 
+//------------------------------------------------BuiltInVault------------------------------------------------
+async fn get_payment_method_data_from_built_in_vault(
+    state: &routes::AppState,
+    merchant_id: &str,
+    lookup_key: &str,
+) -> RouterResult<(Option<api::PaymentMethodData>, SupplementaryVaultData)> {
+    let vault_token = card_vault::get_token(state, merchant_id, lookup_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find payment method in vault")?;
+
+    let (payment_method, supp_data) = api::PaymentMethodData::from_values(
+        vault_token.value1.expose(),
+        vault_token.value2.expose(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error parsing Payment Method from Values")?;
+
+    Ok((Some(payment_method), supp_data))
+}
+
+async fn store_payment_method_data_in_built_in_vault(
+    state: &routes::AppState,
+    merchant_id: &str,
+    token_id: Option<String>,
+    value1: String,
+    value2: String,
+) -> RouterResult<String> {
+    card_vault::store_token(
+        state,
+        merchant_id,
+        token_id,
+        value1,
+        value2,
+        state.conf.vault.token_ttl,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store payment method in vault")
+    .map(|vault_token| vault_token.token)
+}
+
+async fn delete_payment_method_data_from_built_in_vault(
+    state: &routes::AppState,
+    merchant_id: &str,
+    lookup_key: &Option<String>,
+) {
+    if let Some(lookup_key) = lookup_key {
+        match card_vault::delete_token(state, merchant_id, lookup_key).await {
+            Ok(_) => logger::info!("Payment method deleted from vault"),
+            Err(err) => logger::error!("Err: Deleting payment method from vault: {:?}", err),
+        }
+    }
+}
+
 //------------------------------------------------TokenizeService------------------------------------------------
 #[cfg(feature = "basilisk")]
 pub async fn create_tokenize(
@@ -23,11 +23,12 @@ use self::{
 use crate::{
     core::{
         errors::{self, RouterResponse, RouterResult},
+        fraud_check,
         payment_methods::vault,
     },
     db::StorageInterface,
     logger, pii,
-    routes::AppState,
+    routes::{metrics, AppState},
     scheduler::utils as pt_utils,
     services,
     types::{
@@ -122,6 +123,22 @@ where
         false => None,
     };
 
+    if connector.is_some() && operation.to_domain()?.is_confirming_payment() {
+        helpers::validate_payment_method_filters(&*state.store, &merchant_account, &payment_data)
+            .await?;
+
+        if state.conf.fraud_check.enabled {
+            fraud_check::run_fraud_check(
+                state,
+                &merchant_account,
+                &mut payment_data,
+                customer.as_ref(),
+                validate_result.storage_scheme,
+            )
+            .await?;
+        }
+    }
+
     let (operation, mut payment_data) = operation
         .to_update_tracker()?
         .update_trackers(
@@ -174,7 +191,7 @@ where
                     .attach_printable("No connector selected for routing")?;
 
                 let connector_data = api::ConnectorData::get_connector_by_name(
-                    &state.conf.connectors,
+                    &state.dynamic_config.get().connectors,
                     &connector,
                     api::GetToken::Connector,
                 )
@@ -193,11 +210,55 @@ where
                 .await?
             }
         };
-        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await
+
+        if operation.to_domain()?.is_confirming_payment() {
+            record_payment_attempt(&payment_data.payment_attempt);
+        }
+
+        vault::Vault::delete_locker_payment_method_by_lookup_key(
+            state,
+            &merchant_account.merchant_id,
+            &payment_data.token,
+        )
+        .await
     }
     Ok((payment_data, req, customer))
 }
 
+fn record_payment_attempt(payment_attempt: &storage::PaymentAttempt) {
+    metrics::PAYMENT_ATTEMPT_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &payment_attempt_metric_attributes(payment_attempt),
+    );
+}
+
+fn payment_attempt_metric_attributes(
+    payment_attempt: &storage::PaymentAttempt,
+) -> [metrics::KeyValue; 4] {
+    [
+        metrics::KeyValue::new(
+            "connector",
+            payment_attempt.connector.clone().unwrap_or_default(),
+        ),
+        metrics::KeyValue::new(
+            "payment_method",
+            payment_attempt
+                .payment_method
+                .map(|payment_method| payment_method.to_string())
+                .unwrap_or_default(),
+        ),
+        metrics::KeyValue::new(
+            "currency",
+            payment_attempt
+                .currency
+                .map(|currency| currency.to_string())
+                .unwrap_or_default(),
+        ),
+        metrics::KeyValue::new("status", payment_attempt.status.to_string()),
+    ]
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn payments_core<F, Res, Req, Op, FData>(
     state: &AppState,
@@ -266,7 +327,7 @@ where
         })?;
 
     let connector_data = api::ConnectorData::get_connector_by_name(
-        &state.conf.connectors,
+        &state.dynamic_config.get().connectors,
         &connector,
         api::GetToken::Connector,
     )?;
@@ -330,7 +391,7 @@ pub async fn call_connector_service<F, Op, Req>(
     merchant_account: &storage::MerchantAccount,
     payment_id: &api::PaymentIdType,
     connector: api::ConnectorData,
-    _operation: &Op,
+    operation: &Op,
     payment_data: PaymentData<F>,
     customer: &Option<storage::Customer>,
     call_connector_action: CallConnectorAction,
@@ -370,7 +431,8 @@ where
     let router_data_res = if !(add_access_token_result.connector_supports_access_token
         && router_data.access_token.is_none())
     {
-        router_data
+        let connector_call_time = Instant::now();
+        let router_data_res = router_data
             .decide_flows(
                 state,
                 &connector,
@@ -378,7 +440,23 @@ where
                 call_connector_action,
                 merchant_account,
             )
-            .await
+            .await;
+
+        let outcome = match &router_data_res {
+            Ok(router_data) if router_data.response.is_ok() => "success",
+            _ => "failure",
+        };
+        metrics::CONNECTOR_REQUEST_TIME.record(
+            &metrics::CONTEXT,
+            connector_call_time.elapsed().as_secs_f64() * 1000f64,
+            &[
+                metrics::KeyValue::new("connector", connector.connector_name.to_string()),
+                metrics::KeyValue::new("operation", format!("{operation:?}")),
+                metrics::KeyValue::new("outcome", outcome),
+            ],
+        );
+
+        router_data_res
     } else {
         Ok(router_data)
     };
@@ -514,6 +592,7 @@ where
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
+    pub payment_link: Option<storage::PaymentLink>,
 }
 
 #[derive(Debug, Default)]
@@ -603,23 +682,46 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
     }
 }
 
+/// Lists the payments of the merchant accounts, which are all the merchant accounts of an
+/// organization when listing with the API key of the organization.
 #[cfg(feature = "olap")]
 pub async fn list_payments(
     db: &dyn StorageInterface,
-    merchant: storage::MerchantAccount,
+    merchant_accounts: Vec<storage::MerchantAccount>,
     constraints: api::PaymentListConstraints,
 ) -> RouterResponse<api::PaymentListResponse> {
     helpers::validate_payment_list_request(&constraints)?;
-    let merchant_id = &merchant.merchant_id;
-    let payment_intent =
-        helpers::filter_by_constraints(db, &constraints, merchant_id, merchant.storage_scheme)
+    // The payment IDs used as cursors only identify a payment within a merchant account
+    if merchant_accounts.len() > 1
+        && (constraints.starting_after.is_some() || constraints.ending_before.is_some())
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "starting_after and ending_before are not supported when listing the payments of multiple merchant accounts".to_string(),
+        }
+        .into());
+    }
+
+    let mut payment_intent = Vec::new();
+    for merchant in merchant_accounts {
+        payment_intent.extend(
+            helpers::filter_by_constraints(
+                db,
+                &constraints,
+                &merchant.merchant_id,
+                merchant.storage_scheme,
+            )
             .await
             .map_err(|err| {
                 errors::StorageErrorExt::to_not_found_response(
                     err,
                     errors::ApiErrorResponse::PaymentNotFound,
                 )
-            })?;
+            })?,
+        );
+    }
+    // Merge the payments of the merchant accounts in the order they are listed in
+    payment_intent.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+    payment_intent.truncate(usize::try_from(constraints.limit).unwrap_or(payment_intent.len()));
 
     let data: Vec<api::PaymentsResponse> = payment_intent
         .into_iter()
@@ -696,18 +798,201 @@ where
             };
 
             let connector_data = api::ConnectorData::get_connector_by_name(
-                &state.conf.connectors,
+                &state.dynamic_config.get().connectors,
                 &connector_name,
                 api::GetToken::Connector,
             )
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Routing algorithm gave invalid connector")?;
 
+            if !services::circuit_breaker::is_connector_available(
+                state,
+                &merchant_account.merchant_id,
+                &connector_name,
+            )
+            .await
+            {
+                return Err(errors::ApiErrorResponse::ConnectorUnavailable {
+                    connector: connector_name,
+                })
+                .into_report()
+                .attach_printable("Circuit is open for the routed connector");
+            }
+
             payment_data.payment_attempt.connector = Some(connector_name);
 
             Ok(api::ConnectorCallType::Single(connector_data))
         }
 
-        call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
+        api::ConnectorCallType::Multiple(connectors) => {
+            let mut available_connectors = Vec::with_capacity(connectors.len());
+            for connector in connectors {
+                let connector_name = connector.connector_name.to_string();
+                if services::circuit_breaker::is_connector_available(
+                    state,
+                    &merchant_account.merchant_id,
+                    &connector_name,
+                )
+                .await
+                {
+                    available_connectors.push(connector);
+                } else {
+                    logger::warn!(%connector_name, "Skipping connector as its circuit is open");
+                }
+            }
+
+            Ok(api::ConnectorCallType::Multiple(available_connectors))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::test_utils;
+
+    fn is_confirming_payment(
+        operation: BoxedOperation<'_, api::Authorize, api::PaymentsRequest>,
+    ) -> bool {
+        operation.to_domain().unwrap().is_confirming_payment()
+    }
+
+    #[test]
+    fn test_payment_attempts_are_recorded_for_confirmed_payments() {
+        assert!(is_confirming_payment(Box::new(PaymentConfirm)));
+        assert!(is_confirming_payment(is_confirm(
+            &PaymentCreate,
+            Some(true)
+        )));
+        assert!(!is_confirming_payment(is_confirm(
+            &PaymentCreate,
+            Some(false)
+        )));
+        assert!(!is_confirming_payment(Box::new(PaymentUpdate)));
+    }
+
+    #[actix_rt::test]
+    async fn test_payment_attempt_metric_attributes_of_a_confirmed_payment() {
+        let (state, _) = test_utils::get_state().await;
+        let db = &*state.store;
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    payment_id: "pay_1".to_string(),
+                    merchant_id: "merchant".to_string(),
+                    attempt_id: "pay_1_1".to_string(),
+                    status: storage_enums::AttemptStatus::Started,
+                    connector: Some("stripe".to_string()),
+                    payment_method: Some(storage_enums::PaymentMethod::Card),
+                    currency: Some(storage_enums::Currency::USD),
+                    ..storage::PaymentAttemptNew::default()
+                },
+                storage_enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        let attributes = payment_attempt_metric_attributes(&payment_attempt);
+        assert_eq!(
+            attributes,
+            [
+                metrics::KeyValue::new("connector", "stripe"),
+                metrics::KeyValue::new("payment_method", "card"),
+                metrics::KeyValue::new("currency", "USD"),
+                metrics::KeyValue::new("status", "started"),
+            ]
+        );
+
+        let confirmed_payment_attempt = storage::PaymentAttempt {
+            status: storage_enums::AttemptStatus::Charged,
+            ..payment_attempt
+        };
+        let confirmed_attributes = payment_attempt_metric_attributes(&confirmed_payment_attempt);
+        assert_eq!(
+            confirmed_attributes[3],
+            metrics::KeyValue::new("status", "charged")
+        );
+        assert_ne!(attributes, confirmed_attributes);
+    }
+
+    #[cfg(feature = "olap")]
+    fn list_constraints(limit: i64, starting_after: Option<&str>) -> api::PaymentListConstraints {
+        api::PaymentListConstraints {
+            customer_id: None,
+            starting_after: starting_after.map(str::to_string),
+            ending_before: None,
+            limit,
+            created: None,
+            created_lt: None,
+            created_gt: None,
+            created_lte: None,
+            created_gte: None,
+        }
+    }
+
+    #[cfg(feature = "olap")]
+    #[actix_rt::test]
+    async fn test_list_payments_of_multiple_merchant_accounts() {
+        let (state, _) = test_utils::get_state().await;
+        let mut merchant_accounts = Vec::new();
+        for merchant_id in ["merchant_1", "merchant_2"] {
+            merchant_accounts.push(test_utils::insert_merchant(&state, merchant_id).await);
+        }
+        let current_time = common_utils::date_time::now();
+        for (merchant_id, payment_id, minutes_ago) in [
+            ("merchant_1", "pay_1", 3),
+            ("merchant_2", "pay_2", 2),
+            ("merchant_1", "pay_3", 1),
+        ] {
+            state
+                .store
+                .insert_payment_intent(
+                    storage::PaymentIntentNew {
+                        payment_id: payment_id.to_string(),
+                        merchant_id: merchant_id.to_string(),
+                        modified_at: Some(
+                            current_time.saturating_sub(time::Duration::minutes(minutes_ago)),
+                        ),
+                        ..storage::PaymentIntentNew::default()
+                    },
+                    storage::enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+        }
+
+        let response = test_utils::get_json_response(
+            list_payments(
+                &*state.store,
+                merchant_accounts.clone(),
+                list_constraints(2, None),
+            )
+            .await
+            .unwrap(),
+        );
+        let payment_ids: Vec<_> = response
+            .data
+            .into_iter()
+            .map(|payment| payment.payment_id)
+            .collect();
+        assert_eq!(
+            payment_ids,
+            [Some("pay_3".to_string()), Some("pay_2".to_string())]
+        );
+
+        // Cursors only identify a payment within a merchant account
+        let error = list_payments(
+            &*state.store,
+            merchant_accounts,
+            list_constraints(2, Some("pay_3")),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
     }
 }
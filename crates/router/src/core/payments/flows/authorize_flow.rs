@@ -5,8 +5,10 @@ use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
         mandate,
-        payments::{self, access_token, transformers, PaymentData},
+        payment_methods::network_tokenization,
+        payments::{self, access_token, helpers, transformers, PaymentData},
     },
+    logger,
     routes::AppState,
     scheduler::metrics,
     services,
@@ -33,13 +35,25 @@ impl
             types::PaymentsResponseData,
         >,
     > {
-        transformers::construct_payment_router_data::<api::Authorize, types::PaymentsAuthorizeData>(
+        let mut router_data = transformers::construct_payment_router_data::<
+            api::Authorize,
+            types::PaymentsAuthorizeData,
+        >(state, self.clone(), connector_id, merchant_account)
+        .await?;
+
+        if let Some(network_token) = network_tokenization::get_network_token_data(
             state,
-            self.clone(),
             connector_id,
-            merchant_account,
+            router_data.payment_method_id.as_deref(),
+            router_data.request.amount,
+            router_data.request.currency,
         )
         .await
+        {
+            network_tokenization::use_network_token(&mut router_data.request, network_token);
+        }
+
+        Ok(router_data)
     }
 }
 
@@ -97,10 +111,39 @@ impl types::PaymentsAuthorizeRouterData {
                     types::PaymentsAuthorizeData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
+
+                // Reuse the customer created on the connector for a previous payment of the
+                // customer, so that the pretasks only create one when none is stored
+                let connector_name = connector.connector_name.to_string();
+                let stored_connector_customer = maybe_customer.as_ref().and_then(|customer| {
+                    helpers::get_connector_customer_id(customer, &connector_name)
+                });
+                if self.request.connector_customer.is_none() {
+                    self.request.connector_customer = stored_connector_customer.clone();
+                }
+
                 connector_integration
                     .execute_pretasks(self, state)
                     .await
                     .map_err(|error| error.to_payment_failed_response())?;
+
+                if let (Some(customer), Some(connector_customer_id)) =
+                    (maybe_customer, &self.request.connector_customer)
+                {
+                    if stored_connector_customer.as_ref() != Some(connector_customer_id) {
+                        if let Err(error) = helpers::update_connector_customer_id(
+                            &*state.store,
+                            customer,
+                            &connector_name,
+                            connector_customer_id,
+                        )
+                        .await
+                        {
+                            logger::error!(connector_customer_update_error=?error);
+                        }
+                    }
+                }
+
                 let resp = services::execute_connector_processing_step(
                     state,
                     connector_integration,
@@ -1,16 +1,19 @@
 use api_models::payments as payment_types;
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
+use masking::ExposeInterface;
 
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
+    connector,
     core::{
-        errors::{self, ConnectorErrorExt, RouterResult},
+        errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
         payments::{self, access_token, transformers, PaymentData},
     },
-    routes, services,
+    headers, routes,
+    services::{self, envelope_encryption::Decryptable},
     types::{self, api, storage},
-    utils::OptionExt,
+    utils::{self, BytesExt, OptionExt, ValueExt},
 };
 
 #[async_trait]
@@ -105,6 +108,156 @@ fn create_gpay_session_token(
     Ok(response_router_data)
 }
 
+/// Validate the merchant with Apple Pay using the merchant identity certificate configured in the
+/// connector account details of the merchant connector account, and create the session token for
+/// the SDK.
+async fn create_applepay_session_token(
+    state: &routes::AppState,
+    router_data: &types::PaymentsSessionRouterData,
+) -> RouterResult<types::PaymentsSessionRouterData> {
+    let applepay_metadata = get_applepay_metadata(router_data.connector_meta_data.clone())?;
+    let session_token_data = applepay_metadata.session_token_data;
+    let applepay_certificate = get_applepay_certificate(state, router_data).await?;
+
+    let request_body =
+        utils::Encode::<payment_types::ApplepaySessionRequest>::encode_to_string_of_json(
+            &get_applepay_session_request(&session_token_data),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the apple pay session request")?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&format!(
+            "{}paymentservices/paymentSession",
+            state.dynamic_config.get().connectors.applepay.base_url
+        ))
+        .headers(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            "application/json".to_string(),
+        )])
+        .body(Some(request_body))
+        .add_certificate(Some(applepay_certificate.certificate.expose()))
+        .add_certificate_key(Some(applepay_certificate.certificate_keys.expose()))
+        .build();
+
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call apple pay for merchant validation")?
+        .map_err(|error_response| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "Apple pay merchant validation failed with status {}",
+                error_response.status_code
+            ))
+        })?;
+
+    let session_response: payment_types::ApplePaySessionResponse = response
+        .response
+        .parse_struct("ApplePaySessionResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the apple pay session response")?;
+
+    let payment_request =
+        get_applepay_payment_request(&router_data.request, applepay_metadata.payment_request_data)?;
+
+    let response_router_data = types::PaymentsSessionRouterData {
+        response: Ok(types::PaymentsResponseData::SessionResponse {
+            session_token: payment_types::SessionToken::ApplePay(Box::new(
+                payment_types::ApplepaySessionTokenResponse {
+                    session_token_data: session_response,
+                    payment_request_data: payment_request,
+                },
+            )),
+        }),
+        ..router_data.clone()
+    };
+
+    Ok(response_router_data)
+}
+
+fn get_applepay_metadata(
+    connector_metadata: Option<serde_json::Value>,
+) -> RouterResult<payment_types::ApplePayMetadata> {
+    connector_metadata
+        .clone()
+        .parse_value::<payment_types::ApplepaySessionTokenData>("ApplepaySessionTokenData")
+        .change_context(errors::ConnectorError::NoConnectorMetaData)
+        .attach_printable(format!(
+            "cannot parse applepay metadata from the given value {connector_metadata:?}"
+        ))
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_metadata".to_string(),
+            expected_format: "applepay_metadata_format".to_string(),
+        })
+        .map(|applepay_metadata| applepay_metadata.data)
+}
+
+async fn get_applepay_certificate(
+    state: &routes::AppState,
+    router_data: &types::PaymentsSessionRouterData,
+) -> RouterResult<payment_types::ApplePayCertificate> {
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &router_data.merchant_id,
+            &router_data.connector,
+        )
+        .await
+        .and_then(Decryptable::decrypt)
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+
+    parse_applepay_certificate(merchant_connector_account.connector_account_details)
+}
+
+fn parse_applepay_certificate(
+    connector_account_details: serde_json::Value,
+) -> RouterResult<payment_types::ApplePayCertificate> {
+    connector_account_details
+        .parse_value::<payment_types::ApplePayCertificateDetails>("ApplePayCertificateDetails")
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_account_details".to_string(),
+            expected_format: "applepay_certificate_format".to_string(),
+        })
+        .map(|applepay_certificate| applepay_certificate.data)
+}
+
+fn get_applepay_session_request(
+    session_token_data: &payment_types::SessionTokenInfo,
+) -> payment_types::ApplepaySessionRequest {
+    payment_types::ApplepaySessionRequest {
+        merchant_identifier: session_token_data.merchant_identifier.clone(),
+        display_name: session_token_data.display_name.clone(),
+        initiative: session_token_data.initiative.clone(),
+        initiative_context: session_token_data.initiative_context.clone(),
+    }
+}
+
+/// The payment request with which the SDK starts the Apple Pay session, for the amount of the
+/// payment in the base unit of the currency.
+fn get_applepay_payment_request(
+    session_data: &types::PaymentsSessionData,
+    payment_request_data: payment_types::PaymentRequestMetadata,
+) -> RouterResult<payment_types::ApplePayPaymentRequest> {
+    let amount =
+        connector::utils::to_currency_base_unit(session_data.amount, session_data.currency)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(payment_types::ApplePayPaymentRequest {
+        country_code: session_data.country.clone().get_required_value("country")?,
+        currency_code: session_data.currency.to_string(),
+        total: payment_types::AmountInfo {
+            label: payment_request_data.label,
+            total_type: "final".to_string(),
+            amount,
+        },
+        merchant_capabilities: payment_request_data.merchant_capabilities,
+        supported_networks: payment_request_data.supported_networks,
+    })
+}
+
 impl types::PaymentsSessionRouterData {
     pub async fn decide_flow<'a, 'b>(
         &'b self,
@@ -115,7 +268,8 @@ impl types::PaymentsSessionRouterData {
         call_connector_action: payments::CallConnectorAction,
     ) -> RouterResult<Self> {
         match connector.get_token {
-            api::GetToken::Metadata => create_gpay_session_token(self),
+            api::GetToken::GpayMetadata => create_gpay_session_token(self),
+            api::GetToken::ApplePayMetadata => create_applepay_session_token(state, self).await,
             api::GetToken::Connector => {
                 let connector_integration: services::BoxedConnectorIntegration<
                     '_,
@@ -123,10 +277,17 @@ impl types::PaymentsSessionRouterData {
                     types::PaymentsSessionData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
+                // Connectors may need to create other objects, like a client token, before the
+                // session token can be created
+                let mut router_data = self.clone();
+                connector_integration
+                    .execute_pretasks(&mut router_data, state)
+                    .await
+                    .map_err(|error| error.to_payment_failed_response())?;
                 let resp = services::execute_connector_processing_step(
                     state,
                     connector_integration,
-                    self,
+                    &router_data,
                     call_connector_action,
                 )
                 .await
@@ -137,3 +298,131 @@ impl types::PaymentsSessionRouterData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::storage::enums;
+
+    fn applepay_metadata() -> serde_json::Value {
+        serde_json::json!({
+            "apple_pay": {
+                "payment_request_data": {
+                    "supported_networks": ["visa", "masterCard"],
+                    "merchant_capabilities": ["supports3DS"],
+                    "label": "Acme"
+                },
+                "session_token_data": {
+                    "merchant_identifier": "merchant.com.acme",
+                    "display_name": "Acme",
+                    "initiative": "web",
+                    "initiative_context": "acme.com"
+                }
+            }
+        })
+    }
+
+    fn session_data(country: Option<&str>) -> types::PaymentsSessionData {
+        types::PaymentsSessionData {
+            amount: 6540,
+            currency: enums::Currency::USD,
+            country: country.map(str::to_string),
+            order_details: None,
+            client_token: None,
+        }
+    }
+
+    #[test]
+    fn test_applepay_session_request_is_created_from_metadata() {
+        let applepay_metadata = get_applepay_metadata(Some(applepay_metadata())).unwrap();
+        let session_request = get_applepay_session_request(&applepay_metadata.session_token_data);
+
+        assert_eq!(
+            serde_json::to_value(session_request).unwrap(),
+            serde_json::json!({
+                "merchantIdentifier": "merchant.com.acme",
+                "displayName": "Acme",
+                "initiative": "web",
+                "initiativeContext": "acme.com"
+            })
+        );
+    }
+
+    #[test]
+    fn test_applepay_payment_request_has_amount_in_base_unit() {
+        let applepay_metadata = get_applepay_metadata(Some(applepay_metadata())).unwrap();
+        let payment_request = get_applepay_payment_request(
+            &session_data(Some("US")),
+            applepay_metadata.payment_request_data,
+        )
+        .unwrap();
+
+        assert_eq!(payment_request.country_code, "US");
+        assert_eq!(payment_request.currency_code, "USD");
+        assert_eq!(payment_request.total.label, "Acme");
+        assert_eq!(payment_request.total.total_type, "final");
+        assert_eq!(payment_request.total.amount, "65.4");
+        assert_eq!(payment_request.supported_networks, ["visa", "masterCard"]);
+    }
+
+    #[test]
+    fn test_applepay_payment_request_requires_country() {
+        let applepay_metadata = get_applepay_metadata(Some(applepay_metadata())).unwrap();
+        let error = get_applepay_payment_request(
+            &session_data(None),
+            applepay_metadata.payment_request_data,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "country"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_applepay_certificate_is_parsed_from_connector_account_details() {
+        let applepay_certificate = parse_applepay_certificate(serde_json::json!({
+            "auth_type": "HeaderKey",
+            "api_key": "api_key",
+            "apple_pay": {
+                "certificate": "Y2VydGlmaWNhdGU=",
+                "certificate_keys": "a2V5"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            applepay_certificate.certificate.expose(),
+            "Y2VydGlmaWNhdGU="
+        );
+        assert_eq!(applepay_certificate.certificate_keys.expose(), "a2V5");
+
+        let connector_account_details = serde_json::json!({
+            "auth_type": "HeaderKey",
+            "api_key": "api_key"
+        });
+        assert!(matches!(
+            parse_applepay_certificate(connector_account_details)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { .. }
+        ));
+    }
+
+    #[test]
+    fn test_applepay_session_requires_applepay_metadata() {
+        let gpay_metadata = serde_json::json!({ "google_pay": {} });
+
+        assert!(matches!(
+            get_applepay_metadata(Some(gpay_metadata))
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { .. }
+        ));
+        assert!(get_applepay_metadata(None).is_err());
+    }
+}
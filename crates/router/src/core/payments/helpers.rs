@@ -1,27 +1,29 @@
 use std::borrow::Cow;
 
-use common_utils::{ext_traits::AsyncExt, fp_utils};
+use common_utils::{ext_traits::AsyncExt, fp_utils, validation};
 // TODO : Evaluate all the helper functions ()
 use error_stack::{report, IntoReport, ResultExt};
-use masking::ExposeOptionInterface;
+use masking::{ExposeOptionInterface, PeekInterface};
 use router_env::{instrument, tracing};
 use uuid::Uuid;
 
 use super::{
     operations::{BoxedOperation, Operation, PaymentResponse},
-    CustomerDetails, PaymentData,
+    CustomerDetails, PaymentAddress, PaymentData,
 };
 use crate::{
     configs::settings::Server,
     consts,
     core::{
-        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        admin,
+        errors::{self, CustomResult, RouterResult, StorageErrorExt, ValidationErrorExt},
         payment_methods::{cards, vault},
     },
     db::StorageInterface,
+    logger,
     routes::AppState,
     scheduler::{metrics, workflows::payment_sync},
-    services,
+    services::{self, envelope_encryption::Decryptable},
     types::{
         api::{self, enums as api_enums, CustomerAcceptanceExt, MandateValidationFieldsExt},
         storage::{self, enums as storage_enums, ephemeral_key},
@@ -47,6 +49,7 @@ pub async fn get_address_for_payment_request(
                 Some(id) => Some(
                     db.update_address(id.to_owned(), address.foreign_into())
                         .await
+                        .and_then(Decryptable::decrypt)
                         .map_err(|err| {
                             err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
                         })?,
@@ -72,15 +75,18 @@ pub async fn get_address_for_payment_request(
                             ..address_details.foreign_into()
                         })
                         .await
+                        .and_then(Decryptable::decrypt)
                         .map_err(|_| errors::ApiErrorResponse::InternalServerError)?,
                     )
                 }
             }
         }
         None => match address_id {
-            Some(id) => Some(db.find_address(id).await).transpose().map_err(|err| {
-                err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
-            })?,
+            Some(id) => Some(db.find_address(id).await.and_then(Decryptable::decrypt))
+                .transpose()
+                .map_err(|err| {
+                    err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
+                })?,
             None => None,
         },
     })
@@ -92,7 +98,11 @@ pub async fn get_address_by_id(
 ) -> CustomResult<Option<storage::Address>, errors::ApiErrorResponse> {
     match address_id {
         None => Ok(None),
-        Some(address_id) => Ok(db.find_address(&address_id).await.ok()),
+        Some(address_id) => Ok(db
+            .find_address(&address_id)
+            .await
+            .and_then(Decryptable::decrypt)
+            .ok()),
     }
 }
 
@@ -436,6 +446,13 @@ pub fn payment_intent_status_fsm(
     confirm: Option<bool>,
 ) -> storage_enums::IntentStatus {
     match payment_method_data {
+        // Bank debits and transfers settle asynchronously, so they are processing once confirmed
+        Some(api::PaymentMethodData::BankDebit(_) | api::PaymentMethodData::BankTransfer(_)) => {
+            match confirm {
+                Some(true) => storage_enums::IntentStatus::Processing,
+                _ => storage_enums::IntentStatus::RequiresConfirmation,
+            }
+        }
         Some(_) => match confirm {
             Some(true) => storage_enums::IntentStatus::RequiresCustomerAction,
             _ => storage_enums::IntentStatus::RequiresConfirmation,
@@ -462,6 +479,7 @@ where
             &*state.store,
             &connector_name,
             &payment_attempt.merchant_id,
+            payment_attempt.payment_method,
             0,
         )
         .await
@@ -595,7 +613,9 @@ pub async fn get_customer_from_details<F: Clone>(
         Some(c_id) => {
             let customer = db
                 .find_customer_optional_by_customer_id_merchant_id(&c_id, merchant_id)
-                .await?;
+                .await?
+                .map(Decryptable::decrypt)
+                .transpose()?;
             payment_data.email = payment_data
                 .email
                 .clone()
@@ -609,7 +629,8 @@ pub async fn get_connector_default(
     state: &AppState,
     request_connector: Option<&String>,
 ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
-    let connectors = &state.conf.connectors;
+    let dynamic_settings = state.dynamic_config.get();
+    let connectors = &dynamic_settings.connectors;
     if let Some(connector_name) = request_connector {
         let connector_data = api::ConnectorData::get_connector_by_name(
             connectors,
@@ -667,7 +688,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
         operation,
         match optional_customer {
             Some(customer) => {
-                let customer = customer?;
+                let customer = customer?.decrypt()?;
 
                 payment_data.payment_intent.customer_id = Some(customer.customer_id.clone());
                 payment_data.email = payment_data
@@ -682,6 +703,48 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     ))
 }
 
+/// The id of the customer on the connector, stored when the customer was created on the
+/// connector for a previous payment.
+pub fn get_connector_customer_id(
+    customer: &storage::Customer,
+    connector_name: &str,
+) -> Option<String> {
+    customer
+        .connector_customer
+        .as_ref()
+        .and_then(|connector_customer| connector_customer.get(connector_name))
+        .and_then(serde_json::Value::as_str)
+        .map(ToString::to_string)
+}
+
+pub async fn update_connector_customer_id(
+    db: &dyn StorageInterface,
+    customer: &storage::Customer,
+    connector_name: &str,
+    connector_customer_id: &str,
+) -> RouterResult<storage::Customer> {
+    let mut connector_customer = match customer.connector_customer.clone() {
+        Some(serde_json::Value::Object(connector_customer)) => connector_customer,
+        _ => serde_json::Map::new(),
+    };
+    connector_customer.insert(
+        connector_name.to_string(),
+        serde_json::Value::String(connector_customer_id.to_string()),
+    );
+
+    db.update_customer_by_customer_id_merchant_id(
+        customer.customer_id.clone(),
+        customer.merchant_id.clone(),
+        storage::CustomerUpdate::ConnectorCustomer {
+            connector_customer: Some(serde_json::Value::Object(connector_customer)),
+        },
+    )
+    .await
+    .map(Decryptable::into_encrypted)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while updating the connector customer of the customer")
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn make_pm_data<'a, F: Clone, R>(
     operation: BoxedOperation<'a, F, R>,
@@ -696,7 +759,9 @@ pub async fn make_pm_data<'a, F: Clone, R>(
     let payment_method = match (request, token) {
         (_, Some(token)) => {
             let (pm, supplementary_data) = vault::Vault::get_payment_method_data_from_locker(
-                state, &token,
+                state,
+                &payment_data.payment_intent.merchant_id,
+                &token,
             )
             .await
             .attach_printable(
@@ -716,21 +781,30 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                 Some(api::PaymentMethodData::Card(card)) => {
                     payment_data.payment_attempt.payment_method =
                         Some(storage_enums::PaymentMethod::Card);
-                    if let Some(cvc) = card_cvc {
+                    // Track the saved card used for the payment
+                    if payment_data.payment_attempt.payment_method_id.is_none() {
+                        payment_data.payment_attempt.payment_method_id =
+                            supplementary_data.payment_method_id.clone();
+                    }
+                    let card = if let Some(cvc) = card_cvc {
                         let mut updated_card = card;
                         updated_card.card_cvc = cvc;
-                        let updated_pm = api::PaymentMethodData::Card(updated_card);
+                        let updated_pm = api::PaymentMethodData::Card(updated_card.clone());
                         vault::Vault::store_payment_method_data_in_locker(
                             state,
+                            &payment_data.payment_intent.merchant_id,
                             Some(token),
                             &updated_pm,
                             payment_data.payment_intent.customer_id.to_owned(),
                         )
                         .await?;
-                        Some(updated_pm)
+                        updated_card
                     } else {
-                        pm
-                    }
+                        card
+                    };
+                    let card = enrich_card_data(&*state.store, card).await;
+                    set_card_payment_method_type(&mut payment_data.payment_attempt, &card);
+                    Some(api::PaymentMethodData::Card(card))
                 }
 
                 Some(api::PaymentMethodData::Wallet(wallet_data)) => {
@@ -743,6 +817,7 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                             let updated_pm = api::PaymentMethodData::Wallet(wallet_data);
                             vault::Vault::store_payment_method_data_in_locker(
                                 state,
+                                &payment_data.payment_intent.merchant_id,
                                 Some(token),
                                 &updated_pm,
                                 payment_data.payment_intent.customer_id.to_owned(),
@@ -753,6 +828,18 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                     }
                 }
 
+                Some(api::PaymentMethodData::BankDebit(_)) => {
+                    payment_data.payment_attempt.payment_method =
+                        Some(storage_enums::PaymentMethod::BankDebit);
+                    pm
+                }
+
+                Some(api::PaymentMethodData::BankTransfer(_)) => {
+                    payment_data.payment_attempt.payment_method =
+                        Some(storage_enums::PaymentMethod::BankTransfer);
+                    pm
+                }
+
                 Some(_) => Err(errors::ApiErrorResponse::InternalServerError)
                     .into_report()
                     .attach_printable(
@@ -762,22 +849,33 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                 None => None,
             })
         }
-        (pm_opt @ Some(pm @ api::PaymentMethodData::Card(_)), _) => {
+        (Some(pm @ api::PaymentMethodData::Card(card)), _) => {
             let token = vault::Vault::store_payment_method_data_in_locker(
                 state,
+                &payment_data.payment_intent.merchant_id,
                 None,
                 pm,
                 payment_data.payment_intent.customer_id.to_owned(),
             )
             .await?;
             payment_data.token = Some(token);
-            Ok(pm_opt.to_owned())
+            let card = enrich_card_data(&*state.store, card.to_owned()).await;
+            set_card_payment_method_type(&mut payment_data.payment_attempt, &card);
+            Ok(Some(api::PaymentMethodData::Card(card)))
         }
         (pm @ Some(api::PaymentMethodData::PayLater(_)), _) => Ok(pm.to_owned()),
         (pm @ Some(api::PaymentMethodData::BankRedirect(_)), _) => Ok(pm.to_owned()),
-        (pm_opt @ Some(pm @ api::PaymentMethodData::Wallet(_)), _) => {
+        (
+            pm_opt @ Some(
+                pm @ (api::PaymentMethodData::Wallet(_)
+                | api::PaymentMethodData::BankDebit(_)
+                | api::PaymentMethodData::BankTransfer(_)),
+            ),
+            _,
+        ) => {
             let token = vault::Vault::store_payment_method_data_in_locker(
                 state,
+                &payment_data.payment_intent.merchant_id,
                 None,
                 pm,
                 payment_data.payment_intent.customer_id.to_owned(),
@@ -792,6 +890,70 @@ pub async fn make_pm_data<'a, F: Clone, R>(
     Ok((operation, payment_method))
 }
 
+/// Fill in the network, issuer, funding type and issuing country of the card from the BIN
+/// table. The network and issuer found in the BIN table take precedence over the ones provided in
+/// the request, while the funding type and issuing country are only ever taken from the BIN table.
+/// A failure to look up the BIN table is logged and the card is returned without these details.
+#[instrument(skip_all)]
+pub async fn enrich_card_data(db: &dyn StorageInterface, card: api::Card) -> api::Card {
+    let card_number = card
+        .card_number
+        .peek()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    let card_iins = consts::CARD_IIN_LENGTHS
+        .iter()
+        .filter_map(|&length| card_number.get(..length).map(ToString::to_string))
+        .collect::<Vec<_>>();
+
+    let card = api::Card {
+        card_type: None,
+        card_issuing_country: None,
+        ..card
+    };
+    let card_info = match db.find_card_info_by_iins(card_iins).await {
+        Ok(Some(card_info)) => card_info,
+        Ok(None) => return card,
+        Err(error) => {
+            logger::error!(card_info_lookup_error=?error);
+            return card;
+        }
+    };
+
+    api::Card {
+        card_issuer: card_info.card_issuer.or(card.card_issuer),
+        card_network: card_info
+            .card_network
+            .and_then(|card_network| card_network.parse().ok())
+            .or(card.card_network),
+        card_type: card_info.card_type,
+        card_issuing_country: card_info.card_issuing_country,
+        ..card
+    }
+}
+
+/// Set the payment method type of a card payment to the funding type of the card found in the
+/// BIN table, overriding the payment method type provided in the request. The payment method type
+/// is left as is when the funding type of the card is not known.
+fn set_card_payment_method_type(payment_attempt: &mut storage::PaymentAttempt, card: &api::Card) {
+    if let Some(payment_method_type) = card
+        .card_type
+        .as_deref()
+        .and_then(get_card_payment_method_type)
+    {
+        payment_attempt.payment_method_type = Some(payment_method_type);
+    }
+}
+
+fn get_card_payment_method_type(card_type: &str) -> Option<storage_enums::PaymentMethodType> {
+    match card_type.trim().to_lowercase().as_str() {
+        "credit" => Some(storage_enums::PaymentMethodType::Credit),
+        "debit" => Some(storage_enums::PaymentMethodType::Debit),
+        _ => None,
+    }
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_capture_method(
     capture_method: storage_enums::CaptureMethod,
@@ -866,6 +1028,219 @@ pub(crate) fn validate_payment_method_fields_present(
     Ok(())
 }
 
+/// Validate the card number, expiry and CVC of the card provided in the request, so that
+/// invalid cards are rejected before reaching the connector.
+#[instrument(skip_all)]
+pub(crate) fn validate_card_data(req: &api::PaymentsRequest) -> RouterResult<()> {
+    if let Some(api::PaymentMethodData::Card(card)) = &req.payment_method_data {
+        let card_number = card.card_number.peek();
+        validation::validate_card_number(card_number).map_err(|error| {
+            error.to_invalid_field_response("payment_method_data.card.card_number")
+        })?;
+        validation::validate_card_expiry_month(card.card_exp_month.peek()).map_err(|error| {
+            error.to_invalid_field_response("payment_method_data.card.card_exp_month")
+        })?;
+        validation::validate_card_expiry(card.card_exp_month.peek(), card.card_exp_year.peek())
+            .map_err(|error| {
+                error.to_invalid_field_response("payment_method_data.card.card_exp_year")
+            })?;
+        validation::validate_card_cvc(
+            card.card_cvc.peek(),
+            validation::get_card_network(card_number),
+        )
+        .map_err(|error| error.to_invalid_field_response("payment_method_data.card.card_cvc"))?;
+    }
+
+    if let Some(card_cvc) = &req.card_cvc {
+        validation::validate_card_cvc(card_cvc.peek(), None)
+            .map_err(|error| error.to_invalid_field_response("card_cvc"))?;
+    }
+
+    Ok(())
+}
+
+/// Enforce the payment method filters of the merchant and of the connector chosen for the
+/// payment, so that payment methods hidden by `list_payment_methods` cannot be confirmed.
+#[instrument(skip_all)]
+pub async fn validate_payment_method_filters<F: Clone>(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let connector = match payment_data.payment_attempt.connector.as_ref() {
+        Some(connector) => connector,
+        None => return Ok(()),
+    };
+
+    let mca = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector,
+        )
+        .await
+        .map(Decryptable::into_encrypted)
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+    let mca_pm_filters = admin::decode_pm_filters(mca.pm_filters)?;
+    let merchant_pm_filters = admin::decode_pm_filters(merchant_account.pm_filters.clone())?;
+
+    let card = match &payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => Some(card),
+        _ => None,
+    };
+    let payment_method_types = get_pm_filter_payment_method_types(
+        &payment_data.payment_attempt,
+        payment_data.payment_method_data.as_ref(),
+    );
+
+    if payment_method_types.is_empty() {
+        let has_pm_filters = [mca_pm_filters.as_ref(), merchant_pm_filters.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|pm_filters| !pm_filters.is_empty());
+        return utils::when(has_pm_filters, || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The payment method type of this payment could not be determined to apply the payment method filters of connector `{connector}`"
+                ),
+            }))
+        });
+    }
+
+    let country = get_pm_filter_country(&payment_data.address);
+    let currency = Some(payment_data.currency.foreign_into());
+    let amount = Some(payment_data.payment_attempt.amount);
+    let card_network = card.and_then(|card| {
+        card.card_network.clone().or_else(|| {
+            validation::get_card_network(card.card_number.peek()).map(ForeignInto::foreign_into)
+        })
+    });
+
+    for payment_method_type in payment_method_types {
+        let pm_filter = match cards::get_pm_filter(
+            mca_pm_filters.as_ref(),
+            merchant_pm_filters.as_ref(),
+            &payment_method_type,
+        ) {
+            Some(pm_filter) => pm_filter,
+            None => continue,
+        };
+
+        let violation = match card {
+            Some(_) => cards::get_pm_filter_violation(
+                pm_filter,
+                country,
+                currency,
+                amount,
+                card_network.as_ref(),
+            ),
+            None => cards::get_pm_filter_payment_violation(pm_filter, country, currency, amount),
+        };
+
+        if let Some(field_name) = violation {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payment method type `{payment_method_type}` is not available for the {field_name} of this payment on connector `{connector}`"
+                ),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+/// Payment method types whose filters apply to the payment: the payment method type of the
+/// payment attempt, or the one derived from the payment method data. A card whose funding type is
+/// not known is checked against the filters of both credit and debit cards.
+fn get_pm_filter_payment_method_types(
+    payment_attempt: &storage::PaymentAttempt,
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> Vec<api_enums::PaymentMethodType> {
+    if let Some(payment_method_type) = payment_attempt.payment_method_type.clone() {
+        return vec![payment_method_type.foreign_into()];
+    }
+
+    let card_payment_method_types = vec![
+        api_enums::PaymentMethodType::Credit,
+        api_enums::PaymentMethodType::Debit,
+    ];
+
+    match payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => card
+            .card_type
+            .as_deref()
+            .and_then(get_card_payment_method_type)
+            .map(|payment_method_type| vec![payment_method_type.foreign_into()])
+            .unwrap_or(card_payment_method_types),
+        Some(api::PaymentMethodData::Wallet(wallet_data)) => vec![match wallet_data {
+            api::WalletData::GooglePay(_) => api_enums::PaymentMethodType::GooglePay,
+            api::WalletData::ApplePay(_) => api_enums::PaymentMethodType::ApplePay,
+            api::WalletData::PaypalSdk(_) | api::WalletData::PaypalRedirect(_) => {
+                api_enums::PaymentMethodType::Paypal
+            }
+        }],
+        Some(api::PaymentMethodData::PayLater(pay_later_data)) => vec![match pay_later_data {
+            api::PayLaterData::KlarnaRedirect { .. } | api::PayLaterData::KlarnaSdk { .. } => {
+                api_enums::PaymentMethodType::Klarna
+            }
+            api::PayLaterData::AffirmRedirect {} => api_enums::PaymentMethodType::Affirm,
+            api::PayLaterData::AfterpayClearpayRedirect { .. } => {
+                api_enums::PaymentMethodType::AfterpayClearpay
+            }
+        }],
+        Some(api::PaymentMethodData::BankRedirect(bank_redirect_data)) => {
+            vec![match bank_redirect_data {
+                api_models::payments::BankRedirectData::Eps { .. } => {
+                    api_enums::PaymentMethodType::Eps
+                }
+                api_models::payments::BankRedirectData::Giropay { .. } => {
+                    api_enums::PaymentMethodType::Giropay
+                }
+                api_models::payments::BankRedirectData::Ideal { .. } => {
+                    api_enums::PaymentMethodType::Ideal
+                }
+                api_models::payments::BankRedirectData::Sofort { .. } => {
+                    api_enums::PaymentMethodType::Sofort
+                }
+            }]
+        }
+        Some(api::PaymentMethodData::BankDebit(bank_debit_data)) => vec![match bank_debit_data {
+            api::BankDebitData::AchBankDebit { .. } => api_enums::PaymentMethodType::Ach,
+            api::BankDebitData::SepaBankDebit { .. } => api_enums::PaymentMethodType::Sepa,
+            api::BankDebitData::BecsBankDebit { .. } => api_enums::PaymentMethodType::Becs,
+            api::BankDebitData::BacsBankDebit { .. } => api_enums::PaymentMethodType::Bacs,
+        }],
+        Some(api::PaymentMethodData::BankTransfer(bank_transfer_data)) => {
+            vec![match bank_transfer_data {
+                api::BankTransferData::AchBankTransfer { .. } => api_enums::PaymentMethodType::Ach,
+                api::BankTransferData::SepaBankTransfer { .. } => {
+                    api_enums::PaymentMethodType::Sepa
+                }
+                api::BankTransferData::Multibanco { .. } => {
+                    api_enums::PaymentMethodType::Multibanco
+                }
+            }]
+        }
+        None => match payment_attempt.payment_method {
+            Some(storage_enums::PaymentMethod::Card) => card_payment_method_types,
+            _ => Vec::new(),
+        },
+    }
+}
+
+/// Country of the payment checked against the payment method filters: the country of the
+/// shipping address, or the country of the billing address when the shipping address has none.
+fn get_pm_filter_country(address: &PaymentAddress) -> Option<&String> {
+    let country = |address: Option<&api::Address>| {
+        address
+            .and_then(|address| address.address.as_ref())
+            .and_then(|address| address.country.as_ref())
+    };
+
+    country(address.shipping.as_ref()).or_else(|| country(address.billing.as_ref()))
+}
+
 pub fn check_force_psync_precondition(
     status: &storage_enums::AttemptStatus,
     connector_transaction_id: &Option<String>,
@@ -1244,4 +1619,141 @@ mod tests {
         let pi_cs = Some("2".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
     }
+
+    #[test]
+    fn test_card_payment_method_type_from_bin_card_type() {
+        assert_eq!(
+            get_card_payment_method_type("CREDIT"),
+            Some(storage_enums::PaymentMethodType::Credit)
+        );
+        assert_eq!(
+            get_card_payment_method_type("debit"),
+            Some(storage_enums::PaymentMethodType::Debit)
+        );
+        assert_eq!(get_card_payment_method_type("charge"), None);
+    }
+
+    fn card_request(
+        card_number: &str,
+        card_exp_month: &str,
+        card_exp_year: &str,
+        card_cvc: &str,
+    ) -> api::PaymentsRequest {
+        api::PaymentsRequest {
+            payment_method_data: Some(api::PaymentMethodData::Card(api::Card {
+                card_number: card_number.to_string().into(),
+                card_exp_month: card_exp_month.to_string().into(),
+                card_exp_year: card_exp_year.to_string().into(),
+                card_cvc: card_cvc.to_string().into(),
+                ..api::Card::default()
+            })),
+            ..api::PaymentsRequest::default()
+        }
+    }
+
+    fn card_data_error_message(req: &api::PaymentsRequest) -> Option<String> {
+        match validate_card_data(req) {
+            Err(error) => match error.current_context() {
+                errors::ApiErrorResponse::InvalidRequestData { message } => Some(message.clone()),
+                _ => None,
+            },
+            Ok(()) => None,
+        }
+    }
+
+    #[test]
+    fn test_validate_card_data() {
+        assert!(
+            validate_card_data(&card_request("4242 4242 4242 4242", "12", "2099", "123")).is_ok()
+        );
+        assert!(validate_card_data(&card_request("378282246310005", "12", "99", "1234")).is_ok());
+        assert!(validate_card_data(&api::PaymentsRequest::default()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_card_data_reports_the_reason() {
+        assert_eq!(
+            card_data_error_message(&card_request("4242424242424241", "12", "2099", "123")),
+            Some(
+                "payment_method_data.card.card_number: Card number failed the Luhn check"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            card_data_error_message(&card_request("424242", "12", "2099", "123")),
+            Some(
+                "payment_method_data.card.card_number: Card number must have 12 to 19 digits"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            card_data_error_message(&card_request("4242424242424242", "13", "2099", "123")),
+            Some("payment_method_data.card.card_exp_month: Card expiry month must be between 1 and 12".to_string())
+        );
+        assert_eq!(
+            card_data_error_message(&card_request("4242424242424242", "12", "2020", "123")),
+            Some("payment_method_data.card.card_exp_year: Card has expired".to_string())
+        );
+        assert_eq!(
+            card_data_error_message(&card_request("4242424242424242", "12", "20x9", "123")),
+            Some("payment_method_data.card.card_exp_year: Card expiry year must be a 2 or 4 digit year".to_string())
+        );
+        assert_eq!(
+            card_data_error_message(&card_request("4242424242424242", "12", "2099", "1234")),
+            Some("payment_method_data.card.card_cvc: Invalid card CVC".to_string())
+        );
+        assert_eq!(
+            card_data_error_message(&api::PaymentsRequest {
+                card_cvc: Some("12".to_string().into()),
+                ..api::PaymentsRequest::default()
+            }),
+            Some("card_cvc: Invalid card CVC".to_string())
+        );
+    }
+
+    fn address_in(country: Option<&str>) -> Option<api::Address> {
+        Some(api::Address {
+            address: Some(api_models::payments::AddressDetails {
+                country: country.map(ToString::to_string),
+                ..Default::default()
+            }),
+            phone: None,
+        })
+    }
+
+    #[test]
+    fn test_pm_filter_country_falls_back_to_billing_address() {
+        let address = PaymentAddress {
+            shipping: address_in(Some("DE")),
+            billing: address_in(Some("US")),
+        };
+        assert_eq!(
+            get_pm_filter_country(&address).map(String::as_str),
+            Some("DE")
+        );
+
+        let address = PaymentAddress {
+            shipping: address_in(None),
+            billing: address_in(Some("US")),
+        };
+        assert_eq!(
+            get_pm_filter_country(&address).map(String::as_str),
+            Some("US")
+        );
+
+        let address = PaymentAddress {
+            shipping: None,
+            billing: address_in(Some("US")),
+        };
+        assert_eq!(
+            get_pm_filter_country(&address).map(String::as_str),
+            Some("US")
+        );
+
+        let address = PaymentAddress {
+            shipping: None,
+            billing: None,
+        };
+        assert_eq!(get_pm_filter_country(&address), None);
+    }
 }
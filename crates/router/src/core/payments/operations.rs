@@ -127,6 +127,13 @@ pub trait Domain<F: Clone, R>: Send + Sync {
         request: &R,
         previously_used_connector: Option<&String>,
     ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse>;
+
+    /// Whether the payment is confirmed by this operation, in which case its payment method is
+    /// checked against the payment method filters before the payment is sent to the connector,
+    /// and the payment attempt is counted in the payment attempt metrics.
+    fn is_confirming_payment(&self) -> bool {
+        false
+    }
 }
 
 #[async_trait]
@@ -129,6 +129,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: None,
+                    payment_link: None,
                 },
                 None,
             )),
@@ -10,6 +10,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_link,
         payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -73,6 +74,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             "confirm",
         )?;
 
+        let payment_link = payment_link::find_payment_link_for_confirm(db, &payment_intent).await?;
+
         let (token, payment_method, setup_mandate) = helpers::get_token_pm_type_mandate_details(
             state,
             request,
@@ -196,6 +199,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 refunds: vec![],
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                payment_link,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -282,6 +286,10 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         )
         .await
     }
+
+    fn is_confirming_payment(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
@@ -411,6 +419,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        helpers::validate_card_data(request)?;
+
         let mandate_type = helpers::validate_mandate(request)?;
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
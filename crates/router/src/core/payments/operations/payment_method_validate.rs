@@ -151,6 +151,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 refunds: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
+                payment_link: None,
             },
             Some(payments::CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -7,9 +7,11 @@ use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
         payments::PaymentData,
     },
     db::StorageInterface,
+    logger,
     services::RedirectForm,
     types::{
         self, api,
@@ -269,6 +271,7 @@ async fn payment_response_update_tracker<F: Clone, T>(
     router_data: types::RouterData<F, T, types::PaymentsResponseData>,
     storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<PaymentData<F>> {
+    let previous_attempt_status = payment_data.payment_attempt.status;
     let (payment_attempt_update, connector_response_update) = match router_data.response.clone() {
         Err(err) => (
             Some(storage::PaymentAttemptUpdate::ErrorUpdate {
@@ -331,6 +334,7 @@ async fn payment_response_update_tracker<F: Clone, T>(
 
             types::PaymentsResponseData::SessionResponse { .. } => (None, None),
             types::PaymentsResponseData::SessionTokenResponse { .. } => (None, None),
+            types::PaymentsResponseData::ConnectorCustomerResponse { .. } => (None, None),
         },
     };
 
@@ -389,5 +393,16 @@ async fn payment_response_update_tracker<F: Clone, T>(
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
 
+    if previous_attempt_status != enums::AttemptStatus::Charged
+        && payment_data.payment_attempt.status == enums::AttemptStatus::Charged
+    {
+        if let Some(payment_method_id) = payment_data.payment_attempt.payment_method_id.as_deref() {
+            // Failing to record the last used time must not fail the payment
+            if let Err(error) = cards::update_last_used_at(db, payment_method_id).await {
+                logger::error!(last_used_at_update_error=?error);
+            }
+        }
+    }
+
     Ok(payment_data)
 }
@@ -17,6 +17,7 @@ use crate::{
     logger, pii,
     pii::Secret,
     routes::AppState,
+    services::envelope_encryption::Decryptable,
     types::{
         api::{self, enums as api_enums, PaymentIdTypeExt},
         storage::{self, enums as storage_enums},
@@ -158,6 +159,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 sessions_token: vec![],
                 connector_response,
                 card_cvc: None,
+                payment_link: None,
             },
             Some(customer_details),
         ))
@@ -276,145 +278,128 @@ where
         request: &api::PaymentsSessionRequest,
         _previously_used_connector: Option<&String>,
     ) -> RouterResult<api::ConnectorCallType> {
-        let connectors = &state.conf.connectors;
+        let dynamic_settings = state.dynamic_config.get();
+        let connectors = &dynamic_settings.connectors;
         let db = &state.store;
 
-        let supported_connectors: &Vec<String> = state.conf.connectors.supported.wallets.as_ref();
+        let supported_connectors: &Vec<String> = connectors.supported.wallets.as_ref();
 
-        let connector_accounts = db
+        // Only the payment methods enabled on the connector accounts are needed, which are not
+        // encrypted
+        let connector_accounts: Vec<_> = db
             .find_merchant_connector_account_by_merchant_id_and_disabled_list(
                 &merchant_account.merchant_id,
                 false,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Database error when querying for merchant connector accounts")?;
-
-        let normal_connector_names: HashSet<String> = connector_accounts
-            .iter()
-            .filter(|connector_account| {
-                connector_account
-                    .payment_methods_enabled
-                    .clone()
-                    .unwrap_or_default()
-                    .iter()
-                    .any(|payment_method| {
-                        let parsed_payment_method_result: Result<
-                            PaymentMethodsEnabled,
-                            error_stack::Report<errors::ParsingError>,
-                        > = payment_method.clone().parse_value("payment_method");
-
-                        match parsed_payment_method_result {
-                            Ok(parsed_payment_method) => parsed_payment_method
-                                .payment_method_types
-                                .map(|payment_method_types| {
-                                    payment_method_types.iter().any(|payment_method_type| {
-                                        matches!(
-                                        payment_method_type.payment_experience,
-                                        Some(api_models::enums::PaymentExperience::InvokeSdkClient)
-                                    )
-                                    })
-                                })
-                                .unwrap_or(false),
-                            Err(parsing_error) => {
-                                logger::debug!(session_token_parsing_error=?parsing_error);
-                                false
-                            }
-                        }
-                    })
-            })
-            .map(|filtered_connector| filtered_connector.connector_name.clone())
+            .attach_printable("Database error when querying for merchant connector accounts")?
+            .into_iter()
+            .map(Decryptable::into_encrypted)
             .collect();
 
-        // Parse the payment methods enabled to check if the merchant has enabled googlepay ( wallet ) using that connector.
-        // A single connector can support creating session token from metadata as well as by calling the connector.
-        let session_token_from_metadata_connectors = connector_accounts
-            .iter()
-            .filter(|connector_account| {
-                connector_account
-                    .payment_methods_enabled
-                    .clone()
-                    .unwrap_or_default()
-                    .iter()
-                    .any(|payment_method| {
-                        let parsed_payment_method_result: Result<
-                            PaymentMethodsEnabled,
-                            error_stack::Report<errors::ParsingError>,
-                        > = payment_method.clone().parse_value("payment_method");
-
-                        match parsed_payment_method_result {
-                            Ok(parsed_payment_method) => parsed_payment_method
-                                .payment_method_types
-                                .map(|payment_method_types| {
-                                    payment_method_types.iter().any(|payment_method_type| {
-                                        matches!(
-                                            payment_method_type.payment_method_type,
-                                            api_models::enums::PaymentMethodType::GooglePay
-                                        )
-                                    })
-                                })
-                                .unwrap_or(false),
-                            Err(parsing_error) => {
-                                logger::debug!(session_token_parsing_error=?parsing_error);
-                                false
-                            }
-                        }
-                    })
-            })
-            .map(|filtered_connector| filtered_connector.connector_name.clone())
-            .collect::<HashSet<String>>();
-
-        let given_wallets = request.wallets.clone();
-
-        let connectors_data = if !given_wallets.is_empty() {
-            // Create connectors for provided wallets
-            let mut connectors_data = Vec::with_capacity(supported_connectors.len());
-            for wallet in given_wallets {
-                let (connector_name, connector_type) = match wallet {
-                    api_enums::SupportedWallets::Gpay => ("adyen", api::GetToken::Metadata),
-                    api_enums::SupportedWallets::ApplePay => ("applepay", api::GetToken::Connector),
-                    api_enums::SupportedWallets::Paypal => ("braintree", api::GetToken::Connector),
-                    api_enums::SupportedWallets::Klarna => ("klarna", api::GetToken::Connector),
-                };
-
-                // Check if merchant has enabled the required merchant connector account
-                if session_token_from_metadata_connectors.contains(connector_name)
-                    || normal_connector_names.contains(connector_name)
-                {
-                    connectors_data.push(api::ConnectorData::get_connector_by_name(
-                        connectors,
-                        connector_name,
-                        connector_type,
-                    )?);
-                }
-            }
-            connectors_data
+        let given_wallets = if request.wallets.is_empty() {
+            vec![
+                api_enums::SupportedWallets::Gpay,
+                api_enums::SupportedWallets::ApplePay,
+                api_enums::SupportedWallets::Paypal,
+                api_enums::SupportedWallets::Klarna,
+            ]
         } else {
-            // Create connectors for all enabled wallets
-            let mut connectors_data = Vec::with_capacity(
-                normal_connector_names.len() + session_token_from_metadata_connectors.len(),
-            );
+            request.wallets.clone()
+        };
 
-            for connector_name in normal_connector_names {
-                let connector_data = api::ConnectorData::get_connector_by_name(
-                    connectors,
-                    &connector_name,
+        let mut connectors_data = Vec::with_capacity(supported_connectors.len());
+        for wallet in given_wallets {
+            // Google Pay and Apple Pay session tokens are created from the metadata of the merchant
+            // connector account, so any connector which has enabled the wallet can be used.
+            // The other wallets need the connector to create the session (e.g. a PayPal order).
+            let (payment_method_type, connector_type) = match wallet {
+                api_enums::SupportedWallets::Gpay => (
+                    api_enums::PaymentMethodType::GooglePay,
+                    api::GetToken::GpayMetadata,
+                ),
+                api_enums::SupportedWallets::ApplePay => (
+                    api_enums::PaymentMethodType::ApplePay,
+                    api::GetToken::ApplePayMetadata,
+                ),
+                api_enums::SupportedWallets::Paypal => (
+                    api_enums::PaymentMethodType::Paypal,
                     api::GetToken::Connector,
-                )?;
-                connectors_data.push(connector_data);
-            }
+                ),
+                api_enums::SupportedWallets::Klarna => (
+                    api_enums::PaymentMethodType::Klarna,
+                    api::GetToken::Connector,
+                ),
+            };
+            let requires_connector = matches!(connector_type, api::GetToken::Connector);
+
+            let connector_names = get_connectors_with_payment_method_type(
+                &connector_accounts,
+                payment_method_type,
+                requires_connector,
+            )
+            .into_iter()
+            .filter(|connector_name| {
+                !requires_connector || supported_connectors.contains(connector_name)
+            });
 
-            for connector_name in session_token_from_metadata_connectors {
-                let connector_data = api::ConnectorData::get_connector_by_name(
+            for connector_name in connector_names {
+                connectors_data.push(api::ConnectorData::get_connector_by_name(
                     connectors,
                     &connector_name,
-                    api::GetToken::Metadata,
-                )?;
-                connectors_data.push(connector_data);
+                    connector_type,
+                )?);
             }
-            connectors_data
-        };
+        }
 
         Ok(api::ConnectorCallType::Multiple(connectors_data))
     }
 }
+
+/// Names of the merchant connector accounts which have enabled the payment method type. If the
+/// session token has to be created by the connector, the payment experience must be to invoke the
+/// SDK client.
+fn get_connectors_with_payment_method_type(
+    connector_accounts: &[storage::MerchantConnectorAccount],
+    payment_method_type: api_enums::PaymentMethodType,
+    invoke_sdk_client: bool,
+) -> HashSet<String> {
+    connector_accounts
+        .iter()
+        .filter(|connector_account| {
+            connector_account
+                .payment_methods_enabled
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .any(|payment_method| {
+                    let parsed_payment_method_result: Result<
+                        PaymentMethodsEnabled,
+                        error_stack::Report<errors::ParsingError>,
+                    > = payment_method.parse_value("payment_method");
+
+                    match parsed_payment_method_result {
+                        Ok(parsed_payment_method) => parsed_payment_method
+                            .payment_method_types
+                            .unwrap_or_default()
+                            .iter()
+                            .any(|enabled_payment_method_type| {
+                                enabled_payment_method_type.payment_method_type
+                                    == payment_method_type
+                                    && (!invoke_sdk_client
+                                        || matches!(
+                                            enabled_payment_method_type.payment_experience,
+                                            Some(api_enums::PaymentExperience::InvokeSdkClient)
+                                        ))
+                            }),
+                        Err(parsing_error) => {
+                            logger::debug!(session_token_parsing_error=?parsing_error);
+                            false
+                        }
+                    }
+                })
+        })
+        .map(|filtered_connector| filtered_connector.connector_name.clone())
+        .collect()
+}
@@ -233,6 +233,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                payment_link: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -11,7 +11,7 @@ use crate::{
         payments::{self, helpers},
     },
     routes::AppState,
-    services::{self, RedirectForm},
+    services::{self, envelope_encryption::Decryptable, RedirectForm},
     types::{
         self, api,
         storage::{self, enums},
@@ -41,6 +41,7 @@ where
             connector_id,
         )
         .await
+        .and_then(Decryptable::decrypt)
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
@@ -144,6 +145,7 @@ where
             payment_data.address,
             server,
             payment_data.connector_response.authentication_data,
+            payment_data.payment_link,
             operation,
         )
     }
@@ -233,6 +235,7 @@ pub fn payments_to_payments_response<R, Op>(
     address: PaymentAddress,
     server: &Server,
     redirection_data: Option<serde_json::Value>,
+    payment_link: Option<storage::PaymentLink>,
     operation: Op,
 ) -> RouterResponse<api::PaymentsResponse>
 where
@@ -244,6 +247,7 @@ where
         .get_required_value("currency")?
         .to_string();
     let mandate_id = payment_attempt.mandate_id.clone();
+    let payment_link = payment_link.map(ForeignInto::foreign_into);
     let refunds_response = if refunds.is_empty() {
         None
     } else {
@@ -260,13 +264,33 @@ where
             } else {
                 let mut next_action_response = None;
                 if payment_intent.status == enums::IntentStatus::RequiresCustomerAction {
-                    next_action_response = Some(api::NextAction {
-                        next_action_type: api::NextActionType::RedirectToUrl,
-                        redirect_to_url: Some(helpers::create_startpay_url(
-                            server,
-                            &payment_attempt,
-                            &payment_intent,
-                        )),
+                    let bank_transfer_next_steps = payment_attempt
+                        .connector_metadata
+                        .clone()
+                        .map(|metadata| {
+                            metadata.parse_value::<types::BankTransferNextStepsData>(
+                                "BankTransferNextStepsData",
+                            )
+                        })
+                        .transpose()
+                        .ok()
+                        .flatten();
+
+                    next_action_response = Some(match bank_transfer_next_steps {
+                        Some(next_steps) => api::NextAction {
+                            next_action_type: api::NextActionType::DisplayBankTransferInformation,
+                            redirect_to_url: None,
+                            bank_transfer_instructions: Some(next_steps.bank_transfer_instructions),
+                        },
+                        None => api::NextAction {
+                            next_action_type: api::NextActionType::RedirectToUrl,
+                            redirect_to_url: Some(helpers::create_startpay_url(
+                                server,
+                                &payment_attempt,
+                                &payment_intent,
+                            )),
+                            bank_transfer_instructions: None,
+                        },
                     })
                 }
                 let mut response: api::PaymentsResponse = Default::default();
@@ -346,7 +370,13 @@ where
                                 .payment_method_type
                                 .map(ForeignInto::foreign_into),
                         )
+                        .set_fraud_check_decision(
+                            payment_attempt
+                                .fraud_check_decision
+                                .map(ForeignInto::foreign_into),
+                        )
                         .set_metadata(payment_intent.metadata)
+                        .set_payment_link(payment_link)
                         .to_owned(),
                 )
             }
@@ -388,6 +418,10 @@ where
             cancellation_reason: payment_attempt.cancellation_reason,
             payment_token: payment_attempt.payment_token,
             metadata: payment_intent.metadata,
+            payment_link,
+            fraud_check_decision: payment_attempt
+                .fraud_check_decision
+                .map(ForeignInto::foreign_into),
             ..Default::default()
         }),
     })
@@ -440,6 +474,8 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             payment_experience: payment_data.payment_attempt.payment_experience,
             order_details,
             payment_method_type: payment_data.payment_attempt.payment_method_type,
+            network_token: None,
+            connector_customer: None,
         })
     }
 }
@@ -524,6 +560,7 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSessionData {
                 .and_then(|billing_address| billing_address.address.map(|address| address.country))
                 .flatten(),
             order_details,
+            client_token: None,
         })
     }
 }
@@ -11,7 +11,7 @@ use crate::{
         utils as core_utils,
     },
     db, logger,
-    routes::AppState,
+    routes::{metrics, AppState},
     scheduler::{process_data, utils as process_tracker_utils, workflows::payment_sync},
     services,
     types::{
@@ -73,7 +73,7 @@ pub async fn refund_create_core(
         },
     )?;
 
-    validate_and_create_refund(
+    let refund_response = validate_and_create_refund(
         state,
         &merchant_account,
         &payment_attempt,
@@ -81,8 +81,22 @@ pub async fn refund_create_core(
         amount,
         req,
     )
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await?;
+
+    metrics::REFUND_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new(
+                "connector",
+                payment_attempt.connector.clone().unwrap_or_default(),
+            ),
+            metrics::KeyValue::new("currency", refund_response.currency.clone()),
+            metrics::KeyValue::new("status", refund_response.status.to_string()),
+        ],
+    );
+
+    Ok(services::ApplicationResponse::Json(refund_response))
 }
 
 #[instrument(skip_all)]
@@ -99,7 +113,7 @@ pub async fn trigger_refund_to_gateway(
         .ok_or(errors::ApiErrorResponse::InternalServerError)?;
     let connector_id = connector.to_string();
     let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
-        &state.conf.connectors,
+        &state.dynamic_config.get().connectors,
         &connector_id,
         api::GetToken::Connector,
     )
@@ -271,7 +285,7 @@ pub async fn sync_refund_with_gateway(
 ) -> RouterResult<storage::Refund> {
     let connector_id = refund.connector.to_string();
     let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
-        &state.conf.connectors,
+        &state.dynamic_config.get().connectors,
         &connector_id,
         api::GetToken::Connector,
     )
@@ -530,23 +544,68 @@ pub async fn validate_and_create_refund(
 ///   If payment-id is provided, lists all the refunds associated with that particular payment-id
 ///   If payment-id is not provided, lists the refunds associated with that particular merchant - to the limit specified,if no limits given, it is 10 by default
 
+/// Lists the refunds of the merchant accounts, which are all the merchant accounts of an
+/// organization when listing with the API key of the organization.
 #[instrument(skip_all)]
 #[cfg(feature = "olap")]
 pub async fn refund_list(
     db: &dyn db::StorageInterface,
-    merchant_account: storage::merchant_account::MerchantAccount,
+    merchant_accounts: Vec<storage::merchant_account::MerchantAccount>,
     req: api_models::refunds::RefundListRequest,
 ) -> RouterResponse<api_models::refunds::RefundListResponse> {
     let limit = validator::validate_refund_list(req.limit)?;
-    let refund_list = db
-        .filter_refund_by_constraints(
-            &merchant_account.merchant_id,
-            &req,
-            merchant_account.storage_scheme,
-            limit,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::RefundNotFound)?;
+    // One refund more than the limit is fetched to know whether there are more refunds to list
+    let (refund_list, has_more) = match merchant_accounts.as_slice() {
+        [merchant_account] => {
+            let mut refund_list = db
+                .filter_refund_by_constraints(
+                    &merchant_account.merchant_id,
+                    &req,
+                    merchant_account.storage_scheme,
+                    limit + 1,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::RefundNotFound)?;
+            let has_more = refund_list.len() > usize::try_from(limit).unwrap_or_default();
+            if has_more {
+                // The extra refund is the one furthest from the cursor
+                if req.ending_before.is_some() && req.starting_after.is_none() {
+                    refund_list.remove(0);
+                } else {
+                    refund_list.pop();
+                }
+            }
+            (refund_list, has_more)
+        }
+        merchant_accounts => {
+            // The refund IDs used as cursors only identify a refund within a merchant account
+            if req.starting_after.is_some() || req.ending_before.is_some() {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "starting_after and ending_before are not supported when listing the refunds of multiple merchant accounts".to_string(),
+                }))?;
+            }
+
+            let mut refund_list = Vec::new();
+            for merchant_account in merchant_accounts {
+                refund_list.extend(
+                    db.filter_refund_by_constraints(
+                        &merchant_account.merchant_id,
+                        &req,
+                        merchant_account.storage_scheme,
+                        limit + 1,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::RefundNotFound)?,
+                );
+            }
+            // Merge the refunds of the merchant accounts in the order they are listed in
+            refund_list.sort_by(|a, b| b.id.cmp(&a.id));
+            let limit = usize::try_from(limit).unwrap_or_default();
+            let has_more = refund_list.len() > limit;
+            refund_list.truncate(limit);
+            (refund_list, has_more)
+        }
+    };
 
     let data: Vec<refunds::RefundResponse> = refund_list
         .into_iter()
@@ -556,7 +615,7 @@ pub async fn refund_list(
         Err(errors::ApiErrorResponse::RefundNotFound)
     })?;
     Ok(services::ApplicationResponse::Json(
-        api_models::refunds::RefundListResponse { data },
+        api_models::refunds::RefundListResponse { data, has_more },
     ))
 }
 
@@ -949,3 +1008,158 @@ pub async fn retry_refund_sync_task(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::test_utils::{self, get_json_response};
+
+    async fn get_state() -> (AppState, storage::MerchantAccount) {
+        let (state, merchant_account) = test_utils::get_state().await;
+        for (merchant_id, refund_id) in [
+            ("merchant", "ref_1"),
+            ("merchant", "ref_2"),
+            ("other_merchant", "ref_3"),
+            ("merchant", "ref_4"),
+            ("merchant", "ref_5"),
+        ] {
+            state
+                .store
+                .insert_refund(
+                    storage::RefundNew {
+                        merchant_id: merchant_id.to_string(),
+                        refund_id: refund_id.to_string(),
+                        payment_id: "pay_1".to_string(),
+                        ..storage::RefundNew::default()
+                    },
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+        }
+        (state, merchant_account)
+    }
+
+    fn list_request(
+        limit: Option<i64>,
+        starting_after: Option<&str>,
+        ending_before: Option<&str>,
+    ) -> api_models::refunds::RefundListRequest {
+        api_models::refunds::RefundListRequest {
+            payment_id: None,
+            limit,
+            created: None,
+            created_lt: None,
+            created_gt: None,
+            created_lte: None,
+            created_gte: None,
+            starting_after: starting_after.map(str::to_string),
+            ending_before: ending_before.map(str::to_string),
+        }
+    }
+
+    async fn list_refund_ids(
+        (state, merchant_account): &(AppState, storage::MerchantAccount),
+        req: api_models::refunds::RefundListRequest,
+    ) -> (Vec<String>, bool) {
+        let response = refund_list(&*state.store, vec![merchant_account.clone()], req)
+            .await
+            .unwrap();
+        let response = get_json_response(response);
+        let refund_ids = response
+            .data
+            .into_iter()
+            .map(|refund| refund.refund_id)
+            .collect();
+        (refund_ids, response.has_more)
+    }
+
+    #[actix_rt::test]
+    async fn test_refund_list_limit() {
+        let state = get_state().await;
+
+        let (refund_ids, has_more) = list_refund_ids(&state, list_request(None, None, None)).await;
+        assert_eq!(refund_ids, ["ref_5", "ref_4", "ref_2", "ref_1"]);
+        assert!(!has_more);
+
+        let (refund_ids, has_more) =
+            list_refund_ids(&state, list_request(Some(2), None, None)).await;
+        assert_eq!(refund_ids, ["ref_5", "ref_4"]);
+        assert!(has_more);
+    }
+
+    #[actix_rt::test]
+    async fn test_refund_list_cursors() {
+        let state = get_state().await;
+
+        let (refund_ids, has_more) =
+            list_refund_ids(&state, list_request(Some(2), Some("ref_4"), None)).await;
+        assert_eq!(refund_ids, ["ref_2", "ref_1"]);
+        assert!(!has_more);
+
+        let (refund_ids, has_more) =
+            list_refund_ids(&state, list_request(Some(1), None, Some("ref_2"))).await;
+        assert_eq!(refund_ids, ["ref_4"]);
+        assert!(has_more);
+
+        let (refund_ids, has_more) =
+            list_refund_ids(&state, list_request(Some(2), None, Some("ref_1"))).await;
+        assert_eq!(refund_ids, ["ref_4", "ref_2"]);
+        assert!(has_more);
+    }
+
+    #[actix_rt::test]
+    async fn test_refund_list_cursor_of_another_merchant() {
+        let (state, merchant_account) = get_state().await;
+
+        let response = refund_list(
+            &*state.store,
+            vec![merchant_account],
+            list_request(None, Some("ref_3"), None),
+        )
+        .await;
+        assert!(response.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_refund_list_multiple_merchant_accounts() {
+        let (state, merchant_account) = get_state().await;
+        let other_merchant_account = state
+            .store
+            .insert_merchant(storage::MerchantAccountNew {
+                merchant_id: "other_merchant".to_string(),
+                ..storage::MerchantAccountNew::default()
+            })
+            .await
+            .unwrap();
+        let merchant_accounts = vec![merchant_account, other_merchant_account];
+        let list = |req| refund_list(&*state.store, merchant_accounts.clone(), req);
+
+        let response = match list(list_request(Some(4), None, None)).await.unwrap() {
+            services::ApplicationResponse::Json(response) => Some(response),
+            _ => None,
+        }
+        .expect("Expected a json response");
+        let refund_ids: Vec<_> = response
+            .data
+            .into_iter()
+            .map(|refund| refund.refund_id)
+            .collect();
+        assert_eq!(refund_ids, ["ref_5", "ref_4", "ref_3", "ref_2"]);
+        assert!(response.has_more);
+
+        // Cursors only identify a refund within a merchant account
+        for req in [
+            list_request(None, Some("ref_4"), None),
+            list_request(None, None, Some("ref_2")),
+        ] {
+            let error = list(req).await.unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
     consts,
     core::errors::{self, RouterResult},
     routes::AppState,
+    services::envelope_encryption::Decryptable,
     types::{
         self,
         storage::{self, enums},
@@ -33,6 +34,7 @@ pub async fn construct_refund_router_data<'a, F>(
             connector_id,
         )
         .await
+        .and_then(Decryptable::decrypt)
         .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
     let auth_type: types::ConnectorAuthType = merchant_connector_account
@@ -5,6 +5,8 @@ use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "stripe")]
+use crate::compatibility;
 use crate::{
     consts,
     core::{
@@ -13,10 +15,10 @@ use crate::{
     },
     db::StorageInterface,
     logger,
-    routes::AppState,
+    routes::{metrics, AppState},
     services,
     types::{
-        api,
+        api::{self, enums as api_enums},
         storage::{self, enums},
         transformers::{ForeignInto, ForeignTryInto},
     },
@@ -196,7 +198,7 @@ async fn create_event_and_trigger_outgoing_webhook(
         .await
         .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
-    if state.conf.webhooks.outgoing_enabled {
+    if state.dynamic_config.get().webhooks.outgoing_enabled {
         let arbiter = actix::Arbiter::try_current()
             .ok_or(errors::WebhooksFlowError::ForkFlowFailed)
             .into_report()?;
@@ -243,16 +245,34 @@ async fn trigger_webhook_to_merchant(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
+    let event_type = webhook.event_type;
+    let webhook_body =
+        get_outgoing_webhook_body(webhook, webhook_details.webhook_format.unwrap_or_default())?;
+
     let response = reqwest::Client::new()
         .post(&webhook_url)
         .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&webhook)
+        .json(&webhook_body)
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
         ))
         .send()
         .await;
 
+    let outcome = match &response {
+        Err(_) => "call_failed",
+        Ok(res) if !res.status().is_success() => "not_received",
+        Ok(_) => "delivered",
+    };
+    metrics::OUTGOING_WEBHOOK_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new("event_type", event_type.to_string()),
+            metrics::KeyValue::new("outcome", outcome),
+        ],
+    );
+
     match response {
         Err(e) => {
             // [#217]: Schedule webhook for retry.
@@ -271,6 +291,34 @@ async fn trigger_webhook_to_merchant(
     Ok(())
 }
 
+fn get_outgoing_webhook_body(
+    webhook: api::OutgoingWebhook,
+    webhook_format: api_enums::OutgoingWebhookFormat,
+) -> CustomResult<serde_json::Value, errors::WebhooksFlowError> {
+    match webhook_format {
+        api_enums::OutgoingWebhookFormat::Hyperswitch => {
+            Encode::<api::OutgoingWebhook>::encode_to_value(&webhook)
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        }
+        #[cfg(feature = "stripe")]
+        api_enums::OutgoingWebhookFormat::Stripe => {
+            let stripe_webhook =
+                compatibility::stripe::webhooks::StripeOutgoingWebhook::try_from(webhook)
+                    .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+            Encode::<compatibility::stripe::webhooks::StripeOutgoingWebhook>::encode_to_value(
+                &stripe_webhook,
+            )
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        }
+        #[cfg(not(feature = "stripe"))]
+        api_enums::OutgoingWebhookFormat::Stripe => {
+            logger::warn!("Stripe compatibility is disabled, sending the webhook as is");
+            Encode::<api::OutgoingWebhook>::encode_to_value(&webhook)
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn webhooks_core(
     state: &AppState,
@@ -280,7 +328,7 @@ pub async fn webhooks_core(
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
     let connector = api::ConnectorData::get_connector_by_name(
-        &state.conf.connectors,
+        &state.dynamic_config.get().connectors,
         connector_name,
         api::GetToken::Connector,
     )
@@ -350,8 +398,9 @@ pub async fn webhooks_core(
                 )?,
         };
 
+        let event_type_label = event_type.to_string();
         let flow_type: api::WebhookFlow = event_type.to_owned().into();
-        match flow_type {
+        let flow_result = match flow_type {
             api::WebhookFlow::Payment => payments_incoming_webhook_flow(
                 state.clone(),
                 merchant_account,
@@ -360,7 +409,7 @@ pub async fn webhooks_core(
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Incoming webhook flow for payments failed")?,
+            .attach_printable("Incoming webhook flow for payments failed"),
 
             api::WebhookFlow::Refund => refunds_incoming_webhook_flow(
                 state.clone(),
@@ -372,14 +421,24 @@ pub async fn webhooks_core(
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Incoming webhook flow for refunds failed")?,
+            .attach_printable("Incoming webhook flow for refunds failed"),
 
-            api::WebhookFlow::ReturnResponse => {}
+            api::WebhookFlow::ReturnResponse => Ok(()),
 
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .into_report()
-                .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
-        }
+                .attach_printable("Unsupported Flow Type received in incoming webhooks"),
+        };
+
+        let outcome = if flow_result.is_ok() {
+            "processed"
+        } else {
+            "failed"
+        };
+        record_incoming_webhook(connector_name, event_type_label, outcome);
+        flow_result?;
+    } else {
+        record_incoming_webhook(connector_name, event_type.to_string(), "ignored");
     }
 
     let response = connector
@@ -389,3 +448,15 @@ pub async fn webhooks_core(
 
     Ok(response)
 }
+
+fn record_incoming_webhook(connector_name: &str, event_type: String, outcome: &'static str) {
+    metrics::INCOMING_WEBHOOK_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new("connector", connector_name.to_owned()),
+            metrics::KeyValue::new("event_type", event_type),
+            metrics::KeyValue::new("outcome", outcome),
+        ],
+    );
+}
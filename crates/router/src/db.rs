@@ -1,24 +1,36 @@
 pub mod address;
+pub mod admin_principal;
 pub mod api_keys;
+pub mod audit_events;
 pub mod cache;
+pub mod cards_info;
 pub mod configs;
+pub mod connector_call_log;
+pub mod connector_health;
 pub mod connector_response;
 pub mod customers;
 pub mod ephemeral_key;
 pub mod events;
+pub mod fraud_check;
+pub mod health_check;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
 pub mod process_tracker;
 pub mod queue;
+pub mod rate_limit;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod vault;
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use futures::lock::Mutex;
 
@@ -37,24 +49,36 @@ pub trait StorageInterface:
     + Sync
     + dyn_clone::DynClone
     + address::AddressInterface
+    + admin_principal::AdminPrincipalInterface
     + api_keys::ApiKeyInterface
+    + audit_events::AuditEventInterface
+    + cards_info::CardsInfoInterface
     + configs::ConfigInterface
+    + connector_call_log::ConnectorCallLogInterface
+    + connector_health::ConnectorHealthInterface
     + connector_response::ConnectorResponseInterface
     + customers::CustomerInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + fraud_check::FraudCheckInterface
+    + health_check::HealthCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
     + merchant_connector_account::ConnectorAccessToken
     + merchant_connector_account::MerchantConnectorAccountInterface
+    + merchant_key_store::MerchantKeyStoreInterface
+    + organization::OrganizationInterface
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
+    + payment_link::PaymentLinkInterface
     + payment_method::PaymentMethodInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
+    + rate_limit::RateLimitInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + vault::VaultInterface
     + 'static
 {
     async fn close(&mut self) {}
@@ -78,9 +102,19 @@ pub struct MockDb {
     payment_attempts: Arc<Mutex<Vec<storage::PaymentAttempt>>>,
     payment_intents: Arc<Mutex<Vec<storage::PaymentIntent>>>,
     customers: Arc<Mutex<Vec<storage::Customer>>>,
+    payment_methods: Arc<Mutex<Vec<storage::PaymentMethod>>>,
+    lockers: Arc<Mutex<Vec<storage::LockerMockUp>>>,
     refunds: Arc<Mutex<Vec<storage::Refund>>>,
     processes: Arc<Mutex<Vec<storage::ProcessTracker>>>,
     connector_response: Arc<Mutex<Vec<storage::ConnectorResponse>>>,
+    payment_links: Arc<Mutex<Vec<storage::PaymentLink>>>,
+    organizations: Arc<Mutex<Vec<storage::Organization>>>,
+    api_keys: Arc<Mutex<Vec<storage::ApiKey>>>,
+    admin_principals: Arc<Mutex<Vec<storage::AdminPrincipal>>>,
+    vault_cards: Arc<Mutex<Vec<storage::VaultCard>>>,
+    vault_tokens: Arc<Mutex<Vec<storage::VaultToken>>>,
+    vault_access_events: Arc<Mutex<Vec<storage::VaultAccessEvent>>>,
+    velocity_counts: Arc<Mutex<HashMap<String, u64>>>,
     redis: Arc<redis_interface::RedisConnectionPool>,
 }
 
@@ -92,9 +126,19 @@ impl MockDb {
             payment_attempts: Default::default(),
             payment_intents: Default::default(),
             customers: Default::default(),
+            payment_methods: Default::default(),
+            lockers: Default::default(),
             refunds: Default::default(),
             processes: Default::default(),
             connector_response: Default::default(),
+            payment_links: Default::default(),
+            organizations: Default::default(),
+            api_keys: Default::default(),
+            admin_principals: Default::default(),
+            vault_cards: Default::default(),
+            vault_tokens: Default::default(),
+            vault_access_events: Default::default(),
+            velocity_counts: Default::default(),
             redis: Arc::new(crate::connection::redis_connection(redis).await),
         }
     }
@@ -1,9 +1,10 @@
-use error_stack::IntoReport;
+use error_stack::{IntoReport, ResultExt};
 
 use super::{MockDb, Store};
 use crate::{
     connection::pg_connection,
     core::errors::{self, CustomResult},
+    services::envelope_encryption::{Decryptable, EncryptRecord},
     types::storage,
 };
 
@@ -13,24 +14,24 @@ pub trait AddressInterface {
         &self,
         address_id: String,
         address: storage::AddressUpdate,
-    ) -> CustomResult<storage::Address, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError>;
 
     async fn insert_address(
         &self,
         address: storage::AddressNew,
-    ) -> CustomResult<storage::Address, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError>;
 
     async fn find_address(
         &self,
         address_id: &str,
-    ) -> CustomResult<storage::Address, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError>;
 
     async fn update_address_by_merchant_id_customer_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
         address: storage::AddressUpdate,
-    ) -> CustomResult<Vec<storage::Address>, errors::StorageError>;
+    ) -> CustomResult<Vec<Decryptable<storage::Address>>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -38,36 +39,53 @@ impl AddressInterface for Store {
     async fn find_address(
         &self,
         address_id: &str,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await?;
-        storage::Address::find_by_address_id(&conn, address_id)
+        let address = storage::Address::find_by_address_id(&conn, address_id)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report()?;
+        let key = self.get_merchant_data_key(&address.merchant_id).await?;
+        Ok(Decryptable::new(address, key))
     }
 
     async fn update_address(
         &self,
         address_id: String,
         address: storage::AddressUpdate,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await?;
+        // The address is looked up first to find the merchant whose data key encrypts it
+        let merchant_id = storage::Address::find_by_address_id(&conn, &address_id)
+            .await
+            .map_err(Into::into)
+            .into_report()?
+            .merchant_id;
+        let key = self.get_merchant_data_key(&merchant_id).await?;
+        let address = address
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         storage::Address::update_by_address_id(&conn, address_id, address)
             .await
             .map_err(Into::into)
             .into_report()
+            .map(|address| Decryptable::new(address, key))
     }
 
     async fn insert_address(
         &self,
         address: storage::AddressNew,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError> {
+        let key = self.get_merchant_data_key(&address.merchant_id).await?;
         let conn = pg_connection(&self.master_pool).await?;
         address
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?
             .insert(&conn)
             .await
             .map_err(Into::into)
             .into_report()
+            .map(|address| Decryptable::new(address, key))
     }
 
     async fn update_address_by_merchant_id_customer_id(
@@ -75,7 +93,11 @@ impl AddressInterface for Store {
         customer_id: &str,
         merchant_id: &str,
         address: storage::AddressUpdate,
-    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+    ) -> CustomResult<Vec<Decryptable<storage::Address>>, errors::StorageError> {
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        let address = address
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let conn = pg_connection(&self.master_pool).await?;
         storage::Address::update_by_merchant_id_customer_id(
             &conn,
@@ -86,6 +108,12 @@ impl AddressInterface for Store {
         .await
         .map_err(Into::into)
         .into_report()
+        .map(|addresses| {
+            addresses
+                .into_iter()
+                .map(|address| Decryptable::new(address, key.clone()))
+                .collect()
+        })
     }
 }
 
@@ -94,7 +122,7 @@ impl AddressInterface for MockDb {
     async fn find_address(
         &self,
         _address_id: &str,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -103,7 +131,7 @@ impl AddressInterface for MockDb {
         &self,
         _address_id: String,
         _address: storage::AddressUpdate,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -111,7 +139,7 @@ impl AddressInterface for MockDb {
     async fn insert_address(
         &self,
         _address: storage::AddressNew,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Address>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -121,7 +149,7 @@ impl AddressInterface for MockDb {
         _customer_id: &str,
         _merchant_id: &str,
         _address: storage::AddressUpdate,
-    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+    ) -> CustomResult<Vec<Decryptable<storage::Address>>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -0,0 +1,138 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AdminPrincipalInterface {
+    async fn insert_admin_principal(
+        &self,
+        admin_principal: storage::AdminPrincipalNew,
+    ) -> CustomResult<storage::AdminPrincipal, errors::StorageError>;
+
+    async fn find_admin_principal_by_principal_id(
+        &self,
+        principal_id: &str,
+    ) -> CustomResult<storage::AdminPrincipal, errors::StorageError>;
+
+    async fn list_admin_principals(
+        &self,
+    ) -> CustomResult<Vec<storage::AdminPrincipal>, errors::StorageError>;
+
+    async fn revoke_admin_principal(
+        &self,
+        principal_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AdminPrincipalInterface for Store {
+    async fn insert_admin_principal(
+        &self,
+        admin_principal: storage::AdminPrincipalNew,
+    ) -> CustomResult<storage::AdminPrincipal, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        admin_principal
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_admin_principal_by_principal_id(
+        &self,
+        principal_id: &str,
+    ) -> CustomResult<storage::AdminPrincipal, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::AdminPrincipal::find_by_principal_id(&conn, principal_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_admin_principals(
+        &self,
+    ) -> CustomResult<Vec<storage::AdminPrincipal>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::AdminPrincipal::list(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn revoke_admin_principal(
+        &self,
+        principal_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::AdminPrincipal::delete_by_principal_id(&conn, principal_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AdminPrincipalInterface for MockDb {
+    async fn insert_admin_principal(
+        &self,
+        admin_principal: storage::AdminPrincipalNew,
+    ) -> CustomResult<storage::AdminPrincipal, errors::StorageError> {
+        let mut admin_principals = self.admin_principals.lock().await;
+        if admin_principals
+            .iter()
+            .any(|principal| principal.name == admin_principal.name)
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?;
+        }
+
+        let admin_principal = storage::AdminPrincipal {
+            principal_id: admin_principal.principal_id,
+            name: admin_principal.name,
+            description: admin_principal.description,
+            hash_key: admin_principal.hash_key,
+            hashed_api_key: admin_principal.hashed_api_key,
+            prefix: admin_principal.prefix,
+            permissions: admin_principal.permissions,
+            created_at: admin_principal.created_at,
+        };
+        admin_principals.push(admin_principal.clone());
+        Ok(admin_principal)
+    }
+
+    async fn find_admin_principal_by_principal_id(
+        &self,
+        principal_id: &str,
+    ) -> CustomResult<storage::AdminPrincipal, errors::StorageError> {
+        let admin_principals = self.admin_principals.lock().await;
+        admin_principals
+            .iter()
+            .find(|principal| principal.principal_id == principal_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn list_admin_principals(
+        &self,
+    ) -> CustomResult<Vec<storage::AdminPrincipal>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn revoke_admin_principal(
+        &self,
+        _principal_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -98,10 +98,24 @@ impl ApiKeyInterface for Store {
 impl ApiKeyInterface for MockDb {
     async fn insert_api_key(
         &self,
-        _api_key: storage::ApiKeyNew,
+        api_key: storage::ApiKeyNew,
     ) -> CustomResult<storage::ApiKey, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut api_keys = self.api_keys.lock().await;
+        let api_key = storage::ApiKey {
+            key_id: api_key.key_id,
+            merchant_id: api_key.merchant_id,
+            name: api_key.name,
+            description: api_key.description,
+            hash_key: api_key.hash_key,
+            hashed_api_key: api_key.hashed_api_key,
+            prefix: api_key.prefix,
+            created_at: api_key.created_at,
+            expires_at: api_key.expires_at,
+            last_used: api_key.last_used,
+            permissions: api_key.permissions,
+        };
+        api_keys.push(api_key.clone());
+        Ok(api_key)
     }
 
     async fn update_api_key(
@@ -120,10 +134,13 @@ impl ApiKeyInterface for MockDb {
 
     async fn find_api_key_optional(
         &self,
-        _key_id: &str,
+        key_id: &str,
     ) -> CustomResult<Option<storage::ApiKey>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let api_keys = self.api_keys.lock().await;
+        Ok(api_keys
+            .iter()
+            .find(|api_key| api_key.key_id == key_id)
+            .cloned())
     }
 
     async fn list_api_keys_by_merchant_id(
@@ -0,0 +1,72 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums, AuditEventDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait AuditEventInterface {
+    async fn insert_audit_event(
+        &self,
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError>;
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+        resource_type: Option<enums::AuditResourceType>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditEventInterface for Store {
+    async fn insert_audit_event(
+        &self,
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        audit_event
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+        resource_type: Option<enums::AuditResourceType>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError> {
+        let conn = pg_connection(&self.replica_pool).await?;
+        storage::AuditEvent::filter_by_constraints(&conn, constraints, resource_type, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditEventInterface for MockDb {
+    async fn insert_audit_event(
+        &self,
+        _audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        _constraints: &api_models::audit_events::AuditEventListConstraints,
+        _resource_type: Option<enums::AuditResourceType>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -1,7 +1,119 @@
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
 use error_stack::ResultExt;
+use redis_interface::PubSubInterface;
 
 use super::Store;
-use crate::core::errors::{self, CustomResult};
+use crate::{
+    consts,
+    core::errors::{self, CustomResult},
+};
+
+/// In-process LRU cache, which sits in front of the cache in Redis. Entries expire after the TTL,
+/// and are removed from the caches of all the instances when invalidated via [`redact_cache`].
+pub struct InMemoryCache {
+    max_capacity: usize,
+    ttl: Duration,
+    state: Mutex<LruState>,
+}
+
+#[derive(Default)]
+struct LruState {
+    entries: HashMap<String, CacheEntry>,
+    /// Keys by their last access, the first key being the least recently used one
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+struct CacheEntry {
+    value: Arc<dyn Any + Send + Sync>,
+    inserted_at: Instant,
+    last_access: u64,
+}
+
+impl LruState {
+    fn touch(&mut self, key: &str) {
+        self.clock += 1;
+        let clock = self.clock;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.recency.remove(&entry.last_access);
+            entry.last_access = clock;
+            self.recency.insert(clock, key.to_owned());
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_access);
+        }
+    }
+}
+
+impl InMemoryCache {
+    /// A cache with a `max_capacity` of zero does not hold any entries.
+    pub fn new(max_capacity: usize, ttl: Duration) -> Self {
+        Self {
+            max_capacity,
+            ttl,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn get<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        let mut state = self.lock();
+        let entry = state.entries.get(key)?;
+
+        if entry.inserted_at.elapsed() >= self.ttl {
+            state.remove(key);
+            return None;
+        }
+
+        let value = entry.value.downcast_ref::<T>().cloned();
+        if value.is_some() {
+            state.touch(key);
+        }
+        value
+    }
+
+    pub fn insert<T: Send + Sync + 'static>(&self, key: &str, value: T) {
+        if self.max_capacity == 0 {
+            return;
+        }
+
+        let mut state = self.lock();
+        state.remove(key);
+        while state.entries.len() >= self.max_capacity {
+            let least_recently_used = state.recency.values().next().cloned();
+            match least_recently_used {
+                Some(lru_key) => state.remove(&lru_key),
+                None => break,
+            }
+        }
+
+        state.entries.insert(
+            key.to_owned(),
+            CacheEntry {
+                value: Arc::new(value),
+                inserted_at: Instant::now(),
+                last_access: 0,
+            },
+        );
+        state.touch(key);
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.lock().remove(key);
+    }
+}
 
 pub async fn get_or_populate_cache<T, F, Fut>(
     store: &Store,
@@ -9,16 +121,26 @@ pub async fn get_or_populate_cache<T, F, Fut>(
     fun: F,
 ) -> CustomResult<T, errors::StorageError>
 where
-    T: serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
+    T: serde::Serialize
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug
+        + Clone
+        + Send
+        + Sync
+        + 'static,
     F: FnOnce() -> Fut + Send,
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
 {
+    if let Some(value) = store.cache.get::<T>(key) {
+        return Ok(value);
+    }
+
     let type_name = std::any::type_name::<T>();
     let redis = &store
         .redis_conn()
         .map_err(Into::<errors::StorageError>::into)?;
     let redis_val = redis.get_and_deserialize_key::<T>(key, type_name).await;
-    match redis_val {
+    let data = match redis_val {
         Err(err) => match err.current_context() {
             errors::RedisError::NotFound => {
                 let data = fun().await?;
@@ -26,16 +148,21 @@ where
                     .serialize_and_set_key(key, &data)
                     .await
                     .change_context(errors::StorageError::KVError)?;
-                Ok(data)
+                data
             }
             _ => Err(err
                 .change_context(errors::StorageError::KVError)
-                .attach_printable(format!("Error while fetching cache for {type_name}"))),
+                .attach_printable(format!("Error while fetching cache for {type_name}")))?,
         },
-        Ok(val) => Ok(val),
-    }
+        Ok(val) => val,
+    };
+
+    store.cache.insert(key, data.clone());
+    Ok(data)
 }
 
+/// Runs the update and invalidates the cached value, in Redis as well as in the in-memory caches
+/// of all the instances.
 pub async fn redact_cache<T, F, Fut>(
     store: &Store,
     key: &str,
@@ -47,11 +174,82 @@ where
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
 {
     let data = fun().await?;
-    store
+    store.cache.remove(key);
+
+    let redis = store
         .redis_conn()
-        .map_err(Into::<errors::StorageError>::into)?
+        .map_err(Into::<errors::StorageError>::into)?;
+    redis
         .delete_key(key)
         .await
         .change_context(errors::StorageError::KVError)?;
+    redis
+        .publish(consts::PUB_SUB_CHANNEL, key)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable_lazy(|| format!("Failed to publish invalidation of cache key: {key}"))?;
     Ok(data)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::configs::settings::Settings;
+
+    #[test]
+    fn test_in_memory_cache_evicts_least_recently_used() {
+        let cache = InMemoryCache::new(2, Duration::from_secs(60));
+        cache.insert("a", 1_u8);
+        cache.insert("b", 2_u8);
+        assert_eq!(cache.get::<u8>("a"), Some(1));
+
+        // "b" is the least recently used entry now
+        cache.insert("c", 3_u8);
+        assert_eq!(cache.get::<u8>("b"), None);
+        assert_eq!(cache.get::<u8>("a"), Some(1));
+        assert_eq!(cache.get::<u8>("c"), Some(3));
+
+        cache.remove("a");
+        assert_eq!(cache.get::<u8>("a"), None);
+        assert_eq!(cache.get::<String>("c"), None);
+    }
+
+    #[test]
+    fn test_in_memory_cache_expiry() {
+        let cache = InMemoryCache::new(2, Duration::ZERO);
+        cache.insert("a", 1_u8);
+        assert_eq!(cache.get::<u8>("a"), None);
+
+        let cache = InMemoryCache::new(0, Duration::from_secs(60));
+        cache.insert("a", 1_u8);
+        assert_eq!(cache.get::<u8>("a"), None);
+    }
+
+    #[actix_rt::test]
+    #[ignore]
+    async fn test_redact_cache_invalidates_other_instances() {
+        let conf = Settings::new().expect("invalid settings");
+        let store = Store::new(&conf, true).await;
+        let other_store = Store::new(&conf, true).await;
+
+        let key = "test_redact_cache_invalidates_other_instances";
+        other_store.cache.insert(key, 1_u8);
+
+        redact_cache(&store, key, || async { Ok(()) })
+            .await
+            .unwrap();
+
+        // The invalidation reaches the other instance via the pub/sub channel
+        let mut invalidated = false;
+        for _ in 0..50 {
+            if other_store.cache.get::<u8>(key).is_none() {
+                invalidated = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert!(invalidated);
+    }
+}
@@ -0,0 +1,40 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CardsInfoInterface {
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CardsInfoInterface for Store {
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::CardInfo::find_by_iins(&conn, card_iins)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CardsInfoInterface for MockDb {
+    async fn find_card_info_by_iins(
+        &self,
+        _card_iins: Vec<String>,
+    ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
+        Ok(None)
+    }
+}
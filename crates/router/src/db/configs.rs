@@ -1,8 +1,10 @@
-use error_stack::IntoReport;
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::PubSubInterface;
 
 use super::{cache, MockDb, Store};
 use crate::{
     connection::pg_connection,
+    consts,
     core::errors::{self, CustomResult},
     types::storage,
 };
@@ -37,6 +39,10 @@ pub trait ConfigInterface {
     ) -> CustomResult<storage::Config, errors::StorageError>;
 
     async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError>;
+
+    /// Notifies all the instances of the application that the config has changed, so that any
+    /// copies of it held by them are invalidated.
+    async fn publish_config_update(&self, key: &str) -> CustomResult<(), errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -97,6 +103,16 @@ impl ConfigInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn publish_config_update(&self, key: &str) -> CustomResult<(), errors::StorageError> {
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .publish(consts::PUB_SUB_CHANNEL, key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable_lazy(|| format!("Failed to publish update of config: {key}"))
+            .map(|_| ())
+    }
 }
 
 #[async_trait::async_trait]
@@ -146,4 +162,9 @@ impl ConfigInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn publish_config_update(&self, _key: &str) -> CustomResult<(), errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
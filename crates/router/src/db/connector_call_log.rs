@@ -0,0 +1,69 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorCallLogInterface {
+    async fn insert_connector_call_log(
+        &self,
+        call_log: storage::ConnectorCallLogNew,
+    ) -> CustomResult<storage::ConnectorCallLog, errors::StorageError>;
+
+    async fn find_connector_call_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorCallLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorCallLogInterface for Store {
+    async fn insert_connector_call_log(
+        &self,
+        call_log: storage::ConnectorCallLogNew,
+    ) -> CustomResult<storage::ConnectorCallLog, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        call_log
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_connector_call_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorCallLog>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ConnectorCallLog::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorCallLogInterface for MockDb {
+    async fn insert_connector_call_log(
+        &self,
+        _call_log: storage::ConnectorCallLogNew,
+    ) -> CustomResult<storage::ConnectorCallLog, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_connector_call_logs_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorCallLog>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,332 @@
+use common_utils::ext_traits::{ByteSliceExt, Encode};
+use error_stack::ResultExt;
+use redis_interface::SetnxReply;
+
+use super::{MockDb, Store};
+use crate::{
+    core::errors::{self, CustomResult},
+    types::storage::{CircuitBreakerRecord, ConnectorCallOutcome, ConnectorCallStats},
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorHealthInterface {
+    async fn get_circuit_breaker_record(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<Option<CircuitBreakerRecord>, errors::StorageError>;
+
+    async fn set_circuit_breaker_record(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        record: CircuitBreakerRecord,
+        expiry: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn delete_circuit_breaker_record(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Acquire the lock that allows a single probe call through a half-open circuit.
+    /// Returns `true` if the lock was acquired by this caller.
+    async fn acquire_circuit_probe_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        expiry: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn release_circuit_probe_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn record_connector_call(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        window: i64,
+        outcome: ConnectorCallOutcome,
+        expiry: i64,
+    ) -> CustomResult<ConnectorCallStats, errors::StorageError>;
+
+    async fn get_connector_call_stats(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        window: i64,
+    ) -> CustomResult<ConnectorCallStats, errors::StorageError>;
+
+    async fn reset_connector_call_stats(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        window: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+const TOTAL_CALLS: &str = "total_calls";
+const FAILED_CALLS: &str = "failed_calls";
+const SLOW_CALLS: &str = "slow_calls";
+const TOTAL_LATENCY: &str = "total_latency";
+
+fn circuit_breaker_key(merchant_id: &str, connector_name: &str) -> String {
+    format!("circuit_breaker_{merchant_id}_{connector_name}")
+}
+
+fn circuit_probe_key(merchant_id: &str, connector_name: &str) -> String {
+    format!("circuit_probe_{merchant_id}_{connector_name}")
+}
+
+fn connector_health_key(merchant_id: &str, connector_name: &str, window: i64) -> String {
+    format!("connector_health_{merchant_id}_{connector_name}_{window}")
+}
+
+#[async_trait::async_trait]
+impl ConnectorHealthInterface for Store {
+    async fn get_circuit_breaker_record(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<Option<CircuitBreakerRecord>, errors::StorageError> {
+        let key = circuit_breaker_key(merchant_id, connector_name);
+        let maybe_record = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .get_key::<Option<Vec<u8>>>(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get circuit breaker record")?;
+
+        maybe_record
+            .map(|record| record.parse_struct("CircuitBreakerRecord"))
+            .transpose()
+            .change_context(errors::StorageError::DeserializationFailed)
+    }
+
+    async fn set_circuit_breaker_record(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        record: CircuitBreakerRecord,
+        expiry: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        let key = circuit_breaker_key(merchant_id, connector_name);
+        let serialized_record = Encode::<CircuitBreakerRecord>::encode_to_string_of_json(&record)
+            .change_context(errors::StorageError::SerializationFailed)?;
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .set_key_with_expiry(&key, serialized_record, expiry)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to set circuit breaker record")
+    }
+
+    async fn delete_circuit_breaker_record(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        let key = circuit_breaker_key(merchant_id, connector_name);
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .delete_key(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to delete circuit breaker record")
+    }
+
+    async fn acquire_circuit_probe_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        expiry: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let key = circuit_probe_key(merchant_id, connector_name);
+        let reply = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .set_key_if_not_exist_with_expiry(&key, "probe", expiry)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to acquire circuit probe lock")?;
+
+        Ok(matches!(reply, SetnxReply::KeySet))
+    }
+
+    async fn release_circuit_probe_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        let key = circuit_probe_key(merchant_id, connector_name);
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .delete_key(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+    }
+
+    async fn record_connector_call(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        window: i64,
+        outcome: ConnectorCallOutcome,
+        expiry: i64,
+    ) -> CustomResult<ConnectorCallStats, errors::StorageError> {
+        let key = connector_health_key(merchant_id, connector_name, window);
+        let redis_conn = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+
+        let increment = |field: &'static str, value: u64| {
+            let redis_conn = redis_conn.clone();
+            let key = key.clone();
+            async move {
+                redis_conn
+                    .increment_hash_field(
+                        &key,
+                        field,
+                        i64::try_from(value).unwrap_or(i64::MAX),
+                        expiry,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)
+                    .map(|count| u64::try_from(count).unwrap_or_default())
+            }
+        };
+
+        let (total_calls, failed_calls, slow_calls, total_latency) = futures::try_join!(
+            increment(TOTAL_CALLS, 1),
+            increment(FAILED_CALLS, u64::from(outcome.failed)),
+            increment(SLOW_CALLS, u64::from(outcome.slow)),
+            increment(TOTAL_LATENCY, outcome.latency),
+        )?;
+
+        Ok(ConnectorCallStats {
+            total_calls,
+            failed_calls,
+            slow_calls,
+            total_latency,
+        })
+    }
+
+    async fn get_connector_call_stats(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        window: i64,
+    ) -> CustomResult<ConnectorCallStats, errors::StorageError> {
+        let key = connector_health_key(merchant_id, connector_name, window);
+        let fields = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .get_hash_fields::<std::collections::HashMap<String, u64>>(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get connector call stats")?;
+
+        let get_field = |field: &str| fields.get(field).copied().unwrap_or_default();
+
+        Ok(ConnectorCallStats {
+            total_calls: get_field(TOTAL_CALLS),
+            failed_calls: get_field(FAILED_CALLS),
+            slow_calls: get_field(SLOW_CALLS),
+            total_latency: get_field(TOTAL_LATENCY),
+        })
+    }
+
+    async fn reset_connector_call_stats(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        window: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        let key = connector_health_key(merchant_id, connector_name, window);
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .delete_key(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorHealthInterface for MockDb {
+    async fn get_circuit_breaker_record(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+    ) -> CustomResult<Option<CircuitBreakerRecord>, errors::StorageError> {
+        Ok(None)
+    }
+
+    async fn set_circuit_breaker_record(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _record: CircuitBreakerRecord,
+        _expiry: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn delete_circuit_breaker_record(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn acquire_circuit_probe_lock(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _expiry: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Ok(true)
+    }
+
+    async fn release_circuit_probe_lock(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn record_connector_call(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _window: i64,
+        _outcome: ConnectorCallOutcome,
+        _expiry: i64,
+    ) -> CustomResult<ConnectorCallStats, errors::StorageError> {
+        Ok(ConnectorCallStats::default())
+    }
+
+    async fn get_connector_call_stats(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _window: i64,
+    ) -> CustomResult<ConnectorCallStats, errors::StorageError> {
+        Ok(ConnectorCallStats::default())
+    }
+
+    async fn reset_connector_call_stats(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _window: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
-use error_stack::IntoReport;
+use error_stack::{IntoReport, ResultExt};
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -7,6 +8,7 @@ use crate::{
         customers::REDACTED,
         errors::{self, CustomResult},
     },
+    services::envelope_encryption::{DataKey, Decryptable, EncryptRecord},
     types::storage,
 };
 
@@ -22,25 +24,25 @@ pub trait CustomerInterface {
         &self,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<Option<storage::Customer>, errors::StorageError>;
+    ) -> CustomResult<Option<Decryptable<storage::Customer>>, errors::StorageError>;
 
     async fn update_customer_by_customer_id_merchant_id(
         &self,
         customer_id: String,
         merchant_id: String,
         customer: storage::CustomerUpdate,
-    ) -> CustomResult<storage::Customer, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError>;
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<storage::Customer, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError>;
 
     async fn insert_customer(
         &self,
         customer_data: storage::CustomerNew,
-    ) -> CustomResult<storage::Customer, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -49,7 +51,7 @@ impl CustomerInterface for Store {
         &self,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
+    ) -> CustomResult<Option<Decryptable<storage::Customer>>, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await?;
         let maybe_customer = storage::Customer::find_optional_by_customer_id_merchant_id(
             &conn,
@@ -59,14 +61,13 @@ impl CustomerInterface for Store {
         .await
         .map_err(Into::into)
         .into_report()?;
-        maybe_customer.map_or(Ok(None), |customer| {
-            // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
-            // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
-            match customer.name {
-                Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
-                _ => Ok(Some(customer)),
+        match maybe_customer {
+            Some(customer) => {
+                let key = self.get_merchant_data_key(merchant_id).await?;
+                check_customer_redacted(Decryptable::new(customer, key)).map(Some)
             }
-        })
+            None => Ok(None),
+        }
     }
 
     async fn update_customer_by_customer_id_merchant_id(
@@ -74,7 +75,11 @@ impl CustomerInterface for Store {
         customer_id: String,
         merchant_id: String,
         customer: storage::CustomerUpdate,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError> {
+        let key = self.get_merchant_data_key(&merchant_id).await?;
+        let customer = customer
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let conn = pg_connection(&self.master_pool).await?;
         storage::Customer::update_by_customer_id_merchant_id(
             &conn,
@@ -85,35 +90,40 @@ impl CustomerInterface for Store {
         .await
         .map_err(Into::into)
         .into_report()
+        .map(|customer| Decryptable::new(customer, key))
     }
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await?;
         let customer =
             storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
                 .await
                 .map_err(Into::into)
                 .into_report()?;
-        match customer.name {
-            Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
-            _ => Ok(customer),
-        }
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        check_customer_redacted(Decryptable::new(customer, key))
     }
 
     async fn insert_customer(
         &self,
         customer_data: storage::CustomerNew,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError> {
+        let key = self
+            .get_merchant_data_key(&customer_data.merchant_id)
+            .await?;
         let conn = pg_connection(&self.master_pool).await?;
         customer_data
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?
             .insert(&conn)
             .await
             .map_err(Into::into)
             .into_report()
+            .map(|customer| Decryptable::new(customer, key))
     }
 
     async fn delete_customer_by_customer_id_merchant_id(
@@ -129,6 +139,16 @@ impl CustomerInterface for Store {
     }
 }
 
+/// Only the name of the customer is decrypted, to tell whether the customer was redacted.
+fn check_customer_redacted(
+    customer: Decryptable<storage::Customer>,
+) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError> {
+    match customer.decrypt_field(|customer| &customer.name)? {
+        Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
+        _ => Ok(customer),
+    }
+}
+
 #[async_trait::async_trait]
 impl CustomerInterface for MockDb {
     #[allow(clippy::panic)]
@@ -136,41 +156,54 @@ impl CustomerInterface for MockDb {
         &self,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
+    ) -> CustomResult<Option<Decryptable<storage::Customer>>, errors::StorageError> {
         let customers = self.customers.lock().await;
 
+        // Customers of the mock database are not encrypted, so any data key reads them as is
         Ok(customers
             .iter()
             .find(|customer| {
                 customer.customer_id == customer_id && customer.merchant_id == merchant_id
             })
-            .cloned())
+            .cloned()
+            .map(|customer| Decryptable::new(customer, DataKey::generate())))
     }
 
     async fn update_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: String,
-        _merchant_id: String,
-        _customer: storage::CustomerUpdate,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        customer_id: String,
+        merchant_id: String,
+        customer_update: storage::CustomerUpdate,
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError> {
+        let mut customers = self.customers.lock().await;
+
+        let customer = customers
+            .iter_mut()
+            .find(|customer| {
+                customer.customer_id == customer_id && customer.merchant_id == merchant_id
+            })
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+        *customer = customer_update.apply_changeset(customer.clone());
+        Ok(Decryptable::new(customer.clone(), DataKey::generate()))
     }
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        customer_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError> {
+        self.find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id)
+            .await?
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
     }
 
     #[allow(clippy::panic)]
     async fn insert_customer(
         &self,
         customer_data: storage::CustomerNew,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::Customer>, errors::StorageError> {
         let mut customers = self.customers.lock().await;
         let customer = storage::Customer {
             #[allow(clippy::as_conversions)]
@@ -184,9 +217,11 @@ impl CustomerInterface for MockDb {
             description: customer_data.description,
             created_at: common_utils::date_time::now(),
             metadata: customer_data.metadata,
+            connector_customer: customer_data.connector_customer,
+            default_payment_method_id: None,
         };
         customers.push(customer.clone());
-        Ok(customer)
+        Ok(Decryptable::new(customer, DataKey::generate()))
     }
 
     async fn delete_customer_by_customer_id_merchant_id(
@@ -0,0 +1,61 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::core::errors::{self, CustomResult};
+
+#[async_trait::async_trait]
+pub trait FraudCheckInterface {
+    /// Counts a payment against a velocity counter in a window, returning the number of payments
+    /// counted in the window.
+    async fn increment_velocity_count(
+        &self,
+        counter: &str,
+        window: i64,
+        expiry: i64,
+    ) -> CustomResult<u64, errors::StorageError>;
+}
+
+fn velocity_key(counter: &str, window: i64) -> String {
+    format!("fraud_velocity_{counter}_{window}")
+}
+
+#[async_trait::async_trait]
+impl FraudCheckInterface for Store {
+    async fn increment_velocity_count(
+        &self,
+        counter: &str,
+        window: i64,
+        expiry: i64,
+    ) -> CustomResult<u64, errors::StorageError> {
+        let key = velocity_key(counter, window);
+        let count = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .increment_key(&key, 1, expiry)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to increment fraud check velocity count")?;
+
+        u64::try_from(count)
+            .into_report()
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Fraud check velocity count is negative")
+    }
+}
+
+#[async_trait::async_trait]
+impl FraudCheckInterface for MockDb {
+    async fn increment_velocity_count(
+        &self,
+        counter: &str,
+        window: i64,
+        _expiry: i64,
+    ) -> CustomResult<u64, errors::StorageError> {
+        let mut velocity_counts = self.velocity_counts.lock().await;
+        let count = velocity_counts
+            .entry(velocity_key(counter, window))
+            .or_default();
+        *count += 1;
+        Ok(*count)
+    }
+}
@@ -0,0 +1,132 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+};
+
+#[async_trait::async_trait]
+pub trait HealthCheckInterface {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError>;
+
+    async fn health_check_locker_mock_up(&self) -> CustomResult<(), errors::StorageError>;
+
+    async fn health_check_vault(&self) -> CustomResult<(), errors::StorageError>;
+
+    async fn get_stream_length(&self, stream: &str) -> CustomResult<usize, errors::StorageError>;
+
+    /// Number of entries across all the partitions of the drainer stream which are yet to be
+    /// drained into the database.
+    #[cfg(feature = "kv_store")]
+    async fn get_drainer_stream_lag(&self) -> CustomResult<usize, errors::StorageError>;
+}
+
+async fn run_health_check_query(
+    pool: &crate::connection::PgPool,
+    query: &'static str,
+) -> CustomResult<(), errors::StorageError> {
+    let conn = pg_connection(pool).await?;
+    diesel::sql_query(query)
+        .execute_async(&*conn)
+        .await
+        .into_report()
+        .change_context(errors::StorageError::DatabaseConnectionError)
+        .attach_printable_lazy(|| format!("Failed to execute health check query: {query}"))?;
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for Store {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        run_health_check_query(&self.master_pool, "SELECT 1").await
+    }
+
+    #[cfg(feature = "olap")]
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        run_health_check_query(&self.replica_pool, "SELECT 1").await
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError> {
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .ping()
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to ping redis")
+    }
+
+    async fn health_check_locker_mock_up(&self) -> CustomResult<(), errors::StorageError> {
+        run_health_check_query(&self.master_pool, "SELECT 1 FROM locker_mock_up LIMIT 1").await
+    }
+
+    async fn health_check_vault(&self) -> CustomResult<(), errors::StorageError> {
+        run_health_check_query(&self.master_pool, "SELECT 1 FROM vault.cards LIMIT 1").await
+    }
+
+    async fn get_stream_length(&self, stream: &str) -> CustomResult<usize, errors::StorageError> {
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .stream_get_length(stream)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable_lazy(|| format!("Failed to get the length of stream: {stream}"))
+    }
+
+    #[cfg(feature = "kv_store")]
+    async fn get_drainer_stream_lag(&self) -> CustomResult<usize, errors::StorageError> {
+        let mut lag = 0;
+        for partition in 0..self.config.drainer_num_partitions {
+            let stream_name = self.get_drainer_stream_name(&format!("shard_{partition}"));
+            lag += self.get_stream_length(&stream_name).await?;
+        }
+        Ok(lag)
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for MockDb {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    #[cfg(feature = "olap")]
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError> {
+        self.redis
+            .ping()
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to ping redis")
+    }
+
+    async fn health_check_locker_mock_up(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_vault(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn get_stream_length(&self, stream: &str) -> CustomResult<usize, errors::StorageError> {
+        self.redis
+            .stream_get_length(stream)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable_lazy(|| format!("Failed to get the length of stream: {stream}"))
+    }
+
+    #[cfg(feature = "kv_store")]
+    async fn get_drainer_stream_lag(&self) -> CustomResult<usize, errors::StorageError> {
+        Ok(0)
+    }
+}
@@ -46,6 +46,11 @@ pub trait MerchantAccountInterface {
         &self,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -182,6 +187,17 @@ impl MerchantAccountInterface for Store {
             super::cache::redact_cache(self, merchant_id, delete_func).await
         }
     }
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::MerchantAccount::list_by_organization_id(&conn, organization_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -215,6 +231,8 @@ impl MerchantAccountInterface for MockDb {
             storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
             locker_id: merchant_account.locker_id,
             metadata: merchant_account.metadata,
+            pm_filters: merchant_account.pm_filters,
+            organization_id: merchant_account.organization_id,
         };
         accounts.push(account.clone());
         Ok(account)
@@ -285,4 +303,17 @@ impl MerchantAccountInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let accounts = self.merchant_accounts.lock().await;
+
+        Ok(accounts
+            .iter()
+            .filter(|account| account.organization_id.as_deref() == Some(organization_id))
+            .cloned()
+            .collect())
+    }
 }
@@ -1,12 +1,16 @@
 use common_utils::ext_traits::{ByteSliceExt, Encode};
 use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
+use storage_models::errors::DatabaseError;
 
-use super::{MockDb, Store};
+use super::{merchant_account::MerchantAccountInterface, MockDb, Store};
 use crate::{
     connection::pg_connection,
     core::errors::{self, CustomResult},
-    services::logger,
+    services::{
+        envelope_encryption::{DataKey, Decryptable, EncryptRecord},
+        logger,
+    },
     types::{self, storage},
 };
 
@@ -102,30 +106,30 @@ pub trait MerchantConnectorAccountInterface {
         &self,
         merchant_id: &str,
         connector: &str,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError>;
 
     async fn insert_merchant_connector_account(
         &self,
         t: storage::MerchantConnectorAccountNew,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError>;
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
         &self,
         merchant_id: &str,
         merchant_connector_id: &str,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError>;
 
     async fn find_merchant_connector_account_by_merchant_id_and_disabled_list(
         &self,
         merchant_id: &str,
         get_disabled: bool,
-    ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError>;
+    ) -> CustomResult<Vec<Decryptable<storage::MerchantConnectorAccount>>, errors::StorageError>;
 
     async fn update_merchant_connector_account(
         &self,
         this: storage::MerchantConnectorAccount,
         merchant_connector_account: storage::MerchantConnectorAccountUpdate,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError>;
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError>;
 
     async fn delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
         &self,
@@ -134,29 +138,81 @@ pub trait MerchantConnectorAccountInterface {
     ) -> CustomResult<bool, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl MerchantConnectorAccountInterface for Store {
-    async fn find_merchant_connector_account_by_merchant_id_connector(
+impl Store {
+    /// Connector accounts which the parent merchant of a sub-merchant shares with its
+    /// sub-merchants, encrypted with the data key of the parent merchant. Merchants which are not
+    /// sub-merchants do not inherit any connector accounts.
+    async fn find_shared_merchant_connector_accounts(
         &self,
         merchant_id: &str,
-        connector: &str,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    ) -> CustomResult<Vec<Decryptable<storage::MerchantConnectorAccount>>, errors::StorageError>
+    {
+        let merchant_account = self
+            .find_merchant_account_by_merchant_id(merchant_id)
+            .await?;
+        let parent_merchant_id = match merchant_account.parent_merchant_id {
+            Some(parent_merchant_id) if merchant_account.sub_merchants_enabled == Some(true) => {
+                parent_merchant_id
+            }
+            _ => return Ok(Vec::new()),
+        };
+
         let conn = pg_connection(&self.master_pool).await?;
-        storage::MerchantConnectorAccount::find_by_merchant_id_connector(
+        let shared_accounts = storage::MerchantConnectorAccount::find_by_merchant_id(
             &conn,
-            merchant_id,
-            connector,
+            &parent_merchant_id,
+            true,
         )
         .await
         .map_err(Into::into)
-        .into_report()
+        .into_report()?;
+        let key = self.get_merchant_data_key(&parent_merchant_id).await?;
+        Ok(shared_accounts
+            .into_iter()
+            .filter(|mca| mca.shared_with_sub_merchants)
+            .map(|mca| Decryptable::new(mca, key.clone()))
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantConnectorAccountInterface for Store {
+    async fn find_merchant_connector_account_by_merchant_id_connector(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        let merchant_connector_account =
+            match storage::MerchantConnectorAccount::find_by_merchant_id_connector(
+                &conn,
+                merchant_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+            {
+                // Sub-merchants fall back to the connector account shared by the parent merchant
+                Err(error) if error.current_context().is_db_not_found() => {
+                    return self
+                        .find_shared_merchant_connector_accounts(merchant_id)
+                        .await?
+                        .into_iter()
+                        .find(|mca| mca.encrypted().connector_name == connector)
+                        .ok_or(error);
+                }
+                result => result?,
+            };
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        Ok(Decryptable::new(merchant_connector_account, key))
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
         &self,
         merchant_id: &str,
         merchant_connector_id: &str,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
         let find_call = || async {
             let conn = pg_connection(&self.master_pool).await?;
             storage::MerchantConnectorAccount::find_by_merchant_id_merchant_connector_id(
@@ -168,43 +224,88 @@ impl MerchantConnectorAccountInterface for Store {
             .map_err(Into::into)
             .into_report()
         };
+        // The cache holds the encrypted connector account
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            find_call().await
-        }
+        let merchant_connector_account = find_call().await?;
 
         #[cfg(feature = "accounts_cache")]
-        {
-            super::cache::get_or_populate_cache(self, merchant_connector_id, find_call).await
-        }
+        let merchant_connector_account =
+            super::cache::get_or_populate_cache(self, merchant_connector_id, find_call).await?;
+
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        Ok(Decryptable::new(merchant_connector_account, key))
     }
 
     async fn insert_merchant_connector_account(
         &self,
         t: storage::MerchantConnectorAccountNew,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
+        let merchant_id =
+            t.merchant_id
+                .as_deref()
+                .ok_or(errors::StorageError::MissingRequiredField {
+                    field_name: "merchant_id",
+                })?;
+        let key = self.get_merchant_data_key(merchant_id).await?;
         let conn = pg_connection(&self.master_pool).await?;
-        t.insert(&conn).await.map_err(Into::into).into_report()
+        t.encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+            .map(|mca| Decryptable::new(mca, key))
     }
 
     async fn find_merchant_connector_account_by_merchant_id_and_disabled_list(
         &self,
         merchant_id: &str,
         get_disabled: bool,
-    ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
+    ) -> CustomResult<Vec<Decryptable<storage::MerchantConnectorAccount>>, errors::StorageError>
+    {
         let conn = pg_connection(&self.master_pool).await?;
-        storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id, get_disabled)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let merchant_connector_accounts = storage::MerchantConnectorAccount::find_by_merchant_id(
+            &conn,
+            merchant_id,
+            get_disabled,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        let mut merchant_connector_accounts: Vec<_> = merchant_connector_accounts
+            .into_iter()
+            .map(|mca| Decryptable::new(mca, key.clone()))
+            .collect();
+
+        // Connector accounts of the sub-merchant take precedence over the shared ones for the
+        // same connector
+        let shared_accounts = self
+            .find_shared_merchant_connector_accounts(merchant_id)
+            .await?
+            .into_iter()
+            .filter(|shared| get_disabled || shared.encrypted().disabled == Some(false))
+            .filter(|shared| {
+                !merchant_connector_accounts
+                    .iter()
+                    .any(|mca| mca.encrypted().connector_name == shared.encrypted().connector_name)
+            })
+            .collect::<Vec<_>>();
+        merchant_connector_accounts.extend(shared_accounts);
+
+        Ok(merchant_connector_accounts)
     }
 
     async fn update_merchant_connector_account(
         &self,
         this: storage::MerchantConnectorAccount,
         merchant_connector_account: storage::MerchantConnectorAccountUpdate,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
         let _merchant_connector_id = this.merchant_connector_id.clone();
+        let key = self.get_merchant_data_key(&this.merchant_id).await?;
+        let merchant_connector_account = merchant_connector_account
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let update_call = || async {
             let conn = pg_connection(&self.master_pool).await?;
             this.update(&conn, merchant_connector_account)
@@ -213,59 +314,109 @@ impl MerchantConnectorAccountInterface for Store {
                 .into_report()
         };
 
+        #[cfg(feature = "accounts_cache")]
+        let updated_merchant_connector_account =
+            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await?;
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let updated_merchant_connector_account = update_call().await?;
+
+        Ok(Decryptable::new(updated_merchant_connector_account, key))
+    }
+
+    async fn delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &str,
+        merchant_connector_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let delete_call = || async {
+            let conn = pg_connection(&self.master_pool).await?;
+            storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
+                &conn,
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await
+            super::cache::redact_cache(self, merchant_connector_id, delete_call).await
         }
 
         #[cfg(not(feature = "accounts_cache"))]
         {
-            update_call().await
+            delete_call().await
         }
     }
+}
 
-    async fn delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
+impl MockDb {
+    /// Connector accounts which the parent merchant of a sub-merchant shares with its
+    /// sub-merchants, like [`Store`] finds them.
+    async fn find_shared_merchant_connector_accounts(
         &self,
         merchant_id: &str,
-        merchant_connector_id: &str,
-    ) -> CustomResult<bool, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
-            &conn,
-            merchant_id,
-            merchant_connector_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+    ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
+        let merchant_account = self
+            .find_merchant_account_by_merchant_id(merchant_id)
+            .await?;
+        let parent_merchant_id = match merchant_account.parent_merchant_id {
+            Some(parent_merchant_id) if merchant_account.sub_merchants_enabled == Some(true) => {
+                parent_merchant_id
+            }
+            _ => return Ok(Vec::new()),
+        };
+
+        let accounts = self.merchant_connector_accounts.lock().await;
+        Ok(accounts
+            .iter()
+            .filter(|mca| mca.merchant_id == parent_merchant_id && mca.shared_with_sub_merchants)
+            .cloned()
+            .collect())
     }
 }
 
 #[async_trait::async_trait]
 impl MerchantConnectorAccountInterface for MockDb {
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn find_merchant_connector_account_by_merchant_id_connector(
         &self,
         merchant_id: &str,
         connector: &str,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
-        let accounts = self.merchant_connector_accounts.lock().await;
-        let account = accounts
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
+        let account = self
+            .merchant_connector_accounts
+            .lock()
+            .await
             .iter()
             .find(|account| {
                 account.merchant_id == merchant_id && account.connector_name == connector
             })
-            .cloned()
-            .unwrap();
-        Ok(account)
+            .cloned();
+        let account = match account {
+            Some(account) => account,
+            // Sub-merchants fall back to the connector account shared by the parent merchant
+            None => self
+                .find_shared_merchant_connector_accounts(merchant_id)
+                .await?
+                .into_iter()
+                .find(|mca| mca.connector_name == connector)
+                .ok_or_else(|| {
+                    errors::StorageError::DatabaseError(DatabaseError::NotFound.into())
+                })?,
+        };
+        // Connector accounts of the mock database are not encrypted, so any data key reads them
+        // as is
+        Ok(Decryptable::new(account, DataKey::generate()))
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
         &self,
         _merchant_id: &str,
         _merchant_connector_id: &str,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -274,7 +425,7 @@ impl MerchantConnectorAccountInterface for MockDb {
     async fn insert_merchant_connector_account(
         &self,
         t: storage::MerchantConnectorAccountNew,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
         let mut accounts = self.merchant_connector_accounts.lock().await;
         let account = storage::MerchantConnectorAccount {
             #[allow(clippy::as_conversions)]
@@ -287,28 +438,59 @@ impl MerchantConnectorAccountInterface for MockDb {
             merchant_connector_id: t.merchant_connector_id,
             payment_methods_enabled: t.payment_methods_enabled,
             metadata: t.metadata,
+            pm_filters: t.pm_filters,
+            shared_with_sub_merchants: t.shared_with_sub_merchants.unwrap_or_default(),
             connector_type: t
                 .connector_type
                 .unwrap_or(crate::types::storage::enums::ConnectorType::FinOperations),
         };
         accounts.push(account.clone());
-        Ok(account)
+        Ok(Decryptable::new(account, DataKey::generate()))
     }
 
     async fn find_merchant_connector_account_by_merchant_id_and_disabled_list(
         &self,
-        _merchant_id: &str,
-        _get_disabled: bool,
-    ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        merchant_id: &str,
+        get_disabled: bool,
+    ) -> CustomResult<Vec<Decryptable<storage::MerchantConnectorAccount>>, errors::StorageError>
+    {
+        let mut merchant_connector_accounts: Vec<_> = self
+            .merchant_connector_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|mca| {
+                mca.merchant_id == merchant_id && (get_disabled || mca.disabled == Some(false))
+            })
+            .cloned()
+            .collect();
+
+        // Connector accounts of the sub-merchant take precedence over the shared ones for the
+        // same connector
+        let shared_accounts = self
+            .find_shared_merchant_connector_accounts(merchant_id)
+            .await?
+            .into_iter()
+            .filter(|shared| get_disabled || shared.disabled == Some(false))
+            .filter(|shared| {
+                !merchant_connector_accounts
+                    .iter()
+                    .any(|mca| mca.connector_name == shared.connector_name)
+            })
+            .collect::<Vec<_>>();
+        merchant_connector_accounts.extend(shared_accounts);
+
+        Ok(merchant_connector_accounts
+            .into_iter()
+            .map(|mca| Decryptable::new(mca, DataKey::generate()))
+            .collect())
     }
 
     async fn update_merchant_connector_account(
         &self,
         _this: storage::MerchantConnectorAccount,
         _merchant_connector_account: storage::MerchantConnectorAccountUpdate,
-    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    ) -> CustomResult<Decryptable<storage::MerchantConnectorAccount>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -322,3 +504,84 @@ impl MerchantConnectorAccountInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{routes::AppState, test_utils};
+
+    async fn insert_merchant_connector_account(
+        state: &AppState,
+        merchant_id: &str,
+        connector_name: &str,
+        shared_with_sub_merchants: bool,
+    ) {
+        state
+            .store
+            .insert_merchant_connector_account(storage::MerchantConnectorAccountNew {
+                merchant_id: Some(merchant_id.to_string()),
+                connector_name: Some(connector_name.to_string()),
+                disabled: Some(false),
+                merchant_connector_id: format!("{merchant_id}_{connector_name}"),
+                shared_with_sub_merchants: Some(shared_with_sub_merchants),
+                ..storage::MerchantConnectorAccountNew::default()
+            })
+            .await
+            .unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn test_sub_merchant_falls_back_to_shared_connector_accounts() {
+        let (state, _) = test_utils::get_state().await;
+        for merchant_account in [
+            storage::MerchantAccountNew {
+                merchant_id: "parent".to_string(),
+                ..storage::MerchantAccountNew::default()
+            },
+            storage::MerchantAccountNew {
+                merchant_id: "sub_merchant".to_string(),
+                parent_merchant_id: Some("parent".to_string()),
+                sub_merchants_enabled: Some(true),
+                ..storage::MerchantAccountNew::default()
+            },
+        ] {
+            state.store.insert_merchant(merchant_account).await.unwrap();
+        }
+        insert_merchant_connector_account(&state, "parent", "stripe", true).await;
+        insert_merchant_connector_account(&state, "parent", "adyen", true).await;
+        insert_merchant_connector_account(&state, "parent", "checkout", false).await;
+        insert_merchant_connector_account(&state, "sub_merchant", "adyen", false).await;
+
+        let find = |connector: &'static str| {
+            state
+                .store
+                .find_merchant_connector_account_by_merchant_id_connector("sub_merchant", connector)
+        };
+        let shared = find("stripe").await.unwrap().into_encrypted();
+        assert_eq!(shared.merchant_id, "parent");
+
+        // The connector account of the sub-merchant overrides the shared one
+        let own = find("adyen").await.unwrap().into_encrypted();
+        assert_eq!(own.merchant_id, "sub_merchant");
+
+        // Connector accounts which are not shared are not inherited
+        let error = find("checkout").await.unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+
+        let mut merchant_connector_ids = state
+            .store
+            .find_merchant_connector_account_by_merchant_id_and_disabled_list("sub_merchant", true)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|mca| mca.into_encrypted().merchant_connector_id)
+            .collect::<Vec<_>>();
+        merchant_connector_ids.sort();
+        assert_eq!(
+            merchant_connector_ids,
+            ["parent_stripe", "sub_merchant_adyen"]
+        );
+    }
+}
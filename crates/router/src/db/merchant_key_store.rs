@@ -0,0 +1,193 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    services::envelope_encryption::DataKey,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MerchantKeyStoreInterface {
+    async fn insert_merchant_key_store(
+        &self,
+        merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+
+    async fn find_merchant_key_stores_by_not_master_key_identifier(
+        &self,
+        master_key_identifier: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantKeyStore>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for Store {
+    async fn insert_merchant_key_store(
+        &self,
+        merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        merchant_key_store
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::MerchantKeyStore::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::MerchantKeyStore::update_by_merchant_id(
+            &conn,
+            merchant_id,
+            merchant_key_store_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_merchant_key_stores_by_not_master_key_identifier(
+        &self,
+        master_key_identifier: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantKeyStore>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::MerchantKeyStore::find_by_not_master_key_identifier(
+            &conn,
+            master_key_identifier,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+impl Store {
+    /// Returns the data key of the merchant, a new one is generated the first time it is needed.
+    /// Unwrapped data keys are kept in the in-memory cache until the cache TTL expires. Rotating
+    /// the master key re-wraps the data key without changing it, so cached keys stay valid.
+    pub(crate) async fn get_merchant_data_key(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<DataKey, errors::StorageError> {
+        let cache_key = format!("data_key_{merchant_id}");
+        if let Some(data_key) = self.cache.get::<DataKey>(&cache_key) {
+            return Ok(data_key);
+        }
+
+        let merchant_key_store = match self
+            .find_merchant_key_store_by_merchant_id(merchant_id)
+            .await
+        {
+            Err(error) if error.current_context().is_db_not_found() => {
+                self.create_merchant_key_store(merchant_id).await?
+            }
+            result => result?,
+        };
+
+        let data_key = self
+            .envelope_encryption
+            .unwrap_data_key(
+                &merchant_key_store.key,
+                &merchant_key_store.master_key_identifier,
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+
+        self.cache.insert(&cache_key, data_key.clone());
+        Ok(data_key)
+    }
+
+    async fn create_merchant_key_store(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let (key, master_key_identifier) = self
+            .envelope_encryption
+            .wrap_data_key(&DataKey::generate())
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        match self
+            .insert_merchant_key_store(storage::MerchantKeyStoreNew {
+                merchant_id: merchant_id.to_owned(),
+                key,
+                master_key_identifier,
+            })
+            .await
+        {
+            // Another request generated the data key in the meantime
+            Err(error) if error.current_context().is_db_unique_violation() => {
+                self.find_merchant_key_store_by_merchant_id(merchant_id)
+                    .await
+            }
+            result => result,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for MockDb {
+    async fn insert_merchant_key_store(
+        &self,
+        _merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_merchant_key_stores_by_not_master_key_identifier(
+        &self,
+        _master_key_identifier: &str,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantKeyStore>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
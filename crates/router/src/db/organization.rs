@@ -0,0 +1,111 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait OrganizationInterface {
+    async fn insert_organization(
+        &self,
+        organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError>;
+
+    async fn find_organization_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError>;
+
+    async fn update_organization_by_org_id(
+        &self,
+        org_id: &str,
+        organization: storage::OrganizationUpdate,
+    ) -> CustomResult<storage::Organization, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl OrganizationInterface for Store {
+    async fn insert_organization(
+        &self,
+        organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        organization
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_organization_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Organization::find_by_org_id(&conn, org_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_organization_by_org_id(
+        &self,
+        org_id: &str,
+        organization: storage::OrganizationUpdate,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Organization::update_by_org_id(&conn, org_id, organization)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl OrganizationInterface for MockDb {
+    async fn insert_organization(
+        &self,
+        organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let mut organizations = self.organizations.lock().await;
+        let current_time = common_utils::date_time::now();
+        let organization = storage::Organization {
+            org_id: organization.org_id,
+            org_name: organization.org_name,
+            hash_key: organization.hash_key,
+            hashed_api_key: organization.hashed_api_key,
+            prefix: organization.prefix,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        organizations.push(organization.clone());
+        Ok(organization)
+    }
+
+    async fn find_organization_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let organizations = self.organizations.lock().await;
+        organizations
+            .iter()
+            .find(|organization| organization.org_id == org_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn update_organization_by_org_id(
+        &self,
+        _org_id: &str,
+        _organization: storage::OrganizationUpdate,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
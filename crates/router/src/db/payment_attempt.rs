@@ -245,6 +245,9 @@ impl PaymentAttemptInterface for MockDb {
             payment_experience: payment_attempt.payment_experience,
             payment_method_type: payment_attempt.payment_method_type,
             payment_method_data: payment_attempt.payment_method_data,
+            fraud_check_decision: payment_attempt.fraud_check_decision,
+            fraud_check_connector: payment_attempt.fraud_check_connector,
+            fraud_check_reason: payment_attempt.fraud_check_reason,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -385,6 +388,9 @@ mod storage {
                         payment_experience: payment_attempt.payment_experience.clone(),
                         payment_method_type: payment_attempt.payment_method_type.clone(),
                         payment_method_data: payment_attempt.payment_method_data.clone(),
+                        fraud_check_decision: payment_attempt.fraud_check_decision,
+                        fraud_check_connector: payment_attempt.fraud_check_connector.clone(),
+                        fraud_check_reason: payment_attempt.fraud_check_reason.clone(),
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
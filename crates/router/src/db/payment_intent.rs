@@ -307,12 +307,68 @@ impl PaymentIntentInterface for MockDb {
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
-        _merchant_id: &str,
-        _pc: &api::PaymentListConstraints,
-        _storage_scheme: enums::MerchantStorageScheme,
+        merchant_id: &str,
+        pc: &api::PaymentListConstraints,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let starting_after = match &pc.starting_after {
+            Some(payment_id) => Some(
+                self.find_payment_intent_by_payment_id_merchant_id(
+                    payment_id,
+                    merchant_id,
+                    storage_scheme,
+                )
+                .await?
+                .id,
+            ),
+            None => None,
+        };
+        let ending_before = match &pc.ending_before {
+            Some(payment_id) => Some(
+                self.find_payment_intent_by_payment_id_merchant_id(
+                    payment_id,
+                    merchant_id,
+                    storage_scheme,
+                )
+                .await?
+                .id,
+            ),
+            None => None,
+        };
+
+        let payment_intents = self.payment_intents.lock().await;
+        let mut payment_intents: Vec<_> = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && pc.customer_id.as_ref().map_or(true, |customer_id| {
+                        payment_intent.customer_id.as_ref() == Some(customer_id)
+                    })
+                    && pc
+                        .created
+                        .map_or(true, |created| payment_intent.created_at == created)
+                    && pc
+                        .created_lt
+                        .map_or(true, |created_lt| payment_intent.created_at < created_lt)
+                    && pc
+                        .created_gt
+                        .map_or(true, |created_gt| payment_intent.created_at > created_gt)
+                    && pc
+                        .created_lte
+                        .map_or(true, |created_lte| payment_intent.created_at <= created_lte)
+                    && pc
+                        .created_gte
+                        .map_or(true, |created_gte| payment_intent.created_at > created_gte)
+                    && starting_after.map_or(true, |id| payment_intent.id > id)
+                    && ending_before.map_or(true, |id| payment_intent.id < id)
+            })
+            .cloned()
+            .collect();
+
+        // Most recently modified payments first, like the database query
+        payment_intents.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+        payment_intents.truncate(usize::try_from(pc.limit).unwrap_or_default());
+        Ok(payment_intents)
     }
 
     #[allow(clippy::panic)]
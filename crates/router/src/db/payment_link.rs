@@ -0,0 +1,164 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentLinkInterface {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn find_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn find_payment_link_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn update_payment_link(
+        &self,
+        payment_link: storage::PaymentLink,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for Store {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        payment_link
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::PaymentLink::find_by_payment_link_id(&conn, payment_link_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_link_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::PaymentLink::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payment_link(
+        &self,
+        payment_link: storage::PaymentLink,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        payment_link
+            .update(&conn, payment_link_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for MockDb {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+        if payment_links.iter().any(|link| {
+            link.payment_link_id == payment_link.payment_link_id
+                || (link.merchant_id == payment_link.merchant_id
+                    && link.payment_id == payment_link.payment_id)
+        }) {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?
+        }
+
+        let current_time = common_utils::date_time::now();
+        let payment_link = storage::PaymentLink {
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            merchant_id: payment_link.merchant_id,
+            link_to_pay: payment_link.link_to_pay,
+            status: payment_link.status,
+            created_at: current_time,
+            last_modified: current_time,
+            expires_at: payment_link.expires_at,
+        };
+        payment_links.push(payment_link.clone());
+        Ok(payment_link)
+    }
+
+    async fn find_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let payment_links = self.payment_links.lock().await;
+
+        payment_links
+            .iter()
+            .find(|link| link.payment_link_id == payment_link_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn find_payment_link_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let payment_links = self.payment_links.lock().await;
+
+        payment_links
+            .iter()
+            .find(|link| link.merchant_id == merchant_id && link.payment_id == payment_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn update_payment_link(
+        &self,
+        payment_link: storage::PaymentLink,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+
+        let link = payment_links
+            .iter_mut()
+            .find(|link| link.payment_link_id == payment_link.payment_link_id)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+        *link = payment_link_update.apply_changeset(link.clone());
+        Ok(link.clone())
+    }
+}
@@ -1,4 +1,5 @@
 use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -20,11 +21,29 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
 
+    async fn update_payment_method(
+        &self,
+        payment_method: storage::PaymentMethod,
+        payment_method_update: storage::PaymentMethodUpdate,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -45,6 +64,38 @@ impl PaymentMethodInterface for Store {
             .into_report()
     }
 
+    async fn find_payment_method_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::PaymentMethod::find_by_merchant_id_customer_id_card_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::PaymentMethod::find_by_network_token_reference_id(
+            &conn,
+            network_token_reference_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
@@ -53,6 +104,19 @@ impl PaymentMethodInterface for Store {
         m.insert(&conn).await.map_err(Into::into).into_report()
     }
 
+    async fn update_payment_method(
+        &self,
+        payment_method: storage::PaymentMethod,
+        payment_method_update: storage::PaymentMethodUpdate,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        payment_method
+            .update(&conn, payment_method_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
         customer_id: &str,
@@ -86,7 +150,43 @@ impl PaymentMethodInterface for Store {
 impl PaymentMethodInterface for MockDb {
     async fn find_payment_method(
         &self,
-        _payment_method_id: &str,
+        payment_method_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+
+        payment_methods
+            .iter()
+            .find(|pm| pm.payment_method_id == payment_method_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn find_payment_method_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+
+        payment_methods
+            .iter()
+            .find(|pm| {
+                pm.merchant_id == merchant_id
+                    && pm.customer_id == customer_id
+                    && pm.card_fingerprint.as_deref() == Some(card_fingerprint)
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        _network_token_reference_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -94,27 +194,100 @@ impl PaymentMethodInterface for MockDb {
 
     async fn insert_payment_method(
         &self,
-        _m: storage::PaymentMethodNew,
+        m: storage::PaymentMethodNew,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_methods = self.payment_methods.lock().await;
+
+        if m.card_fingerprint.is_some()
+            && payment_methods.iter().any(|pm| {
+                pm.merchant_id == m.merchant_id
+                    && pm.customer_id == m.customer_id
+                    && pm.card_fingerprint == m.card_fingerprint
+            })
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?
+        }
+
+        let payment_method = storage::PaymentMethod {
+            #[allow(clippy::as_conversions)]
+            id: payment_methods.len() as i32,
+            customer_id: m.customer_id,
+            merchant_id: m.merchant_id,
+            payment_method_id: m.payment_method_id,
+            accepted_currency: m.accepted_currency,
+            scheme: m.scheme,
+            token: m.token,
+            cardholder_name: m.cardholder_name,
+            issuer_name: m.issuer_name,
+            issuer_country: m.issuer_country,
+            payer_country: m.payer_country,
+            is_stored: m.is_stored,
+            swift_code: m.swift_code,
+            direct_debit_token: m.direct_debit_token,
+            created_at: m.created_at,
+            last_modified: m.last_modified,
+            payment_method: m.payment_method,
+            payment_method_type: m.payment_method_type,
+            payment_method_issuer: m.payment_method_issuer,
+            payment_method_issuer_code: m.payment_method_issuer_code,
+            metadata: m.metadata,
+            card_fingerprint: m.card_fingerprint,
+            last_used_at: m.last_used_at,
+            network_token_reference_id: m.network_token_reference_id,
+            network_token: m.network_token,
+            network_token_exp_month: m.network_token_exp_month,
+            network_token_exp_year: m.network_token_exp_year,
+            network_token_cryptogram_capable: m.network_token_cryptogram_capable,
+            network_token_status: m.network_token_status,
+        };
+        payment_methods.push(payment_method.clone());
+        Ok(payment_method)
+    }
+
+    async fn update_payment_method(
+        &self,
+        payment_method: storage::PaymentMethod,
+        payment_method_update: storage::PaymentMethodUpdate,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let mut payment_methods = self.payment_methods.lock().await;
+
+        let pm = payment_methods
+            .iter_mut()
+            .find(|pm| pm.id == payment_method.id)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+        *pm = payment_method_update.apply_changeset(payment_method);
+        Ok(pm.clone())
     }
 
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
+        customer_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let payment_methods = self.payment_methods.lock().await;
+
+        Ok(payment_methods
+            .iter()
+            .filter(|pm| pm.customer_id == customer_id && pm.merchant_id == merchant_id)
+            .cloned()
+            .collect())
     }
 
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
-        _merchant_id: &str,
-        _payment_method_id: &str,
+        merchant_id: &str,
+        payment_method_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_methods = self.payment_methods.lock().await;
+
+        let index = payment_methods
+            .iter()
+            .position(|pm| {
+                pm.merchant_id == merchant_id && pm.payment_method_id == payment_method_id
+            })
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+        Ok(payment_methods.remove(index))
     }
 }
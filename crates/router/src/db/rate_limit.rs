@@ -0,0 +1,121 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::core::errors::{self, CustomResult};
+
+#[async_trait::async_trait]
+pub trait RateLimitInterface {
+    /// Number of requests counted by a rate limit counter in a window.
+    async fn get_rate_limit_count(
+        &self,
+        counter: &str,
+        window: i64,
+    ) -> CustomResult<u64, errors::StorageError>;
+
+    /// Counts a request in a window, returning the number of requests counted in the window.
+    async fn increment_rate_limit_count(
+        &self,
+        counter: &str,
+        window: i64,
+        expiry: i64,
+    ) -> CustomResult<u64, errors::StorageError>;
+
+    /// Removes a request counted in a window, for requests which were rejected after being counted.
+    async fn decrement_rate_limit_count(
+        &self,
+        counter: &str,
+        window: i64,
+        expiry: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+fn rate_limit_key(counter: &str, window: i64) -> String {
+    format!("rate_limit_{counter}_{window}")
+}
+
+#[async_trait::async_trait]
+impl RateLimitInterface for Store {
+    async fn get_rate_limit_count(
+        &self,
+        counter: &str,
+        window: i64,
+    ) -> CustomResult<u64, errors::StorageError> {
+        let key = rate_limit_key(counter, window);
+        let count = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .get_key::<Option<u64>>(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get rate limit count")?;
+
+        Ok(count.unwrap_or_default())
+    }
+
+    async fn increment_rate_limit_count(
+        &self,
+        counter: &str,
+        window: i64,
+        expiry: i64,
+    ) -> CustomResult<u64, errors::StorageError> {
+        let key = rate_limit_key(counter, window);
+        let count = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .increment_key(&key, 1, expiry)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to increment rate limit count")?;
+
+        u64::try_from(count)
+            .into_report()
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Rate limit count is negative")
+    }
+
+    async fn decrement_rate_limit_count(
+        &self,
+        counter: &str,
+        window: i64,
+        expiry: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        let key = rate_limit_key(counter, window);
+        self.redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .increment_key(&key, -1, expiry)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to decrement rate limit count")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitInterface for MockDb {
+    async fn get_rate_limit_count(
+        &self,
+        _counter: &str,
+        _window: i64,
+    ) -> CustomResult<u64, errors::StorageError> {
+        Ok(0)
+    }
+
+    async fn increment_rate_limit_count(
+        &self,
+        _counter: &str,
+        _window: i64,
+        _expiry: i64,
+    ) -> CustomResult<u64, errors::StorageError> {
+        Ok(1)
+    }
+
+    async fn decrement_rate_limit_count(
+        &self,
+        _counter: &str,
+        _window: i64,
+        _expiry: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+}
@@ -779,12 +779,67 @@ impl RefundInterface for MockDb {
     #[cfg(feature = "olap")]
     async fn filter_refund_by_constraints(
         &self,
-        _merchant_id: &str,
-        _refund_details: &api_models::refunds::RefundListRequest,
-        _storage_scheme: enums::MerchantStorageScheme,
-        _limit: i64,
+        merchant_id: &str,
+        refund_details: &api_models::refunds::RefundListRequest,
+        storage_scheme: enums::MerchantStorageScheme,
+        limit: i64,
     ) -> CustomResult<Vec<storage_models::refund::Refund>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let starting_after = match &refund_details.starting_after {
+            Some(refund_id) => Some(
+                self.find_refund_by_merchant_id_refund_id(merchant_id, refund_id, storage_scheme)
+                    .await?
+                    .id,
+            ),
+            None => None,
+        };
+        let ending_before = match &refund_details.ending_before {
+            Some(refund_id) => Some(
+                self.find_refund_by_merchant_id_refund_id(merchant_id, refund_id, storage_scheme)
+                    .await?
+                    .id,
+            ),
+            None => None,
+        };
+
+        let refunds = self.refunds.lock().await;
+        let mut refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == merchant_id
+                    && refund_details
+                        .payment_id
+                        .as_ref()
+                        .map_or(true, |payment_id| &refund.payment_id == payment_id)
+                    && refund_details
+                        .created
+                        .map_or(true, |created| refund.created_at == created)
+                    && refund_details
+                        .created_lt
+                        .map_or(true, |created_lt| refund.created_at < created_lt)
+                    && refund_details
+                        .created_gt
+                        .map_or(true, |created_gt| refund.created_at > created_gt)
+                    && refund_details
+                        .created_lte
+                        .map_or(true, |created_lte| refund.created_at <= created_lte)
+                    && refund_details
+                        .created_gte
+                        .map_or(true, |created_gte| refund.created_at >= created_gte)
+                    && starting_after.map_or(true, |id| refund.id < id)
+                    && ending_before.map_or(true, |id| refund.id > id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        refunds.sort_by(|a, b| b.id.cmp(&a.id));
+        let limit = usize::try_from(limit).unwrap_or_default();
+        if ending_before.is_some() && starting_after.is_none() {
+            // The refunds right before the cursor are the oldest ones of the list
+            let skip = refunds.len().saturating_sub(limit);
+            refunds.drain(..skip);
+        } else {
+            refunds.truncate(limit);
+        }
+
+        Ok(refunds)
     }
 }
@@ -0,0 +1,429 @@
+use error_stack::{IntoReport, ResultExt};
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    services::envelope_encryption::{DecryptRecord, EncryptRecord},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait VaultInterface {
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn find_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn delete_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn insert_vault_token(
+        &self,
+        token: storage::VaultTokenNew,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError>;
+
+    async fn find_vault_token_by_merchant_id_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError>;
+
+    async fn update_vault_token_by_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+        vault_token_update: storage::VaultTokenUpdate,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError>;
+
+    async fn delete_vault_token_by_merchant_id_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn delete_expired_vault_tokens(&self) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_vault_access_event(
+        &self,
+        access_event: storage::VaultAccessEventNew,
+    ) -> CustomResult<storage::VaultAccessEvent, errors::StorageError>;
+
+    async fn find_vault_access_events_by_merchant_id_resource_id(
+        &self,
+        merchant_id: &str,
+        resource_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultAccessEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultInterface for Store {
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let key = self.get_merchant_data_key(&card.merchant_id).await?;
+        let card = card
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
+        let conn = pg_connection(&self.master_pool).await?;
+        card.insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()?
+            .decrypt_record(&key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn find_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        let card = storage::VaultCard::find_by_merchant_id_card_id(&conn, merchant_id, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        card.decrypt_record(&key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn delete_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        let card = storage::VaultCard::delete_by_merchant_id_card_id(&conn, merchant_id, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        card.decrypt_record(&key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn insert_vault_token(
+        &self,
+        token: storage::VaultTokenNew,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let key = self.get_merchant_data_key(&token.merchant_id).await?;
+        let token = token
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
+        let conn = pg_connection(&self.master_pool).await?;
+        token
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()?
+            .decrypt_record(&key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn find_vault_token_by_merchant_id_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        let token = storage::VaultToken::find_by_merchant_id_token(&conn, merchant_id, token)
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        token
+            .decrypt_record(&key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn update_vault_token_by_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+        vault_token_update: storage::VaultTokenUpdate,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        let vault_token_update = vault_token_update
+            .encrypt_record(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::VaultToken::update_by_token(&conn, token, vault_token_update)
+            .await
+            .map_err(Into::into)
+            .into_report()?
+            .decrypt_record(&key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn delete_vault_token_by_merchant_id_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::VaultToken::delete_by_merchant_id_token(&conn, merchant_id, token)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_expired_vault_tokens(&self) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::VaultToken::delete_expired(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_vault_access_event(
+        &self,
+        access_event: storage::VaultAccessEventNew,
+    ) -> CustomResult<storage::VaultAccessEvent, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        access_event
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vault_access_events_by_merchant_id_resource_id(
+        &self,
+        merchant_id: &str,
+        resource_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultAccessEvent>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::VaultAccessEvent::filter_by_merchant_id_resource_id(
+            &conn,
+            merchant_id,
+            resource_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultInterface for MockDb {
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let mut vault_cards = self.vault_cards.lock().await;
+        if vault_cards
+            .iter()
+            .any(|vault_card| vault_card.card_id == card.card_id)
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?
+        }
+
+        let vault_card = storage::VaultCard {
+            card_id: card.card_id,
+            merchant_id: card.merchant_id,
+            customer_id: card.customer_id,
+            card_number: card.card_number,
+            card_exp_month: card.card_exp_month,
+            card_exp_year: card.card_exp_year,
+            name_on_card: card.name_on_card,
+            nickname: card.nickname,
+            card_fingerprint: card.card_fingerprint,
+            created_at: common_utils::date_time::now(),
+        };
+        vault_cards.push(vault_card.clone());
+        Ok(vault_card)
+    }
+
+    async fn find_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let vault_cards = self.vault_cards.lock().await;
+        vault_cards
+            .iter()
+            .find(|vault_card| {
+                vault_card.merchant_id == merchant_id && vault_card.card_id == card_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn delete_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let mut vault_cards = self.vault_cards.lock().await;
+        let position = vault_cards
+            .iter()
+            .position(|vault_card| {
+                vault_card.merchant_id == merchant_id && vault_card.card_id == card_id
+            })
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+        Ok(vault_cards.remove(position))
+    }
+
+    async fn insert_vault_token(
+        &self,
+        token: storage::VaultTokenNew,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let mut vault_tokens = self.vault_tokens.lock().await;
+        if vault_tokens
+            .iter()
+            .any(|vault_token| vault_token.token == token.token)
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?
+        }
+
+        let vault_token = storage::VaultToken {
+            token: token.token,
+            merchant_id: token.merchant_id,
+            value1: token.value1,
+            value2: token.value2,
+            created_at: common_utils::date_time::now(),
+            expires_at: token.expires_at,
+        };
+        vault_tokens.push(vault_token.clone());
+        Ok(vault_token)
+    }
+
+    async fn find_vault_token_by_merchant_id_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let vault_tokens = self.vault_tokens.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        // Expired tokens are treated as not found, like the database query does
+        vault_tokens
+            .iter()
+            .find(|vault_token| {
+                vault_token.merchant_id == merchant_id
+                    && vault_token.token == token
+                    && vault_token.expires_at > current_time
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn update_vault_token_by_token(
+        &self,
+        _merchant_id: &str,
+        token: &str,
+        vault_token_update: storage::VaultTokenUpdate,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let mut vault_tokens = self.vault_tokens.lock().await;
+        let vault_token = vault_tokens
+            .iter_mut()
+            .find(|vault_token| vault_token.token == token)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+
+        match vault_token_update {
+            storage::VaultTokenUpdate::ValueUpdate {
+                value1,
+                value2,
+                expires_at,
+            } => {
+                vault_token.value1 = value1;
+                vault_token.value2 = value2;
+                vault_token.expires_at = expires_at;
+            }
+        }
+        Ok(vault_token.clone())
+    }
+
+    async fn delete_vault_token_by_merchant_id_token(
+        &self,
+        merchant_id: &str,
+        token: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut vault_tokens = self.vault_tokens.lock().await;
+        let count = vault_tokens.len();
+        vault_tokens.retain(|vault_token| {
+            vault_token.merchant_id != merchant_id || vault_token.token != token
+        });
+
+        if vault_tokens.len() < count {
+            Ok(true)
+        } else {
+            Err(errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into())
+        }
+    }
+
+    async fn delete_expired_vault_tokens(&self) -> CustomResult<bool, errors::StorageError> {
+        let mut vault_tokens = self.vault_tokens.lock().await;
+        let current_time = common_utils::date_time::now();
+        let count = vault_tokens.len();
+        vault_tokens.retain(|vault_token| vault_token.expires_at > current_time);
+
+        Ok(vault_tokens.len() < count)
+    }
+
+    async fn insert_vault_access_event(
+        &self,
+        access_event: storage::VaultAccessEventNew,
+    ) -> CustomResult<storage::VaultAccessEvent, errors::StorageError> {
+        let mut vault_access_events = self.vault_access_events.lock().await;
+        let access_event = storage::VaultAccessEvent {
+            id: i32::try_from(vault_access_events.len())
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: access_event.merchant_id,
+            resource_type: access_event.resource_type,
+            resource_id: access_event.resource_id,
+            action: access_event.action,
+            created_at: common_utils::date_time::now(),
+        };
+        vault_access_events.push(access_event.clone());
+        Ok(access_event)
+    }
+
+    async fn find_vault_access_events_by_merchant_id_resource_id(
+        &self,
+        merchant_id: &str,
+        resource_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultAccessEvent>, errors::StorageError> {
+        let vault_access_events = self.vault_access_events.lock().await;
+        let limit = usize::try_from(limit)
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?;
+
+        // Most recent events first, like the database query
+        Ok(vault_access_events
+            .iter()
+            .rev()
+            .filter(|access_event| {
+                access_event.merchant_id == merchant_id && access_event.resource_id == resource_id
+            })
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
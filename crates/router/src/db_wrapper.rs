@@ -9,6 +9,7 @@ use storage_models::enums::MerchantStorageScheme;
 use crate::{
     core::errors::{self, RouterResult, StorageErrorExt},
     db::StorageInterface,
+    services::envelope_encryption::Decryptable,
     types::storage::{self as storage_types},
 };
 
@@ -235,6 +236,7 @@ impl QueryEntity for EntityRequest {
             EntityRequest::Address { address_id } => Entity::Address(
                 db.find_address(address_id)
                     .await
+                    .and_then(Decryptable::decrypt)
                     .change_context(errors::ApiErrorResponse::AddressNotFound), //FIXME: do not change context
             ),
             EntityRequest::ConnectorResponse {
@@ -19,6 +19,8 @@ pub mod middleware;
 #[cfg(feature = "openapi")]
 pub mod openapi;
 pub mod services;
+#[cfg(test)]
+pub(crate) mod test_utils;
 pub mod types;
 pub mod utils;
 
@@ -99,6 +101,17 @@ pub fn mk_app(
             .service(routes::Mandates::server(state.clone()));
     }
 
+    #[cfg(feature = "oltp")]
+    {
+        server_app = server_app
+            .service(routes::PaymentLink::server(state.clone()))
+            .service(routes::Vault::server(state.clone()));
+        // Token lifecycle events are only accepted when network tokenization is enabled
+        if state.conf.network_tokenization.enabled {
+            server_app = server_app.service(routes::NetworkTokens::server(state.clone()));
+        }
+    }
+
     #[cfg(feature = "oltp")]
     {
         server_app = server_app
@@ -111,7 +124,10 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
-            .service(routes::ApiKeys::server(state.clone()));
+            .service(routes::Organization::server(state.clone()))
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::AdminPrincipals::server(state.clone()))
+            .service(routes::AuditEvents::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
@@ -51,12 +51,17 @@ Never share your secret api keys. Keep them guarded and secure.
     ),
     tags(
         (name = "Merchant Account", description = "Create and manage merchant accounts"),
+        (name = "Organization", description = "Create and manage organizations of merchant accounts"),
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Payment Link", description = "Track payment links shared with customers"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
+        (name = "Vault", description = "Store sensitive data in the vault in exchange for tokens"),
+        (name = "Admin Principal", description = "Create and manage admin principals with scoped access to the admin APIs"),
+        (name = "Audit Event", description = "List the changes made through the admin APIs"),
         // (name = "API Key", description = "Create and manage API Keys"),
     ),
     paths(
@@ -68,13 +73,20 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::admin::retrieve_merchant_account,
         crate::routes::admin::update_merchant_account,
         crate::routes::admin::delete_merchant_account,
+        crate::routes::admin::organization_create,
+        crate::routes::admin::organization_retrieve,
+        crate::routes::admin::organization_update,
+        crate::routes::admin::organization_merchant_accounts_list,
         crate::routes::admin::payment_connector_create,
         crate::routes::admin::payment_connector_retrieve,
         crate::routes::admin::payment_connector_list,
+        crate::routes::admin::payment_connector_health,
+        crate::routes::admin::connector_call_logs_retrieve,
         crate::routes::admin::payment_connector_update,
         crate::routes::admin::payment_connector_delete,
         crate::routes::mandates::get_mandate,
         crate::routes::mandates::revoke_mandate,
+        crate::routes::payment_link::retrieve_payment_link,
         crate::routes::payments::payments_create,
        // crate::routes::payments::payments_start,
         crate::routes::payments::payments_retrieve,
@@ -88,6 +100,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
         crate::routes::payment_methods::list_customer_payment_method_api,
+        crate::routes::payment_methods::default_payment_method_set_api,
         crate::routes::payment_methods::payment_method_retrieve_api,
         crate::routes::payment_methods::payment_method_update_api,
         crate::routes::payment_methods::payment_method_delete_api,
@@ -95,6 +108,13 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::customers::customers_retrieve,
         crate::routes::customers::customers_update,
         crate::routes::customers::customers_delete,
+        crate::routes::vault::tokenize_data,
+        crate::routes::vault::detokenize_data,
+        crate::routes::vault::delete_token,
+        crate::routes::admin_principals::admin_principal_create,
+        crate::routes::admin_principals::admin_principal_list,
+        crate::routes::admin_principals::admin_principal_revoke,
+        crate::routes::audit_events::audit_event_list,
         // crate::routes::api_keys::api_key_create,
         // crate::routes::api_keys::api_key_retrieve,
         // crate::routes::api_keys::api_key_update,
@@ -118,6 +138,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::payment_methods::CustomerPaymentMethod,
         crate::types::api::payment_methods::ListPaymentMethodResponse,
         crate::types::api::payment_methods::ListCustomerPaymentMethodsResponse,
+        crate::types::api::payment_methods::CustomerDefaultPaymentMethodResponse,
         crate::types::api::payment_methods::DeletePaymentMethodResponse,
         crate::types::api::payment_methods::UpdatePaymentMethod,
         crate::types::api::payment_methods::CardDetailFromLocker,
@@ -125,10 +146,17 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::customers::CustomerResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
+        api_models::admin::PaymentMethodFilter,
+        api_models::admin::ConnectorHealthResponse,
+        api_models::admin::ConnectorHealth,
+        api_models::admin::ConnectorCallLogResponse,
+        api_models::admin::ConnectorCallLog,
         api_models::enums::RoutingAlgorithm,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodType,
         api_models::enums::ConnectorType,
+        api_models::enums::CircuitState,
+        api_models::enums::OutgoingWebhookFormat,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
@@ -139,6 +167,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::SupportedWallets,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::PaymentLinkStatus,
+        api_models::enums::Permission,
+        api_models::enums::AuditActorType,
+        api_models::enums::FraudCheckDecision,
+        api_models::enums::AuditAction,
+        api_models::enums::AuditResourceType,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::CardNetwork,
@@ -147,12 +181,17 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::AddressDetails,
         api_models::payments::Address,
         api_models::payments::BankRedirectData,
+        api_models::payments::BankDebitData,
+        api_models::payments::BankDebitBilling,
+        api_models::payments::BankTransferData,
+        api_models::payments::BankTransferBilling,
         api_models::payments::BankRedirectBilling,
         api_models::payments::OrderDetails,
         api_models::payments::NextActionType,
         api_models::payments::Metadata,
         api_models::payments::WalletData,
         api_models::payments::NextAction,
+        api_models::payments::BankTransferInstructions,
         api_models::payments::PayLaterData,
         api_models::payments::MandateData,
         api_models::payments::PhoneDetails,
@@ -165,6 +204,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::CustomerAcceptance,
         api_models::payments::PaymentsRequest,
         api_models::payments::PaymentsResponse,
+        api_models::payments::PaymentLinkRequest,
+        api_models::payments::PaymentLinkResponse,
         api_models::payments::PaymentsStartRequest,
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
@@ -204,13 +245,26 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::admin::MerchantAccountResponse,
         crate::types::api::admin::MerchantConnectorId,
         crate::types::api::admin::MerchantDetails,
+        crate::types::api::admin::CreateOrganizationResponse,
+        crate::types::api::admin::OrganizationRequest,
+        crate::types::api::admin::OrganizationResponse,
         crate::types::api::admin::WebhookDetails,
         crate::types::api::api_keys::ApiKeyExpiration,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
         crate::types::api::api_keys::RevokeApiKeyResponse,
-        crate::types::api::api_keys::UpdateApiKeyRequest
+        crate::types::api::api_keys::UpdateApiKeyRequest,
+        crate::types::api::admin_principals::CreateAdminPrincipalRequest,
+        crate::types::api::admin_principals::CreateAdminPrincipalResponse,
+        crate::types::api::admin_principals::AdminPrincipalResponse,
+        crate::types::api::admin_principals::RevokeAdminPrincipalResponse,
+        crate::types::api::audit_events::AuditEventResponse,
+        api_models::vault::TokenizeRequest,
+        api_models::vault::TokenizeResponse,
+        api_models::vault::DetokenizeRequest,
+        api_models::vault::DetokenizeResponse,
+        api_models::vault::DeleteTokenResponse
     )),
     modifiers(&SecurityAddon)
 )]
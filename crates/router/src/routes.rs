@@ -1,21 +1,27 @@
 pub mod admin;
+pub mod admin_principals;
 pub mod api_keys;
 pub mod app;
+pub mod audit_events;
 pub mod configs;
 pub mod customers;
 pub mod ephemeral_key;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
+pub mod network_tokenization;
+pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod vault;
 pub mod webhooks;
 
 pub use self::app::{
-    ApiKeys, AppState, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    AdminPrincipals, ApiKeys, AppState, AuditEvents, Configs, Customers, EphemeralKey, Health,
+    Mandates, MerchantAccount, MerchantConnectorAccount, NetworkTokens, Organization, PaymentLink,
+    PaymentMethods, Payments, Payouts, Refunds, Vault, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::admin::*,
-    services::{api, authentication as auth},
+    services::{api, audit, authentication as auth},
     types::api::admin,
 };
 
@@ -35,6 +35,7 @@ pub async fn merchant_account_create(
         json_payload.into_inner(),
         |state, _, req| create_merchant_account(&*state.store, req),
         &auth::AdminApiAuth,
+        auth::Permission::MerchantAccountsWrite,
     )
     .await
 }
@@ -70,6 +71,7 @@ pub async fn retrieve_merchant_account(
         payload,
         |state, _, req| get_merchant_account(&*state.store, req),
         &auth::AdminApiAuth,
+        auth::Permission::MerchantAccountsRead,
     )
     .await
 }
@@ -98,12 +100,17 @@ pub async fn update_merchant_account(
     json_payload: web::Json<admin::CreateMerchantAccount>,
 ) -> HttpResponse {
     let merchant_id = mid.into_inner();
+    let request_id = audit::get_request_id(&req);
     api::server_wrap(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
-        |state, _, req| merchant_account_update(&*state.store, &merchant_id, req),
+        |state, actor, req| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            merchant_account_update(&*state.store, &merchant_id, req, audit_context)
+        },
         &auth::AdminApiAuth,
+        auth::Permission::MerchantAccountsWrite,
     )
     .await
 }
@@ -140,6 +147,144 @@ pub async fn delete_merchant_account(
         payload,
         |state, _, req| merchant_account_delete(&*state.store, req.merchant_id),
         &auth::AdminApiAuth,
+        auth::Permission::MerchantAccountsWrite,
+    )
+    .await
+}
+
+/// Organization - Create
+///
+/// Create a new organization, which groups merchant accounts. The API key of the organization can be used to access the APIs on behalf of any merchant account of the organization.
+#[utoipa::path(
+    post,
+    path = "/organizations",
+    request_body = OrganizationRequest,
+    responses(
+        (status = 200, description = "Organization Created", body = CreateOrganizationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Organization",
+    operation_id = "Create an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationCreate))]
+pub async fn organization_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<admin::OrganizationRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| create_organization(&*state.store, req),
+        &auth::AdminApiAuth,
+        auth::Permission::OrganizationsWrite,
+    )
+    .await
+}
+
+/// Organization - Retrieve
+///
+/// Retrieve an organization.
+#[utoipa::path(
+    get,
+    path = "/organizations/{org_id}",
+    params (("org_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Organization Retrieved", body = OrganizationResponse),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Retrieve an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationRetrieve))]
+pub async fn organization_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    org_id: web::Path<String>,
+) -> HttpResponse {
+    let payload = admin::OrganizationId {
+        org_id: org_id.into_inner(),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| get_organization(&*state.store, req),
+        &auth::AdminApiAuth,
+        auth::Permission::OrganizationsRead,
+    )
+    .await
+}
+
+/// Organization - Update
+///
+/// Update an organization.
+#[utoipa::path(
+    post,
+    path = "/organizations/{org_id}",
+    request_body = OrganizationRequest,
+    params (("org_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Organization Updated", body = OrganizationResponse),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Update an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationUpdate))]
+pub async fn organization_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    org_id: web::Path<String>,
+    json_payload: web::Json<admin::OrganizationRequest>,
+) -> HttpResponse {
+    let org_id = org_id.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| update_organization(&*state.store, &org_id, req),
+        &auth::AdminApiAuth,
+        auth::Permission::OrganizationsWrite,
+    )
+    .await
+}
+
+/// Organization - List Merchant Accounts
+///
+/// List the merchant accounts of an organization.
+#[utoipa::path(
+    get,
+    path = "/organizations/{org_id}/accounts",
+    params (("org_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Merchant Accounts of the Organization", body = Vec<MerchantAccountResponse>),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "List the Merchant Accounts of an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationMerchantAccountsList))]
+pub async fn organization_merchant_accounts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    org_id: web::Path<String>,
+) -> HttpResponse {
+    let payload = admin::OrganizationId {
+        org_id: org_id.into_inner(),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| list_organization_merchant_accounts(&*state.store, req),
+        &auth::AdminApiAuth,
+        auth::Permission::OrganizationsRead,
     )
     .await
 }
@@ -167,12 +312,17 @@ pub async fn payment_connector_create(
     json_payload: web::Json<admin::PaymentConnectorCreate>,
 ) -> HttpResponse {
     let merchant_id = path.into_inner();
+    let request_id = audit::get_request_id(&req);
     api::server_wrap(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
-        |state, _, req| create_payment_connector(&*state.store, req, &merchant_id),
+        |state, actor, req| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            create_payment_connector(&*state.store, req, &merchant_id, audit_context)
+        },
         &auth::AdminApiAuth,
+        auth::Permission::ConnectorsWrite,
     )
     .await
 }
@@ -216,6 +366,7 @@ pub async fn payment_connector_retrieve(
             retrieve_payment_connector(&*state.store, req.merchant_id, req.merchant_connector_id)
         },
         &auth::AdminApiAuth,
+        auth::Permission::ConnectorsRead,
     )
     .await
 }
@@ -251,6 +402,82 @@ pub async fn payment_connector_list(
         merchant_id,
         |state, _, merchant_id| list_payment_connectors(&*state.store, merchant_id),
         &auth::AdminApiAuth,
+        auth::Permission::ConnectorsRead,
+    )
+    .await
+}
+
+/// Payment Connector - Health
+///
+/// Retrieve the circuit breaker state and call statistics of the Payment Connectors configured for the merchant
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/health",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Payment Connector health retrieved successfully", body = ConnectorHealthResponse),
+        (status = 404, description = "Merchant Account does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve Merchant Connectors Health",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentConnectorsHealth))]
+pub async fn payment_connector_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| get_payment_connectors_health(state, merchant_id),
+        &auth::AdminApiAuth,
+        auth::Permission::ConnectorsRead,
+    )
+    .await
+}
+
+/// Payment - Connector Call Logs
+///
+/// Retrieve the requests sent to connectors while processing a payment, with sensitive data masked
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/payments/{payment_id}/connector_logs",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("payment_id" = String, Path, description = "The unique identifier for the payment"),
+    ),
+    responses(
+        (status = 200, description = "Connector call logs retrieved successfully", body = ConnectorCallLogResponse),
+        (status = 404, description = "Merchant Account or Payment does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve Connector Call Logs of a Payment",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCallLogsRetrieve))]
+pub async fn connector_call_logs_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (merchant_id, payment_id) = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (merchant_id, payment_id),
+        |state, _, (merchant_id, payment_id)| {
+            get_connector_call_logs(&*state.store, merchant_id, payment_id)
+        },
+        &auth::AdminApiAuth,
+        auth::Permission::ConnectorsRead,
     )
     .await
 }
@@ -283,14 +510,23 @@ pub async fn payment_connector_update(
     json_payload: web::Json<admin::PaymentConnectorCreate>,
 ) -> HttpResponse {
     let (merchant_id, merchant_connector_id) = path.into_inner();
+    let request_id = audit::get_request_id(&req);
     api::server_wrap(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
-        |state, _, req| {
-            update_payment_connector(&*state.store, &merchant_id, &merchant_connector_id, req)
+        |state, actor, req| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            update_payment_connector(
+                &*state.store,
+                &merchant_id,
+                &merchant_connector_id,
+                req,
+                audit_context,
+            )
         },
         &auth::AdminApiAuth,
+        auth::Permission::ConnectorsWrite,
     )
     .await
 }
@@ -326,14 +562,22 @@ pub async fn payment_connector_delete(
         merchant_connector_id,
     })
     .into_inner();
+    let request_id = audit::get_request_id(&req);
     api::server_wrap(
         state.get_ref(),
         &req,
         payload,
-        |state, _, req| {
-            delete_payment_connector(&*state.store, req.merchant_id, req.merchant_connector_id)
+        |state, actor, req| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            delete_payment_connector(
+                &*state.store,
+                req.merchant_id,
+                req.merchant_connector_id,
+                audit_context,
+            )
         },
         &auth::AdminApiAuth,
+        auth::Permission::ConnectorsWrite,
     )
     .await
 }
@@ -350,14 +594,22 @@ pub async fn merchant_account_toggle_kv(
 ) -> HttpResponse {
     let payload = json_payload.into_inner();
     let merchant_id = path.into_inner();
+    let request_id = audit::get_request_id(&req);
     api::server_wrap(
         state.get_ref(),
         &req,
         (merchant_id, payload),
-        |state, _, (merchant_id, payload)| {
-            kv_for_merchant(&*state.store, merchant_id, payload.kv_enabled)
+        |state, actor, (merchant_id, payload)| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            kv_for_merchant(
+                &*state.store,
+                merchant_id,
+                payload.kv_enabled,
+                audit_context,
+            )
         },
         &auth::AdminApiAuth,
+        auth::Permission::MerchantAccountsWrite,
     )
     .await
 }
@@ -378,6 +630,23 @@ pub async fn merchant_account_kv_status(
         merchant_id,
         |state, _, req| check_merchant_account_kv_status(&*state.store, req),
         &auth::AdminApiAuth,
+        auth::Permission::MerchantAccountsRead,
+    )
+    .await
+}
+
+/// Merchant Account - Rotate Master Key
+///
+/// Schedule re-wrapping the data keys of all merchants with the active master key
+#[instrument(skip_all)]
+pub async fn merchant_key_rotation(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, _, _| schedule_merchant_key_rotation(state),
+        &auth::AdminApiAuth,
+        auth::Permission::MerchantAccountsWrite,
     )
     .await
 }
@@ -0,0 +1,104 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::admin_principals,
+    services::{api, authentication as auth},
+    types::api::admin_principals as admin_principals_api,
+};
+
+/// Admin Principal - Create
+///
+/// Create a new admin principal, with its own admin API Key and permissions on the admin APIs. The
+/// plaintext admin API Key will be displayed only once on creation, so ensure you store it
+/// securely.
+#[utoipa::path(
+    post,
+    path = "/admin_principals",
+    request_body = CreateAdminPrincipalRequest,
+    responses(
+        (status = 200, description = "Admin principal created", body = CreateAdminPrincipalResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Admin Principal",
+    operation_id = "Create an Admin Principal",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AdminPrincipalCreate))]
+pub async fn admin_principal_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<admin_principals_api::CreateAdminPrincipalRequest>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload| admin_principals::create_admin_principal(&*state.store, payload),
+        &auth::AdminApiAuth,
+        auth::Permission::AdminPrincipalsWrite,
+    )
+    .await
+}
+
+/// Admin Principal - List
+///
+/// List all the admin principals.
+#[utoipa::path(
+    get,
+    path = "/admin_principals",
+    responses(
+        (status = 200, description = "List of admin principals retrieved successfully", body = Vec<AdminPrincipalResponse>),
+    ),
+    tag = "Admin Principal",
+    operation_id = "List all Admin Principals",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AdminPrincipalList))]
+pub async fn admin_principal_list(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, _, _| admin_principals::list_admin_principals(&*state.store),
+        &auth::AdminApiAuth,
+        auth::Permission::AdminPrincipalsRead,
+    )
+    .await
+}
+
+/// Admin Principal - Revoke
+///
+/// Revoke the specified admin principal. Once revoked, its admin API Key can no longer be used for
+/// authenticating with the admin APIs.
+#[utoipa::path(
+    delete,
+    path = "/admin_principals/{principal_id}",
+    params (("principal_id" = String, Path, description = "The unique identifier for the admin principal")),
+    responses(
+        (status = 200, description = "Admin principal revoked", body = RevokeAdminPrincipalResponse),
+        (status = 404, description = "Admin principal not found")
+    ),
+    tag = "Admin Principal",
+    operation_id = "Revoke an Admin Principal",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AdminPrincipalRevoke))]
+pub async fn admin_principal_revoke(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        |state, _, principal_id| {
+            admin_principals::revoke_admin_principal(&*state.store, principal_id)
+        },
+        &auth::AdminApiAuth,
+        auth::Permission::AdminPrincipalsWrite,
+    )
+    .await
+}
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::api_keys,
-    services::{api, authentication as auth},
+    services::{api, audit, authentication as auth},
     types::api as api_types,
 };
 
@@ -33,15 +33,18 @@ pub async fn api_key_create(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
     let merchant_id = path.into_inner();
+    let request_id = audit::get_request_id(&req);
 
     api::server_wrap(
         state.get_ref(),
         &req,
         payload,
-        |state, _, payload| async {
-            api_keys::create_api_key(&*state.store, payload, merchant_id.clone()).await
+        |state, actor, payload| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            api_keys::create_api_key(&*state.store, payload, merchant_id.clone(), audit_context)
         },
         &auth::AdminApiAuth,
+        auth::Permission::ApiKeysWrite,
     )
     .await
 }
@@ -75,6 +78,7 @@ pub async fn api_key_retrieve(
         &key_id,
         |state, _, key_id| api_keys::retrieve_api_key(&*state.store, key_id),
         &auth::AdminApiAuth,
+        auth::Permission::ApiKeysRead,
     )
     .await
 }
@@ -104,13 +108,18 @@ pub async fn api_key_update(
 ) -> impl Responder {
     let (_merchant_id, key_id) = path.into_inner();
     let payload = json_payload.into_inner();
+    let request_id = audit::get_request_id(&req);
 
     api::server_wrap(
         state.get_ref(),
         &req,
         (&key_id, payload),
-        |state, _, (key_id, payload)| api_keys::update_api_key(&*state.store, key_id, payload),
+        |state, actor, (key_id, payload)| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            api_keys::update_api_key(&*state.store, key_id, payload, audit_context)
+        },
         &auth::AdminApiAuth,
+        auth::Permission::ApiKeysWrite,
     )
     .await
 }
@@ -138,13 +147,18 @@ pub async fn api_key_revoke(
     path: web::Path<(String, String)>,
 ) -> impl Responder {
     let (_merchant_id, key_id) = path.into_inner();
+    let request_id = audit::get_request_id(&req);
 
     api::server_wrap(
         state.get_ref(),
         &req,
         &key_id,
-        |state, _, key_id| api_keys::revoke_api_key(&*state.store, key_id),
+        |state, actor, key_id| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            api_keys::revoke_api_key(&*state.store, key_id, audit_context)
+        },
         &auth::AdminApiAuth,
+        auth::Permission::ApiKeysWrite,
     )
     .await
 }
@@ -186,6 +200,7 @@ pub async fn api_key_list(
             api_keys::list_api_keys(&*state.store, merchant_id, limit, offset).await
         },
         &auth::AdminApiAuth,
+        auth::Permission::ApiKeysRead,
     )
     .await
 }
@@ -1,16 +1,24 @@
+use std::sync::Arc;
+
 use actix_web::{web, Scope};
 
 use super::health::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*};
+use super::{admin::*, admin_principals::*, api_keys::*, audit_events::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{
+    ephemeral_key::*, network_tokenization::*, payment_link::*, payment_methods::*, vault::*,
+    webhooks::*,
+};
 use crate::{
-    configs::settings::Settings,
+    configs::{
+        dynamic::{self, DynamicConfig},
+        settings::Settings,
+    },
     db::{MockDb, StorageImpl, StorageInterface},
-    services::Store,
+    services::{secrets_management, Store},
 };
 
 #[derive(Clone)]
@@ -18,6 +26,9 @@ pub struct AppState {
     pub flow_name: String,
     pub store: Box<dyn StorageInterface>,
     pub conf: Settings,
+    /// Settings which can be overridden at runtime, these take precedence over the ones in `conf`
+    pub dynamic_config: Arc<DynamicConfig>,
+    pub secrets_manager: Arc<dyn secrets_management::SecretManagementInterface>,
 }
 
 pub trait AppStateInfo {
@@ -40,10 +51,26 @@ impl AppStateInfo for AppState {
 
 impl AppState {
     pub async fn with_storage(conf: Settings, storage_impl: StorageImpl) -> Self {
+        #[allow(clippy::expect_used)]
+        let secrets_manager = secrets_management::get_secrets_manager(&conf.secrets_management)
+            .await
+            .expect("Failed to create the secrets manager");
+        #[allow(clippy::expect_used)]
+        let conf = conf
+            .decrypt_secrets(&*secrets_manager)
+            .await
+            .expect("Failed to decrypt the secrets in the configuration");
+
+        let dynamic_config = Arc::new(DynamicConfig::new(&conf));
         let testable = storage_impl == StorageImpl::PostgresqlTest;
         let store: Box<dyn StorageInterface> = match storage_impl {
             StorageImpl::Postgresql | StorageImpl::PostgresqlTest => {
-                Box::new(Store::new(&conf, testable).await)
+                let store = Store::new(&conf, testable).await;
+                let redis_conn = store.redis_conn.clone();
+                let store: Box<dyn StorageInterface> = Box::new(store);
+                dynamic::start_refresh_listener(dynamic_config.clone(), store.clone(), redis_conn)
+                    .await;
+                store
             }
             StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
         };
@@ -52,6 +79,8 @@ impl AppState {
             flow_name: String::from("default"),
             store,
             conf,
+            dynamic_config,
+            secrets_manager,
         }
     }
 
@@ -68,6 +97,7 @@ impl Health {
         web::scope("")
             .app_data(web::Data::new(state))
             .service(web::resource("/health").route(web::get().to(health)))
+            .service(web::resource("/health/deep").route(web::get().to(deep_health_check)))
     }
 }
 
@@ -145,6 +175,10 @@ impl Customers {
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
+                        .route(web::post().to(default_payment_method_set_api)),
                 );
         }
         route
@@ -230,6 +264,7 @@ impl MerchantAccount {
         web::scope("/accounts")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(merchant_account_create)))
+            .service(web::resource("/keys/rotate").route(web::post().to(merchant_key_rotation)))
             .service(
                 web::resource("/{id}/kv")
                     .route(web::post().to(merchant_account_toggle_kv))
@@ -244,6 +279,55 @@ impl MerchantAccount {
     }
 }
 
+pub struct Organization;
+
+#[cfg(feature = "olap")]
+impl Organization {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/organizations")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(organization_create)))
+            .service(
+                web::resource("/{org_id}")
+                    .route(web::get().to(organization_retrieve))
+                    .route(web::post().to(organization_update)),
+            )
+            .service(
+                web::resource("/{org_id}/accounts")
+                    .route(web::get().to(organization_merchant_accounts_list)),
+            )
+    }
+}
+
+pub struct AdminPrincipals;
+
+#[cfg(feature = "olap")]
+impl AdminPrincipals {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/admin_principals")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(admin_principal_create))
+                    .route(web::get().to(admin_principal_list)),
+            )
+            .service(
+                web::resource("/{principal_id}").route(web::delete().to(admin_principal_revoke)),
+            )
+    }
+}
+
+pub struct AuditEvents;
+
+#[cfg(feature = "olap")]
+impl AuditEvents {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(audit_event_list)))
+    }
+}
+
 pub struct MerchantConnectorAccount;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -261,6 +345,14 @@ impl MerchantConnectorAccount {
                         .route(web::post().to(payment_connector_create))
                         .route(web::get().to(payment_connector_list)),
                 )
+                .service(
+                    web::resource("/{merchant_id}/connectors/health")
+                        .route(web::get().to(payment_connector_health)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/payments/{payment_id}/connector_logs")
+                        .route(web::get().to(connector_call_logs_retrieve)),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors/{merchant_connector_id}")
                         .route(web::get().to(payment_connector_retrieve))
@@ -310,6 +402,49 @@ impl Mandates {
     }
 }
 
+pub struct PaymentLink;
+
+#[cfg(feature = "oltp")]
+impl PaymentLink {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payment_link")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{payment_link_id}").route(web::get().to(retrieve_payment_link)),
+            )
+            .service(
+                web::resource("/pay/{payment_link_id}").route(web::get().to(initiate_payment_link)),
+            )
+    }
+}
+
+pub struct NetworkTokens;
+
+#[cfg(feature = "oltp")]
+impl NetworkTokens {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/network_tokens")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/lifecycle")
+                    .route(web::post().to(receive_network_token_lifecycle_event)),
+            )
+    }
+}
+
+pub struct Vault;
+
+#[cfg(feature = "oltp")]
+impl Vault {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/vault")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/tokenize").route(web::post().to(tokenize_data)))
+            .service(web::resource("/detokenize").route(web::post().to(detokenize_data)))
+            .service(web::resource("/tokens/{token}").route(web::delete().to(delete_token)))
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(feature = "oltp")]
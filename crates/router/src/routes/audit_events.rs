@@ -0,0 +1,52 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::audit_events,
+    services::{api, authentication as auth},
+    types::api::audit_events as audit_events_api,
+};
+
+/// Audit Event - List
+///
+/// List the changes made through the admin APIs to merchant accounts, payment connectors, API Keys
+/// and configs, most recent first. Secrets, such as the credentials of payment connectors, are
+/// masked.
+#[utoipa::path(
+    get,
+    path = "/audit_events",
+    params(
+        ("merchant_id" = Option<String>, Query, description = "Only list the events of the merchant account"),
+        ("resource_type" = Option<AuditResourceType>, Query, description = "Only list the events of the kind of resource"),
+        ("resource_id" = Option<String>, Query, description = "Only list the events of the resource"),
+        ("actor_id" = Option<String>, Query, description = "Only list the events of the actor, such as an admin principal ID"),
+        ("created.gte" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded at or after this time"),
+        ("created.lte" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded at or before this time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of audit events to include in the response"),
+        ("skip" = Option<i64>, Query, description = "The number of audit events to skip when retrieving the list of audit events"),
+    ),
+    responses(
+        (status = 200, description = "List of audit events retrieved successfully", body = Vec<AuditEventResponse>),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Audit Event",
+    operation_id = "List Audit Events",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AuditEventList))]
+pub async fn audit_event_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<audit_events_api::AuditEventListConstraints>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query.into_inner(),
+        |state, _, constraints| audit_events::list_audit_events(&*state.store, constraints),
+        &auth::AdminApiAuth,
+        auth::Permission::AuditEventsRead,
+    )
+    .await
+}
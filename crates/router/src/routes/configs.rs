@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::configs,
-    services::{api, authentication as auth},
+    services::{api, audit, authentication as auth},
     types::api as api_types,
 };
 
@@ -22,6 +22,7 @@ pub async fn config_key_retrieve(
         &key,
         |state, _, key| configs::read_config(&*state.store, key),
         &auth::AdminApiAuth,
+        auth::Permission::ConfigsRead,
     )
     .await
 }
@@ -36,13 +37,18 @@ pub async fn config_key_update(
     let mut payload = json_payload.into_inner();
     let key = path.into_inner();
     payload.key = key;
+    let request_id = audit::get_request_id(&req);
 
     api::server_wrap(
         state.get_ref(),
         &req,
         &payload,
-        |state, _, payload| configs::update_config(&*state.store, payload),
+        |state, actor, payload| {
+            let audit_context = audit::AuditContext::new(actor, request_id.clone());
+            configs::update_config(state, payload, audit_context)
+        },
         &auth::AdminApiAuth,
+        auth::Permission::ConfigsWrite,
     )
     .await
 }
@@ -35,6 +35,7 @@ pub async fn customers_create(
         json_payload.into_inner(),
         |state, merchant_account, req| create_customer(&*state.store, merchant_account, req),
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
@@ -77,6 +78,7 @@ pub async fn customers_retrieve(
         payload,
         |state, merchant_account, req| retrieve_customer(&*state.store, merchant_account, req),
         &*auth,
+        auth::Permission::CustomersRead,
     )
     .await
 }
@@ -112,6 +114,7 @@ pub async fn customers_update(
         json_payload.into_inner(),
         |state, merchant_account, req| update_customer(&*state.store, merchant_account, req),
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
@@ -147,6 +150,7 @@ pub async fn customers_delete(
         payload,
         delete_customer,
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
@@ -169,6 +173,7 @@ pub async fn get_customer_mandates(
             crate::core::mandate::get_customer_mandates(state, merchant_account, req)
         },
         &auth::ApiKeyAuth,
+        auth::Permission::MandatesRead,
     )
     .await
 }
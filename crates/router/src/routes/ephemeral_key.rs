@@ -23,6 +23,7 @@ pub async fn ephemeral_key_create(
             helpers::make_ephemeral_key(state, req.customer_id, merchant_account.merchant_id)
         },
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
@@ -40,6 +41,7 @@ pub async fn ephemeral_key_delete(
         payload,
         |state, _, req| helpers::delete_ephemeral_key(&*state.store, req),
         &auth::ApiKeyAuth,
+        auth::Permission::CustomersWrite,
     )
     .await
 }
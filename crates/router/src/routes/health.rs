@@ -1,6 +1,9 @@
+use actix_web::web;
+use api_models::health_check::HealthStatus;
 use router_env::{instrument, logger, tracing};
 
-use crate::routes::metrics;
+use super::app::AppState;
+use crate::{core::health_check, routes::metrics};
 
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
@@ -11,3 +14,17 @@ pub async fn health() -> impl actix_web::Responder {
     logger::info!("Health was called");
     actix_web::HttpResponse::Ok().body("health is good")
 }
+
+/// Checks the health of every component the application depends on, responding with a
+/// `503 Service Unavailable` if any component required to serve requests is unhealthy.
+#[instrument(skip_all)]
+pub async fn deep_health_check(state: web::Data<AppState>) -> impl actix_web::Responder {
+    metrics::HEALTH_METRIC.add(&metrics::CONTEXT, 1, &[]);
+    logger::info!("Deep health check was called");
+    let response = health_check::deep_health_check(&state).await;
+    let status_code = match response.status {
+        HealthStatus::Unhealthy => actix_web::http::StatusCode::SERVICE_UNAVAILABLE,
+        HealthStatus::Healthy | HealthStatus::Degraded => actix_web::http::StatusCode::OK,
+    };
+    actix_web::HttpResponse::build(status_code).json(response)
+}
@@ -41,6 +41,7 @@ pub async fn get_mandate(
         mandate_id,
         mandate::get_mandate,
         &auth::ApiKeyAuth,
+        auth::Permission::MandatesRead,
     )
     .await
 }
@@ -80,6 +81,7 @@ pub async fn revoke_mandate(
             mandate::revoke_mandate(&*state.store, merchant_account, req)
         },
         &auth::ApiKeyAuth,
+        auth::Permission::MandatesWrite,
     )
     .await
 }
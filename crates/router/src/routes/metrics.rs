@@ -1,7 +1,8 @@
 use once_cell::sync::Lazy;
+pub use router_env::opentelemetry::KeyValue;
 use router_env::opentelemetry::{
     global,
-    metrics::{Counter, Meter},
+    metrics::{Counter, Histogram, Meter},
     Context,
 };
 
@@ -13,3 +14,38 @@ pub(crate) static HEALTH_METRIC: Lazy<Counter<u64>> =
 
 pub(crate) static KV_MISS: Lazy<Counter<u64>> =
     Lazy::new(|| GLOBAL_METER.u64_counter("KV_MISS").init());
+
+pub(crate) static CIRCUIT_BREAKER_OPENED: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("CIRCUIT_BREAKER_OPENED").init());
+
+pub(crate) static CIRCUIT_BREAKER_REJECTED_CALLS: Lazy<Counter<u64>> = Lazy::new(|| {
+    GLOBAL_METER
+        .u64_counter("CIRCUIT_BREAKER_REJECTED_CALLS")
+        .init()
+});
+
+// Requests rejected for exceeding a rate limit, by route group
+pub(crate) static RATE_LIMITED_REQUESTS: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("RATE_LIMITED_REQUESTS").init());
+
+// Payments checked for fraud, by FRM connector and decision
+pub(crate) static FRAUD_CHECK_DECISIONS: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("FRAUD_CHECK_DECISIONS").init());
+
+// Payment attempts sent to a connector, by connector, payment method, currency and the
+// resulting attempt status
+pub(crate) static PAYMENT_ATTEMPT_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("PAYMENT_ATTEMPT_COUNT").init());
+
+// Time in (ms) milliseconds taken by a connector to process a payment operation
+pub(crate) static CONNECTOR_REQUEST_TIME: Lazy<Histogram<f64>> =
+    Lazy::new(|| GLOBAL_METER.f64_histogram("CONNECTOR_REQUEST_TIME").init());
+
+pub(crate) static REFUND_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("REFUND_COUNT").init());
+
+pub(crate) static INCOMING_WEBHOOK_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("INCOMING_WEBHOOK_COUNT").init());
+
+pub(crate) static OUTGOING_WEBHOOK_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("OUTGOING_WEBHOOK_COUNT").init());
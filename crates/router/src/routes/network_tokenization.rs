@@ -0,0 +1,25 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::payment_methods::network_tokenization,
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::NetworkTokenLifecycleEvent))]
+pub async fn receive_network_token_lifecycle_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        body,
+        |state, _, body| network_tokenization::handle_lifecycle_event(state, req.headers(), body),
+        &auth::NoAuth,
+        auth::Permission::PaymentMethodsWrite,
+    )
+    .await
+}
@@ -0,0 +1,62 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::payment_link,
+    services::{api, authentication as auth},
+};
+
+/// Payment Link - Retrieve
+///
+/// Retrieve a payment link, to track whether it has been used to complete the payment
+#[utoipa::path(
+    get,
+    path = "/payment_link/{payment_link_id}",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for the payment link")
+    ),
+    responses(
+        (status = 200, description = "The payment link was retrieved successfully", body = PaymentLinkResponse),
+        (status = 404, description = "Payment link does not exist in our records")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkRetrieve))]
+pub async fn retrieve_payment_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payment_link_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payment_link_id,
+        payment_link::retrieve_payment_link,
+        &auth::ApiKeyAuth,
+        auth::Permission::PaymentsRead,
+    )
+    .await
+}
+
+/// Render the checkout page of the payment link, which is opened by the customer
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkInitiate))]
+pub async fn initiate_payment_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payment_link_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payment_link_id,
+        payment_link::initiate_payment_link,
+        &auth::NoAuth,
+        auth::Permission::PaymentsWrite,
+    )
+    .await
+}
@@ -37,6 +37,7 @@ pub async fn create_payment_method_api(
             cards::add_payment_method(state, req, &merchant_account).await
         },
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentMethodsWrite,
     )
     .await
 }
@@ -84,6 +85,7 @@ pub async fn list_payment_method_api(
         payload,
         cards::list_payment_methods,
         &*auth,
+        auth::Permission::PaymentMethodsRead,
     )
     .await
 }
@@ -135,6 +137,55 @@ pub async fn list_customer_payment_method_api(
             cards::list_customer_payment_method(state, merchant_account, &customer_id)
         },
         &*auth_type,
+        auth::Permission::PaymentMethodsRead,
+    )
+    .await
+}
+
+/// Payment Method - Set Default
+///
+/// To set the default payment method of a Customer, which is listed first among the saved payment methods of the Customer
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}/default",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Default Payment Method set for the Customer", body = CustomerDefaultPaymentMethodResponse),
+        (status = 404, description = "Customer or Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Set the Default Payment Method for a Customer",
+    security(("api_key" = []), ("ephemeral_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DefaultPaymentMethodsSet))]
+pub async fn default_payment_method_set_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (customer_id, payment_method_id) = path.into_inner();
+
+    let auth_type = match auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await
+    {
+        Ok(auth_type) => auth_type,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payment_methods::DefaultPaymentMethod {
+            customer_id,
+            payment_method_id,
+        },
+        |state, merchant_account, req| {
+            cards::set_default_payment_method(&*state.store, merchant_account, req)
+        },
+        &*auth_type,
+        auth::Permission::PaymentMethodsWrite,
     )
     .await
 }
@@ -173,6 +224,7 @@ pub async fn payment_method_retrieve_api(
         payload,
         |state, merchant_account, pm| cards::retrieve_payment_method(state, pm, merchant_account),
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentMethodsRead,
     )
     .await
 }
@@ -217,6 +269,7 @@ pub async fn payment_method_update_api(
             )
         },
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentMethodsWrite,
     )
     .await
 }
@@ -253,6 +306,7 @@ pub async fn payment_method_delete_api(
         pm,
         cards::delete_payment_method,
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentMethodsWrite,
     )
     .await
 }
@@ -5,7 +5,7 @@ use router_env::{instrument, tracing, Flow};
 use crate::{
     self as app,
     core::{errors::http_not_implemented, payments},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, rate_limit},
     types::api::{self as api_types, enums as api_enums, payments as payment_types},
 };
 
@@ -18,7 +18,8 @@ use crate::{
     request_body=PaymentsRequest,
     responses(
         (status = 200, description = "Payment created", body = PaymentsResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 429, description = "Too many requests, retry after the seconds in the Retry-After header")
     ),
     tag = "Payments",
     operation_id = "Create a Payment",
@@ -50,7 +51,8 @@ pub async fn payments_create(
                 api::AuthFlow::Merchant,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::PaymentsCreate),
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -100,6 +102,7 @@ pub async fn payments_start(
             )
         },
         &auth::MerchantIdAuth(merchant_id),
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -157,6 +160,7 @@ pub async fn payments_retrieve(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsRead,
     )
     .await
 }
@@ -216,6 +220,7 @@ pub async fn payments_update(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -276,6 +281,7 @@ pub async fn payments_confirm(
             )
         },
         &*auth_type,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -326,6 +332,7 @@ pub async fn payments_capture(
             )
         },
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -374,6 +381,7 @@ pub async fn payments_connector_session(
             )
         },
         &auth::PublishableKeyAuth,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -424,6 +432,7 @@ pub async fn payments_redirect_response(
             )
         },
         &auth::MerchantIdAuth(merchant_id),
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -473,6 +482,7 @@ pub async fn payments_cancel(
             )
         },
         &auth::ApiKeyAuth,
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
@@ -496,7 +506,8 @@ pub async fn payments_cancel(
     ),
     responses(
         (status = 200, description = "Received payment list"),
-        (status = 404, description = "No payments found")
+        (status = 404, description = "No payments found"),
+        (status = 429, description = "Too many requests, retry after the seconds in the Retry-After header")
     ),
     tag = "Payments",
     operation_id = "List all Payments",
@@ -515,10 +526,11 @@ pub async fn payments_list(
         state.get_ref(),
         &req,
         payload,
-        |state, merchant_account, req| {
-            payments::list_payments(&*state.store, merchant_account, req)
+        |state, merchant_accounts, req| {
+            payments::list_payments(&*state.store, merchant_accounts, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::PaymentsList),
+        auth::Permission::PaymentsRead,
     )
     .await
 }
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::refunds::*,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, rate_limit},
     types::api::refunds,
 };
 
@@ -17,7 +17,8 @@ use crate::{
     request_body=RefundRequest,
     responses(
         (status = 200, description = "Refund created", body = RefundResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 429, description = "Too many requests, retry after the seconds in the Retry-After header")
     ),
     tag = "Refunds",
     operation_id = "Create a Refund",
@@ -35,7 +36,8 @@ pub async fn refunds_create(
         &req,
         json_payload.into_inner(),
         refund_create_core,
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsWrite,
     )
     .await
 }
@@ -51,7 +53,8 @@ pub async fn refunds_create(
     ),
     responses(
         (status = 200, description = "Refund retrieved", body = RefundResponse),
-        (status = 404, description = "Refund does not exist in our records")
+        (status = 404, description = "Refund does not exist in our records"),
+        (status = 429, description = "Too many requests, retry after the seconds in the Retry-After header")
     ),
     tag = "Refunds",
     operation_id = "Retrieve a Refund",
@@ -73,7 +76,8 @@ pub async fn refunds_retrieve(
         |state, merchant_account, refund_id| {
             refund_response_wrapper(state, merchant_account, refund_id, refund_retrieve_core)
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsRead,
     )
     .await
 }
@@ -90,7 +94,8 @@ pub async fn refunds_retrieve(
     request_body=RefundUpdateRequest,
     responses(
         (status = 200, description = "Refund updated", body = RefundResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 429, description = "Too many requests, retry after the seconds in the Retry-After header")
     ),
     tag = "Refunds",
     operation_id = "Update a Refund",
@@ -112,7 +117,8 @@ pub async fn refunds_update(
         |state, merchant_account, req| {
             refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsWrite,
     )
     .await
 }
@@ -130,11 +136,14 @@ pub async fn refunds_update(
         ("created_lt" = PrimitiveDateTime, Query, description = "Time less than the refund created time"),
         ("created_gt" = PrimitiveDateTime, Query, description = "Time greater than the refund created time"),
         ("created_lte" = PrimitiveDateTime, Query, description = "Time less than or equals to the refund created time"),
-        ("created_gte" = PrimitiveDateTime, Query, description = "Time greater than or equals to the refund created time")
+        ("created_gte" = PrimitiveDateTime, Query, description = "Time greater than or equals to the refund created time"),
+        ("starting_after" = String, Query, description = "A cursor for use in pagination, fetch the next list after some object"),
+        ("ending_before" = String, Query, description = "A cursor for use in pagination, fetch the previous list before some object")
     ),
     responses(
         (status = 200, description = "List of refunds", body = RefundListResponse),
-        (status = 404, description = "Refund does not exist in our records")
+        (status = 404, description = "Refund does not exist in our records"),
+        (status = 429, description = "Too many requests, retry after the seconds in the Retry-After header")
     ),
     tag = "Refunds",
     operation_id = "List all Refunds",
@@ -152,8 +161,9 @@ pub async fn refunds_list(
         state.get_ref(),
         &req,
         payload.into_inner(),
-        |state, merchant_account, req| refund_list(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        |state, merchant_accounts, req| refund_list(&*state.store, merchant_accounts, req),
+        &auth::RateLimitedAuth::new(&auth::ApiKeyAuth, rate_limit::RouteGroup::Refunds),
+        auth::Permission::RefundsRead,
     )
     .await
 }
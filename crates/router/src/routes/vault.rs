@@ -0,0 +1,107 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::card_vault,
+    services::{api, authentication as auth},
+    types::api::vault as vault_api,
+};
+
+/// Vault - Tokenize
+///
+/// Store data in the vault in exchange for a token, which expires after the given time
+#[utoipa::path(
+    post,
+    path = "/vault/tokenize",
+    request_body = TokenizeRequest,
+    responses(
+        (status = 200, description = "Data stored in the vault", body = TokenizeResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Vault",
+    operation_id = "Tokenize data",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::VaultTokenize))]
+pub async fn tokenize_data(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<vault_api::TokenizeRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        card_vault::tokenize_data,
+        &auth::ApiKeyAuth,
+        auth::Permission::VaultWrite,
+    )
+    .await
+}
+
+/// Vault - Detokenize
+///
+/// Retrieve the data stored in the vault against a token
+#[utoipa::path(
+    post,
+    path = "/vault/detokenize",
+    request_body = DetokenizeRequest,
+    responses(
+        (status = 200, description = "Data retrieved from the vault", body = DetokenizeResponse),
+        (status = 404, description = "Token does not exist or has expired")
+    ),
+    tag = "Vault",
+    operation_id = "Detokenize data",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::VaultDetokenize))]
+pub async fn detokenize_data(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<vault_api::DetokenizeRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        card_vault::detokenize_data,
+        &auth::ApiKeyAuth,
+        auth::Permission::VaultRead,
+    )
+    .await
+}
+
+/// Vault - Delete Token
+///
+/// Delete a token along with the data stored against it
+#[utoipa::path(
+    delete,
+    path = "/vault/tokens/{token}",
+    params (
+        ("token" = String, Path, description = "The token returned while storing the data"),
+    ),
+    responses(
+        (status = 200, description = "Token deleted", body = DeleteTokenResponse),
+        (status = 404, description = "Token does not exist or has expired")
+    ),
+    tag = "Vault",
+    operation_id = "Delete a token",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::VaultTokenDelete))]
+pub async fn delete_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        card_vault::delete_token_data,
+        &auth::ApiKeyAuth,
+        auth::Permission::VaultWrite,
+    )
+    .await
+}
@@ -24,6 +24,7 @@ pub async fn receive_incoming_webhook(
             webhooks::webhooks_core(state, &req, merchant_account, &connector_name, body)
         },
         &auth::MerchantIdAuth(merchant_id),
+        auth::Permission::PaymentsWrite,
     )
     .await
 }
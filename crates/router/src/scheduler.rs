@@ -12,7 +12,10 @@ use std::sync::Arc;
 pub use self::types::*;
 use crate::{
     configs::settings::SchedulerSettings,
-    core::errors::{self, CustomResult},
+    core::{
+        card_vault,
+        errors::{self, CustomResult},
+    },
     logger::error,
     routes::AppState,
 };
@@ -25,6 +28,7 @@ pub async fn start_process_tracker(
 ) -> CustomResult<(), errors::ProcessTrackerError> {
     match scheduler_flow {
         SchedulerFlow::Producer => {
+            card_vault::schedule_vault_token_cleanup(state).await?;
             producer::start_producer(state, Arc::clone(&options), scheduler_settings).await?
         }
         SchedulerFlow::Consumer => {
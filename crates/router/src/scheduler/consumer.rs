@@ -68,6 +68,8 @@ pub async fn start_consumer(
         match rx.try_recv() {
             Err(oneshot::error::TryRecvError::Empty) => {
                 interval.tick().await;
+                let settings = pt_utils::get_scheduler_settings(state, &settings);
+                pt_utils::update_loop_interval(&mut interval, &settings);
 
                 // A guard from env to disable the consumer
                 if settings.consumer.disabled {
@@ -77,7 +79,7 @@ pub async fn start_consumer(
                 tokio::task::spawn(pt_utils::consumer_operation_handler(
                     state.clone(),
                     options.clone(),
-                    settings.clone(),
+                    settings,
                     |err| {
                         logger::error!(%err);
                     },
@@ -35,6 +35,8 @@ pub async fn start_producer(
 
         let is_ready = options.readiness.is_ready;
         if is_ready {
+            let scheduler_settings = get_scheduler_settings(state, &scheduler_settings);
+            update_loop_interval(&mut interval, &scheduler_settings);
             match run_producer_flow(state, &options, &scheduler_settings).await {
                 Ok(_) => (),
                 Err(error) => {
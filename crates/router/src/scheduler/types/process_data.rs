@@ -24,6 +24,10 @@ pub struct ConnectorPTMapping {
     pub default_mapping: RetryMapping,
     pub custom_merchant_mapping: HashMap<String, RetryMapping>,
     pub max_retries_count: i32,
+    /// Retry schedule for payment methods which settle asynchronously, such as bank debits and
+    /// bank transfers, whose final status may only be known days after the payment is confirmed
+    #[serde(default = "default_async_payment_mapping")]
+    pub async_payment_mapping: RetryMapping,
 }
 
 impl Default for ConnectorPTMapping {
@@ -36,6 +40,16 @@ impl Default for ConnectorPTMapping {
                 count: vec![5],
             },
             max_retries_count: 5,
+            async_payment_mapping: default_async_payment_mapping(),
         }
     }
 }
+
+/// Sync every hour for the first day and every six hours for the following week
+fn default_async_payment_mapping() -> RetryMapping {
+    RetryMapping {
+        start_after: 900,
+        frequency: vec![3600, 21600],
+        count: vec![24, 28],
+    }
+}
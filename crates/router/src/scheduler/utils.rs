@@ -21,6 +21,34 @@ use crate::{
     utils::{OptionExt, StringExt},
 };
 
+/// Scheduler settings overridden at runtime take precedence over the ones the scheduler was
+/// started with.
+pub fn get_scheduler_settings(
+    state: &AppState,
+    settings: &sync::Arc<SchedulerSettings>,
+) -> sync::Arc<SchedulerSettings> {
+    state
+        .dynamic_config
+        .get()
+        .scheduler
+        .clone()
+        .map_or_else(|| sync::Arc::clone(settings), sync::Arc::new)
+}
+
+/// Resets the interval of the scheduler loop when the loop interval of the scheduler settings has
+/// been overridden at runtime.
+pub fn update_loop_interval(interval: &mut tokio::time::Interval, settings: &SchedulerSettings) {
+    let loop_interval = std_time::Duration::from_millis(settings.loop_interval);
+    if interval.period() != loop_interval {
+        logger::info!(
+            loop_interval = settings.loop_interval,
+            "Updating the scheduler loop interval"
+        );
+        *interval =
+            tokio::time::interval_at(tokio::time::Instant::now() + loop_interval, loop_interval);
+    }
+}
+
 pub async fn divide_and_append_tasks(
     state: &AppState,
     flow: SchedulerFlow,
@@ -304,6 +332,17 @@ pub fn get_schedule_time(
         None => mapping.default_mapping,
     };
 
+    get_retry_delay(mapping, retry_count)
+}
+
+pub fn get_async_payment_schedule_time(
+    mapping: process_data::ConnectorPTMapping,
+    retry_count: i32,
+) -> Option<i32> {
+    get_retry_delay(mapping.async_payment_mapping, retry_count)
+}
+
+fn get_retry_delay(mapping: process_data::RetryMapping, retry_count: i32) -> Option<i32> {
     if retry_count == 0 {
         Some(mapping.start_after)
     } else {
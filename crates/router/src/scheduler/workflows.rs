@@ -4,8 +4,10 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+pub mod merchant_key_rotation;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod vault_token_cleanup;
 
 macro_rules! runners {
     ($($body:tt),*) => {
@@ -44,7 +46,9 @@ macro_rules! as_item {
 
 runners! {
     PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    RefundWorkflowRouter,
+    MerchantKeyRotationWorkflow,
+    VaultTokenCleanupWorkflow
 }
 
 #[async_trait]
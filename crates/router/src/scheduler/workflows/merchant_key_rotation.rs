@@ -0,0 +1,87 @@
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::{MerchantKeyRotationWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    consts,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services::envelope_encryption::EnvelopeEncryption,
+    types::storage::{self, ProcessTrackerExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for MerchantKeyRotationWorkflow {
+    /// Re-wraps a batch of data keys with the active master key, the task is retried right away
+    /// until no data key is wrapped by another master key.
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let envelope_encryption = EnvelopeEncryption::new(state.conf.jwekey.clone());
+        let master_key_identifier = envelope_encryption.active_master_key_identifier();
+
+        let merchant_key_stores = db
+            .find_merchant_key_stores_by_not_master_key_identifier(
+                master_key_identifier,
+                consts::MERCHANT_KEY_ROTATION_BATCH_SIZE,
+            )
+            .await?;
+        let batch_size = merchant_key_stores.len();
+
+        for merchant_key_store in merchant_key_stores {
+            let (key, master_key_identifier) = envelope_encryption
+                .rewrap_data_key(
+                    &merchant_key_store.key,
+                    &merchant_key_store.master_key_identifier,
+                )
+                .await
+                .change_context(errors::StorageError::EncryptionError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed to re-wrap data key of merchant {}",
+                        merchant_key_store.merchant_id
+                    )
+                })?;
+
+            db.update_merchant_key_store_by_merchant_id(
+                &merchant_key_store.merchant_id,
+                storage::MerchantKeyStoreUpdate::KeyRewrap {
+                    key,
+                    master_key_identifier,
+                },
+            )
+            .await?;
+        }
+
+        logger::info!(
+            rewrapped_data_keys = batch_size,
+            master_key_identifier,
+            "Re-wrapped data keys with the active master key"
+        );
+
+        let is_last_batch = i64::try_from(batch_size)
+            .map(|batch_size| batch_size < consts::MERCHANT_KEY_ROTATION_BATCH_SIZE)
+            .unwrap_or(false);
+        if is_last_batch {
+            process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await
+        } else {
+            process.retry(db, common_utils::date_time::now()).await
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -71,6 +71,7 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
                     db,
                     connector,
                     payment_data.payment_attempt.merchant_id,
+                    payment_data.payment_attempt.payment_method,
                     process,
                 )
                 .await?
@@ -93,6 +94,7 @@ pub async fn get_sync_process_schedule_time(
     db: &dyn StorageInterface,
     connector: &str,
     merchant_id: &str,
+    payment_method: Option<enums::PaymentMethod>,
     retry_count: i32,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
     let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
@@ -104,19 +106,38 @@ pub async fn get_sync_process_schedule_time(
             process_data::ConnectorPTMapping::default()
         }
     };
-    let time_delta = utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
+    let time_delta = if is_async_payment_method(payment_method) {
+        utils::get_async_payment_schedule_time(mapping, retry_count + 1)
+    } else {
+        utils::get_schedule_time(mapping, merchant_id, retry_count + 1)
+    };
 
     Ok(utils::get_time_from_delta(time_delta))
 }
 
+/// Bank debits and bank transfers stay pending until the funds are received by the connector
+fn is_async_payment_method(payment_method: Option<enums::PaymentMethod>) -> bool {
+    matches!(
+        payment_method,
+        Some(enums::PaymentMethod::BankDebit | enums::PaymentMethod::BankTransfer)
+    )
+}
+
 pub async fn retry_sync_task(
     db: &dyn StorageInterface,
     connector: String,
     merchant_id: String,
+    payment_method: Option<enums::PaymentMethod>,
     pt: storage::ProcessTracker,
 ) -> Result<(), errors::ProcessTrackerError> {
-    let schedule_time =
-        get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
+    let schedule_time = get_sync_process_schedule_time(
+        db,
+        &connector,
+        &merchant_id,
+        payment_method,
+        pt.retry_count,
+    )
+    .await?;
 
     match schedule_time {
         Some(s_time) => pt.retry(db, s_time).await,
@@ -144,4 +165,29 @@ mod tests {
             vec![cpt_default.start_after, cpt_default.frequency[0]]
         );
     }
+
+    #[test]
+    fn test_get_async_payment_schedule_time() {
+        let cpt_default = process_data::ConnectorPTMapping::default().async_payment_mapping;
+        let schedule_time_deltas: Vec<Option<i32>> = [0, 1, 24, 25, 52, 53]
+            .into_iter()
+            .map(|retry_count| {
+                utils::get_async_payment_schedule_time(
+                    process_data::ConnectorPTMapping::default(),
+                    retry_count,
+                )
+            })
+            .collect();
+        assert_eq!(
+            schedule_time_deltas,
+            vec![
+                Some(cpt_default.start_after),
+                Some(cpt_default.frequency[0]),
+                Some(cpt_default.frequency[0]),
+                Some(cpt_default.frequency[1]),
+                Some(cpt_default.frequency[1]),
+                None
+            ]
+        );
+    }
 }
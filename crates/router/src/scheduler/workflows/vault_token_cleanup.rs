@@ -0,0 +1,55 @@
+use error_stack::IntoReport;
+use router_env::logger;
+
+use super::{ProcessTrackerWorkflow, VaultTokenCleanupWorkflow};
+use crate::{
+    core::card_vault,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, ProcessTrackerExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for VaultTokenCleanupWorkflow {
+    /// Deletes the expired tokens from the vault, the task is then scheduled again after the
+    /// cleanup interval.
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let deleted = db.delete_expired_vault_tokens().await?;
+
+        logger::info!(
+            expired_tokens_deleted = deleted,
+            "Cleaned up the expired vault tokens"
+        );
+
+        process
+            .retry(
+                db,
+                card_vault::get_next_token_cleanup_time(&state.conf.vault),
+            )
+            .await
+    }
+
+    /// A failed cleanup is retried at the next interval instead of finishing the task.
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(pt.id = %process.id, ?error, "Failed to delete the expired vault tokens");
+
+        process
+            .retry(
+                &*state.store,
+                card_vault::get_next_token_cleanup_time(&state.conf.vault),
+            )
+            .await
+            .into_report()
+    }
+}
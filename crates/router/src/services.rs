@@ -1,11 +1,21 @@
 pub mod api;
+pub mod audit;
 pub mod authentication;
+pub mod circuit_breaker;
+pub mod connector_call_log;
 #[cfg(feature = "basilisk")]
 pub mod encryption;
+pub mod envelope_encryption;
 pub mod logger;
+pub mod rate_limit;
+pub mod secrets_management;
 
-use std::sync::{atomic, Arc};
+use std::{
+    sync::{atomic, Arc},
+    time::Duration,
+};
 
+use futures::StreamExt;
 use redis_interface::{errors::RedisError, PubSubInterface};
 
 pub use self::api::*;
@@ -16,6 +26,7 @@ use crate::{
     connection::{diesel_make_pg_pool, PgPool},
     consts,
     core::errors,
+    db,
 };
 
 #[derive(Clone)]
@@ -26,6 +37,8 @@ pub struct Store {
     pub redis_conn: Arc<redis_interface::RedisConnectionPool>,
     #[cfg(feature = "kv_store")]
     pub(crate) config: StoreConfig,
+    pub(crate) envelope_encryption: Arc<envelope_encryption::EnvelopeEncryption>,
+    pub(crate) cache: Arc<db::cache::InMemoryCache>,
 }
 
 #[cfg(feature = "kv_store")]
@@ -40,13 +53,21 @@ impl Store {
         let redis_conn = Arc::new(crate::connection::redis_connection(config).await);
         let redis_clone = redis_conn.clone();
 
-        let subscriber_conn = redis_conn.clone();
+        let cache = Arc::new(db::cache::InMemoryCache::new(
+            config.cache.max_capacity,
+            Duration::from_secs(config.cache.ttl),
+        ));
 
-        redis_conn.subscribe(consts::PUB_SUB_CHANNEL).await.ok();
+        if let Err(error) = redis_conn.subscribe(consts::PUB_SUB_CHANNEL).await {
+            logger::error!(pubsub_err=?error);
+        }
 
+        // Keys invalidated by any of the instances are removed from the in-memory cache
+        let mut invalidated_keys = redis_conn.on_message_keys();
+        let invalidated_cache = cache.clone();
         async_spawn!({
-            if let Err(e) = subscriber_conn.on_message().await {
-                logger::error!(pubsub_err=?e);
+            while let Some(key) = invalidated_keys.next().await {
+                invalidated_cache.remove(&key);
             }
         });
 
@@ -64,6 +85,10 @@ impl Store {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
             },
+            envelope_encryption: Arc::new(envelope_encryption::EnvelopeEncryption::new(
+                config.jwekey.clone(),
+            )),
+            cache,
         }
     }
 
@@ -19,7 +19,7 @@ use serde::Serialize;
 use self::request::{ContentType, HeaderExt, RequestBuilderExt};
 pub use self::request::{Method, Request, RequestBuilder};
 use crate::{
-    configs::settings::Connectors,
+    configs::settings::{ConnectorParams, Connectors},
     core::{
         errors::{self, CustomResult, RouterResult},
         payments,
@@ -27,7 +27,7 @@ use crate::{
     db::StorageInterface,
     logger,
     routes::{app::AppStateInfo, AppState},
-    services::authentication as auth,
+    services::{authentication as auth, circuit_breaker, connector_call_log},
     types::{
         self,
         api::{self},
@@ -184,10 +184,41 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
-            match connector_integration.build_request(req, &state.conf.connectors)? {
+            let (request, secrets) = connector_call_log::collect_secrets(state, || {
+                connector_integration.build_request(req, &state.dynamic_config.get().connectors)
+            });
+            match request? {
                 Some(request) => {
+                    let permission = circuit_breaker::acquire_permission(
+                        state,
+                        &req.merchant_id,
+                        &req.connector,
+                    )
+                    .await;
+                    if permission == circuit_breaker::CallPermission::Rejected {
+                        return Err(errors::ConnectorError::CircuitOpen {
+                            connector: req.connector.clone(),
+                        })
+                        .into_report();
+                    }
+
                     logger::debug!(connector_request=?request);
-                    let response = call_connector_api(state, request).await;
+                    let call_log =
+                        connector_call_log::build_call_log(state, req, &request, secrets);
+                    let current_time = Instant::now();
+                    let response =
+                        call_connector_api_with_transport(state, &req.connector, request).await;
+                    let latency = current_time.elapsed();
+                    connector_call_log::record_call(state, call_log, &response, latency);
+                    circuit_breaker::record_call(
+                        state,
+                        &req.merchant_id,
+                        &req.connector,
+                        permission,
+                        circuit_breaker::is_failed_call(&response),
+                        latency,
+                    )
+                    .await;
                     logger::debug!(connector_response=?response);
                     match response {
                         Ok(body) => {
@@ -219,7 +250,29 @@ pub async fn call_connector_api(
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let current_time = Instant::now();
 
-    let response = send_request(state, request).await;
+    let response = send_request(state, request, None).await;
+
+    let elapsed_time = current_time.elapsed();
+    logger::info!(request_time=?elapsed_time);
+
+    handle_response(response).await
+}
+
+/// Same as [`call_connector_api`], but uses the transport settings configured for the connector.
+#[instrument(skip_all)]
+pub async fn call_connector_api_with_transport(
+    state: &AppState,
+    connector_name: &str,
+    request: Request,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    let current_time = Instant::now();
+
+    let connector_params = state
+        .conf
+        .connectors
+        .get_connector_params(connector_name)
+        .map(|connector_params| (connector_name, connector_params));
+    let response = send_request(state, request, connector_params).await;
 
     let elapsed_time = current_time.elapsed();
     logger::info!(request_time=?elapsed_time);
@@ -231,6 +284,7 @@ pub async fn call_connector_api(
 async fn send_request(
     state: &AppState,
     request: Request,
+    connector: Option<(&str, &ConnectorParams)>,
 ) -> CustomResult<reqwest::Response, errors::ApiClientError> {
     logger::debug!(method=?request.method, headers=?request.headers, payload=?request.payload, ?request);
     let url = &request.url;
@@ -238,9 +292,13 @@ async fn send_request(
     let client = client::create_client(
         &state.conf.proxy,
         should_bypass_proxy,
+        connector,
         request.certificate,
         request.certificate_key,
     )?;
+    let request_timeout = connector
+        .and_then(|(_, connector_params)| connector_params.read_timeout)
+        .unwrap_or(crate::consts::REQUEST_TIME_OUT);
     let headers = request.headers.construct_header_map()?;
     match request.method {
         Method::Get => client.get(url),
@@ -278,7 +336,7 @@ async fn send_request(
         Method::Delete => client.delete(url),
     }
     .add_headers(headers)
-    .timeout(Duration::from_secs(crate::consts::REQUEST_TIME_OUT))
+    .timeout(Duration::from_secs(request_timeout))
     .send()
     .await
     .map_err(|error| match error {
@@ -372,6 +430,7 @@ pub enum ApplicationResponse<R> {
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
     Form(RedirectForm),
+    PaymentLinkForm(Box<PaymentLinkFormData>),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -399,6 +458,21 @@ pub struct RedirectForm {
     pub form_fields: HashMap<String, String>,
 }
 
+/// The details required to render the checkout page of a payment link
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PaymentLinkFormData {
+    pub merchant_name: String,
+    pub payment_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub status: api_models::enums::PaymentLinkStatus,
+    pub client_secret: String,
+    pub publishable_key: String,
+    pub base_url: String,
+    pub return_url: Option<String>,
+    pub payment_methods: Vec<api_models::payment_methods::ResponsePaymentMethodsEnabled>,
+}
+
 impl From<(url::Url, Method)> for RedirectForm {
     fn from((mut redirect_url, method): (url::Url, Method)) -> Self {
         let form_fields = std::collections::HashMap::from_iter(
@@ -431,6 +505,7 @@ pub async fn server_wrap_util<'a, 'b, A, U, T, Q, F, Fut, E, OErr>(
     payload: T,
     func: F,
     api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
+    permission: auth::Permission,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
     F: Fn(&'b A, U, T) -> Fut,
@@ -442,12 +517,15 @@ where
     CustomResult<U, errors::ApiErrorResponse>: ReportSwitchExt<U, OErr>,
 {
     let auth_out = api_auth
-        .authenticate_and_fetch(request.headers(), state)
+        .authenticate_and_fetch(request.headers(), state, permission)
         .await
         .switch()?;
     func(state, auth_out, payload).await.switch()
 }
 
+/// Authenticates the request with `api_auth`, which also checks that the credentials of the
+/// request grant the `permission` declared by the route, and then calls `func` with the output of
+/// the authentication.
 #[instrument(
     skip(request, payload, state, func, api_auth),
     fields(request_method, request_url_path)
@@ -458,6 +536,7 @@ pub async fn server_wrap<'a, 'b, A, T, U, Q, F, Fut, E>(
     payload: T,
     func: F,
     api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
+    permission: auth::Permission,
 ) -> HttpResponse
 where
     F: Fn(&'b A, U, T) -> Fut,
@@ -476,7 +555,7 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest);
 
-    let res = match server_wrap_util(state, request, payload, func, api_auth).await {
+    let res = match server_wrap_util(state, request, payload, func, api_auth, permission).await {
         Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
             Ok(res) => http_response_json(res),
             Err(_) => http_response_err(
@@ -504,6 +583,11 @@ where
         Ok(ApplicationResponse::Form(response)) => build_redirection_form(&response)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(ApplicationResponse::PaymentLinkForm(payment_link_data)) => {
+            build_payment_link_html(&payment_link_data)
+                .respond_to(request)
+                .map_into_boxed_body()
+        }
 
         Err(error) => log_and_return_error_response(error),
     };
@@ -664,10 +748,218 @@ pub fn build_redirection_form(form: &RedirectForm) -> maud::Markup {
     }
 }
 
+/// Render the checkout page of a payment link. The payment methods are listed on the page, and
+/// the payment is confirmed from the browser using the publishable key and the client secret.
+pub fn build_payment_link_html(payment_link_data: &PaymentLinkFormData) -> maud::Markup {
+    use api_models::enums::{PaymentLinkStatus, PaymentMethod};
+    use maud::PreEscaped;
+
+    // Escape `<` so that the values cannot close the script tag they are embedded in
+    let script_config = serde_json::json!({
+        "base_url": payment_link_data.base_url,
+        "payment_id": payment_link_data.payment_id,
+        "client_secret": payment_link_data.client_secret,
+        "publishable_key": payment_link_data.publishable_key,
+        "return_url": payment_link_data.return_url,
+    })
+    .to_string()
+    .replace('<', "\\u003c");
+
+    maud::html! {
+        (maud::DOCTYPE)
+        html {
+            head {
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "Pay " (payment_link_data.merchant_name) }
+                (PreEscaped(r##"
+                <style>
+                    body { background-color: #f6f8fa; font-family: Arial, Helvetica, Sans-Serif; padding: 20px; }
+                    .checkout { background-color: #ffffff; border-radius: 8px; margin: auto; max-width: 420px; padding: 24px; }
+                    .amount { font-size: 28px; font-weight: bold; margin: 8px 0 24px; }
+                    .payment-method { display: block; margin: 8px 0; }
+                    input[type=text] { box-sizing: border-box; margin: 4px 0; padding: 10px; width: 100%; }
+                    button { background-color: #006df9; border: none; border-radius: 4px; color: #ffffff; font-size: 16px; margin-top: 16px; padding: 12px; width: 100%; }
+                    #payment_message { margin-top: 16px; text-align: center; }
+                </style>
+                "##))
+            }
+            body {
+                div class="checkout" {
+                    h3 { (payment_link_data.merchant_name) }
+                    div class="amount" { (payment_link_data.amount) " " (payment_link_data.currency) }
+
+                    @match payment_link_data.status {
+                        PaymentLinkStatus::Completed => {
+                            p { "This payment has been completed." }
+                        }
+                        PaymentLinkStatus::Expired => {
+                            p { "This payment link has expired." }
+                        }
+                        PaymentLinkStatus::Active => {
+                            form #payment_link_form {
+                                @for payment_method in &payment_link_data.payment_methods {
+                                    @for payment_method_type in &payment_method.payment_method_types {
+                                        label class="payment-method" {
+                                            input type="radio" name="payment_method"
+                                                value=(format!("{}:{}", payment_method.payment_method, payment_method_type.payment_method_type))
+                                                data-payment-experience=[payment_method_type
+                                                    .payment_experience
+                                                    .as_ref()
+                                                    .and_then(|experiences| experiences.first())
+                                                    .map(|experience| experience.payment_experience_type.to_string())]
+                                                checked[payment_method.payment_method == PaymentMethod::Card];
+                                            " " (payment_method_type.payment_method_type)
+                                        }
+                                    }
+                                }
+                                div #card_details {
+                                    input type="text" name="card_number" placeholder="Card number" autocomplete="cc-number";
+                                    input type="text" name="card_exp_month" placeholder="MM" autocomplete="cc-exp-month";
+                                    input type="text" name="card_exp_year" placeholder="YYYY" autocomplete="cc-exp-year";
+                                    input type="text" name="card_cvc" placeholder="CVC" autocomplete="cc-csc";
+                                    input type="text" name="card_holder_name" placeholder="Name on card" autocomplete="cc-name";
+                                }
+                                button type="submit" { "Pay" }
+                            }
+                            div #payment_message {}
+
+                            script { (PreEscaped(format!("var paymentLinkConfig = {script_config};"))) }
+                            (PreEscaped(r##"
+                            <script type="text/javascript">
+                            var form = document.getElementById("payment_link_form");
+                            var message = document.getElementById("payment_message");
+
+                            function selectedPaymentMethod() {
+                                return form.querySelector('input[name="payment_method"]:checked');
+                            }
+
+                            form.addEventListener("change", function () {
+                                var selected = selectedPaymentMethod();
+                                var isCard = selected && selected.value.split(":")[0] === "card";
+                                document.getElementById("card_details").style.display = isCard ? "block" : "none";
+                            });
+
+                            form.addEventListener("submit", async function (event) {
+                                event.preventDefault();
+                                var selected = selectedPaymentMethod();
+                                if (!selected) {
+                                    message.innerText = "Please select a payment method";
+                                    return;
+                                }
+                                var [paymentMethod, paymentMethodType] = selected.value.split(":");
+                                var request = {
+                                    client_secret: paymentLinkConfig.client_secret,
+                                    payment_method: paymentMethod,
+                                    payment_method_type: paymentMethodType,
+                                    return_url: paymentLinkConfig.return_url || window.location.href
+                                };
+                                if (paymentMethod === "card") {
+                                    request.payment_method_data = {
+                                        card: {
+                                            card_number: form.card_number.value,
+                                            card_exp_month: form.card_exp_month.value,
+                                            card_exp_year: form.card_exp_year.value,
+                                            card_holder_name: form.card_holder_name.value,
+                                            card_cvc: form.card_cvc.value
+                                        }
+                                    };
+                                } else if (selected.dataset.paymentExperience) {
+                                    request.payment_experience = selected.dataset.paymentExperience;
+                                }
+
+                                message.innerText = "Please wait while we process your payment...";
+                                var response = await fetch(paymentLinkConfig.base_url + "/payments/" + paymentLinkConfig.payment_id + "/confirm", {
+                                    method: "POST",
+                                    headers: {
+                                        "Content-Type": "application/json",
+                                        "api-key": paymentLinkConfig.publishable_key
+                                    },
+                                    body: JSON.stringify(request)
+                                });
+                                var payment = await response.json();
+                                if (!response.ok) {
+                                    message.innerText = payment.error ? payment.error.message : "Payment failed";
+                                } else if (payment.next_action && payment.next_action.redirect_to_url) {
+                                    window.location.href = payment.next_action.redirect_to_url;
+                                } else if (payment.status === "succeeded" || payment.status === "processing") {
+                                    form.style.display = "none";
+                                    message.innerText = "Thank you! Your payment is " + payment.status + ".";
+                                } else {
+                                    message.innerText = payment.error_message || "Payment " + payment.status;
+                                }
+                            });
+                            </script>
+                            "##))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use api_models::{enums, payment_methods};
+
+    use super::*;
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    fn get_payment_link_data(status: enums::PaymentLinkStatus) -> PaymentLinkFormData {
+        PaymentLinkFormData {
+            merchant_name: "Juspay".to_string(),
+            payment_id: "pay_1".to_string(),
+            amount: "65.4".to_string(),
+            currency: "USD".to_string(),
+            status,
+            client_secret: "pay_1_secret_abc".to_string(),
+            publishable_key: "pk_dev_123".to_string(),
+            base_url: "http://localhost:8080".to_string(),
+            return_url: Some("https://hyperswitch.io/</script>".to_string()),
+            payment_methods: vec![payment_methods::ResponsePaymentMethodsEnabled {
+                payment_method: enums::PaymentMethod::Card,
+                payment_method_types: vec![payment_methods::ResponsePaymentMethodTypes {
+                    payment_method_type: enums::PaymentMethodType::Credit,
+                    payment_experience: None,
+                    card_networks: None,
+                    bank_names: None,
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_active_payment_link_html() {
+        let html =
+            build_payment_link_html(&get_payment_link_data(enums::PaymentLinkStatus::Active))
+                .into_string();
+
+        assert!(html.contains("<title>Pay Juspay</title>"));
+        assert!(html.contains("65.4 USD"));
+        assert!(html.contains(r#"value="card:credit""#));
+        assert!(html.contains(r#""client_secret":"pay_1_secret_abc""#));
+        assert!(html.contains(r#""publishable_key":"pk_dev_123""#));
+        // The values embedded in the script cannot close the script tag
+        assert!(html.contains(r#""return_url":"https://hyperswitch.io/\u003c/script>""#));
+    }
+
+    #[test]
+    fn test_inactive_payment_link_html() {
+        let html =
+            build_payment_link_html(&get_payment_link_data(enums::PaymentLinkStatus::Expired))
+                .into_string();
+        assert!(html.contains("This payment link has expired."));
+        assert!(!html.contains("payment_link_form"));
+        assert!(!html.contains("pay_1_secret_abc"));
+
+        let html =
+            build_payment_link_html(&get_payment_link_data(enums::PaymentLinkStatus::Completed))
+                .into_string();
+        assert!(html.contains("This payment has been completed."));
+        assert!(!html.contains("pay_1_secret_abc"));
+    }
 }
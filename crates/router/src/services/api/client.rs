@@ -1,20 +1,29 @@
+use std::{collections::HashMap, sync::RwLock, time::Duration};
+
 use base64::Engine;
 use error_stack::{IntoReport, ResultExt};
-use once_cell::sync::OnceCell;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::{
-    configs::settings::{Locker, Proxy},
+    configs::settings::{ConnectorParams, Locker, Proxy},
     consts,
     core::errors::{self, CustomResult},
 };
 
 const HTTP_PROXY: &str = "ROUTER_HTTP_PROXY";
 const HTTPS_PROXY: &str = "ROUTER_HTTPS_PROXY";
+const PEM_CERTIFICATE_END: &str = "-----END CERTIFICATE-----";
 
 static PLAIN_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 static HTTPS_PROXY_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 static HTTP_PROXY_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 
+/// Clients built with the transport settings of a connector, keyed by the connector name and the
+/// type of proxy used by the client.
+static CONNECTOR_CLIENTS: Lazy<RwLock<HashMap<(String, Option<ProxyType>), reqwest::Client>>> =
+    Lazy::new(Default::default);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 enum ProxyType {
     Http,
     Https,
@@ -35,6 +44,74 @@ impl ProxyType {
     }
 }
 
+fn get_client_builder(
+    proxy: Option<(ProxyType, String)>,
+    connector_params: Option<&ConnectorParams>,
+) -> CustomResult<reqwest::ClientBuilder, errors::ApiClientError> {
+    let mut client_builder = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+
+    client_builder = match proxy {
+        None => client_builder,
+        Some((proxy_type, url)) => client_builder.proxy(
+            match proxy_type {
+                ProxyType::Http => reqwest::Proxy::http(url),
+                ProxyType::Https => reqwest::Proxy::https(url),
+            }
+            .into_report()
+            .change_context(errors::ApiClientError::InvalidProxyConfiguration)
+            .attach_printable("HTTP proxy configuration error")?,
+        ),
+    };
+
+    match connector_params {
+        Some(connector_params) => apply_transport_settings(client_builder, connector_params),
+        None => Ok(client_builder),
+    }
+}
+
+fn apply_transport_settings(
+    mut client_builder: reqwest::ClientBuilder,
+    connector_params: &ConnectorParams,
+) -> CustomResult<reqwest::ClientBuilder, errors::ApiClientError> {
+    if let Some(connect_timeout) = connector_params.connect_timeout {
+        client_builder = client_builder.connect_timeout(Duration::from_secs(connect_timeout));
+    }
+
+    if let Some(pool_size) = connector_params.pool_max_idle_per_host {
+        client_builder = client_builder.pool_max_idle_per_host(pool_size);
+    }
+
+    if connector_params.http2_prior_knowledge {
+        client_builder = client_builder.http2_prior_knowledge();
+    }
+
+    if let Some(ca_bundle) = &connector_params.ca_bundle {
+        // Only the pinned certificates are trusted for connections to the connector
+        client_builder = client_builder.tls_built_in_root_certs(false);
+        for certificate in parse_ca_bundle(ca_bundle)? {
+            client_builder = client_builder.add_root_certificate(certificate);
+        }
+    }
+
+    Ok(client_builder)
+}
+
+fn parse_ca_bundle(
+    ca_bundle: &str,
+) -> CustomResult<Vec<reqwest::Certificate>, errors::ApiClientError> {
+    ca_bundle
+        .split_inclusive(PEM_CERTIFICATE_END)
+        .map(str::trim)
+        .filter(|certificate| !certificate.is_empty())
+        .map(|certificate| {
+            reqwest::Certificate::from_pem(certificate.as_bytes())
+                .into_report()
+                .change_context(errors::ApiClientError::CertificateDecodeFailed)
+                .attach_printable("Failed to parse CA bundle")
+        })
+        .collect()
+}
+
 fn create_base_client(
     proxy: Option<(ProxyType, String)>,
 ) -> CustomResult<reqwest::Client, errors::ApiClientError> {
@@ -44,20 +121,8 @@ fn create_base_client(
         Some((ProxyType::Https, _)) => &HTTPS_PROXY_CLIENT,
     }
     .get_or_try_init(|| {
-        let mut cb = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
-        cb = match proxy {
-            None => cb,
-            Some((proxy_type, url)) => cb.proxy(
-                match proxy_type {
-                    ProxyType::Http => reqwest::Proxy::http(url),
-                    ProxyType::Https => reqwest::Proxy::https(url),
-                }
-                .into_report()
-                .change_context(errors::ApiClientError::InvalidProxyConfiguration)
-                .attach_printable("HTTP proxy configuration error")?,
-            ),
-        };
-        cb.build()
+        get_client_builder(proxy, None)?
+            .build()
             .into_report()
             .change_context(errors::ApiClientError::ClientConstructionFailed)
             .attach_printable("Error with client library")
@@ -65,30 +130,74 @@ fn create_base_client(
     .clone())
 }
 
+fn create_connector_client(
+    connector_name: &str,
+    connector_params: &ConnectorParams,
+    proxy: Option<(ProxyType, String)>,
+) -> CustomResult<reqwest::Client, errors::ApiClientError> {
+    let key = (
+        connector_name.to_owned(),
+        proxy.as_ref().map(|(proxy_type, _)| *proxy_type),
+    );
+
+    if let Some(client) = CONNECTOR_CLIENTS
+        .read()
+        .map_err(|_| errors::ApiClientError::ClientConstructionFailed)
+        .into_report()
+        .attach_printable("Connector client cache lock is poisoned")?
+        .get(&key)
+    {
+        return Ok(client.clone());
+    }
+
+    let client = get_client_builder(proxy, Some(connector_params))?
+        .build()
+        .into_report()
+        .change_context(errors::ApiClientError::ClientConstructionFailed)
+        .attach_printable_lazy(|| format!("Error building client for {connector_name}"))?;
+
+    Ok(CONNECTOR_CLIENTS
+        .write()
+        .map_err(|_| errors::ApiClientError::ClientConstructionFailed)
+        .into_report()
+        .attach_printable("Connector client cache lock is poisoned")?
+        .entry(key)
+        .or_insert(client)
+        .clone())
+}
+
 // We may need to use outbound proxy to connect to external world.
 // Precedence will be the environment variables, followed by the config.
 pub(super) fn create_client(
     proxy: &Proxy,
     should_bypass_proxy: bool,
+    connector: Option<(&str, &ConnectorParams)>,
     client_certificate: Option<String>,
     client_certificate_key: Option<String>,
 ) -> CustomResult<reqwest::Client, errors::ApiClientError> {
     if client_certificate.is_none() && client_certificate_key.is_none() {
-        return match should_bypass_proxy {
-            true => create_base_client(None),
-            false => create_base_client(
-                ProxyType::Https
-                    .get_proxy_url(proxy)
-                    .map(|url| (ProxyType::Https, url))
-                    .or_else(|| {
-                        ProxyType::Http
-                            .get_proxy_url(proxy)
-                            .map(|url| (ProxyType::Http, url))
-                    }),
-            ),
+        let proxy = match should_bypass_proxy {
+            true => None,
+            false => ProxyType::Https
+                .get_proxy_url(proxy)
+                .map(|url| (ProxyType::Https, url))
+                .or_else(|| {
+                    ProxyType::Http
+                        .get_proxy_url(proxy)
+                        .map(|url| (ProxyType::Http, url))
+                }),
+        };
+        return match connector {
+            Some((connector_name, connector_params)) => {
+                create_connector_client(connector_name, connector_params, proxy)
+            }
+            None => create_base_client(proxy),
         };
     }
-    let mut client_builder = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+    let mut client_builder = get_client_builder(
+        None,
+        connector.map(|(_, connector_params)| connector_params),
+    )?;
 
     if !should_bypass_proxy {
         if let Some(url) = ProxyType::Http.get_proxy_url(proxy) {
@@ -161,3 +270,83 @@ pub(super) fn proxy_bypass_urls(locker: &Locker) -> Vec<String> {
         format!("{basilisk_host}/tokenize/delete/token"),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const CERTIFICATE: &str = "\
+-----BEGIN CERTIFICATE-----\n\
+MIIBiTCCAS+gAwIBAgIUPtfb0Ilq40vmwYwaQipNH4s5BkwwCgYIKoZIzj0EAwIw\n\
+GTEXMBUGA1UEAwwOY29ubmVjdG9yLnRlc3QwIBcNMjYxMDE4MjIyNTM3WhgPMjEy\n\
+NjA5MjQyMjI1MzdaMBkxFzAVBgNVBAMMDmNvbm5lY3Rvci50ZXN0MFkwEwYHKoZI\n\
+zj0CAQYIKoZIzj0DAQcDQgAECWk+lPlBCUGqz+0CgVYbgb+6Fdw+XV/UNLgBujPx\n\
+5KhC7lvWM9CfIpMJeckI75KsyjVlPf8+LKPLwOEkoSMCWqNTMFEwHQYDVR0OBBYE\n\
+FBm0x8TOhPYeIG8/EjXPka/8Q1rhMB8GA1UdIwQYMBaAFBm0x8TOhPYeIG8/EjXP\n\
+ka/8Q1rhMA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwIDSAAwRQIgWZT888dN\n\
+eQfbIFn+swANocdkRdFmPEtTiwiabLJ0LsICIQC3QvtOYm6IR7pmjx3+iDOHlzpK\n\
+MuQ12wEtlGlsmy8GOw==\n\
+-----END CERTIFICATE-----\n\
+";
+
+    #[test]
+    fn test_parse_ca_bundle() {
+        assert_eq!(parse_ca_bundle(CERTIFICATE).unwrap().len(), 1);
+
+        let ca_bundle = format!("{CERTIFICATE}\n\n{CERTIFICATE}\n");
+        assert_eq!(parse_ca_bundle(&ca_bundle).unwrap().len(), 2);
+
+        assert!(parse_ca_bundle("").unwrap().is_empty());
+        assert!(parse_ca_bundle(&CERTIFICATE.replace("MIIB", "%%%%")).is_err());
+    }
+
+    #[test]
+    fn test_apply_transport_settings() {
+        let connector_params = ConnectorParams {
+            connect_timeout: Some(5),
+            pool_max_idle_per_host: Some(4),
+            http2_prior_knowledge: true,
+            ca_bundle: Some(CERTIFICATE.to_string()),
+            ..Default::default()
+        };
+        assert!(get_client_builder(None, Some(&connector_params))
+            .unwrap()
+            .build()
+            .is_ok());
+
+        let connector_params = ConnectorParams {
+            ca_bundle: Some("-----BEGIN CERTIFICATE-----\n%%%%\n-----END CERTIFICATE-----".into()),
+            ..Default::default()
+        };
+        assert!(get_client_builder(None, Some(&connector_params)).is_err());
+    }
+
+    #[test]
+    fn test_connector_clients_are_cached() {
+        let connector_params = ConnectorParams {
+            connect_timeout: Some(5),
+            ..Default::default()
+        };
+        let key = ("client_test_connector".to_string(), None);
+
+        create_connector_client("client_test_connector", &connector_params, None).unwrap();
+        assert!(CONNECTOR_CLIENTS.read().unwrap().contains_key(&key));
+        let cached_clients = CONNECTOR_CLIENTS.read().unwrap().len();
+
+        create_connector_client("client_test_connector", &connector_params, None).unwrap();
+        assert_eq!(CONNECTOR_CLIENTS.read().unwrap().len(), cached_clients);
+
+        // Clients which fail to build are not cached
+        let connector_params = ConnectorParams {
+            ca_bundle: Some("-----BEGIN CERTIFICATE-----\n%%%%\n-----END CERTIFICATE-----".into()),
+            ..Default::default()
+        };
+        assert!(create_connector_client("client_test_invalid", &connector_params, None).is_err());
+        assert!(!CONNECTOR_CLIENTS
+            .read()
+            .unwrap()
+            .contains_key(&("client_test_invalid".to_string(), None)));
+    }
+}
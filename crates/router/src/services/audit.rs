@@ -0,0 +1,286 @@
+//! Audit log of the changes made through the admin APIs.
+//!
+//! Each change to a merchant account, a payment connector, an API key or a config is persisted as
+//! an append-only audit event, along with the principal and the request which made it. Only the
+//! fields which were changed are recorded, re-serialized with
+//! [`masking::with_masked_serialization`], so that secrets such as connector credentials are
+//! stored in their masked form.
+
+use actix_web::HttpRequest;
+use router_env::tracing_actix_web::RequestId;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    consts,
+    db::StorageInterface,
+    logger,
+    types::storage::{self, enums},
+    utils,
+};
+
+/// The principal which made a change, as authenticated by [`super::authentication::AdminApiAuth`].
+#[derive(Clone, Debug)]
+pub struct AuditActor {
+    pub actor_type: enums::AuditActorType,
+    pub actor_id: String,
+}
+
+/// The principal and the request which make changes, recorded along with each change.
+#[derive(Clone, Debug)]
+pub struct AuditContext {
+    pub actor: AuditActor,
+    pub request_id: Option<String>,
+}
+
+/// The resource changed by an audit event.
+#[derive(Clone, Debug)]
+pub struct AuditResource {
+    resource_type: enums::AuditResourceType,
+    resource_id: String,
+    merchant_id: Option<String>,
+}
+
+impl AuditResource {
+    pub fn merchant_account(merchant_id: &str) -> Self {
+        Self {
+            resource_type: enums::AuditResourceType::MerchantAccount,
+            resource_id: merchant_id.to_owned(),
+            merchant_id: Some(merchant_id.to_owned()),
+        }
+    }
+
+    pub fn merchant_connector_account(merchant_id: &str, merchant_connector_id: &str) -> Self {
+        Self {
+            resource_type: enums::AuditResourceType::MerchantConnectorAccount,
+            resource_id: merchant_connector_id.to_owned(),
+            merchant_id: Some(merchant_id.to_owned()),
+        }
+    }
+
+    pub fn api_key(merchant_id: &str, key_id: &str) -> Self {
+        Self {
+            resource_type: enums::AuditResourceType::ApiKey,
+            resource_id: key_id.to_owned(),
+            merchant_id: Some(merchant_id.to_owned()),
+        }
+    }
+
+    pub fn config(key: &str) -> Self {
+        Self {
+            resource_type: enums::AuditResourceType::Config,
+            resource_id: key.to_owned(),
+            merchant_id: None,
+        }
+    }
+}
+
+/// The ID assigned to the request by the tracing middleware, which is also returned to the client
+/// in the `x-request-id` header.
+pub fn get_request_id(request: &HttpRequest) -> Option<String> {
+    request
+        .extensions()
+        .get::<RequestId>()
+        .map(|request_id| request_id.as_hyphenated().to_string())
+}
+
+impl AuditContext {
+    pub fn new(actor: AuditActor, request_id: Option<String>) -> Self {
+        Self { actor, request_id }
+    }
+
+    /// Record a change to a resource, given the resource as it was before the change (`None` if it
+    /// was created) and as it is after the change (`None` if it was deleted).
+    ///
+    /// The change has already been made when it is recorded, so a failure to record it is logged
+    /// instead of failing the request.
+    pub async fn record<T: Serialize>(
+        &self,
+        db: &dyn StorageInterface,
+        action: enums::AuditAction,
+        resource: AuditResource,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) {
+        let snapshots = before.map(Snapshot::new).transpose().and_then(|before| {
+            after
+                .map(Snapshot::new)
+                .transpose()
+                .map(|after| (before, after))
+        });
+        let (before_state, after_state) = match snapshots {
+            Ok((before, after)) => diff(before, after),
+            Err(error) => {
+                logger::error!(audit_event_error=?error);
+                (None, None)
+            }
+        };
+
+        let audit_event = storage::AuditEventNew {
+            event_id: utils::generate_id(consts::ID_LENGTH, "aud"),
+            actor_type: self.actor.actor_type,
+            actor_id: self.actor.actor_id.clone(),
+            action,
+            resource_type: resource.resource_type,
+            resource_id: resource.resource_id,
+            merchant_id: resource.merchant_id,
+            before_state,
+            after_state,
+            request_id: self.request_id.clone(),
+        };
+
+        if let Err(error) = db.insert_audit_event(audit_event).await {
+            logger::error!(audit_event_error=?error);
+        }
+    }
+}
+
+/// A resource serialized both as is, to find the fields which were changed, and masked, to be
+/// recorded.
+struct Snapshot {
+    value: Value,
+    masked: Value,
+}
+
+impl Snapshot {
+    fn new<T: Serialize>(resource: &T) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            value: serde_json::to_value(resource)?,
+            masked: masking::with_masked_serialization(|| serde_json::to_value(resource))?,
+        })
+    }
+}
+
+/// Keep the fields which were changed by an update. The unmasked values are compared, so that a
+/// change to a secret is recorded even though its masked value is the same.
+fn diff(before: Option<Snapshot>, after: Option<Snapshot>) -> (Option<Value>, Option<Value>) {
+    match (before, after) {
+        (Some(before), Some(after)) => match (before.value, after.value) {
+            (Value::Object(before_value), Value::Object(after_value)) => {
+                let retain_changed = |masked: Value| match masked {
+                    Value::Object(mut masked) => {
+                        masked.retain(|key, _| before_value.get(key) != after_value.get(key));
+                        Value::Object(masked)
+                    }
+                    masked => masked,
+                };
+                (
+                    Some(retain_changed(before.masked)),
+                    Some(retain_changed(after.masked)),
+                )
+            }
+            _ => (Some(before.masked), Some(after.masked)),
+        },
+        (before, after) => (
+            before.map(|before| before.masked),
+            after.map(|after| after.masked),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::api;
+
+    #[derive(Serialize)]
+    struct Connector {
+        connector_name: String,
+        connector_account_details: Secret<String>,
+        disabled: bool,
+    }
+
+    fn connector(api_key: &str, disabled: bool) -> Connector {
+        Connector {
+            connector_name: "stripe".to_string(),
+            connector_account_details: Secret::new(api_key.to_string()),
+            disabled,
+        }
+    }
+
+    #[test]
+    fn test_diff_keeps_changed_fields() {
+        let before = Snapshot::new(&connector("sk_test_1", false)).unwrap();
+        let after = Snapshot::new(&connector("sk_test_1", true)).unwrap();
+
+        let (before, after) = diff(Some(before), Some(after));
+
+        assert_eq!(before, Some(json!({ "disabled": false })));
+        assert_eq!(after, Some(json!({ "disabled": true })));
+    }
+
+    #[test]
+    fn test_diff_masks_changed_secrets() {
+        let before = Snapshot::new(&connector("sk_test_1", false)).unwrap();
+        let after = Snapshot::new(&connector("sk_test_2", false)).unwrap();
+
+        let (before, after) = diff(Some(before), Some(after));
+        let (before, after) = (before.unwrap(), after.unwrap());
+
+        assert_eq!(before.as_object().unwrap().len(), 1);
+        assert_eq!(after.as_object().unwrap().len(), 1);
+        assert!(!before.to_string().contains("sk_test_1"));
+        assert!(!after.to_string().contains("sk_test_2"));
+    }
+
+    #[test]
+    fn test_diff_keeps_created_resource() {
+        let after = Snapshot::new(&connector("sk_test_1", false)).unwrap();
+
+        let (before, after) = diff(None, Some(after));
+        let after = after.unwrap();
+
+        assert_eq!(before, None);
+        assert_eq!(after["connector_name"], json!("stripe"));
+        assert!(!after.to_string().contains("sk_test_1"));
+    }
+
+    fn merchant_account(hash_key: &str, webhook_password: &str) -> api::MerchantAccountResponse {
+        api::MerchantAccountResponse {
+            merchant_id: "merchant_1".to_string(),
+            merchant_name: None,
+            api_key: None,
+            return_url: None,
+            enable_payment_response_hash: true,
+            payment_response_hash_key: Some(Secret::new(hash_key.to_string())),
+            redirect_to_merchant_with_http_post: false,
+            merchant_details: None,
+            webhook_details: Some(Secret::new(json!({
+                "webhook_url": "https://example.com/webhooks",
+                "webhook_password": webhook_password,
+            }))),
+            routing_algorithm: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            organization_id: None,
+            publishable_key: None,
+            metadata: None,
+            locker_id: None,
+            pm_filters: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_masks_merchant_account_secrets() {
+        let before = Snapshot::new(&merchant_account("hash_key_1", "password_1")).unwrap();
+        let after = Snapshot::new(&merchant_account("hash_key_2", "password_2")).unwrap();
+
+        let (before, after) = diff(Some(before), Some(after));
+        let (before, after) = (before.unwrap(), after.unwrap());
+
+        for state in [&before, &after] {
+            let state = state.as_object().unwrap();
+            assert_eq!(state.len(), 2);
+            assert!(state.contains_key("payment_response_hash_key"));
+            assert!(state.contains_key("webhook_details"));
+        }
+        for secret in ["hash_key_1", "password_1", "hash_key_2", "password_2"] {
+            assert!(!before.to_string().contains(secret));
+            assert!(!after.to_string().contains(secret));
+        }
+    }
+}
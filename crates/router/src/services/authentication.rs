@@ -3,12 +3,19 @@ use api_models::{payment_methods::ListPaymentMethodRequest, payments::PaymentsRe
 use async_trait::async_trait;
 use error_stack::{report, IntoReport, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use router_env::Tag;
 
+pub use crate::types::storage::enums::Permission;
 use crate::{
-    core::errors::{self, RouterResult},
+    consts,
+    core::{
+        api_keys,
+        errors::{self, RouterResult},
+    },
     db::StorageInterface,
+    logger,
     routes::{app::AppStateInfo, AppState},
-    services::api,
+    services::{api, audit, rate_limit},
     types::storage,
     utils::OptionExt,
 };
@@ -18,13 +25,22 @@ pub trait AuthenticateAndFetch<T, A>
 where
     A: AppStateInfo,
 {
+    /// Authenticates the request and checks that the credentials grant the permission declared by
+    /// the route. Credentials which are not scoped to permissions grant every permission.
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        permission: Permission,
     ) -> RouterResult<T>;
 }
 
+/// Authentication with the API key of a merchant, or with the API key of an organization. Requests
+/// authenticated with the API key of an organization act on behalf of the merchant account of the
+/// organization selected with the `x-merchant-id` header.
+///
+/// Only the API keys created through the API keys APIs can be scoped to permissions, the API key of
+/// the merchant account and the API keys of organizations grant every permission.
 #[derive(Debug)]
 pub struct ApiKeyAuth;
 
@@ -37,9 +53,28 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        permission: Permission,
     ) -> RouterResult<storage::MerchantAccount> {
         let api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+        if api_key.starts_with(consts::ORGANIZATION_API_KEY_PREFIX) {
+            let organization = find_organization_by_api_key(state, api_key).await?;
+            let merchant_id = get_merchant_id_header(request_headers)?
+                .get_required_value(crate::headers::X_MERCHANT_ID)?;
+            return find_organization_merchant_account(state, &organization, merchant_id).await;
+        }
+
+        if let Some((key_id, plaintext_api_key)) = api_keys::split_api_key(api_key) {
+            let api_key = find_api_key(state, key_id, &plaintext_api_key, permission).await?;
+            return state
+                .store()
+                .find_merchant_account_by_merchant_id(&api_key.merchant_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the merchant account of the API key");
+        }
+
         state
             .store()
             .find_merchant_account_by_api_key(api_key)
@@ -54,11 +89,263 @@ where
     }
 }
 
+/// Used by the APIs which list resources, so that the API key of an organization lists the resources
+/// of all the merchant accounts of the organization, unless a single merchant account is selected
+/// with the `x-merchant-id` header.
+#[async_trait]
+impl<A> AuthenticateAndFetch<Vec<storage::MerchantAccount>, A> for ApiKeyAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+        permission: Permission,
+    ) -> RouterResult<Vec<storage::MerchantAccount>> {
+        let api_key =
+            get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+        if !api_key.starts_with(consts::ORGANIZATION_API_KEY_PREFIX)
+            || get_merchant_id_header(request_headers)?.is_some()
+        {
+            let merchant_account =
+                <Self as AuthenticateAndFetch<storage::MerchantAccount, A>>::authenticate_and_fetch(
+                    self,
+                    request_headers,
+                    state,
+                    permission,
+                )
+                .await?;
+            return Ok(vec![merchant_account]);
+        }
+
+        let organization = find_organization_by_api_key(state, api_key).await?;
+        state
+            .store()
+            .list_merchant_accounts_by_organization_id(&organization.org_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the merchant accounts of the organization")
+    }
+}
+
+/// Verifies an API key created through the API keys APIs, which is rejected if it has expired or
+/// does not grant the permission.
+async fn find_api_key<A: AppStateInfo>(
+    state: &A,
+    key_id: &str,
+    plaintext_api_key: &api_keys::PlaintextApiKey,
+    permission: Permission,
+) -> RouterResult<storage::ApiKey> {
+    let api_key = state
+        .store()
+        .find_api_key_optional(key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the API key")?
+        .ok_or_else(|| report!(errors::ApiErrorResponse::Unauthorized))?;
+
+    plaintext_api_key
+        .verify_stored_hash(&api_key.hash_key, &api_key.hashed_api_key)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+    if api_key.expires_at.map_or(false, |expires_at| {
+        expires_at <= common_utils::date_time::now()
+    }) {
+        Err(
+            report!(errors::ApiErrorResponse::Unauthorized).attach_printable("API key has expired")
+        )?;
+    }
+
+    match &api_key.permissions {
+        Some(permissions) if !permissions.contains(&permission) => {
+            Err(report!(errors::ApiErrorResponse::GenericUnauthorized {
+                message: format!("The API key does not grant the {permission} permission"),
+            }))
+        }
+        _ => Ok(api_key),
+    }
+}
+
+/// Verifies an API key of an organization, which is issued in the `<org_id>-<plaintext key>`
+/// format.
+async fn find_organization_by_api_key<A: AppStateInfo>(
+    state: &A,
+    api_key: &str,
+) -> RouterResult<storage::Organization> {
+    let (org_id, plaintext_api_key) = api_keys::split_api_key(api_key)
+        .ok_or_else(|| report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Organization API key is not in the `<org_id>-<plaintext key>` format")?;
+
+    let organization = state
+        .store()
+        .find_organization_by_org_id(org_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(errors::ApiErrorResponse::Unauthorized)
+            } else {
+                e.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })?;
+
+    plaintext_api_key
+        .verify_stored_hash(&organization.hash_key, &organization.hashed_api_key)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+    Ok(organization)
+}
+
+/// Merchant accounts outside of the organization are treated as not found.
+async fn find_organization_merchant_account<A: AppStateInfo>(
+    state: &A,
+    organization: &storage::Organization,
+    merchant_id: &str,
+) -> RouterResult<storage::MerchantAccount> {
+    let merchant_account = state
+        .store()
+        .find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(errors::ApiErrorResponse::Unauthorized)
+            } else {
+                e.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })?;
+
+    if merchant_account.organization_id.as_ref() != Some(&organization.org_id) {
+        Err(
+            report!(errors::ApiErrorResponse::Unauthorized).attach_printable(
+                "Merchant account does not belong to the organization of the API key",
+            ),
+        )?;
+    }
+
+    Ok(merchant_account)
+}
+
+/// Rate limits the requests authenticated by another authentication, in a route group. The requests
+/// are counted against the merchant account, and against the API key if one created through the
+/// API keys APIs was used, see [`rate_limit`].
+pub struct RateLimitedAuth<'a, T> {
+    pub auth: &'a (dyn AuthenticateAndFetch<T, AppState> + Sync),
+    pub route_group: rate_limit::RouteGroup,
+}
+
+impl<'a, T> RateLimitedAuth<'a, T> {
+    pub fn new(
+        auth: &'a (dyn AuthenticateAndFetch<T, AppState> + Sync),
+        route_group: rate_limit::RouteGroup,
+    ) -> Self {
+        Self { auth, route_group }
+    }
+}
+
+/// ID of the API key used for a request, for API keys created through the API keys APIs.
+fn get_api_key_id(request_headers: &HeaderMap) -> Option<&str> {
+    get_api_key(request_headers)
+        .ok()
+        .filter(|api_key| !api_key.starts_with(consts::ORGANIZATION_API_KEY_PREFIX))
+        .and_then(api_keys::split_api_key)
+        .map(|(key_id, _)| key_id)
+}
+
+#[async_trait]
+impl AuthenticateAndFetch<storage::MerchantAccount, AppState>
+    for RateLimitedAuth<'_, storage::MerchantAccount>
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &AppState,
+        permission: Permission,
+    ) -> RouterResult<storage::MerchantAccount> {
+        let merchant_account = self
+            .auth
+            .authenticate_and_fetch(request_headers, state, permission)
+            .await?;
+
+        let mut subjects = vec![rate_limit::RateLimitSubject::Merchant(
+            &merchant_account.merchant_id,
+        )];
+        subjects.extend(get_api_key_id(request_headers).map(rate_limit::RateLimitSubject::ApiKey));
+        rate_limit::check_rate_limit(state, self.route_group, &subjects).await?;
+
+        Ok(merchant_account)
+    }
+}
+
+/// The requests listing the resources of all the merchant accounts of an organization are counted
+/// against the organization.
+#[async_trait]
+impl AuthenticateAndFetch<Vec<storage::MerchantAccount>, AppState>
+    for RateLimitedAuth<'_, Vec<storage::MerchantAccount>>
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &AppState,
+        permission: Permission,
+    ) -> RouterResult<Vec<storage::MerchantAccount>> {
+        let merchant_accounts = self
+            .auth
+            .authenticate_and_fetch(request_headers, state, permission)
+            .await?;
+
+        let mut subjects = match merchant_accounts.as_slice() {
+            [merchant_account] => vec![rate_limit::RateLimitSubject::Merchant(
+                &merchant_account.merchant_id,
+            )],
+            merchant_accounts => merchant_accounts
+                .first()
+                .and_then(|merchant_account| merchant_account.organization_id.as_deref())
+                .map(rate_limit::RateLimitSubject::Organization)
+                .into_iter()
+                .collect(),
+        };
+        subjects.extend(get_api_key_id(request_headers).map(rate_limit::RateLimitSubject::ApiKey));
+        rate_limit::check_rate_limit(state, self.route_group, &subjects).await?;
+
+        Ok(merchant_accounts)
+    }
+}
+
+/// Authentication for requests which are verified by the handler itself, such as the checkout page
+/// of a payment link, which is found from an identifier that cannot be guessed, or events signed by
+/// an external service.
+#[derive(Debug)]
+pub struct NoAuth;
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<(), A> for NoAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        _request_headers: &HeaderMap,
+        _state: &A,
+        _permission: Permission,
+    ) -> RouterResult<()> {
+        Ok(())
+    }
+}
+
+/// Authentication with an admin API key: either the `admin_api_key` from the configuration, which
+/// grants every permission and is meant for creating admin principals, or the admin API key of an
+/// admin principal. Every request to the admin APIs is logged along with the principal which made
+/// it, and the principal is returned so that the changes it makes are recorded in the audit log.
 #[derive(Debug)]
 pub struct AdminApiAuth;
 
+/// Name recorded in the audit log for the requests authenticated with the `admin_api_key` from the
+/// configuration.
+const CONFIG_ADMIN_PRINCIPAL: &str = "admin_api_key";
+
 #[async_trait]
-impl<A> AuthenticateAndFetch<(), A> for AdminApiAuth
+impl<A> AuthenticateAndFetch<audit::AuditActor, A> for AdminApiAuth
 where
     A: AppStateInfo + Sync,
 {
@@ -66,18 +353,78 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<()> {
+        permission: Permission,
+    ) -> RouterResult<audit::AuditActor> {
         let admin_api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
         let conf = state.conf();
-        if admin_api_key != conf.secrets.admin_api_key {
-            Err(report!(errors::ApiErrorResponse::Unauthorized)
-                .attach_printable("Admin Authentication Failure"))?;
+        if admin_api_key == conf.secrets.admin_api_key {
+            logger::info!(
+                tag = ?Tag::AdminAudit,
+                principal = CONFIG_ADMIN_PRINCIPAL,
+                %permission,
+                authorized = true,
+            );
+            return Ok(audit::AuditActor {
+                actor_type: storage::enums::AuditActorType::AdminApiKey,
+                actor_id: CONFIG_ADMIN_PRINCIPAL.to_string(),
+            });
+        }
+
+        let admin_principal = find_admin_principal(state, admin_api_key).await?;
+        let authorized = admin_principal.permissions.contains(&permission);
+        logger::info!(
+            tag = ?Tag::AdminAudit,
+            principal = %admin_principal.name,
+            principal_id = %admin_principal.principal_id,
+            %permission,
+            authorized,
+        );
+
+        if authorized {
+            Ok(audit::AuditActor {
+                actor_type: storage::enums::AuditActorType::AdminPrincipal,
+                actor_id: admin_principal.principal_id,
+            })
+        } else {
+            Err(report!(errors::ApiErrorResponse::GenericUnauthorized {
+                message: format!("The admin principal does not have the {permission} permission"),
+            }))
         }
-        Ok(())
     }
 }
 
+async fn find_admin_principal<A: AppStateInfo>(
+    state: &A,
+    admin_api_key: &str,
+) -> RouterResult<storage::AdminPrincipal> {
+    let (principal_id, plaintext_api_key) =
+        api_keys::split_api_key(admin_api_key).ok_or_else(|| {
+            report!(errors::ApiErrorResponse::Unauthorized)
+                .attach_printable("Admin Authentication Failure")
+        })?;
+
+    let admin_principal = state
+        .store()
+        .find_admin_principal_by_principal_id(principal_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(errors::ApiErrorResponse::Unauthorized)
+                    .attach_printable("Admin Authentication Failure")
+            } else {
+                e.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })?;
+
+    plaintext_api_key
+        .verify_stored_hash(&admin_principal.hash_key, &admin_principal.hashed_api_key)
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Admin Authentication Failure")?;
+
+    Ok(admin_principal)
+}
+
 #[derive(Debug)]
 pub struct MerchantIdAuth(pub String);
 
@@ -87,6 +434,7 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for MerchantIdAuth
         &self,
         _request_headers: &HeaderMap,
         state: &AppState,
+        _permission: Permission,
     ) -> RouterResult<storage::MerchantAccount> {
         state
             .store
@@ -111,6 +459,7 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for PublishableKey
         &self,
         request_headers: &HeaderMap,
         state: &AppState,
+        _permission: Permission,
     ) -> RouterResult<storage::MerchantAccount> {
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
@@ -146,6 +495,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        _permission: Permission,
     ) -> RouterResult<()> {
         let mut token = get_jwt(request_headers)?;
         token = strip_jwt_token(token)?;
@@ -167,6 +517,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        _permission: Permission,
     ) -> RouterResult<storage::MerchantAccount> {
         let mut token = get_jwt(request_headers)?;
         token = strip_jwt_token(token)?;
@@ -306,6 +657,19 @@ pub fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
         .attach_printable("Failed to convert API key to string")
 }
 
+pub fn get_merchant_id_header(headers: &HeaderMap) -> RouterResult<Option<&str>> {
+    headers
+        .get(crate::headers::X_MERCHANT_ID)
+        .map(|merchant_id| {
+            merchant_id.to_str().into_report().change_context(
+                errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: crate::headers::X_MERCHANT_ID,
+                },
+            )
+        })
+        .transpose()
+}
+
 pub fn get_jwt(headers: &HeaderMap) -> RouterResult<&str> {
     headers
         .get(crate::headers::AUTHORIZATION)
@@ -321,3 +685,270 @@ pub fn strip_jwt_token(token: &str) -> RouterResult<&str> {
         .strip_prefix("Bearer ")
         .ok_or_else(|| errors::ApiErrorResponse::InvalidJwtToken.into())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use actix_web::http::header::{HeaderName, HeaderValue};
+    use masking::PeekInterface;
+
+    use super::*;
+    use crate::{
+        core::{admin, admin_principals},
+        test_utils::{self, get_json_response},
+        types::api,
+    };
+
+    /// Creates an organization with the `merchant_1` and `merchant_2` merchant accounts, and the
+    /// `merchant_3` merchant account outside of the organization. Returns the organization API key.
+    async fn get_state() -> (AppState, String) {
+        let (state, _) = test_utils::get_state().await;
+        let organization = get_json_response(
+            admin::create_organization(&*state.store, api::OrganizationRequest { org_name: None })
+                .await
+                .unwrap(),
+        );
+
+        for (merchant_id, organization_id) in [
+            ("merchant_1", Some(organization.org_id.clone())),
+            ("merchant_2", Some(organization.org_id.clone())),
+            ("merchant_3", None),
+        ] {
+            state
+                .store
+                .insert_merchant(storage::MerchantAccountNew {
+                    merchant_id: merchant_id.to_string(),
+                    organization_id,
+                    ..storage::MerchantAccountNew::default()
+                })
+                .await
+                .unwrap();
+        }
+        (state, organization.api_key.peek().clone())
+    }
+
+    fn get_headers(api_key: &str, merchant_id: Option<&str>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("api-key"),
+            HeaderValue::from_str(api_key).unwrap(),
+        );
+        if let Some(merchant_id) = merchant_id {
+            headers.insert(
+                HeaderName::from_static("x-merchant-id"),
+                HeaderValue::from_str(merchant_id).unwrap(),
+            );
+        }
+        headers
+    }
+
+    /// Creates an API key for the `merchant_1` merchant account and returns it.
+    async fn create_api_key(
+        state: &AppState,
+        expiration: api::ApiKeyExpiration,
+        permissions: Option<Vec<api::enums::Permission>>,
+    ) -> String {
+        let api_key = get_json_response(
+            api_keys::create_api_key(
+                &*state.store,
+                api::CreateApiKeyRequest {
+                    name: "test_key".to_string(),
+                    description: None,
+                    expiration,
+                    permissions,
+                },
+                "merchant_1".to_string(),
+            )
+            .await
+            .unwrap(),
+        );
+        api_key.api_key.peek().clone()
+    }
+
+    async fn authenticate(
+        state: &AppState,
+        headers: HeaderMap,
+        permission: Permission,
+    ) -> RouterResult<storage::MerchantAccount> {
+        ApiKeyAuth
+            .authenticate_and_fetch(&headers, state, permission)
+            .await
+    }
+
+    async fn authenticate_list(
+        state: &AppState,
+        headers: HeaderMap,
+    ) -> RouterResult<Vec<storage::MerchantAccount>> {
+        ApiKeyAuth
+            .authenticate_and_fetch(&headers, state, Permission::PaymentsRead)
+            .await
+    }
+
+    #[actix_rt::test]
+    async fn test_organization_api_key_acts_on_merchant_of_organization() {
+        let (state, api_key) = get_state().await;
+
+        let merchant_account = authenticate(
+            &state,
+            get_headers(&api_key, Some("merchant_2")),
+            Permission::PaymentsRead,
+        )
+        .await
+        .unwrap();
+        assert_eq!(merchant_account.merchant_id, "merchant_2");
+
+        // Merchant accounts outside of the organization are rejected
+        let error = authenticate(
+            &state,
+            get_headers(&api_key, Some("merchant_3")),
+            Permission::PaymentsRead,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::Unauthorized
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn test_organization_api_key_requires_merchant_id_header() {
+        let (state, api_key) = get_state().await;
+
+        let error = authenticate(
+            &state,
+            get_headers(&api_key, None),
+            Permission::PaymentsRead,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::MissingRequiredField { .. }
+        ));
+
+        // Listing without the header lists the merchant accounts of the organization
+        let mut merchant_ids: Vec<_> = authenticate_list(&state, get_headers(&api_key, None))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|merchant_account| merchant_account.merchant_id)
+            .collect();
+        merchant_ids.sort();
+        assert_eq!(merchant_ids, ["merchant_1", "merchant_2"]);
+
+        let merchant_accounts =
+            authenticate_list(&state, get_headers(&api_key, Some("merchant_1")))
+                .await
+                .unwrap();
+        assert_eq!(merchant_accounts.len(), 1);
+    }
+
+    #[actix_rt::test]
+    async fn test_scoped_api_key_is_denied_permissions_it_does_not_grant() {
+        let (state, _) = get_state().await;
+        let api_key = create_api_key(
+            &state,
+            api::ApiKeyExpiration::Never,
+            Some(vec![api::enums::Permission::PaymentsRead]),
+        )
+        .await;
+
+        let merchant_account = authenticate(
+            &state,
+            get_headers(&api_key, None),
+            Permission::PaymentsRead,
+        )
+        .await
+        .unwrap();
+        assert_eq!(merchant_account.merchant_id, "merchant_1");
+
+        let error = authenticate(
+            &state,
+            get_headers(&api_key, None),
+            Permission::PaymentsWrite,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::GenericUnauthorized { .. }
+        ));
+
+        // API keys without permissions grant every permission
+        let api_key = create_api_key(&state, api::ApiKeyExpiration::Never, None).await;
+        let merchant_account = authenticate(
+            &state,
+            get_headers(&api_key, None),
+            Permission::PaymentsWrite,
+        )
+        .await
+        .unwrap();
+        assert_eq!(merchant_account.merchant_id, "merchant_1");
+    }
+
+    #[actix_rt::test]
+    async fn test_expired_api_key_is_rejected() {
+        let (state, _) = get_state().await;
+        let api_key = create_api_key(
+            &state,
+            api::ApiKeyExpiration::DateTime(
+                common_utils::date_time::now() - time::Duration::minutes(1),
+            ),
+            None,
+        )
+        .await;
+
+        let error = authenticate(
+            &state,
+            get_headers(&api_key, None),
+            Permission::PaymentsRead,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::Unauthorized
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn test_admin_principal_is_denied_permissions_it_does_not_have() {
+        let (state, _) = get_state().await;
+        let admin_principal = get_json_response(
+            admin_principals::create_admin_principal(
+                &*state.store,
+                api::admin_principals::CreateAdminPrincipalRequest {
+                    name: "support-team".to_string(),
+                    description: None,
+                    permissions: vec![api::enums::Permission::MerchantAccountsRead],
+                },
+            )
+            .await
+            .unwrap(),
+        );
+        let headers = get_headers(admin_principal.api_key.peek(), None);
+
+        AdminApiAuth
+            .authenticate_and_fetch(&headers, &state, Permission::MerchantAccountsRead)
+            .await
+            .unwrap();
+
+        let error = AdminApiAuth
+            .authenticate_and_fetch(&headers, &state, Permission::MerchantAccountsWrite)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::GenericUnauthorized { .. }
+        ));
+
+        // The admin API key from the configuration grants every permission
+        let headers = get_headers(&state.conf.secrets.admin_api_key, None);
+        AdminApiAuth
+            .authenticate_and_fetch(&headers, &state, Permission::MerchantAccountsWrite)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,370 @@
+//! Per merchant, per connector circuit breaker.
+//!
+//! Call outcomes are counted in fixed windows stored in Redis, so that all router instances
+//! share the same view of a connector's health. Once the error rate or the slow call rate within
+//! a window crosses the configured threshold, the circuit opens and connector calls are rejected
+//! for `open_duration` seconds. After that, a single probe call is allowed through: the circuit
+//! closes if the probe succeeds and opens again if it fails.
+
+use std::time::Duration;
+
+use common_utils::date_time;
+use router_env::opentelemetry::KeyValue;
+
+use crate::{
+    configs::settings::CircuitBreakerSettings,
+    core::errors::{self, CustomResult},
+    logger,
+    routes::{metrics, AppState},
+    types::{self, storage},
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CallPermission {
+    /// The circuit is closed or the circuit breaker is disabled
+    Allowed,
+    /// The circuit is half-open and this call is the probe allowed through
+    Probe,
+    /// The circuit is open
+    Rejected,
+}
+
+fn current_window(config: &CircuitBreakerSettings) -> i64 {
+    date_time::now_unix_timestamp() / config.window_size
+}
+
+/// Obtain the current state of the circuit for the given merchant and connector.
+///
+/// An open circuit is reported as half-open once `open_duration` has elapsed since it was opened.
+/// Errors while reading the state from Redis are logged and the circuit is treated as closed.
+pub async fn get_circuit_state(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+) -> storage::CircuitState {
+    let config = &state.conf.circuit_breaker;
+    let record = match state
+        .store
+        .get_circuit_breaker_record(merchant_id, connector_name)
+        .await
+    {
+        Ok(record) => record.unwrap_or_default(),
+        Err(error) => {
+            logger::error!(circuit_breaker_error=?error);
+            return storage::CircuitState::Closed;
+        }
+    };
+
+    effective_circuit_state(&record, config, date_time::now_unix_timestamp())
+}
+
+/// State of the circuit described by `record` at the unix timestamp `now`, where an open circuit
+/// becomes half-open once `open_duration` has elapsed since it was opened.
+fn effective_circuit_state(
+    record: &storage::CircuitBreakerRecord,
+    config: &CircuitBreakerSettings,
+    now: i64,
+) -> storage::CircuitState {
+    match record.state {
+        storage::CircuitState::Open
+            if record.opened_at.map_or(true, |opened_at| {
+                now >= opened_at.saturating_add(config.open_duration)
+            }) =>
+        {
+            storage::CircuitState::HalfOpen
+        }
+        circuit_state => circuit_state,
+    }
+}
+
+/// Obtain the state of the circuit along with the call statistics for the current window.
+pub async fn get_connector_health(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+) -> (storage::CircuitState, storage::ConnectorCallStats) {
+    let circuit_state = get_circuit_state(state, merchant_id, connector_name).await;
+    let stats = state
+        .store
+        .get_connector_call_stats(
+            merchant_id,
+            connector_name,
+            current_window(&state.conf.circuit_breaker),
+        )
+        .await
+        .map_err(|error| logger::error!(circuit_breaker_error=?error))
+        .unwrap_or_default();
+
+    (circuit_state, stats)
+}
+
+/// Whether calls may currently be routed to the given connector.
+pub async fn is_connector_available(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+) -> bool {
+    !state.conf.circuit_breaker.enabled
+        || get_circuit_state(state, merchant_id, connector_name).await
+            != storage::CircuitState::Open
+}
+
+/// Decide whether a connector call may be made.
+///
+/// When the circuit is half-open, only the caller that acquires the probe lock is allowed
+/// through. Errors while communicating with Redis never block a connector call.
+pub async fn acquire_permission(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+) -> CallPermission {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return CallPermission::Allowed;
+    }
+
+    let permission = match get_circuit_state(state, merchant_id, connector_name).await {
+        storage::CircuitState::Closed => CallPermission::Allowed,
+        storage::CircuitState::Open => CallPermission::Rejected,
+        storage::CircuitState::HalfOpen => match state
+            .store
+            .acquire_circuit_probe_lock(merchant_id, connector_name, config.open_duration)
+            .await
+        {
+            Ok(true) => CallPermission::Probe,
+            Ok(false) => CallPermission::Rejected,
+            Err(error) => {
+                logger::error!(circuit_breaker_error=?error);
+                CallPermission::Allowed
+            }
+        },
+    };
+
+    if permission == CallPermission::Rejected {
+        logger::warn!(%merchant_id, %connector_name, "Circuit is open, rejecting connector call");
+        metrics::CIRCUIT_BREAKER_REJECTED_CALLS.add(
+            &metrics::CONTEXT,
+            1,
+            &[KeyValue::new("connector", connector_name.to_string())],
+        );
+    }
+
+    permission
+}
+
+/// Whether the outcome of a connector call should count as a failure.
+/// Only network errors and server errors returned by the connector are considered failures.
+pub fn is_failed_call(
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) -> bool {
+    match response {
+        Ok(Ok(_)) => false,
+        Ok(Err(error_response)) => error_response.status_code >= 500,
+        Err(_) => true,
+    }
+}
+
+/// Record the outcome of a connector call and update the state of the circuit accordingly.
+pub async fn record_call(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+    permission: CallPermission,
+    failed: bool,
+    latency: Duration,
+) {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return;
+    }
+
+    let latency = u64::try_from(latency.as_millis()).unwrap_or(u64::MAX);
+    let outcome = storage::ConnectorCallOutcome {
+        failed,
+        slow: latency >= config.slow_call_threshold,
+        latency,
+    };
+
+    let result = match permission {
+        CallPermission::Allowed => {
+            on_call_completion(state, config, merchant_id, connector_name, outcome).await
+        }
+        CallPermission::Probe => {
+            on_probe_completion(state, config, merchant_id, connector_name, outcome).await
+        }
+        CallPermission::Rejected => Ok(()),
+    };
+
+    if let Err(error) = result {
+        logger::error!(circuit_breaker_error=?error);
+    }
+}
+
+async fn on_call_completion(
+    state: &AppState,
+    config: &CircuitBreakerSettings,
+    merchant_id: &str,
+    connector_name: &str,
+    outcome: storage::ConnectorCallOutcome,
+) -> CustomResult<(), errors::StorageError> {
+    let stats = state
+        .store
+        .record_connector_call(
+            merchant_id,
+            connector_name,
+            current_window(config),
+            outcome,
+            config.window_size.saturating_mul(2),
+        )
+        .await?;
+
+    if should_open_circuit(config, &stats) {
+        logger::warn!(%merchant_id, %connector_name, ?stats, "Opening circuit for connector");
+        open_circuit(state, config, merchant_id, connector_name).await?;
+    }
+
+    Ok(())
+}
+
+/// Whether the calls of a window are failing or slow often enough for the circuit to open.
+fn should_open_circuit(
+    config: &CircuitBreakerSettings,
+    stats: &storage::ConnectorCallStats,
+) -> bool {
+    stats.total_calls >= config.min_calls
+        && (stats.error_rate() >= config.error_rate_threshold
+            || stats.slow_call_rate() >= config.slow_call_rate_threshold)
+}
+
+async fn on_probe_completion(
+    state: &AppState,
+    config: &CircuitBreakerSettings,
+    merchant_id: &str,
+    connector_name: &str,
+    outcome: storage::ConnectorCallOutcome,
+) -> CustomResult<(), errors::StorageError> {
+    if outcome.failed || outcome.slow {
+        logger::warn!(%merchant_id, %connector_name, "Probe call failed, reopening circuit");
+        open_circuit(state, config, merchant_id, connector_name).await?;
+    } else {
+        logger::info!(%merchant_id, %connector_name, "Probe call succeeded, closing circuit");
+        state
+            .store
+            .delete_circuit_breaker_record(merchant_id, connector_name)
+            .await?;
+        state
+            .store
+            .reset_connector_call_stats(merchant_id, connector_name, current_window(config))
+            .await?;
+    }
+
+    state
+        .store
+        .release_circuit_probe_lock(merchant_id, connector_name)
+        .await
+}
+
+async fn open_circuit(
+    state: &AppState,
+    config: &CircuitBreakerSettings,
+    merchant_id: &str,
+    connector_name: &str,
+) -> CustomResult<(), errors::StorageError> {
+    let record = storage::CircuitBreakerRecord {
+        state: storage::CircuitState::Open,
+        opened_at: Some(date_time::now_unix_timestamp()),
+    };
+
+    // The record outlives the open duration so that the circuit is reported as half-open
+    // until a probe call completes, and is cleaned up if no calls are made for a while.
+    state
+        .store
+        .set_circuit_breaker_record(
+            merchant_id,
+            connector_name,
+            record,
+            config.open_duration.saturating_add(config.window_size),
+        )
+        .await?;
+
+    metrics::CIRCUIT_BREAKER_OPENED.add(
+        &metrics::CONTEXT,
+        1,
+        &[KeyValue::new("connector", connector_name.to_string())],
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(total_calls: u64, failed_calls: u64, slow_calls: u64) -> storage::ConnectorCallStats {
+        storage::ConnectorCallStats {
+            total_calls,
+            failed_calls,
+            slow_calls,
+            total_latency: 0,
+        }
+    }
+
+    fn response(status_code: u16) -> types::Response {
+        types::Response {
+            response: bytes::Bytes::new(),
+            status_code,
+        }
+    }
+
+    #[test]
+    fn test_circuit_stays_closed_below_min_calls() {
+        let config = CircuitBreakerSettings::default();
+        assert!(!should_open_circuit(
+            &config,
+            &stats(config.min_calls - 1, config.min_calls - 1, 0)
+        ));
+    }
+
+    #[test]
+    fn test_circuit_opens_on_error_or_slow_call_rate() {
+        let config = CircuitBreakerSettings::default();
+        // 50% errors
+        assert!(should_open_circuit(&config, &stats(20, 10, 0)));
+        assert!(!should_open_circuit(&config, &stats(20, 9, 0)));
+        // 80% slow calls
+        assert!(should_open_circuit(&config, &stats(20, 0, 16)));
+        assert!(!should_open_circuit(&config, &stats(20, 0, 15)));
+    }
+
+    #[test]
+    fn test_open_circuit_becomes_half_open_after_open_duration() {
+        let config = CircuitBreakerSettings::default();
+        let record = storage::CircuitBreakerRecord {
+            state: storage::CircuitState::Open,
+            opened_at: Some(1000),
+        };
+
+        assert_eq!(
+            effective_circuit_state(&record, &config, 1000 + config.open_duration - 1),
+            storage::CircuitState::Open
+        );
+        assert_eq!(
+            effective_circuit_state(&record, &config, 1000 + config.open_duration),
+            storage::CircuitState::HalfOpen
+        );
+        assert_eq!(
+            effective_circuit_state(&storage::CircuitBreakerRecord::default(), &config, 1000),
+            storage::CircuitState::Closed
+        );
+    }
+
+    #[test]
+    fn test_only_server_and_network_errors_are_failures() {
+        assert!(!is_failed_call(&Ok(Ok(response(200)))));
+        assert!(!is_failed_call(&Ok(Err(response(400)))));
+        assert!(is_failed_call(&Ok(Err(response(503)))));
+        assert!(is_failed_call(&Err(error_stack::report!(
+            errors::ApiClientError::RequestTimeoutReceived
+        ))));
+    }
+}
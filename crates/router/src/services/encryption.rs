@@ -50,72 +50,6 @@ impl NonceSequence for NonceGen {
     }
 }
 
-pub struct KeyHandler;
-
-#[cfg(feature = "kms")]
-mod kms {
-    use aws_config::meta::region::RegionProviderChain;
-    use aws_sdk_kms::{types::Blob, Client, Region};
-    use base64::Engine;
-
-    use super::*;
-    use crate::consts;
-
-    impl KeyHandler {
-        // Fetching KMS decrypted key
-        // | Amazon KMS decryption
-        // This expect a base64 encoded input but we values are set via aws cli in env than cli
-        // already does that so we don't need to
-        pub async fn get_kms_decrypted_key(
-            aws_keys: &Jwekey,
-            kms_enc_key: String,
-        ) -> CustomResult<String, errors::EncryptionError> {
-            let region = aws_keys.aws_region.to_string();
-            let key_id = aws_keys.aws_key_id.clone();
-            let region_provider = RegionProviderChain::first_try(Region::new(region));
-            let shared_config = aws_config::from_env().region(region_provider).load().await;
-            let client = Client::new(&shared_config);
-            let data = consts::BASE64_ENGINE
-                .decode(kms_enc_key)
-                .into_report()
-                .change_context(errors::EncryptionError)
-                .attach_printable("Error decoding from base64")?;
-            let blob = Blob::new(data);
-            let resp = client
-                .decrypt()
-                .key_id(key_id)
-                .ciphertext_blob(blob)
-                .send()
-                .await
-                .into_report()
-                .change_context(errors::EncryptionError)
-                .attach_printable("Error decrypting kms encrypted data")?;
-            match resp.plaintext() {
-                Some(inner) => {
-                    let bytes = inner.as_ref().to_vec();
-                    let res = String::from_utf8(bytes)
-                        .into_report()
-                        .change_context(errors::EncryptionError)
-                        .attach_printable("Could not convert to UTF-8")?;
-                    Ok(res)
-                }
-                None => Err(report!(errors::EncryptionError)
-                    .attach_printable("Missing plaintext in response")),
-            }
-        }
-    }
-}
-
-#[cfg(not(feature = "kms"))]
-impl KeyHandler {
-    pub async fn get_kms_decrypted_key(
-        _aws_keys: &Jwekey,
-        key: String,
-    ) -> CustomResult<String, errors::EncryptionError> {
-        Ok(key)
-    }
-}
-
 pub fn encrypt(msg: &String, key: &[u8]) -> CustomResult<Vec<u8>, errors::EncryptionError> {
     let nonce_seed = rand::random();
     let mut sealing_key = {
@@ -184,9 +118,9 @@ pub async fn encrypt_jwe(
     let alg = jwe::RSA_OAEP_256;
     let key_id = get_key_id(keys);
     let public_key = if key_id == keys.locker_key_identifier1 {
-        KeyHandler::get_kms_decrypted_key(keys, keys.locker_encryption_key1.to_string()).await?
+        keys.locker_encryption_key1.as_str()
     } else {
-        KeyHandler::get_kms_decrypted_key(keys, keys.locker_encryption_key2.to_string()).await?
+        keys.locker_encryption_key2.as_str()
     };
     let payload = msg.as_bytes();
     let enc = "A256GCM";
@@ -213,9 +147,9 @@ pub async fn decrypt_jwe(
     let alg = jwe::RSA_OAEP_256;
     let key_id = get_key_id(keys);
     let private_key = if key_id == keys.locker_key_identifier1 {
-        KeyHandler::get_kms_decrypted_key(keys, keys.locker_decryption_key1.to_string()).await?
+        keys.locker_decryption_key1.as_str()
     } else {
-        KeyHandler::get_kms_decrypted_key(keys, keys.locker_decryption_key2.to_string()).await?
+        keys.locker_decryption_key2.as_str()
     };
 
     let decrypter = alg
@@ -0,0 +1,433 @@
+//! Envelope encryption of sensitive columns.
+//!
+//! Every merchant has its own data key, which encrypts the connector credentials and the PII of
+//! the customers of the merchant. The data keys are stored in the `merchant_key_store` table,
+//! wrapped by one of the master keys configured in [`Jwekey`], so rotating a master key only
+//! requires the data keys to be re-wrapped.
+
+use std::collections::HashMap;
+
+use base64::Engine;
+use common_utils::crypto::{self, GcmAes256};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret, Strategy};
+use tokio::sync::OnceCell;
+
+use crate::{
+    configs::settings::Jwekey,
+    consts,
+    core::errors::{self, CustomResult},
+};
+
+/// Marks values encrypted with a data key, which tells them apart from values that were stored
+/// before encryption was enabled.
+const ENCRYPTED_VALUE_PREFIX: &str = "enc:v1:";
+
+const DATA_KEY_LENGTH: usize = 32;
+
+/// Plaintext data key of a merchant.
+#[derive(Clone, Debug)]
+pub struct DataKey(Secret<Vec<u8>>);
+
+impl DataKey {
+    pub fn generate() -> Self {
+        Self(Secret::new(
+            crypto::generate_cryptographically_secure_random_bytes::<DATA_KEY_LENGTH>().to_vec(),
+        ))
+    }
+
+    fn encrypt(&self, data: &[u8]) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+        GcmAes256::encrypt(self.0.peek(), data).change_context(errors::EncryptionError)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+        GcmAes256::decrypt(self.0.peek(), data).change_context(errors::EncryptionError)
+    }
+}
+
+pub struct EnvelopeEncryption {
+    jwekey: Jwekey,
+    master_keys: OnceCell<HashMap<String, Secret<Vec<u8>>>>,
+}
+
+impl EnvelopeEncryption {
+    pub fn new(jwekey: Jwekey) -> Self {
+        Self {
+            jwekey,
+            master_keys: OnceCell::new(),
+        }
+    }
+
+    pub fn active_master_key_identifier(&self) -> &str {
+        &self.jwekey.active_master_key_identifier
+    }
+
+    /// The master keys are decoded the first time they are needed.
+    async fn get_master_key(
+        &self,
+        master_key_identifier: &str,
+    ) -> CustomResult<&Secret<Vec<u8>>, errors::EncryptionError> {
+        let master_keys = self
+            .master_keys
+            .get_or_try_init(|| async {
+                let mut master_keys = HashMap::new();
+                for (identifier, key) in [
+                    (
+                        &self.jwekey.master_key_identifier1,
+                        &self.jwekey.master_encryption_key1,
+                    ),
+                    (
+                        &self.jwekey.master_key_identifier2,
+                        &self.jwekey.master_encryption_key2,
+                    ),
+                ] {
+                    if identifier.is_empty() || key.is_empty() {
+                        continue;
+                    }
+                    let key = hex::decode(key)
+                        .into_report()
+                        .change_context(errors::EncryptionError)
+                        .attach_printable("Master key is not hex encoded")?;
+                    master_keys.insert(identifier.to_owned(), Secret::new(key));
+                }
+                Ok::<_, error_stack::Report<errors::EncryptionError>>(master_keys)
+            })
+            .await?;
+
+        master_keys.get(master_key_identifier).ok_or_else(|| {
+            report!(errors::EncryptionError).attach_printable(format!(
+                "Master key with identifier {master_key_identifier} is not configured"
+            ))
+        })
+    }
+
+    /// Wraps the data key with the active master key, returning the wrapped key along with the
+    /// identifier of the master key.
+    pub async fn wrap_data_key(
+        &self,
+        data_key: &DataKey,
+    ) -> CustomResult<(Secret<Vec<u8>>, String), errors::EncryptionError> {
+        let master_key_identifier = self.active_master_key_identifier();
+        let master_key = self.get_master_key(master_key_identifier).await?;
+        let wrapped_key = GcmAes256::encrypt(master_key.peek(), data_key.0.peek())
+            .change_context(errors::EncryptionError)
+            .attach_printable("Failed to wrap data key")?;
+
+        Ok((Secret::new(wrapped_key), master_key_identifier.to_owned()))
+    }
+
+    pub async fn unwrap_data_key(
+        &self,
+        wrapped_key: &Secret<Vec<u8>>,
+        master_key_identifier: &str,
+    ) -> CustomResult<DataKey, errors::EncryptionError> {
+        let master_key = self.get_master_key(master_key_identifier).await?;
+        let data_key = GcmAes256::decrypt(master_key.peek(), wrapped_key.peek())
+            .change_context(errors::EncryptionError)
+            .attach_printable("Failed to unwrap data key")?;
+
+        Ok(DataKey(Secret::new(data_key)))
+    }
+
+    /// Re-wraps a data key with the active master key, the data key itself stays the same.
+    pub async fn rewrap_data_key(
+        &self,
+        wrapped_key: &Secret<Vec<u8>>,
+        master_key_identifier: &str,
+    ) -> CustomResult<(Secret<Vec<u8>>, String), errors::EncryptionError> {
+        let data_key = self
+            .unwrap_data_key(wrapped_key, master_key_identifier)
+            .await?;
+        self.wrap_data_key(&data_key).await
+    }
+}
+
+/// Encryption of a single column with the data key of the merchant.
+pub trait FieldEncryption: Sized {
+    fn encrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError>;
+
+    /// Values stored before encryption was enabled are returned as is.
+    fn decrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError>;
+}
+
+impl FieldEncryption for String {
+    fn encrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        let encrypted = key.encrypt(self.as_bytes())?;
+        Ok(format!(
+            "{ENCRYPTED_VALUE_PREFIX}{}",
+            consts::BASE64_ENGINE.encode(encrypted)
+        ))
+    }
+
+    fn decrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        match self.strip_prefix(ENCRYPTED_VALUE_PREFIX) {
+            Some(encoded) => {
+                let encrypted = consts::BASE64_ENGINE
+                    .decode(encoded)
+                    .into_report()
+                    .change_context(errors::EncryptionError)
+                    .attach_printable("Error decoding encrypted value from base64")?;
+                String::from_utf8(key.decrypt(&encrypted)?)
+                    .into_report()
+                    .change_context(errors::EncryptionError)
+                    .attach_printable("Could not convert decrypted value to UTF-8")
+            }
+            None => Ok(self),
+        }
+    }
+}
+
+impl<T, S> FieldEncryption for Secret<T, S>
+where
+    T: FieldEncryption,
+    S: Strategy<T>,
+{
+    fn encrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        self.expose().encrypt_field(key).map(Self::new)
+    }
+
+    fn decrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        self.expose().decrypt_field(key).map(Self::new)
+    }
+}
+
+/// JSON values are encrypted as a whole and stored as a JSON string.
+impl FieldEncryption for serde_json::Value {
+    fn encrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        serde_json::to_string(&self)
+            .into_report()
+            .change_context(errors::EncryptionError)
+            .attach_printable("Failed to serialize JSON value")?
+            .encrypt_field(key)
+            .map(Self::String)
+    }
+
+    fn decrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        match self {
+            Self::String(value) if value.starts_with(ENCRYPTED_VALUE_PREFIX) => {
+                serde_json::from_str(&value.decrypt_field(key)?)
+                    .into_report()
+                    .change_context(errors::EncryptionError)
+                    .attach_printable("Failed to deserialize decrypted JSON value")
+            }
+            value => Ok(value),
+        }
+    }
+}
+
+impl<T> FieldEncryption for Option<T>
+where
+    T: FieldEncryption,
+{
+    fn encrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        self.map(|value| value.encrypt_field(key)).transpose()
+    }
+
+    fn decrypt_field(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        self.map(|value| value.decrypt_field(key)).transpose()
+    }
+}
+
+/// Encrypts the sensitive columns of a record before it is written to the database.
+pub trait EncryptRecord: Sized {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError>;
+}
+
+/// Decrypts the sensitive columns of a record read from the database.
+pub trait DecryptRecord: Sized {
+    fn decrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError>;
+}
+
+impl<T> DecryptRecord for Vec<T>
+where
+    T: DecryptRecord,
+{
+    fn decrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        self.into_iter()
+            .map(|record| record.decrypt_record(key))
+            .collect()
+    }
+}
+
+/// Record read from the database whose sensitive columns are only decrypted when they are
+/// accessed. The other columns can be read from the encrypted record without decrypting it.
+#[derive(Clone)]
+pub struct Decryptable<R> {
+    record: R,
+    key: DataKey,
+}
+
+impl<R> Decryptable<R> {
+    pub fn new(record: R, key: DataKey) -> Self {
+        Self { record, key }
+    }
+
+    /// The record with its sensitive columns still encrypted.
+    pub fn encrypted(&self) -> &R {
+        &self.record
+    }
+
+    /// Takes the record with its sensitive columns still encrypted, when only its other columns
+    /// are needed.
+    pub fn into_encrypted(self) -> R {
+        self.record
+    }
+
+    /// Decrypts a single column of the record.
+    pub fn decrypt_field<T, F>(&self, field: F) -> CustomResult<T, errors::StorageError>
+    where
+        T: FieldEncryption + Clone,
+        F: FnOnce(&R) -> &T,
+    {
+        field(&self.record)
+            .clone()
+            .decrypt_field(&self.key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+}
+
+impl<R> Decryptable<R>
+where
+    R: DecryptRecord,
+{
+    pub fn decrypt(self) -> CustomResult<R, errors::StorageError> {
+        self.record
+            .decrypt_record(&self.key)
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    pub fn decrypt_all(records: Vec<Self>) -> CustomResult<Vec<R>, errors::StorageError> {
+        records.into_iter().map(Self::decrypt).collect()
+    }
+}
+
+impl<R> std::fmt::Debug for Decryptable<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "*** encrypted {} ***", std::any::type_name::<R>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_field_encryption() {
+        let key = DataKey::generate();
+        let name = "John Doe".to_string();
+
+        let encrypted = name.clone().encrypt_field(&key).unwrap();
+        assert!(encrypted.starts_with(ENCRYPTED_VALUE_PREFIX));
+        assert_ne!(encrypted, name);
+        assert_eq!(encrypted.decrypt_field(&key).unwrap(), name);
+
+        // Values stored before encryption was enabled are read as is
+        assert_eq!(name.clone().decrypt_field(&key).unwrap(), name);
+
+        let other_key = DataKey::generate();
+        let encrypted = name.encrypt_field(&key).unwrap();
+        assert!(encrypted.decrypt_field(&other_key).is_err());
+    }
+
+    #[test]
+    fn test_json_field_encryption() {
+        let key = DataKey::generate();
+        let details = serde_json::json!({ "auth_type": "HeaderKey", "api_key": "secret" });
+
+        let encrypted = details.clone().encrypt_field(&key).unwrap();
+        assert!(encrypted.is_string());
+        assert_eq!(encrypted.decrypt_field(&key).unwrap(), details);
+        assert_eq!(details.clone().decrypt_field(&key).unwrap(), details);
+    }
+
+    #[test]
+    fn test_decryptable_record() {
+        use crate::types::storage;
+
+        let key = DataKey::generate();
+        let customer = storage::CustomerNew {
+            customer_id: "cus_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            name: Some("John Doe".to_string()),
+            email: Some("john@example.com".to_string().into()),
+            phone: None,
+            description: None,
+            phone_country_code: None,
+            metadata: None,
+            connector_customer: None,
+        }
+        .encrypt_record(&key)
+        .unwrap();
+        let encrypted_customer = storage::Customer {
+            id: 1,
+            customer_id: customer.customer_id,
+            merchant_id: customer.merchant_id,
+            name: customer.name,
+            email: customer.email,
+            phone: customer.phone,
+            phone_country_code: customer.phone_country_code,
+            description: customer.description,
+            created_at: common_utils::date_time::now(),
+            metadata: customer.metadata,
+            connector_customer: customer.connector_customer,
+            default_payment_method_id: None,
+        };
+        let decryptable = Decryptable::new(encrypted_customer, key);
+
+        // Columns which are not encrypted are readable without decrypting the record
+        assert_eq!(decryptable.encrypted().customer_id, "cus_123");
+        assert_ne!(decryptable.encrypted().name.as_deref(), Some("John Doe"));
+        assert!(!format!("{decryptable:?}").contains("cus_123"));
+
+        let name = decryptable
+            .decrypt_field(|customer| &customer.name)
+            .unwrap();
+        assert_eq!(name.as_deref(), Some("John Doe"));
+
+        let customer = decryptable.decrypt().unwrap();
+        assert_eq!(customer.name.as_deref(), Some("John Doe"));
+        assert_eq!(
+            customer.email.map(|email| email.expose()).as_deref(),
+            Some("john@example.com")
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_data_key_wrapping() {
+        let jwekey = Jwekey {
+            master_key_identifier1: "1".to_string(),
+            master_key_identifier2: "2".to_string(),
+            master_encryption_key1: hex::encode(
+                crypto::generate_cryptographically_secure_random_bytes::<32>(),
+            ),
+            master_encryption_key2: hex::encode(
+                crypto::generate_cryptographically_secure_random_bytes::<32>(),
+            ),
+            active_master_key_identifier: "1".to_string(),
+            ..Default::default()
+        };
+        let data_key = DataKey::generate();
+        let envelope_encryption = EnvelopeEncryption::new(jwekey.clone());
+        let (wrapped_key, master_key_identifier) =
+            envelope_encryption.wrap_data_key(&data_key).await.unwrap();
+        assert_eq!(master_key_identifier, "1");
+
+        let rotated_envelope_encryption = EnvelopeEncryption::new(Jwekey {
+            active_master_key_identifier: "2".to_string(),
+            ..jwekey
+        });
+        let (rewrapped_key, master_key_identifier) = rotated_envelope_encryption
+            .rewrap_data_key(&wrapped_key, &master_key_identifier)
+            .await
+            .unwrap();
+        assert_eq!(master_key_identifier, "2");
+
+        let unwrapped_key = rotated_envelope_encryption
+            .unwrap_data_key(&rewrapped_key, &master_key_identifier)
+            .await
+            .unwrap();
+        assert_eq!(unwrapped_key.0.peek(), data_key.0.peek());
+    }
+}
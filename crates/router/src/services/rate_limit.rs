@@ -0,0 +1,277 @@
+//! Per merchant, per API key rate limiting of route groups.
+//!
+//! Requests are counted in fixed windows stored in Redis, so that the limits are shared by all
+//! router instances. The number of requests in the sliding window ending now is estimated from the
+//! count of the current window and the count of the previous window, weighted by the part of the
+//! previous window which overlaps the sliding window. Requests which are rejected are not counted,
+//! so a client which backs off is let through as soon as its rate drops below the limit.
+//!
+//! Errors while reading or updating the counts in Redis are logged and the request is allowed, so
+//! that a Redis outage does not take down the APIs along with it.
+
+use common_utils::date_time;
+use error_stack::report;
+use router_env::opentelemetry::KeyValue;
+
+use crate::{
+    configs::settings::{RateLimitOverrides, RateLimitSettings, RateLimits},
+    core::errors::{self, RouterResult},
+    logger,
+    routes::{metrics, AppState},
+};
+
+/// Routes whose requests are counted against the same limit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum RouteGroup {
+    PaymentsCreate,
+    PaymentsList,
+    Refunds,
+}
+
+impl RouteGroup {
+    fn default_limit(self, limits: &RateLimits) -> u64 {
+        match self {
+            Self::PaymentsCreate => limits.payments_create,
+            Self::PaymentsList => limits.payments_list,
+            Self::Refunds => limits.refunds,
+        }
+    }
+
+    fn overridden_limit(self, overrides: &RateLimitOverrides) -> Option<u64> {
+        match self {
+            Self::PaymentsCreate => overrides.payments_create,
+            Self::PaymentsList => overrides.payments_list,
+            Self::Refunds => overrides.refunds,
+        }
+    }
+}
+
+/// The principal whose requests are counted.
+#[derive(Clone, Copy, Debug)]
+pub enum RateLimitSubject<'a> {
+    Merchant(&'a str),
+    /// Requests made with the API key of an organization on behalf of all of its merchant
+    /// accounts, which are limited by the default limits.
+    Organization(&'a str),
+    ApiKey(&'a str),
+}
+
+impl RateLimitSubject<'_> {
+    fn counter(&self, route_group: RouteGroup) -> String {
+        match self {
+            Self::Merchant(merchant_id) => format!("merchant_{merchant_id}_{route_group}"),
+            Self::Organization(org_id) => format!("organization_{org_id}_{route_group}"),
+            Self::ApiKey(key_id) => format!("api_key_{key_id}_{route_group}"),
+        }
+    }
+
+    fn limit(&self, config: &RateLimitSettings, route_group: RouteGroup) -> u64 {
+        let overrides = match self {
+            Self::Merchant(merchant_id) => config.merchants.get(*merchant_id),
+            Self::Organization(_) => None,
+            Self::ApiKey(key_id) => config.api_keys.get(*key_id),
+        };
+
+        overrides
+            .and_then(|overrides| route_group.overridden_limit(overrides))
+            .unwrap_or_else(|| route_group.default_limit(&config.default))
+    }
+}
+
+/// Check that a request to a route group is within the limits of each of the subjects, and count
+/// it against all of them if it is. Requests over any of the limits are rejected with
+/// [`errors::ApiErrorResponse::TooManyRequests`], without being counted.
+///
+/// The request is counted before it is checked against each limit, so that concurrent requests
+/// cannot all pass the check before any of them is counted. A rejected request is then removed
+/// from the counters it was counted against.
+pub async fn check_rate_limit(
+    state: &AppState,
+    route_group: RouteGroup,
+    subjects: &[RateLimitSubject<'_>],
+) -> RouterResult<()> {
+    let dynamic_config = state.dynamic_config.get();
+    let config = &dynamic_config.rate_limit;
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let now = date_time::now_unix_timestamp();
+    let current_window = now / config.window;
+    let counters = subjects
+        .iter()
+        .map(|subject| {
+            (
+                subject.counter(route_group),
+                subject.limit(config, route_group),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // The previous window is still read during the window after it
+    let expiry = config.window * 2;
+    let mut counted = Vec::with_capacity(counters.len());
+    for (counter, limit) in &counters {
+        let counts = futures::try_join!(
+            state
+                .store
+                .get_rate_limit_count(counter, current_window - 1),
+            state
+                .store
+                .increment_rate_limit_count(counter, current_window, expiry),
+        );
+        let (previous, current) = match counts {
+            Ok(counts) => counts,
+            Err(error) => {
+                logger::error!(rate_limit_error=?error);
+                return Ok(());
+            }
+        };
+        counted.push(counter);
+
+        // The requests counted in the current window before this one
+        let current = current.saturating_sub(1);
+
+        let retry_after = get_retry_after(
+            *limit,
+            config.window,
+            now.rem_euclid(config.window),
+            previous,
+            current,
+        );
+        if let Some(retry_after) = retry_after {
+            logger::warn!(%counter, limit, previous, current, "Rate limit exceeded");
+            metrics::RATE_LIMITED_REQUESTS.add(
+                &metrics::CONTEXT,
+                1,
+                &[KeyValue::new("route_group", route_group.to_string())],
+            );
+
+            for counter in counted {
+                if let Err(error) = state
+                    .store
+                    .decrement_rate_limit_count(counter, current_window, expiry)
+                    .await
+                {
+                    logger::error!(rate_limit_error=?error);
+                }
+            }
+            return Err(report!(errors::ApiErrorResponse::TooManyRequests {
+                retry_after
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+/// Seconds after which a request would be within the limit, or `None` if it already is, given the
+/// time elapsed since the start of the current window and the counts of the previous and current
+/// windows.
+fn get_retry_after(
+    limit: u64,
+    window: i64,
+    elapsed: i64,
+    previous: u64,
+    current: u64,
+) -> Option<u64> {
+    let (window, elapsed) = match (u64::try_from(window), u64::try_from(elapsed)) {
+        (Ok(window), Ok(elapsed)) if elapsed < window => (window, elapsed),
+        _ => return None,
+    };
+    let remaining = window - elapsed;
+
+    // The estimated count is below the limit when `previous * overlap / window + current < limit`,
+    // where `overlap` is the part of the previous window which overlaps the sliding window.
+    if previous * remaining / window + current < limit {
+        return None;
+    }
+
+    if current < limit {
+        // The overlap with the previous window has to shrink below
+        // `(limit - current) * window / previous`, which happens within the current window.
+        let max_overlap = div_ceil((limit - current) * window, previous);
+        Some(remaining.saturating_sub(max_overlap) + 1)
+    } else {
+        // The current window becomes the previous one, and its overlap has to shrink below
+        // `limit * window / current`.
+        let max_overlap = div_ceil(limit * window, current);
+        Some(remaining + window.saturating_sub(max_overlap) + 1)
+    }
+}
+
+fn div_ceil(dividend: u64, divisor: u64) -> u64 {
+    dividend
+        .saturating_add(divisor.saturating_sub(1))
+        .checked_div(divisor)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    /// Estimated count of the sliding window `seconds` from now, when no further requests are
+    /// counted.
+    fn estimate_after(window: u64, elapsed: u64, previous: u64, current: u64, seconds: u64) -> u64 {
+        let elapsed = elapsed + seconds;
+        match elapsed / window {
+            0 => previous * (window - elapsed) / window + current,
+            1 => current * (window - (elapsed - window)) / window,
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_requests_within_limit_are_allowed() {
+        assert_eq!(get_retry_after(10, 60, 30, 0, 9), None);
+        assert_eq!(get_retry_after(10, 60, 30, 19, 0), None);
+        assert_eq!(get_retry_after(10, 60, 0, 0, 0), None);
+    }
+
+    #[test]
+    fn test_retry_after_previous_window_overlap_shrinks() {
+        // 20 * 30 / 60 = 10 requests are estimated from the previous window
+        assert_eq!(get_retry_after(10, 60, 30, 20, 0), Some(1));
+        assert_eq!(get_retry_after(10, 60, 30, 20, 5), Some(16));
+    }
+
+    #[test]
+    fn test_retry_after_current_window_is_full() {
+        assert_eq!(get_retry_after(10, 60, 50, 0, 10), Some(11));
+        assert_eq!(get_retry_after(10, 60, 50, 30, 20), Some(41));
+    }
+
+    #[test]
+    fn test_retry_after_is_exact() {
+        let (limit, window) = (10, 60);
+        for elapsed in 0..window {
+            for previous in 0..30 {
+                for current in 0..30 {
+                    let window_size = i64::try_from(window).unwrap();
+                    let elapsed_seconds = i64::try_from(elapsed).unwrap();
+                    let retry_after =
+                        get_retry_after(limit, window_size, elapsed_seconds, previous, current);
+
+                    match retry_after {
+                        None => {
+                            assert!(estimate_after(window, elapsed, previous, current, 0) < limit)
+                        }
+                        Some(seconds) => {
+                            assert!(
+                                estimate_after(window, elapsed, previous, current, seconds - 1)
+                                    >= limit
+                            );
+                            assert!(
+                                estimate_after(window, elapsed, previous, current, seconds) < limit
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
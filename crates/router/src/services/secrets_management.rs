@@ -0,0 +1,45 @@
+//! Secrets management.
+//!
+//! The secrets in the configuration (admin API key, JWE keys, database and Redis passwords, ...)
+//! can be stored encrypted by one of the secrets managers of the `external_services` crate. They
+//! are decrypted once when the application starts, so the rest of the application only ever sees
+//! plaintext values.
+
+pub use external_services::secrets_management::{
+    get_secrets_manager, SecretManagementInterface, SecretsManagementError,
+};
+
+use crate::{configs::settings::Settings, core::errors::CustomResult};
+
+impl Settings {
+    /// Replaces the secrets in the configuration with their plaintext values. Secrets which are
+    /// not configured are left empty.
+    pub async fn decrypt_secrets(
+        mut self,
+        secrets_manager: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let mut secrets = vec![
+            &mut self.secrets.admin_api_key,
+            &mut self.secrets.jwt_secret,
+            &mut self.secrets.card_fingerprint_hash_key,
+            &mut self.secrets.velocity_hash_key,
+            &mut self.jwekey.locker_encryption_key1,
+            &mut self.jwekey.locker_encryption_key2,
+            &mut self.jwekey.locker_decryption_key1,
+            &mut self.jwekey.locker_decryption_key2,
+            &mut self.jwekey.master_encryption_key1,
+            &mut self.jwekey.master_encryption_key2,
+            &mut self.master_database.password,
+            &mut self.network_tokenization.lifecycle_event_secret,
+        ];
+        #[cfg(feature = "olap")]
+        secrets.push(&mut self.replica_database.password);
+        if let Some(password) = self.redis.password.as_mut() {
+            secrets.push(password);
+        }
+
+        external_services::secrets_management::decrypt_secrets(secrets_manager, secrets).await?;
+
+        Ok(self)
+    }
+}
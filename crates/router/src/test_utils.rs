@@ -0,0 +1,54 @@
+#![allow(
+    dead_code,
+    clippy::expect_used,
+    clippy::missing_panics_doc,
+    clippy::unwrap_used
+)]
+
+use crate::{
+    configs::settings::Settings, db::StorageImpl, routes::AppState, services, types::storage,
+};
+
+pub const MERCHANT_ID: &str = "merchant";
+
+/// Application state backed by the mock database, with the merchant account `merchant`.
+pub async fn get_state() -> (AppState, storage::MerchantAccount) {
+    get_state_with_settings(Settings::new().expect("invalid settings")).await
+}
+
+/// Application state with the provided settings backed by the mock database, with the merchant
+/// account `merchant`.
+pub async fn get_state_with_settings(conf: Settings) -> (AppState, storage::MerchantAccount) {
+    let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+    let merchant_account = insert_merchant(&state, MERCHANT_ID).await;
+    (state, merchant_account)
+}
+
+/// Application state backed by the mock database, with the provided merchant account.
+pub async fn get_state_with_merchant(
+    merchant_account: storage::MerchantAccountNew,
+) -> (AppState, storage::MerchantAccount) {
+    let conf = Settings::new().expect("invalid settings");
+    let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+    let merchant_account = state.store.insert_merchant(merchant_account).await.unwrap();
+    (state, merchant_account)
+}
+
+pub async fn insert_merchant(state: &AppState, merchant_id: &str) -> storage::MerchantAccount {
+    state
+        .store
+        .insert_merchant(storage::MerchantAccountNew {
+            merchant_id: merchant_id.to_string(),
+            ..storage::MerchantAccountNew::default()
+        })
+        .await
+        .unwrap()
+}
+
+pub fn get_json_response<T>(response: services::ApplicationResponse<T>) -> T {
+    match response {
+        services::ApplicationResponse::Json(response) => Some(response),
+        _ => None,
+    }
+    .expect("Expected a json response")
+}
@@ -24,12 +24,16 @@ pub type PaymentsAuthorizeRouterData =
     RouterData<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsAuthorizeSessionTokenRouterData =
     RouterData<api::AuthorizeSessionToken, AuthorizeSessionTokenData, PaymentsResponseData>;
+pub type ConnectorCustomerRouterData =
+    RouterData<api::CreateConnectorCustomer, ConnectorCustomerData, PaymentsResponseData>;
 pub type PaymentsSyncRouterData = RouterData<api::PSync, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsCaptureRouterData =
     RouterData<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
 pub type PaymentsCancelRouterData = RouterData<api::Void, PaymentsCancelData, PaymentsResponseData>;
 pub type PaymentsSessionRouterData =
     RouterData<api::Session, PaymentsSessionData, PaymentsResponseData>;
+pub type ClientTokenRouterData =
+    RouterData<api::CreateClientToken, PaymentsSessionData, PaymentsResponseData>;
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
@@ -58,12 +62,22 @@ pub type PaymentsPreAuthorizeType = dyn services::ConnectorIntegration<
     AuthorizeSessionTokenData,
     PaymentsResponseData,
 >;
+pub type ConnectorCustomerType = dyn services::ConnectorIntegration<
+    api::CreateConnectorCustomer,
+    ConnectorCustomerData,
+    PaymentsResponseData,
+>;
 pub type PaymentsSyncType =
     dyn services::ConnectorIntegration<api::PSync, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsCaptureType =
     dyn services::ConnectorIntegration<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
 pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
+pub type ClientTokenType = dyn services::ConnectorIntegration<
+    api::CreateClientToken,
+    PaymentsSessionData,
+    PaymentsResponseData,
+>;
 pub type PaymentsVoidType =
     dyn services::ConnectorIntegration<api::Void, PaymentsCancelData, PaymentsResponseData>;
 
@@ -77,6 +91,8 @@ pub type RefreshTokenType =
 
 pub type VerifyRouterData = RouterData<api::Verify, VerifyRequestData, PaymentsResponseData>;
 
+pub type FraudCheckRouterData = RouterData<api::FraudCheck, FraudCheckData, FraudCheckResponseData>;
+
 #[derive(Debug, Clone)]
 pub struct RouterData<Flow, Request, Response> {
     pub flow: PhantomData<Flow>,
@@ -126,6 +142,22 @@ pub struct PaymentsAuthorizeData {
     pub order_details: Option<api_models::payments::OrderDetails>,
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    /// Network token of the saved card, sent in place of the card number to the connectors which
+    /// support network tokens
+    pub network_token: Option<NetworkTokenData>,
+    /// Customer created on the connector for the payment, by the connectors which require one
+    pub connector_customer: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkTokenData {
+    pub token_number: masking::Secret<String>,
+    pub token_exp_month: masking::Secret<String>,
+    pub token_exp_year: masking::Secret<String>,
+    /// Cryptogram generated for the payment, if the network token supports cryptograms
+    pub cryptogram: Option<masking::Secret<String>>,
+    /// Electronic commerce indicator (ECI) generated along with the cryptogram
+    pub eci: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -144,6 +176,12 @@ pub struct AuthorizeSessionTokenData {
     pub amount: i64,
 }
 
+#[derive(Debug, Clone)]
+pub struct ConnectorCustomerData {
+    pub email: Option<masking::Secret<String, Email>>,
+    pub name: Option<masking::Secret<String>>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentsSyncData {
     //TODO : add fields based on the connector requirements
@@ -166,6 +204,8 @@ pub struct PaymentsSessionData {
     pub currency: storage_enums::Currency,
     pub country: Option<String>,
     pub order_details: Option<api_models::payments::OrderDetails>,
+    /// Client token of the connector, for connectors which create it before the session token
+    pub client_token: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -180,6 +220,27 @@ pub struct VerifyRequestData {
     pub setup_mandate_details: Option<payments::MandateData>,
 }
 
+/// Details of a payment which are checked by a fraud and risk management (FRM) connector before
+/// the payment is sent to the payment connector.
+#[derive(Debug, Clone)]
+pub struct FraudCheckData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payment_method_data: Option<payments::PaymentMethodData>,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub customer_id: Option<String>,
+    pub browser_info: Option<BrowserInformation>,
+    pub order_details: Option<api_models::payments::OrderDetails>,
+    /// Hash of the card number, if the payment is made with a card
+    pub card_fingerprint: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckResponseData {
+    pub decision: storage_enums::FraudCheckDecision,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AccessTokenRequestData {
     pub app_id: String,
@@ -198,6 +259,13 @@ pub struct AccessToken {
     pub expires: i64,
 }
 
+/// Connector metadata of a payment attempt which is awaiting a bank transfer from the customer.
+/// The instructions are shown to the customer in the `next_action` of the payment response.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct BankTransferNextStepsData {
+    pub bank_transfer_instructions: api_models::payments::BankTransferInstructions,
+}
+
 #[derive(Debug, Clone)]
 pub enum PaymentsResponseData {
     TransactionResponse {
@@ -212,6 +280,9 @@ pub enum PaymentsResponseData {
     SessionTokenResponse {
         session_token: String,
     },
+    ConnectorCustomerResponse {
+        connector_customer_id: String,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1,5 +1,7 @@
 pub mod admin;
+pub mod admin_principals;
 pub mod api_keys;
+pub mod audit_events;
 pub mod configs;
 pub mod customers;
 pub mod enums;
@@ -7,6 +9,7 @@ pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
 pub mod refunds;
+pub mod vault;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
@@ -113,8 +116,10 @@ type BoxedConnector = Box<&'static (dyn Connector + Sync)>;
 
 // Normal flow will call the connector and follow the flow specific operations (capture, authorize)
 // SessionTokenFromMetadata will avoid calling the connector instead create the session token ( for sdk )
+#[derive(Clone, Copy)]
 pub enum GetToken {
-    Metadata,
+    GpayMetadata,
+    ApplePayMetadata,
     Connector,
 }
 
@@ -1,31 +1,81 @@
 pub use api_models::admin::{
-    CreateMerchantAccount, DeleteMcaResponse, DeleteMerchantAccountResponse,
-    MerchantAccountResponse, MerchantConnectorId, MerchantDetails, MerchantId,
-    PaymentConnectorCreate, PaymentMethodsEnabled, RoutingAlgorithm, ToggleKVRequest,
-    ToggleKVResponse, WebhookDetails,
+    ConnectorCallLog, ConnectorCallLogResponse, ConnectorHealth, ConnectorHealthResponse,
+    CreateMerchantAccount, CreateOrganizationResponse, DeleteMcaResponse,
+    DeleteMerchantAccountResponse, MerchantAccountResponse, MerchantConnectorId, MerchantDetails,
+    MerchantId, OrganizationId, OrganizationRequest, OrganizationResponse, PaymentConnectorCreate,
+    PaymentMethodFilter, PaymentMethodFilters, PaymentMethodsEnabled, RoutingAlgorithm,
+    ToggleKVRequest, ToggleKVResponse, WebhookDetails,
 };
 
-use crate::types::{storage, transformers::ForeignFrom};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use masking::Secret;
 
-impl ForeignFrom<storage::MerchantAccount> for MerchantAccountResponse {
-    fn foreign_from(value: storage::MerchantAccount) -> Self {
+use crate::{
+    core::errors,
+    types::{
+        storage,
+        transformers::{ForeignFrom, ForeignTryFrom},
+    },
+};
+
+impl ForeignTryFrom<storage::MerchantAccount> for MerchantAccountResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn foreign_try_from(value: storage::MerchantAccount) -> Result<Self, Self::Error> {
         let item = value;
-        Self {
+        Ok(Self {
             merchant_id: item.merchant_id,
             merchant_name: item.merchant_name,
             api_key: item.api_key,
             return_url: item.return_url,
             enable_payment_response_hash: item.enable_payment_response_hash,
-            payment_response_hash_key: item.payment_response_hash_key,
+            payment_response_hash_key: item.payment_response_hash_key.map(Secret::new),
             redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
             merchant_details: item.merchant_details,
-            webhook_details: item.webhook_details,
+            webhook_details: item.webhook_details.map(Secret::new),
             routing_algorithm: item.routing_algorithm,
             sub_merchants_enabled: item.sub_merchants_enabled,
             parent_merchant_id: item.parent_merchant_id,
+            organization_id: item.organization_id,
             publishable_key: item.publishable_key,
             metadata: item.metadata,
             locker_id: item.locker_id,
+            pm_filters: item
+                .pm_filters
+                .map(|pm_filters| pm_filters.parse_value("PaymentMethodFilters"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        })
+    }
+}
+
+impl ForeignFrom<storage::Organization> for OrganizationResponse {
+    fn foreign_from(organization: storage::Organization) -> Self {
+        Self {
+            prefix: format!("{}-{}", organization.org_id, organization.prefix).into(),
+            org_id: organization.org_id,
+            org_name: organization.org_name,
+            created_at: organization.created_at,
+            modified_at: organization.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::ConnectorCallLog> for ConnectorCallLog {
+    fn foreign_from(call_log: storage::ConnectorCallLog) -> Self {
+        Self {
+            attempt_id: call_log.attempt_id,
+            connector: call_log.connector,
+            flow: call_log.flow,
+            method: call_log.method,
+            url: call_log.url,
+            request_headers: call_log.request_headers,
+            request_body: call_log.request_body,
+            response_body: call_log.response_body,
+            status_code: call_log.status_code,
+            error_message: call_log.error_message,
+            latency: call_log.latency,
+            created_at: call_log.created_at,
         }
     }
 }
@@ -0,0 +1,4 @@
+pub use api_models::admin_principals::{
+    AdminPrincipalResponse, CreateAdminPrincipalRequest, CreateAdminPrincipalResponse,
+    RevokeAdminPrincipalResponse,
+};
@@ -0,0 +1 @@
+pub use api_models::audit_events::{AuditEventListConstraints, AuditEventResponse};
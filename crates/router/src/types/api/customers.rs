@@ -46,6 +46,7 @@ impl From<storage::Customer> for CustomerResponse {
             created_at: cust.created_at,
             metadata: cust.metadata,
             address: None,
+            default_payment_method_id: cust.default_payment_method_id,
         }
         .into()
     }
@@ -54,6 +54,7 @@ impl MandateResponseExt for MandateResponse {
                 .get_required_value("locker_id")?;
             let get_card_resp = payment_methods::cards::get_card_from_legacy_locker(
                 state,
+                &merchant_account.merchant_id,
                 &locker_id,
                 &payment_method.payment_method_id,
             )
@@ -1,19 +1,24 @@
 use std::collections::HashMap;
 
 pub use api_models::payment_methods::{
-    CardDetail, CardDetailFromLocker, CreatePaymentMethod, CustomerPaymentMethod,
-    DeletePaymentMethodResponse, DeleteTokenizeByDateRequest, DeleteTokenizeByTokenRequest,
-    GetTokenizePayloadRequest, GetTokenizePayloadResponse, ListCustomerPaymentMethodsResponse,
-    ListPaymentMethod, ListPaymentMethodRequest, ListPaymentMethodResponse, PaymentMethodId,
-    PaymentMethodResponse, TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizedCardValue1,
-    TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2, UpdatePaymentMethod,
+    CardDetail, CardDetailFromLocker, CreatePaymentMethod, CustomerDefaultPaymentMethodResponse,
+    CustomerPaymentMethod, DefaultPaymentMethod, DeletePaymentMethodResponse,
+    DeleteTokenizeByDateRequest, DeleteTokenizeByTokenRequest, GetTokenizePayloadRequest,
+    GetTokenizePayloadResponse, ListCustomerPaymentMethodsResponse, ListPaymentMethod,
+    ListPaymentMethodRequest, ListPaymentMethodResponse, PaymentMethodAttachRequest,
+    PaymentMethodId, PaymentMethodResponse, TokenizePayloadEncrypted, TokenizePayloadRequest,
+    TokenizedBankDebitValue1, TokenizedBankDebitValue2, TokenizedBankTransferValue1,
+    TokenizedBankTransferValue2, TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1,
+    TokenizedWalletValue2, UpdatePaymentMethod,
 };
+use common_utils::validation;
 use error_stack::report;
 use literally::hmap;
+use masking::PeekInterface;
 use once_cell::sync::Lazy;
 
 use crate::{
-    core::errors::{self, RouterResult},
+    core::errors::{self, RouterResult, ValidationErrorExt},
     types::api::enums as api_enums,
 };
 
@@ -93,6 +98,15 @@ impl CreatePaymentMethodExt for CreatePaymentMethod {
             .attach_printable("Invalid payment method issuer code"));
         }
 
+        if let Some(card) = &self.card {
+            validation::validate_card_number(card.card_number.peek())
+                .map_err(|error| error.to_invalid_field_response("card.card_number"))?;
+            validation::validate_card_expiry_month(card.card_exp_month.peek())
+                .map_err(|error| error.to_invalid_field_response("card.card_exp_month"))?;
+            validation::validate_card_expiry(card.card_exp_month.peek(), card.card_exp_year.peek())
+                .map_err(|error| error.to_invalid_field_response("card.card_exp_year"))?;
+        }
+
         Ok(())
     }
 
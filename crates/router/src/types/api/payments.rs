@@ -1,13 +1,14 @@
 pub use api_models::payments::{
-    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
+    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse,
+    BankDebitBilling, BankDebitData, BankTransferBilling, BankTransferData, Card,
     CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
-    PaymentListResponse, PaymentMethodData, PaymentMethodDataResponse, PaymentOp,
-    PaymentRetrieveBody, PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
-    PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
-    PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
-    PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, UrlDetails, VerifyRequest,
-    VerifyResponse, WalletData,
+    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentLinkRequest,
+    PaymentLinkResponse, PaymentListConstraints, PaymentListResponse, PaymentMethodData,
+    PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentsCancelRequest,
+    PaymentsCaptureRequest, PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRequest,
+    PaymentsResponse, PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest,
+    PaymentsSessionResponse, PaymentsStartRequest, PgRedirectResponse, PhoneDetails,
+    RedirectionResponse, SessionToken, UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
@@ -67,6 +68,12 @@ pub struct Authorize;
 
 #[derive(Debug, Clone)]
 pub struct AuthorizeSessionToken;
+
+#[derive(Debug, Clone)]
+pub struct CreateConnectorCustomer;
+
+#[derive(Debug, Clone)]
+pub struct CreateClientToken;
 #[derive(Debug, Clone)]
 pub struct Capture;
 
@@ -81,6 +88,9 @@ pub struct Session;
 #[derive(Debug, Clone)]
 pub struct Verify;
 
+#[derive(Debug, Clone)]
+pub struct FraudCheck;
+
 pub(crate) trait PaymentIdTypeExt {
     fn get_payment_intent_id(&self) -> errors::CustomResult<String, errors::ValidationError>;
 }
@@ -193,6 +203,8 @@ mod payments_test {
             card_cvc: "123".to_string().into(),
             card_issuer: Some("HDFC".to_string()),
             card_network: Some(api_models::enums::CardNetwork::Visa),
+            card_type: None,
+            card_issuing_country: None,
         }
     }
 
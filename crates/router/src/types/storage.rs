@@ -1,6 +1,11 @@
 pub mod address;
+pub mod admin_principal;
 pub mod api_keys;
+pub mod audit_events;
+pub mod cards_info;
 pub mod configs;
+pub mod connector_call_log;
+pub mod connector_health;
 pub mod connector_response;
 pub mod customers;
 pub mod enums;
@@ -10,11 +15,15 @@ pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
 pub mod process_tracker;
 pub mod reverse_lookup;
+pub mod vault;
 
 mod query;
 pub mod refund;
@@ -23,8 +32,9 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, api_keys::*, configs::*, connector_response::*, customers::*, events::*,
+    address::*, admin_principal::*, api_keys::*, audit_events::*, cards_info::*, configs::*,
+    connector_call_log::*, connector_health::*, connector_response::*, customers::*, events::*,
     locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    payment_attempt::*, payment_intent::*, payment_method::*, process_tracker::*, refund::*,
-    reverse_lookup::*,
+    merchant_key_store::*, organization::*, payment_attempt::*, payment_intent::*, payment_link::*,
+    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, vault::*,
 };
@@ -1 +1,70 @@
 pub use storage_models::address::{Address, AddressNew, AddressUpdate, AddressUpdateInternal};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    services::envelope_encryption::{DataKey, DecryptRecord, EncryptRecord, FieldEncryption},
+};
+
+impl EncryptRecord for AddressNew {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            line1: self.line1.encrypt_field(key)?,
+            line2: self.line2.encrypt_field(key)?,
+            line3: self.line3.encrypt_field(key)?,
+            state: self.state.encrypt_field(key)?,
+            zip: self.zip.encrypt_field(key)?,
+            first_name: self.first_name.encrypt_field(key)?,
+            last_name: self.last_name.encrypt_field(key)?,
+            phone_number: self.phone_number.encrypt_field(key)?,
+            ..self
+        })
+    }
+}
+
+impl EncryptRecord for AddressUpdate {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        match self {
+            Self::Update {
+                city,
+                country,
+                line1,
+                line2,
+                line3,
+                state,
+                zip,
+                first_name,
+                last_name,
+                phone_number,
+                country_code,
+            } => Ok(Self::Update {
+                city,
+                country,
+                line1: line1.encrypt_field(key)?,
+                line2: line2.encrypt_field(key)?,
+                line3: line3.encrypt_field(key)?,
+                state: state.encrypt_field(key)?,
+                zip: zip.encrypt_field(key)?,
+                first_name: first_name.encrypt_field(key)?,
+                last_name: last_name.encrypt_field(key)?,
+                phone_number: phone_number.encrypt_field(key)?,
+                country_code,
+            }),
+        }
+    }
+}
+
+impl DecryptRecord for Address {
+    fn decrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            line1: self.line1.decrypt_field(key)?,
+            line2: self.line2.decrypt_field(key)?,
+            line3: self.line3.decrypt_field(key)?,
+            state: self.state.decrypt_field(key)?,
+            zip: self.zip.decrypt_field(key)?,
+            first_name: self.first_name.decrypt_field(key)?,
+            last_name: self.last_name.decrypt_field(key)?,
+            phone_number: self.phone_number.decrypt_field(key)?,
+            ..self
+        })
+    }
+}
@@ -0,0 +1 @@
+pub use storage_models::admin_principal::{AdminPrincipal, AdminPrincipalNew};
@@ -0,0 +1,64 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+pub use storage_models::audit_events::{AuditEvent, AuditEventNew};
+use storage_models::{enums, errors, schema::audit_events::dsl};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait AuditEventDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+        resource_type: Option<enums::AuditResourceType>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl AuditEventDbExt for AuditEvent {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: &api_models::audit_events::AuditEventListConstraints,
+        resource_type: Option<enums::AuditResourceType>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(merchant_id) = &constraints.merchant_id {
+            filter = filter.filter(dsl::merchant_id.eq(merchant_id.to_owned()));
+        }
+        if let Some(resource_type) = resource_type {
+            filter = filter.filter(dsl::resource_type.eq(resource_type));
+        }
+        if let Some(resource_id) = &constraints.resource_id {
+            filter = filter.filter(dsl::resource_id.eq(resource_id.to_owned()));
+        }
+        if let Some(actor_id) = &constraints.actor_id {
+            filter = filter.filter(dsl::actor_id.eq(actor_id.to_owned()));
+        }
+        if let Some(created_gte) = constraints.created_gte {
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+        if let Some(created_lte) = constraints.created_lte {
+            filter = filter.filter(dsl::created_at.le(created_lte));
+        }
+        if let Some(skip) = constraints.skip {
+            filter = filter.offset(skip);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering audit events by constraints")
+    }
+}
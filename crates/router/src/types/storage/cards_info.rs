@@ -0,0 +1 @@
+pub use storage_models::cards_info::CardInfo;
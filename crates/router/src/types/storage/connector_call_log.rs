@@ -0,0 +1 @@
+pub use storage_models::connector_call_log::{ConnectorCallLog, ConnectorCallLogNew};
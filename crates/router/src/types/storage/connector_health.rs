@@ -0,0 +1,3 @@
+pub use storage_models::connector_health::{
+    CircuitBreakerRecord, CircuitState, ConnectorCallOutcome, ConnectorCallStats,
+};
@@ -1,3 +1,53 @@
 pub use storage_models::customers::{
     Customer, CustomerNew, CustomerUpdate, CustomerUpdateInternal,
 };
+
+use crate::{
+    core::errors::{self, CustomResult},
+    services::envelope_encryption::{DataKey, DecryptRecord, EncryptRecord, FieldEncryption},
+};
+
+impl EncryptRecord for CustomerNew {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            name: self.name.encrypt_field(key)?,
+            email: self.email.encrypt_field(key)?,
+            phone: self.phone.encrypt_field(key)?,
+            ..self
+        })
+    }
+}
+
+impl EncryptRecord for CustomerUpdate {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        match self {
+            Self::Update {
+                name,
+                email,
+                phone,
+                description,
+                phone_country_code,
+                metadata,
+            } => Ok(Self::Update {
+                name: name.encrypt_field(key)?,
+                email: email.encrypt_field(key)?,
+                phone: phone.encrypt_field(key)?,
+                description,
+                phone_country_code,
+                metadata,
+            }),
+            Self::ConnectorCustomer { .. } | Self::DefaultPaymentMethodUpdate { .. } => Ok(self),
+        }
+    }
+}
+
+impl DecryptRecord for Customer {
+    fn decrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            name: self.name.decrypt_field(key)?,
+            email: self.email.decrypt_field(key)?,
+            phone: self.phone.decrypt_field(key)?,
+            ..self
+        })
+    }
+}
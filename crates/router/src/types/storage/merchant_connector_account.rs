@@ -2,3 +2,58 @@ pub use storage_models::merchant_connector_account::{
     MerchantConnectorAccount, MerchantConnectorAccountNew, MerchantConnectorAccountUpdate,
     MerchantConnectorAccountUpdateInternal,
 };
+
+use crate::{
+    core::errors::{self, CustomResult},
+    services::envelope_encryption::{DataKey, DecryptRecord, EncryptRecord, FieldEncryption},
+};
+
+impl EncryptRecord for MerchantConnectorAccountNew {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            connector_account_details: self.connector_account_details.encrypt_field(key)?,
+            ..self
+        })
+    }
+}
+
+impl EncryptRecord for MerchantConnectorAccountUpdate {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        match self {
+            Self::Update {
+                merchant_id,
+                connector_type,
+                connector_name,
+                connector_account_details,
+                test_mode,
+                disabled,
+                merchant_connector_id,
+                payment_methods_enabled,
+                metadata,
+                pm_filters,
+                shared_with_sub_merchants,
+            } => Ok(Self::Update {
+                merchant_id,
+                connector_type,
+                connector_name,
+                connector_account_details: connector_account_details.encrypt_field(key)?,
+                test_mode,
+                disabled,
+                merchant_connector_id,
+                payment_methods_enabled,
+                metadata,
+                pm_filters,
+                shared_with_sub_merchants,
+            }),
+        }
+    }
+}
+
+impl DecryptRecord for MerchantConnectorAccount {
+    fn decrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            connector_account_details: self.connector_account_details.decrypt_field(key)?,
+            ..self
+        })
+    }
+}
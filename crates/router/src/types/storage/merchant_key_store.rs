@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+pub use storage_models::merchant_key_store::{
+    MerchantKeyStore, MerchantKeyStoreNew, MerchantKeyStoreUpdate,
+};
+
+/// Tracking data of the task that re-wraps the data keys of merchants with the active master key
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MerchantKeyRotationTrackingData {
+    pub master_key_identifier: String,
+}
@@ -0,0 +1 @@
+pub use storage_models::organization::{Organization, OrganizationNew, OrganizationUpdate};
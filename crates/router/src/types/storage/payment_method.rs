@@ -1 +1 @@
-pub use storage_models::payment_method::{PaymentMethod, PaymentMethodNew};
+pub use storage_models::payment_method::{PaymentMethod, PaymentMethodNew, PaymentMethodUpdate};
@@ -32,17 +32,11 @@ impl RefundDbExt for Refund {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
             .into_boxed();
 
-        match &refund_list_details.payment_id {
-            Some(pid) => {
-                filter = filter.filter(dsl::payment_id.eq(pid.to_owned()));
-            }
-            None => {
-                filter = filter.limit(limit);
-            }
-        };
+        if let Some(pid) = &refund_list_details.payment_id {
+            filter = filter.filter(dsl::payment_id.eq(pid.to_owned()));
+        }
 
         if let Some(created) = refund_list_details.created {
             filter = filter.filter(dsl::created_at.eq(created));
@@ -59,14 +53,42 @@ impl RefundDbExt for Refund {
         if let Some(created_gte) = refund_list_details.created_gte {
             filter = filter.filter(dsl::created_at.gt(created_gte));
         }
+        // Refunds are listed from the most recent one, so the refunds after a refund are the ones
+        // created before it. The refunds right before a refund are fetched in ascending order and
+        // reversed afterwards.
+        if let Some(starting_after) = &refund_list_details.starting_after {
+            let id = Self::find_by_merchant_id_refund_id(conn, merchant_id, starting_after)
+                .await?
+                .id;
+            filter = filter.filter(dsl::id.lt(id));
+        }
+        if let Some(ending_before) = &refund_list_details.ending_before {
+            let id = Self::find_by_merchant_id_refund_id(conn, merchant_id, ending_before)
+                .await?
+                .id;
+            filter = filter.filter(dsl::id.gt(id));
+        }
+        let ascending = refund_list_details.ending_before.is_some()
+            && refund_list_details.starting_after.is_none();
+        filter = if ascending {
+            filter.order(dsl::id.asc())
+        } else {
+            filter.order(dsl::id.desc())
+        };
+
+        filter = filter.limit(limit);
 
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
-        filter
+        let mut refunds: Vec<Self> = filter
             .get_results_async(conn)
             .await
             .into_report()
             .change_context(errors::DatabaseError::NotFound)
-            .attach_printable_lazy(|| "Error filtering records by predicate")
+            .attach_printable_lazy(|| "Error filtering records by predicate")?;
+        if ascending {
+            refunds.reverse();
+        }
+        Ok(refunds)
     }
 }
@@ -0,0 +1,69 @@
+pub use storage_models::vault::{
+    VaultAccessEvent, VaultAccessEventNew, VaultCard, VaultCardNew, VaultToken, VaultTokenNew,
+    VaultTokenUpdate,
+};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    services::envelope_encryption::{DataKey, DecryptRecord, EncryptRecord, FieldEncryption},
+};
+
+impl EncryptRecord for VaultCardNew {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            card_number: self.card_number.encrypt_field(key)?,
+            card_exp_month: self.card_exp_month.encrypt_field(key)?,
+            card_exp_year: self.card_exp_year.encrypt_field(key)?,
+            name_on_card: self.name_on_card.encrypt_field(key)?,
+            ..self
+        })
+    }
+}
+
+impl DecryptRecord for VaultCard {
+    fn decrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            card_number: self.card_number.decrypt_field(key)?,
+            card_exp_month: self.card_exp_month.decrypt_field(key)?,
+            card_exp_year: self.card_exp_year.decrypt_field(key)?,
+            name_on_card: self.name_on_card.decrypt_field(key)?,
+            ..self
+        })
+    }
+}
+
+impl EncryptRecord for VaultTokenNew {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            value1: self.value1.encrypt_field(key)?,
+            value2: self.value2.encrypt_field(key)?,
+            ..self
+        })
+    }
+}
+
+impl EncryptRecord for VaultTokenUpdate {
+    fn encrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        match self {
+            Self::ValueUpdate {
+                value1,
+                value2,
+                expires_at,
+            } => Ok(Self::ValueUpdate {
+                value1: value1.encrypt_field(key)?,
+                value2: value2.encrypt_field(key)?,
+                expires_at,
+            }),
+        }
+    }
+}
+
+impl DecryptRecord for VaultToken {
+    fn decrypt_record(self, key: &DataKey) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            value1: self.value1.decrypt_field(key)?,
+            value2: self.value2.decrypt_field(key)?,
+            ..self
+        })
+    }
+}
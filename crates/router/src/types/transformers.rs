@@ -75,6 +75,73 @@ impl ForeignFrom<storage_enums::MandateStatus> for api_enums::MandateStatus {
     }
 }
 
+impl ForeignFrom<storage_enums::PaymentLinkStatus> for api_enums::PaymentLinkStatus {
+    fn foreign_from(status: storage_enums::PaymentLinkStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<api_enums::Permission> for storage_enums::Permission {
+    fn foreign_from(permission: api_enums::Permission) -> Self {
+        frunk::labelled_convert_from(permission)
+    }
+}
+
+impl ForeignFrom<storage_enums::Permission> for api_enums::Permission {
+    fn foreign_from(permission: storage_enums::Permission) -> Self {
+        frunk::labelled_convert_from(permission)
+    }
+}
+
+impl ForeignFrom<storage_enums::AuditActorType> for api_enums::AuditActorType {
+    fn foreign_from(actor_type: storage_enums::AuditActorType) -> Self {
+        frunk::labelled_convert_from(actor_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::FraudCheckDecision> for api_enums::FraudCheckDecision {
+    fn foreign_from(decision: storage_enums::FraudCheckDecision) -> Self {
+        frunk::labelled_convert_from(decision)
+    }
+}
+
+impl ForeignFrom<storage_enums::AuditAction> for api_enums::AuditAction {
+    fn foreign_from(action: storage_enums::AuditAction) -> Self {
+        frunk::labelled_convert_from(action)
+    }
+}
+
+impl ForeignFrom<api_enums::AuditResourceType> for storage_enums::AuditResourceType {
+    fn foreign_from(resource_type: api_enums::AuditResourceType) -> Self {
+        frunk::labelled_convert_from(resource_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::AuditResourceType> for api_enums::AuditResourceType {
+    fn foreign_from(resource_type: storage_enums::AuditResourceType) -> Self {
+        frunk::labelled_convert_from(resource_type)
+    }
+}
+
+impl ForeignFrom<storage::PaymentLink> for api_types::PaymentLinkResponse {
+    fn foreign_from(payment_link: storage::PaymentLink) -> Self {
+        Self {
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            link_to_pay: payment_link.link_to_pay,
+            status: payment_link.status.foreign_into(),
+            created_at: payment_link.created_at,
+            expires_at: payment_link.expires_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::CircuitState> for api_enums::CircuitState {
+    fn foreign_from(state: storage::CircuitState) -> Self {
+        frunk::labelled_convert_from(state)
+    }
+}
+
 impl ForeignFrom<api_enums::PaymentMethod> for storage_enums::PaymentMethod {
     fn foreign_from(pm_type: api_enums::PaymentMethod) -> Self {
         frunk::labelled_convert_from(pm_type)
@@ -156,6 +223,10 @@ impl ForeignTryFrom<api_enums::IntentStatus> for storage_enums::EventType {
     fn foreign_try_from(value: api_enums::IntentStatus) -> Result<Self, Self::Error> {
         match value {
             api_enums::IntentStatus::Succeeded => Ok(Self::PaymentSucceeded),
+            api_enums::IntentStatus::Failed => Ok(Self::PaymentFailed),
+            api_enums::IntentStatus::Processing => Ok(Self::PaymentProcessing),
+            api_enums::IntentStatus::RequiresCustomerAction => Ok(Self::ActionRequired),
+            api_enums::IntentStatus::Cancelled => Ok(Self::PaymentCancelled),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "intent_status",
             }),
@@ -336,8 +407,14 @@ impl ForeignTryFrom<storage::MerchantConnectorAccount>
             )),
             test_mode: merchant_ca.test_mode,
             disabled: merchant_ca.disabled,
+            shared_with_sub_merchants: Some(merchant_ca.shared_with_sub_merchants),
             metadata: merchant_ca.metadata,
             payment_methods_enabled,
+            pm_filters: merchant_ca
+                .pm_filters
+                .map(|pm_filters| pm_filters.parse_value("PaymentMethodFilters"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
         })
     }
 }
@@ -358,6 +435,21 @@ impl ForeignFrom<storage_models::enums::PaymentMethodType>
     }
 }
 
+impl ForeignFrom<common_utils::validation::CardNetwork> for api_enums::CardNetwork {
+    fn foreign_from(card_network: common_utils::validation::CardNetwork) -> Self {
+        match card_network {
+            common_utils::validation::CardNetwork::Visa => Self::Visa,
+            common_utils::validation::CardNetwork::Mastercard => Self::Mastercard,
+            common_utils::validation::CardNetwork::AmericanExpress => Self::AmericanExpress,
+            common_utils::validation::CardNetwork::DinersClub => Self::DinersClub,
+            common_utils::validation::CardNetwork::Discover => Self::Discover,
+            common_utils::validation::CardNetwork::JCB => Self::JCB,
+            common_utils::validation::CardNetwork::UnionPay => Self::UnionPay,
+            common_utils::validation::CardNetwork::Maestro => Self::Maestro,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::payments::AddressDetails> for storage_models::address::AddressNew {
     fn foreign_from(item: api_models::payments::AddressDetails) -> Self {
         let address = item;
@@ -403,6 +495,12 @@ impl
             )),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key.permissions.map(|permissions| {
+                permissions
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
         }
     }
 }
@@ -420,6 +518,12 @@ impl ForeignFrom<storage_models::api_keys::ApiKey>
             prefix: format!("{}-{}", api_key.key_id, api_key.prefix).into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key.permissions.map(|permissions| {
+                permissions
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
         }
     }
 }
@@ -434,6 +538,33 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            permissions: api_key.permissions.map(|permissions| {
+                permissions
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl ForeignFrom<storage::AdminPrincipal> for api_models::admin_principals::AdminPrincipalResponse {
+    fn foreign_from(admin_principal: storage::AdminPrincipal) -> Self {
+        Self {
+            principal_id: admin_principal.principal_id.clone(),
+            name: admin_principal.name,
+            description: admin_principal.description,
+            prefix: format!(
+                "{}-{}",
+                admin_principal.principal_id, admin_principal.prefix
+            )
+            .into(),
+            permissions: admin_principal
+                .permissions
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+            created: admin_principal.created_at,
         }
     }
 }
@@ -443,3 +574,63 @@ impl ForeignFrom<storage_enums::AttemptStatus> for api_enums::AttemptStatus {
         frunk::labelled_convert_from(status)
     }
 }
+
+impl ForeignFrom<storage::AuditEvent> for api_models::audit_events::AuditEventResponse {
+    fn foreign_from(audit_event: storage::AuditEvent) -> Self {
+        Self {
+            event_id: audit_event.event_id,
+            actor_type: audit_event.actor_type.foreign_into(),
+            actor_id: audit_event.actor_id,
+            action: audit_event.action.foreign_into(),
+            resource_type: audit_event.resource_type.foreign_into(),
+            resource_id: audit_event.resource_id,
+            merchant_id: audit_event.merchant_id,
+            before: audit_event.before_state,
+            after: audit_event.after_state,
+            request_id: audit_event.request_id,
+            created: audit_event.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_intent_status_to_event_type() {
+        let event_type = |status: api_enums::IntentStatus| {
+            storage_enums::EventType::foreign_try_from(status).unwrap()
+        };
+
+        assert_eq!(
+            event_type(api_enums::IntentStatus::Succeeded),
+            storage_enums::EventType::PaymentSucceeded
+        );
+        assert_eq!(
+            event_type(api_enums::IntentStatus::Failed),
+            storage_enums::EventType::PaymentFailed
+        );
+        assert_eq!(
+            event_type(api_enums::IntentStatus::Processing),
+            storage_enums::EventType::PaymentProcessing
+        );
+        assert_eq!(
+            event_type(api_enums::IntentStatus::RequiresCustomerAction),
+            storage_enums::EventType::ActionRequired
+        );
+        assert_eq!(
+            event_type(api_enums::IntentStatus::Cancelled),
+            storage_enums::EventType::PaymentCancelled
+        );
+    }
+
+    #[test]
+    fn test_intent_status_without_event_type() {
+        assert!(storage_enums::EventType::foreign_try_from(
+            api_enums::IntentStatus::RequiresPaymentMethod
+        )
+        .is_err());
+    }
+}
@@ -41,6 +41,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
                 card_cvc: Secret::new("999".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_type: None,
+                card_issuing_country: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -54,6 +56,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            network_token: None,
+            connector_customer: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
@@ -167,6 +171,8 @@ async fn payments_create_failure() {
                 card_cvc: Secret::new("99".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_type: None,
+                card_issuing_country: None,
             });
 
         let response = services::api::execute_connector_processing_step(
@@ -69,6 +69,8 @@ impl AdyenTest {
                 card_cvc: Secret::new(card_cvc.to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_type: None,
+                card_issuing_country: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -82,6 +84,8 @@ impl AdyenTest {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            network_token: None,
+            connector_customer: None,
         })
     }
 }
@@ -0,0 +1,170 @@
+use router::types::{self, api};
+use serde_json::json;
+use serial_test::serial;
+use wiremock::{
+    matchers::{body_partial_json, method, path_regex},
+    Mock, ResponseTemplate,
+};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions, LocalMock, MockConfig, PaymentInfo},
+};
+
+struct Braintree;
+
+impl LocalMock for Braintree {}
+impl ConnectorActions for Braintree {}
+impl utils::Connector for Braintree {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Braintree;
+        types::api::ConnectorData {
+            connector: Box::new(&Braintree),
+            connector_name: types::Connector::Braintree,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .braintree
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "braintree".to_string()
+    }
+}
+
+// Client token with the authorization fingerprint "fingerprint"
+const CLIENT_TOKEN: &str =
+    "eyJ2ZXJzaW9uIjoyLCJhdXRob3JpemF0aW9uRmluZ2VycHJpbnQiOiJmaW5nZXJwcmludCJ9";
+
+fn get_payment_info() -> Option<PaymentInfo> {
+    Some(PaymentInfo {
+        router_return_url: Some("https://hyperswitch.io/return".to_string()),
+        ..Default::default()
+    })
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_create_paypal_order_with_client_token() {
+    let connector = Braintree {};
+    let _mock = connector.start_server(get_mock_config()).await;
+    let response = connector
+        .create_session_token(None, get_payment_info())
+        .await
+        .unwrap();
+
+    match response.response.unwrap() {
+        types::PaymentsResponseData::SessionResponse {
+            session_token: api::SessionToken::Paypal(paypal_session_token),
+        } => {
+            assert_eq!(paypal_session_token.session_token, CLIENT_TOKEN);
+            assert_eq!(paypal_session_token.order_id, "EC-8FG83951PW906474J");
+            assert_eq!(paypal_session_token.connector, "braintree");
+        }
+        response => panic!("Unexpected session response {response:?}"),
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_create_paypal_order_with_given_client_token() {
+    let connector = Braintree {};
+    let _mock = connector
+        .start_server(MockConfig {
+            mocks: vec![get_paypal_order_mock()],
+            ..get_mock_config()
+        })
+        .await;
+    let response = connector
+        .create_session_token(
+            Some(types::PaymentsSessionData {
+                client_token: Some(CLIENT_TOKEN.to_string()),
+                ..utils::PaymentSessionType::default().0
+            }),
+            get_payment_info(),
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        response.response,
+        Ok(types::PaymentsResponseData::SessionResponse {
+            session_token: api::SessionToken::Paypal(_),
+        })
+    ));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_fail_paypal_order_without_return_url() {
+    let connector = Braintree {};
+    let _mock = connector.start_server(get_mock_config()).await;
+    let response = connector.create_session_token(None, None).await;
+
+    assert!(response.is_err());
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_fail_paypal_order_if_client_token_is_not_created() {
+    let connector = Braintree {};
+    let _mock = connector
+        .start_server(MockConfig {
+            mocks: vec![Mock::given(method("POST"))
+                .and(path_regex(r"^/merchants/[^/]+/client_token$"))
+                .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                    "apiErrorResponse": {
+                        "message": "Authentication failed"
+                    }
+                })))],
+            ..get_mock_config()
+        })
+        .await;
+    let response = connector
+        .create_session_token(None, get_payment_info())
+        .await;
+
+    assert!(response.is_err());
+}
+
+fn get_paypal_order_mock() -> Mock {
+    Mock::given(method("POST"))
+        .and(path_regex(
+            r"^/merchants/[^/]+/client_api/v1/paypal_hermes/create_payment_resource$",
+        ))
+        .and(body_partial_json(json!({
+            "authorization_fingerprint": "fingerprint",
+            "amount": "1",
+            "currency_iso_code": "USD",
+            "return_url": "https://hyperswitch.io/return",
+            "cancel_url": "https://hyperswitch.io/return"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "paymentResource": {
+                "paymentToken": "EC-8FG83951PW906474J",
+                "redirectUrl": "https://www.sandbox.paypal.com/checkoutnow?token=EC-8FG83951PW906474J"
+            }
+        })))
+}
+
+fn get_mock_config() -> MockConfig {
+    MockConfig {
+        address: Some("127.0.0.1:9090".to_string()),
+        mocks: vec![
+            Mock::given(method("POST"))
+                .and(path_regex(r"^/merchants/[^/]+/client_token$"))
+                .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                    "clientToken": {
+                        "value": CLIENT_TOKEN
+                    }
+                }))),
+            get_paypal_order_mock(),
+        ],
+    }
+}
@@ -38,6 +38,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
                 card_cvc: "123".to_string().into(),
                 card_issuer: None,
                 card_network: None,
+                card_type: None,
+                card_issuing_country: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -51,6 +53,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            network_token: None,
+            connector_customer: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
@@ -57,6 +57,23 @@ pub trait ConnectorActions: Connector {
         call_connector(request, integration).await
     }
 
+    async fn create_session_token(
+        &self,
+        payment_data: Option<types::PaymentsSessionData>,
+        payment_info: Option<PaymentInfo>,
+    ) -> Result<types::PaymentsSessionRouterData, Report<ConnectorError>> {
+        let integration = self.get_data().connector.get_connector_integration();
+        let mut request = self.generate_data(
+            payment_data.unwrap_or_else(|| PaymentSessionType::default().0),
+            payment_info,
+        );
+        let state =
+            routes::AppState::with_storage(Settings::new().unwrap(), StorageImpl::PostgresqlTest)
+                .await;
+        integration.execute_pretasks(&mut request, &state).await?;
+        call_connector(request, integration).await
+    }
+
     async fn make_payment(
         &self,
         payment_data: Option<types::PaymentsAuthorizeData>,
@@ -397,6 +414,7 @@ pub trait ConnectorActions: Connector {
             }
             Ok(types::PaymentsResponseData::SessionResponse { .. }) => None,
             Ok(types::PaymentsResponseData::SessionTokenResponse { .. }) => None,
+            Ok(types::PaymentsResponseData::ConnectorCustomerResponse { .. }) => None,
             Err(_) => None,
         }
     }
@@ -449,6 +467,7 @@ pub struct PaymentAuthorizeType(pub types::PaymentsAuthorizeData);
 pub struct PaymentCaptureType(pub types::PaymentsCaptureData);
 pub struct PaymentCancelType(pub types::PaymentsCancelData);
 pub struct PaymentSyncType(pub types::PaymentsSyncData);
+pub struct PaymentSessionType(pub types::PaymentsSessionData);
 pub struct PaymentRefundType(pub types::RefundsData);
 pub struct CCardType(pub api::Card);
 pub struct BrowserInfoType(pub types::BrowserInformation);
@@ -463,6 +482,8 @@ impl Default for CCardType {
             card_cvc: Secret::new("999".to_string()),
             card_issuer: None,
             card_network: None,
+            card_type: None,
+            card_issuing_country: None,
         })
     }
 }
@@ -485,6 +506,8 @@ impl Default for PaymentAuthorizeType {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            network_token: None,
+            connector_customer: None,
         };
         Self(data)
     }
@@ -529,6 +552,18 @@ impl Default for BrowserInfoType {
     }
 }
 
+impl Default for PaymentSessionType {
+    fn default() -> Self {
+        Self(types::PaymentsSessionData {
+            amount: 100,
+            currency: enums::Currency::USD,
+            country: Some("US".to_string()),
+            order_details: None,
+            client_token: None,
+        })
+    }
+}
+
 impl Default for PaymentSyncType {
     fn default() -> Self {
         let data = types::PaymentsSyncData {
@@ -567,6 +602,7 @@ pub fn get_connector_transaction_id(
         }
         Ok(types::PaymentsResponseData::SessionResponse { .. }) => None,
         Ok(types::PaymentsResponseData::SessionTokenResponse { .. }) => None,
+        Ok(types::PaymentsResponseData::ConnectorCustomerResponse { .. }) => None,
         Err(_) => None,
     }
 }
@@ -49,6 +49,9 @@ pub enum Tag {
 
     /// Event: general.
     Event,
+
+    /// Audit: access to the admin APIs.
+    AdminAudit,
 }
 
 /// API Flow
@@ -62,6 +65,14 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Organization create flow.
+    OrganizationCreate,
+    /// Organization retrieve flow.
+    OrganizationRetrieve,
+    /// Organization update flow.
+    OrganizationUpdate,
+    /// Organization merchant accounts list flow.
+    OrganizationMerchantAccountsList,
     /// Payment connectors create flow.
     PaymentConnectorsCreate,
     /// Payment connectors retrieve flow.
@@ -72,6 +83,10 @@ pub enum Flow {
     PaymentConnectorsDelete,
     /// Payment connectors list flow.
     PaymentConnectorsList,
+    /// Payment connectors health flow.
+    PaymentConnectorsHealth,
+    /// Connector call logs retrieve flow.
+    ConnectorCallLogsRetrieve,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -102,6 +117,16 @@ pub enum Flow {
     PaymentMethodsList,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
+    /// Customer default payment method set flow.
+    DefaultPaymentMethodsSet,
+    /// Network token lifecycle event receive flow.
+    NetworkTokenLifecycleEvent,
+    /// Vault tokenize flow.
+    VaultTokenize,
+    /// Vault detokenize flow.
+    VaultDetokenize,
+    /// Vault token delete flow.
+    VaultTokenDelete,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
@@ -126,6 +151,10 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payment link retrieve flow.
+    PaymentLinkRetrieve,
+    /// Payment link checkout page flow.
+    PaymentLinkInitiate,
     /// Payouts create flow
     PayoutsCreate,
     /// Payouts retrieve flow.
@@ -160,6 +189,14 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// Admin principal create flow
+    AdminPrincipalCreate,
+    /// Admin principal list flow
+    AdminPrincipalList,
+    /// Admin principal revoke flow
+    AdminPrincipalRevoke,
+    /// Audit event list flow
+    AuditEventList,
 }
 
 /// Category of log event.
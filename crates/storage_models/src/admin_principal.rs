@@ -0,0 +1,32 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use crate::{api_keys::HashedApiKey, enums as storage_enums, schema::admin_principal};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = admin_principal, primary_key(principal_id))]
+pub struct AdminPrincipal {
+    pub principal_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub hash_key: Secret<String>,
+    pub hashed_api_key: HashedApiKey,
+    pub prefix: String,
+    #[diesel(deserialize_as = super::DieselArray<storage_enums::Permission>)]
+    pub permissions: Vec<storage_enums::Permission>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = admin_principal)]
+pub struct AdminPrincipalNew {
+    pub principal_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub hash_key: Secret<String>,
+    pub hashed_api_key: HashedApiKey,
+    pub prefix: String,
+    pub permissions: Vec<storage_enums::Permission>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,40 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::audit_events};
+
+/// An audit event is never updated or deleted once it has been inserted.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_events)]
+#[serde(deny_unknown_fields)]
+pub struct AuditEventNew {
+    pub event_id: String,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: String,
+    pub action: storage_enums::AuditAction,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub merchant_id: Option<String>,
+    pub before_state: Option<serde_json::Value>,
+    pub after_state: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = audit_events, primary_key(event_id))]
+pub struct AuditEvent {
+    pub event_id: String,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: String,
+    pub action: storage_enums::AuditAction,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub merchant_id: Option<String>,
+    pub before_state: Option<serde_json::Value>,
+    pub after_state: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,19 @@
+use diesel::{Identifiable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::cards_info;
+
+/// Card metadata for an issuer identification number (the leading digits of the card number),
+/// loaded from a BIN table.
+#[derive(Clone, Debug, Queryable, Identifiable, serde::Deserialize, serde::Serialize)]
+#[diesel(table_name = cards_info, primary_key(card_iin))]
+pub struct CardInfo {
+    pub card_iin: String,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub date_created: PrimitiveDateTime,
+    pub last_updated: PrimitiveDateTime,
+}
@@ -0,0 +1,47 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::connector_call_log;
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_call_log)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorCallLogNew {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub method: String,
+    pub url: String,
+    pub request_headers: serde_json::Value,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+    pub status_code: Option<i16>,
+    pub error_message: Option<String>,
+    pub latency: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = connector_call_log)]
+pub struct ConnectorCallLog {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub method: String,
+    pub url: String,
+    pub request_headers: serde_json::Value,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+    pub status_code: Option<i16>,
+    pub error_message: Option<String>,
+    pub latency: i64,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,71 @@
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CircuitState {
+    #[default]
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// Circuit breaker state shared across router instances for a merchant and connector pair.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct CircuitBreakerRecord {
+    pub state: CircuitState,
+    /// Unix timestamp at which the circuit was last opened
+    pub opened_at: Option<i64>,
+}
+
+/// Connector call counters collected within a single health window.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorCallStats {
+    pub total_calls: u64,
+    pub failed_calls: u64,
+    pub slow_calls: u64,
+    /// Sum of latencies of all calls in the window (in milliseconds)
+    pub total_latency: u64,
+}
+
+impl ConnectorCallStats {
+    pub fn error_rate(&self) -> u64 {
+        Self::percentage(self.failed_calls, self.total_calls)
+    }
+
+    pub fn slow_call_rate(&self) -> u64 {
+        Self::percentage(self.slow_calls, self.total_calls)
+    }
+
+    pub fn average_latency(&self) -> u64 {
+        self.total_latency
+            .checked_div(self.total_calls)
+            .unwrap_or_default()
+    }
+
+    fn percentage(count: u64, total: u64) -> u64 {
+        count
+            .saturating_mul(100)
+            .checked_div(total)
+            .unwrap_or_default()
+    }
+}
+
+/// The outcome of a single connector call, as recorded by the circuit breaker.
+#[derive(Clone, Copy, Debug)]
+pub struct ConnectorCallOutcome {
+    pub failed: bool,
+    pub slow: bool,
+    /// Latency of the call (in milliseconds)
+    pub latency: u64,
+}
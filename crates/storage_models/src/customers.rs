@@ -16,6 +16,7 @@ pub struct CustomerNew {
     pub description: Option<String>,
     pub phone_country_code: Option<String>,
     pub metadata: Option<serde_json::Value>,
+    pub connector_customer: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Identifiable, Queryable)]
@@ -31,6 +32,8 @@ pub struct Customer {
     pub description: Option<String>,
     pub created_at: PrimitiveDateTime,
     pub metadata: Option<serde_json::Value>,
+    pub connector_customer: Option<serde_json::Value>,
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -43,6 +46,12 @@ pub enum CustomerUpdate {
         phone_country_code: Option<String>,
         metadata: Option<serde_json::Value>,
     },
+    ConnectorCustomer {
+        connector_customer: Option<serde_json::Value>,
+    },
+    DefaultPaymentMethodUpdate {
+        default_payment_method_id: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -54,6 +63,8 @@ pub struct CustomerUpdateInternal {
     description: Option<String>,
     phone_country_code: Option<String>,
     metadata: Option<serde_json::Value>,
+    connector_customer: Option<serde_json::Value>,
+    default_payment_method_id: Option<Option<String>>,
 }
 
 impl From<CustomerUpdate> for CustomerUpdateInternal {
@@ -73,7 +84,38 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 description,
                 phone_country_code,
                 metadata,
+                ..Default::default()
+            },
+            CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
+                connector_customer,
+                ..Default::default()
+            },
+            CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id,
+            } => Self {
+                default_payment_method_id: Some(default_payment_method_id),
+                ..Default::default()
             },
         }
     }
 }
+
+impl CustomerUpdate {
+    pub fn apply_changeset(self, source: Customer) -> Customer {
+        let customer_update: CustomerUpdateInternal = self.into();
+        Customer {
+            name: customer_update.name.or(source.name),
+            email: customer_update.email.or(source.email),
+            phone: customer_update.phone.or(source.phone),
+            description: customer_update.description.or(source.description),
+            phone_country_code: customer_update
+                .phone_country_code
+                .or(source.phone_country_code),
+            metadata: customer_update.metadata.or(source.metadata),
+            default_payment_method_id: customer_update
+                .default_payment_method_id
+                .unwrap_or(source.default_payment_method_id),
+            ..source
+        }
+    }
+}
@@ -311,6 +311,10 @@ pub enum EventObjectType {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentFailed,
+    PaymentProcessing,
+    ActionRequired,
+    PaymentCancelled,
     RefundSucceeded,
     RefundFailed,
 }
@@ -437,6 +441,8 @@ pub enum PaymentMethod {
     PayLater,
     Wallet,
     BankRedirect,
+    BankTransfer,
+    BankDebit,
 }
 
 #[derive(
@@ -605,6 +611,11 @@ pub enum PaymentMethodType {
     GooglePay,
     ApplePay,
     Paypal,
+    Ach,
+    Sepa,
+    Bacs,
+    Becs,
+    Multibanco,
 }
 
 #[derive(
@@ -706,3 +717,270 @@ pub enum PaymentExperience {
     LinkWallet,
     InvokePaymentApp,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    #[default]
+    Active,
+    Completed,
+    Expired,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NetworkTokenStatus {
+    /// The network token can be used for payments
+    #[default]
+    Active,
+    /// The network token has been suspended by the issuer or the card holder
+    Suspended,
+    /// The network token has been deleted and can no longer be used
+    Deleted,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VaultResourceType {
+    Card,
+    Token,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VaultAction {
+    Store,
+    Retrieve,
+    Delete,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum Permission {
+    #[serde(rename = "payments:read")]
+    #[strum(serialize = "payments:read")]
+    PaymentsRead,
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+    #[serde(rename = "refunds:read")]
+    #[strum(serialize = "refunds:read")]
+    RefundsRead,
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+    #[serde(rename = "customers:read")]
+    #[strum(serialize = "customers:read")]
+    CustomersRead,
+    #[serde(rename = "customers:write")]
+    #[strum(serialize = "customers:write")]
+    CustomersWrite,
+    #[serde(rename = "mandates:read")]
+    #[strum(serialize = "mandates:read")]
+    MandatesRead,
+    #[serde(rename = "mandates:write")]
+    #[strum(serialize = "mandates:write")]
+    MandatesWrite,
+    #[serde(rename = "payment_methods:read")]
+    #[strum(serialize = "payment_methods:read")]
+    PaymentMethodsRead,
+    #[serde(rename = "payment_methods:write")]
+    #[strum(serialize = "payment_methods:write")]
+    PaymentMethodsWrite,
+    #[serde(rename = "vault:read")]
+    #[strum(serialize = "vault:read")]
+    VaultRead,
+    #[serde(rename = "vault:write")]
+    #[strum(serialize = "vault:write")]
+    VaultWrite,
+    #[serde(rename = "merchant_accounts:read")]
+    #[strum(serialize = "merchant_accounts:read")]
+    MerchantAccountsRead,
+    #[serde(rename = "merchant_accounts:write")]
+    #[strum(serialize = "merchant_accounts:write")]
+    MerchantAccountsWrite,
+    #[serde(rename = "organizations:read")]
+    #[strum(serialize = "organizations:read")]
+    OrganizationsRead,
+    #[serde(rename = "organizations:write")]
+    #[strum(serialize = "organizations:write")]
+    OrganizationsWrite,
+    #[serde(rename = "connectors:read")]
+    #[strum(serialize = "connectors:read")]
+    ConnectorsRead,
+    #[serde(rename = "connectors:write")]
+    #[strum(serialize = "connectors:write")]
+    ConnectorsWrite,
+    #[serde(rename = "api_keys:read")]
+    #[strum(serialize = "api_keys:read")]
+    ApiKeysRead,
+    #[serde(rename = "api_keys:write")]
+    #[strum(serialize = "api_keys:write")]
+    ApiKeysWrite,
+    #[serde(rename = "configs:read")]
+    #[strum(serialize = "configs:read")]
+    ConfigsRead,
+    #[serde(rename = "configs:write")]
+    #[strum(serialize = "configs:write")]
+    ConfigsWrite,
+    #[serde(rename = "admin_principals:read")]
+    #[strum(serialize = "admin_principals:read")]
+    AdminPrincipalsRead,
+    #[serde(rename = "admin_principals:write")]
+    #[strum(serialize = "admin_principals:write")]
+    AdminPrincipalsWrite,
+    #[serde(rename = "audit_events:read")]
+    #[strum(serialize = "audit_events:read")]
+    AuditEventsRead,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    AdminApiKey,
+    AdminPrincipal,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditResourceType {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    ApiKey,
+    Config,
+}
+
+/// Result of the fraud check of a payment, before it is authorized.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckDecision {
+    /// The payment is authorized as requested
+    Accept,
+    /// The payment is authorized, but held for manual capture until it is reviewed
+    Review,
+    /// The payment is not authorized
+    Decline,
+}
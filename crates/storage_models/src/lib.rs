@@ -1,6 +1,11 @@
 pub mod address;
+pub mod admin_principal;
 pub mod api_keys;
+pub mod audit_events;
+pub mod cards_info;
 pub mod configs;
+pub mod connector_call_log;
+pub mod connector_health;
 pub mod connector_response;
 pub mod customers;
 pub mod dispute;
@@ -14,14 +19,18 @@ pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod schema;
+pub mod vault;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -33,6 +33,8 @@ pub struct MerchantAccount {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub pm_filters: Option<serde_json::Value>,
+    pub organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -53,6 +55,8 @@ pub struct MerchantAccountNew {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub pm_filters: Option<serde_json::Value>,
+    pub organization_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -72,6 +76,8 @@ pub enum MerchantAccountUpdate {
         locker_id: Option<String>,
         metadata: Option<serde_json::Value>,
         routing_algorithm: Option<serde_json::Value>,
+        pm_filters: Option<serde_json::Value>,
+        organization_id: Option<String>,
     },
     StorageSchemeUpdate {
         storage_scheme: storage_enums::MerchantStorageScheme,
@@ -96,6 +102,8 @@ pub struct MerchantAccountUpdateInternal {
     locker_id: Option<String>,
     metadata: Option<serde_json::Value>,
     routing_algorithm: Option<serde_json::Value>,
+    pm_filters: Option<serde_json::Value>,
+    organization_id: Option<String>,
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -116,6 +124,8 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                pm_filters,
+                organization_id,
             } => Self {
                 merchant_name,
                 api_key,
@@ -131,6 +141,8 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                pm_filters,
+                organization_id,
                 ..Default::default()
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
@@ -27,6 +27,8 @@ pub struct MerchantConnectorAccount {
     pub payment_methods_enabled: Option<Vec<serde_json::Value>>,
     pub connector_type: storage_enums::ConnectorType,
     pub metadata: Option<serde_json::Value>,
+    pub pm_filters: Option<serde_json::Value>,
+    pub shared_with_sub_merchants: bool,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -41,6 +43,8 @@ pub struct MerchantConnectorAccountNew {
     pub merchant_connector_id: String,
     pub payment_methods_enabled: Option<Vec<serde_json::Value>>,
     pub metadata: Option<serde_json::Value>,
+    pub pm_filters: Option<serde_json::Value>,
+    pub shared_with_sub_merchants: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -55,6 +59,8 @@ pub enum MerchantConnectorAccountUpdate {
         merchant_connector_id: Option<String>,
         payment_methods_enabled: Option<Vec<serde_json::Value>>,
         metadata: Option<serde_json::Value>,
+        pm_filters: Option<serde_json::Value>,
+        shared_with_sub_merchants: Option<bool>,
     },
 }
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -69,6 +75,8 @@ pub struct MerchantConnectorAccountUpdateInternal {
     merchant_connector_id: Option<String>,
     payment_methods_enabled: Option<Vec<serde_json::Value>>,
     metadata: Option<serde_json::Value>,
+    pm_filters: Option<serde_json::Value>,
+    shared_with_sub_merchants: Option<bool>,
 }
 
 impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInternal {
@@ -84,6 +92,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 merchant_connector_id,
                 payment_methods_enabled,
                 metadata,
+                pm_filters,
+                shared_with_sub_merchants,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -94,6 +104,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 merchant_connector_id,
                 payment_methods_enabled,
                 metadata,
+                pm_filters,
+                shared_with_sub_merchants,
             },
         }
     }
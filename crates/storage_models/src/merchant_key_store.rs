@@ -0,0 +1,55 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use crate::schema::merchant_key_store;
+
+/// Data encryption key of a merchant, wrapped by one of the master keys.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = merchant_key_store, primary_key(merchant_id))]
+pub struct MerchantKeyStore {
+    pub merchant_id: String,
+    pub key: Secret<Vec<u8>>,
+    pub master_key_identifier: String,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_key_store)]
+pub struct MerchantKeyStoreNew {
+    pub merchant_id: String,
+    pub key: Secret<Vec<u8>>,
+    pub master_key_identifier: String,
+}
+
+#[derive(Debug)]
+pub enum MerchantKeyStoreUpdate {
+    KeyRewrap {
+        key: Secret<Vec<u8>>,
+        master_key_identifier: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_key_store)]
+pub(crate) struct MerchantKeyStoreUpdateInternal {
+    key: Option<Secret<Vec<u8>>>,
+    master_key_identifier: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<MerchantKeyStoreUpdate> for MerchantKeyStoreUpdateInternal {
+    fn from(merchant_key_store_update: MerchantKeyStoreUpdate) -> Self {
+        match merchant_key_store_update {
+            MerchantKeyStoreUpdate::KeyRewrap {
+                key,
+                master_key_identifier,
+            } => Self {
+                key: Some(key),
+                master_key_identifier: Some(master_key_identifier),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
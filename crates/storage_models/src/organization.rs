@@ -0,0 +1,50 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use crate::{api_keys::HashedApiKey, schema::organization};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = organization, primary_key(org_id))]
+pub struct Organization {
+    pub org_id: String,
+    pub org_name: Option<String>,
+    pub hash_key: Secret<String>,
+    pub hashed_api_key: HashedApiKey,
+    pub prefix: String,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = organization)]
+pub struct OrganizationNew {
+    pub org_id: String,
+    pub org_name: Option<String>,
+    pub hash_key: Secret<String>,
+    pub hashed_api_key: HashedApiKey,
+    pub prefix: String,
+}
+
+#[derive(Debug)]
+pub enum OrganizationUpdate {
+    Update { org_name: Option<String> },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = organization)]
+pub(crate) struct OrganizationUpdateInternal {
+    org_name: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<OrganizationUpdate> for OrganizationUpdateInternal {
+    fn from(organization_update: OrganizationUpdate) -> Self {
+        match organization_update {
+            OrganizationUpdate::Update { org_name } => Self {
+                org_name,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -40,6 +40,9 @@ pub struct PaymentAttempt {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub fraud_check_decision: Option<storage_enums::FraudCheckDecision>,
+    pub fraud_check_connector: Option<String>,
+    pub fraud_check_reason: Option<String>,
 }
 
 #[derive(
@@ -80,6 +83,9 @@ pub struct PaymentAttemptNew {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub fraud_check_decision: Option<storage_enums::FraudCheckDecision>,
+    pub fraud_check_connector: Option<String>,
+    pub fraud_check_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -98,6 +104,8 @@ pub enum PaymentAttemptUpdate {
     UpdateTrackers {
         payment_token: Option<String>,
         connector: Option<String>,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+        payment_method_data: Option<serde_json::Value>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -137,6 +145,15 @@ pub enum PaymentAttemptUpdate {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    FraudCheckUpdate {
+        fraud_check_decision: storage_enums::FraudCheckDecision,
+        fraud_check_connector: String,
+        fraud_check_reason: Option<String>,
+        capture_method: Option<storage_enums::CaptureMethod>,
+        status: Option<storage_enums::AttemptStatus>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -161,6 +178,10 @@ pub struct PaymentAttemptUpdateInternal {
     payment_method_data: Option<serde_json::Value>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_experience: Option<storage_enums::PaymentExperience>,
+    capture_method: Option<storage_enums::CaptureMethod>,
+    fraud_check_decision: Option<storage_enums::FraudCheckDecision>,
+    fraud_check_connector: Option<String>,
+    fraud_check_reason: Option<String>,
 }
 
 impl PaymentAttemptUpdate {
@@ -183,6 +204,16 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            payment_method_data: pa_update.payment_method_data.or(source.payment_method_data),
+            error_code: pa_update.error_code.or(source.error_code),
+            capture_method: pa_update.capture_method.or(source.capture_method),
+            fraud_check_decision: pa_update
+                .fraud_check_decision
+                .or(source.fraud_check_decision),
+            fraud_check_connector: pa_update
+                .fraud_check_connector
+                .or(source.fraud_check_connector),
+            fraud_check_reason: pa_update.fraud_check_reason.or(source.fraud_check_reason),
             ..source
         }
     }
@@ -290,6 +321,25 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_check_decision,
+                fraud_check_connector,
+                fraud_check_reason,
+                capture_method,
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                fraud_check_decision: Some(fraud_check_decision),
+                fraud_check_connector: Some(fraud_check_connector),
+                fraud_check_reason,
+                capture_method,
+                status,
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentAttemptUpdate::StatusUpdate { status } => Self {
                 status: Some(status),
                 ..Default::default()
@@ -297,9 +347,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
+                payment_method_type,
+                payment_method_data,
             } => Self {
                 payment_token,
                 connector,
+                payment_method_type,
+                payment_method_data,
                 ..Default::default()
             },
         }
@@ -0,0 +1,65 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_link};
+
+#[derive(Clone, Debug, Deserialize, Identifiable, Queryable, Serialize)]
+#[diesel(table_name = payment_link, primary_key(payment_link_id))]
+pub struct PaymentLink {
+    pub payment_link_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub link_to_pay: String,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkNew {
+    pub payment_link_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub link_to_pay: String,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentLinkUpdate {
+    StatusUpdate {
+        status: storage_enums::PaymentLinkStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub(crate) struct PaymentLinkUpdateInternal {
+    pub status: Option<storage_enums::PaymentLinkStatus>,
+    pub last_modified: PrimitiveDateTime,
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(payment_link_update: PaymentLinkUpdate) -> Self {
+        match payment_link_update {
+            PaymentLinkUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                last_modified: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl PaymentLinkUpdate {
+    pub fn apply_changeset(self, source: PaymentLink) -> PaymentLink {
+        let payment_link_update: PaymentLinkUpdateInternal = self.into();
+        PaymentLink {
+            status: payment_link_update.status.unwrap_or(source.status),
+            last_modified: payment_link_update.last_modified,
+            ..source
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
 use time::PrimitiveDateTime;
 
@@ -30,6 +30,14 @@ pub struct PaymentMethod {
     pub payment_method_issuer: Option<String>,
     pub payment_method_issuer_code: Option<storage_enums::PaymentMethodIssuerCode>,
     pub metadata: Option<serde_json::Value>,
+    pub card_fingerprint: Option<String>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token: Option<Secret<String>>,
+    pub network_token_exp_month: Option<Secret<String>>,
+    pub network_token_exp_year: Option<Secret<String>>,
+    pub network_token_cryptogram_capable: Option<bool>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable, Queryable, router_derive::DebugAsDisplay)]
@@ -55,6 +63,14 @@ pub struct PaymentMethodNew {
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
     pub metadata: Option<serde_json::Value>,
+    pub card_fingerprint: Option<String>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token: Option<Secret<String>>,
+    pub network_token_exp_month: Option<Secret<String>>,
+    pub network_token_exp_year: Option<Secret<String>>,
+    pub network_token_cryptogram_capable: Option<bool>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 impl Default for PaymentMethodNew {
@@ -82,6 +98,133 @@ impl Default for PaymentMethodNew {
             created_at: now,
             last_modified: now,
             metadata: Option::default(),
+            card_fingerprint: Option::default(),
+            last_used_at: Option::default(),
+            network_token_reference_id: Option::default(),
+            network_token: Option::default(),
+            network_token_exp_month: Option::default(),
+            network_token_exp_year: Option::default(),
+            network_token_cryptogram_capable: Option::default(),
+            network_token_status: Option::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PaymentMethodUpdate {
+    CustomerUpdate {
+        customer_id: String,
+    },
+    LastUsedUpdate {
+        last_used_at: PrimitiveDateTime,
+    },
+    MetadataUpdate {
+        metadata: Option<serde_json::Value>,
+    },
+    NetworkTokenUpdate {
+        network_token_reference_id: String,
+        network_token: Secret<String>,
+        network_token_exp_month: Secret<String>,
+        network_token_exp_year: Secret<String>,
+        network_token_cryptogram_capable: bool,
+        network_token_status: storage_enums::NetworkTokenStatus,
+    },
+    NetworkTokenLifecycleUpdate {
+        network_token_status: storage_enums::NetworkTokenStatus,
+        network_token_exp_month: Option<Secret<String>>,
+        network_token_exp_year: Option<Secret<String>>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_methods)]
+pub struct PaymentMethodUpdateInternal {
+    customer_id: Option<String>,
+    last_used_at: Option<PrimitiveDateTime>,
+    metadata: Option<serde_json::Value>,
+    network_token_reference_id: Option<String>,
+    network_token: Option<Secret<String>>,
+    network_token_exp_month: Option<Secret<String>>,
+    network_token_exp_year: Option<Secret<String>>,
+    network_token_cryptogram_capable: Option<bool>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    last_modified: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
+    fn from(payment_method_update: PaymentMethodUpdate) -> Self {
+        match payment_method_update {
+            PaymentMethodUpdate::CustomerUpdate { customer_id } => Self {
+                customer_id: Some(customer_id),
+                last_modified: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
+                last_used_at: Some(last_used_at),
+                ..Default::default()
+            },
+            PaymentMethodUpdate::MetadataUpdate { metadata } => Self {
+                metadata,
+                last_modified: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference_id,
+                network_token,
+                network_token_exp_month,
+                network_token_exp_year,
+                network_token_cryptogram_capable,
+                network_token_status,
+            } => Self {
+                network_token_reference_id: Some(network_token_reference_id),
+                network_token: Some(network_token),
+                network_token_exp_month: Some(network_token_exp_month),
+                network_token_exp_year: Some(network_token_exp_year),
+                network_token_cryptogram_capable: Some(network_token_cryptogram_capable),
+                network_token_status: Some(network_token_status),
+                last_modified: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentMethodUpdate::NetworkTokenLifecycleUpdate {
+                network_token_status,
+                network_token_exp_month,
+                network_token_exp_year,
+            } => Self {
+                network_token_status: Some(network_token_status),
+                network_token_exp_month,
+                network_token_exp_year,
+                last_modified: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl PaymentMethodUpdate {
+    pub fn apply_changeset(self, source: PaymentMethod) -> PaymentMethod {
+        let pm_update: PaymentMethodUpdateInternal = self.into();
+        PaymentMethod {
+            customer_id: pm_update.customer_id.unwrap_or(source.customer_id),
+            last_used_at: pm_update.last_used_at.or(source.last_used_at),
+            metadata: pm_update.metadata.or(source.metadata),
+            network_token_reference_id: pm_update
+                .network_token_reference_id
+                .or(source.network_token_reference_id),
+            network_token: pm_update.network_token.or(source.network_token),
+            network_token_exp_month: pm_update
+                .network_token_exp_month
+                .or(source.network_token_exp_month),
+            network_token_exp_year: pm_update
+                .network_token_exp_year
+                .or(source.network_token_exp_year),
+            network_token_cryptogram_capable: pm_update
+                .network_token_cryptogram_capable
+                .or(source.network_token_cryptogram_capable),
+            network_token_status: pm_update
+                .network_token_status
+                .or(source.network_token_status),
+            last_modified: pm_update.last_modified.unwrap_or(source.last_modified),
+            ..source
         }
     }
 }
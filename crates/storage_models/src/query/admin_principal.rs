@@ -0,0 +1,54 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    admin_principal::{AdminPrincipal, AdminPrincipalNew},
+    schema::admin_principal::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AdminPrincipalNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AdminPrincipal> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AdminPrincipal {
+    #[instrument(skip(conn))]
+    pub async fn find_by_principal_id(
+        conn: &PgPooledConn,
+        principal_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_by_id::<<Self as HasTable>::Table, _, _>(
+            conn,
+            principal_id.to_owned(),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::principal_id.is_not_null(),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_principal_id(
+        conn: &PgPooledConn,
+        principal_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::principal_id.eq(principal_id.to_owned()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,14 @@
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    audit_events::{AuditEvent, AuditEventNew},
+    PgPooledConn, StorageResult,
+};
+
+impl AuditEventNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuditEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
@@ -0,0 +1,26 @@
+use diesel::{associations::HasTable, ExpressionMethods, Table};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{cards_info::CardInfo, schema::cards_info::dsl, PgPooledConn, StorageResult};
+
+impl CardInfo {
+    /// Find the entry matching the longest of the given issuer identification numbers.
+    #[instrument(skip(conn))]
+    pub async fn find_by_iins(
+        conn: &PgPooledConn,
+        card_iins: Vec<String>,
+    ) -> StorageResult<Option<Self>> {
+        let cards_info = generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::card_iin.eq_any(card_iins), None, None, None)
+        .await?;
+
+        Ok(cards_info
+            .into_iter()
+            .max_by_key(|card_info| card_info.card_iin.len()))
+    }
+}
@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -101,4 +101,24 @@ impl MerchantAccount {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_organization_id(
+        conn: &PgPooledConn,
+        organization_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::organization_id.eq(organization_id.to_owned()),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
 }
@@ -0,0 +1,43 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    organization::{Organization, OrganizationNew, OrganizationUpdate, OrganizationUpdateInternal},
+    schema::organization::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl OrganizationNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Organization> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Organization {
+    #[instrument(skip(conn))]
+    pub async fn update_by_org_id(
+        conn: &PgPooledConn,
+        org_id: &str,
+        organization_update: OrganizationUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::org_id.eq(org_id.to_owned()),
+            OrganizationUpdateInternal::from(organization_update),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_org_id(conn: &PgPooledConn, org_id: &str) -> StorageResult<Self> {
+        generics::generic_find_by_id::<<Self as HasTable>::Table, _, _>(conn, org_id.to_owned())
+            .await
+    }
+}
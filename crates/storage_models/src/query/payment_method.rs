@@ -3,7 +3,10 @@ use router_env::{instrument, tracing};
 
 use super::generics;
 use crate::{
-    payment_method::{PaymentMethod, PaymentMethodNew},
+    errors,
+    payment_method::{
+        PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
+    },
     schema::payment_methods::dsl,
     PgPooledConn, StorageResult,
 };
@@ -16,6 +19,27 @@ impl PaymentMethodNew {
 }
 
 impl PaymentMethod {
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payment_method: PaymentMethodUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            PaymentMethodUpdateInternal::from(payment_method),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_payment_method_id(
         conn: &PgPooledConn,
@@ -97,4 +121,33 @@ impl PaymentMethod {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_network_token_reference_id(
+        conn: &PgPooledConn,
+        network_token_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::network_token_reference_id.eq(network_token_reference_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_customer_id_card_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::card_fingerprint.eq(card_fingerprint.to_owned())),
+        )
+        .await
+    }
 }
@@ -0,0 +1,153 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    schema::vault::{access_events, cards, tokens},
+    vault::{
+        VaultAccessEvent, VaultAccessEventNew, VaultCard, VaultCardNew, VaultToken, VaultTokenNew,
+        VaultTokenUpdate, VaultTokenUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl VaultCardNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultCard> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultCard {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_card_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            cards::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(cards::dsl::card_id.eq(card_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_card_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            cards::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(cards::dsl::card_id.eq(card_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl VaultTokenNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultToken> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultToken {
+    /// Expired tokens are treated as not found.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_token(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        token: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            tokens::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(tokens::dsl::token.eq(token.to_owned()))
+                .and(tokens::dsl::expires_at.gt(common_utils::date_time::now())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_token(
+        conn: &PgPooledConn,
+        token: &str,
+        vault_token_update: VaultTokenUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            token.to_owned(),
+            VaultTokenUpdateInternal::from(vault_token_update),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_token(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        token: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            tokens::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(tokens::dsl::token.eq(token.to_owned())),
+        )
+        .await
+    }
+
+    /// Returns `false` if there were no expired tokens to delete.
+    #[instrument(skip(conn))]
+    pub async fn delete_expired(conn: &PgPooledConn) -> StorageResult<bool> {
+        match generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            tokens::dsl::expires_at.le(common_utils::date_time::now()),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Ok(false),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl VaultAccessEventNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultAccessEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultAccessEvent {
+    /// Most recent access events for a card or token of the merchant.
+    #[instrument(skip(conn))]
+    pub async fn filter_by_merchant_id_resource_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        resource_id: &str,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            access_events::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(access_events::dsl::resource_id.eq(resource_id.to_owned())),
+            Some(limit),
+            None,
+            Some(access_events::dsl::created_at.desc()),
+        )
+        .await
+    }
+}
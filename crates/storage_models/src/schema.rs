@@ -9,14 +9,14 @@ diesel::table! {
         address_id -> Varchar,
         city -> Nullable<Varchar>,
         country -> Nullable<Varchar>,
-        line1 -> Nullable<Varchar>,
-        line2 -> Nullable<Varchar>,
-        line3 -> Nullable<Varchar>,
-        state -> Nullable<Varchar>,
-        zip -> Nullable<Varchar>,
-        first_name -> Nullable<Varchar>,
-        last_name -> Nullable<Varchar>,
-        phone_number -> Nullable<Varchar>,
+        line1 -> Nullable<Text>,
+        line2 -> Nullable<Text>,
+        line3 -> Nullable<Text>,
+        state -> Nullable<Text>,
+        zip -> Nullable<Text>,
+        first_name -> Nullable<Text>,
+        last_name -> Nullable<Text>,
+        phone_number -> Nullable<Text>,
         country_code -> Nullable<Varchar>,
         created_at -> Timestamp,
         modified_at -> Timestamp,
@@ -25,6 +25,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    admin_principal (principal_id) {
+        principal_id -> Varchar,
+        name -> Varchar,
+        description -> Nullable<Varchar>,
+        hash_key -> Varchar,
+        hashed_api_key -> Varchar,
+        prefix -> Varchar,
+        permissions -> Array<Nullable<Text>>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -40,6 +56,42 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permissions -> Nullable<Array<Nullable<Text>>>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_events (event_id) {
+        event_id -> Varchar,
+        actor_type -> Varchar,
+        actor_id -> Varchar,
+        action -> Varchar,
+        resource_type -> Varchar,
+        resource_id -> Varchar,
+        merchant_id -> Nullable<Varchar>,
+        before_state -> Nullable<Jsonb>,
+        after_state -> Nullable<Jsonb>,
+        request_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    cards_info (card_iin) {
+        card_iin -> Varchar,
+        card_issuer -> Nullable<Text>,
+        card_network -> Nullable<Text>,
+        card_type -> Nullable<Text>,
+        card_subtype -> Nullable<Text>,
+        card_issuing_country -> Nullable<Text>,
+        date_created -> Timestamp,
+        last_updated -> Timestamp,
     }
 }
 
@@ -54,6 +106,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_call_log (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        connector -> Varchar,
+        flow -> Varchar,
+        method -> Varchar,
+        url -> Text,
+        request_headers -> Jsonb,
+        request_body -> Nullable<Text>,
+        response_body -> Nullable<Text>,
+        status_code -> Nullable<Int2>,
+        error_message -> Nullable<Text>,
+        latency -> Int8,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -80,13 +155,15 @@ diesel::table! {
         id -> Int4,
         customer_id -> Varchar,
         merchant_id -> Varchar,
-        name -> Nullable<Varchar>,
-        email -> Nullable<Varchar>,
-        phone -> Nullable<Varchar>,
+        name -> Nullable<Text>,
+        email -> Nullable<Text>,
+        phone -> Nullable<Text>,
         phone_country_code -> Nullable<Varchar>,
         description -> Nullable<Varchar>,
         created_at -> Timestamp,
         metadata -> Nullable<Json>,
+        connector_customer -> Nullable<Jsonb>,
+        default_payment_method_id -> Nullable<Varchar>,
     }
 }
 
@@ -178,6 +255,8 @@ diesel::table! {
         locker_id -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         routing_algorithm -> Nullable<Json>,
+        pm_filters -> Nullable<Jsonb>,
+        organization_id -> Nullable<Varchar>,
     }
 }
 
@@ -196,6 +275,36 @@ diesel::table! {
         payment_methods_enabled -> Nullable<Array<Nullable<Json>>>,
         connector_type -> ConnectorType,
         metadata -> Nullable<Jsonb>,
+        pm_filters -> Nullable<Jsonb>,
+        shared_with_sub_merchants -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_key_store (merchant_id) {
+        merchant_id -> Varchar,
+        key -> Bytea,
+        master_key_identifier -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    organization (org_id) {
+        org_id -> Varchar,
+        org_name -> Nullable<Varchar>,
+        hash_key -> Varchar,
+        hashed_api_key -> Varchar,
+        prefix -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -237,6 +346,9 @@ diesel::table! {
         payment_experience -> Nullable<Varchar>,
         payment_method_type -> Nullable<Varchar>,
         payment_method_data -> Nullable<Jsonb>,
+        fraud_check_decision -> Nullable<Varchar>,
+        fraud_check_connector -> Nullable<Varchar>,
+        fraud_check_reason -> Nullable<Varchar>,
     }
 }
 
@@ -270,6 +382,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_link (payment_link_id) {
+        payment_link_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        link_to_pay -> Text,
+        status -> Varchar,
+        created_at -> Timestamp,
+        last_modified -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -296,6 +424,14 @@ diesel::table! {
         payment_method_issuer -> Nullable<Varchar>,
         payment_method_issuer_code -> Nullable<PaymentMethodIssuerCode>,
         metadata -> Nullable<Json>,
+        card_fingerprint -> Nullable<Varchar>,
+        last_used_at -> Nullable<Timestamp>,
+        network_token_reference_id -> Nullable<Varchar>,
+        network_token -> Nullable<Varchar>,
+        network_token_exp_month -> Nullable<Varchar>,
+        network_token_exp_year -> Nullable<Varchar>,
+        network_token_cryptogram_capable -> Nullable<Bool>,
+        network_token_status -> Nullable<Varchar>,
     }
 }
 
@@ -366,8 +502,12 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    admin_principal,
     api_keys,
+    audit_events,
+    cards_info,
     configs,
+    connector_call_log,
     connector_response,
     customers,
     events,
@@ -375,10 +515,64 @@ diesel::allow_tables_to_appear_in_same_query!(
     mandate,
     merchant_account,
     merchant_connector_account,
+    merchant_key_store,
+    organization,
     payment_attempt,
     payment_intent,
+    payment_link,
     payment_methods,
     process_tracker,
     refund,
     reverse_lookup,
 );
+
+/// Tables of the card vault, which live in the separate `vault` schema.
+pub mod vault {
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::enums::diesel_exports::*;
+
+        vault.access_events (id) {
+            id -> Int4,
+            merchant_id -> Varchar,
+            resource_type -> Varchar,
+            resource_id -> Varchar,
+            action -> Varchar,
+            created_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::enums::diesel_exports::*;
+
+        vault.cards (card_id) {
+            card_id -> Varchar,
+            merchant_id -> Varchar,
+            customer_id -> Varchar,
+            card_number -> Text,
+            card_exp_month -> Text,
+            card_exp_year -> Text,
+            name_on_card -> Nullable<Text>,
+            nickname -> Nullable<Varchar>,
+            card_fingerprint -> Varchar,
+            created_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::enums::diesel_exports::*;
+
+        vault.tokens (token) {
+            token -> Varchar,
+            merchant_id -> Varchar,
+            value1 -> Text,
+            value2 -> Text,
+            created_at -> Timestamp,
+            expires_at -> Timestamp,
+        }
+    }
+
+    diesel::allow_tables_to_appear_in_same_query!(access_events, cards, tokens,);
+}
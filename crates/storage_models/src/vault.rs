@@ -0,0 +1,111 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::vault::{access_events, cards, tokens},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = cards, primary_key(card_id))]
+pub struct VaultCard {
+    pub card_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_number: Secret<String>,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+    pub name_on_card: Option<Secret<String>>,
+    pub nickname: Option<String>,
+    pub card_fingerprint: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = cards)]
+pub struct VaultCardNew {
+    pub card_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_number: Secret<String>,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+    pub name_on_card: Option<Secret<String>>,
+    pub nickname: Option<String>,
+    pub card_fingerprint: String,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = tokens, primary_key(token))]
+pub struct VaultToken {
+    pub token: String,
+    pub merchant_id: String,
+    pub value1: Secret<String>,
+    pub value2: Secret<String>,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = tokens)]
+pub struct VaultTokenNew {
+    pub token: String,
+    pub merchant_id: String,
+    pub value1: Secret<String>,
+    pub value2: Secret<String>,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum VaultTokenUpdate {
+    ValueUpdate {
+        value1: Secret<String>,
+        value2: Secret<String>,
+        expires_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = tokens)]
+pub(crate) struct VaultTokenUpdateInternal {
+    value1: Secret<String>,
+    value2: Secret<String>,
+    expires_at: PrimitiveDateTime,
+}
+
+impl From<VaultTokenUpdate> for VaultTokenUpdateInternal {
+    fn from(vault_token_update: VaultTokenUpdate) -> Self {
+        match vault_token_update {
+            VaultTokenUpdate::ValueUpdate {
+                value1,
+                value2,
+                expires_at,
+            } => Self {
+                value1,
+                value2,
+                expires_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = access_events)]
+pub struct VaultAccessEvent {
+    pub id: i32,
+    pub merchant_id: String,
+    pub resource_type: storage_enums::VaultResourceType,
+    pub resource_id: String,
+    pub action: storage_enums::VaultAction,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = access_events)]
+pub struct VaultAccessEventNew {
+    pub merchant_id: String,
+    pub resource_type: storage_enums::VaultResourceType,
+    pub resource_id: String,
+    pub action: storage_enums::VaultAction,
+}
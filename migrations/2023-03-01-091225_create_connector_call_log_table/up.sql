@@ -0,0 +1,19 @@
+CREATE TABLE connector_call_log (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    flow VARCHAR(64) NOT NULL,
+    method VARCHAR(16) NOT NULL,
+    url TEXT NOT NULL,
+    request_headers JSONB NOT NULL,
+    request_body TEXT DEFAULT NULL,
+    response_body TEXT DEFAULT NULL,
+    status_code SMALLINT DEFAULT NULL,
+    error_message TEXT DEFAULT NULL,
+    latency BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX connector_call_log_merchant_id_payment_id_index ON connector_call_log (merchant_id, payment_id);
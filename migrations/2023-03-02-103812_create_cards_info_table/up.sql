@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE cards_info (
+    card_iin VARCHAR(16) PRIMARY KEY,
+    card_issuer TEXT,
+    card_network TEXT,
+    card_type TEXT,
+    card_subtype TEXT,
+    card_issuing_country TEXT,
+    date_created TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_updated TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
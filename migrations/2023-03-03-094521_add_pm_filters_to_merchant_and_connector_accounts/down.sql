@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_account DROP COLUMN pm_filters;
+
+ALTER TABLE merchant_connector_account DROP COLUMN pm_filters;
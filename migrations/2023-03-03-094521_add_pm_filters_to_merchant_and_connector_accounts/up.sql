@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account ADD COLUMN pm_filters JSONB DEFAULT NULL;
+
+ALTER TABLE merchant_connector_account ADD COLUMN pm_filters JSONB DEFAULT NULL;
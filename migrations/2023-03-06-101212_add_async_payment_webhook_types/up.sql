@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE 'payment_failed';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_processing';
+
+ALTER TYPE "EventType" ADD VALUE 'action_required';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_cancelled';
@@ -0,0 +1,12 @@
+CREATE TABLE payment_link (
+    payment_link_id VARCHAR(64) PRIMARY KEY,
+    payment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    link_to_pay TEXT NOT NULL,
+    status VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL
+);
+
+CREATE UNIQUE INDEX payment_link_merchant_id_payment_id_index ON payment_link (merchant_id, payment_id);
@@ -0,0 +1,7 @@
+DROP INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index;
+
+ALTER TABLE payment_methods
+DROP COLUMN card_fingerprint,
+DROP COLUMN last_used_at;
+
+ALTER TABLE customers DROP COLUMN default_payment_method_id;
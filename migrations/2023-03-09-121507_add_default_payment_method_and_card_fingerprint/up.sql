@@ -0,0 +1,7 @@
+ALTER TABLE customers ADD COLUMN default_payment_method_id VARCHAR(64);
+
+ALTER TABLE payment_methods
+ADD COLUMN card_fingerprint VARCHAR(64),
+ADD COLUMN last_used_at TIMESTAMP;
+
+CREATE UNIQUE INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index ON payment_methods (merchant_id, customer_id, card_fingerprint) WHERE card_fingerprint IS NOT NULL;
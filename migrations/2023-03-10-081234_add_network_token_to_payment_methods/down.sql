@@ -0,0 +1,9 @@
+DROP INDEX payment_methods_network_token_reference_id_index;
+
+ALTER TABLE payment_methods
+DROP COLUMN network_token_reference_id,
+DROP COLUMN network_token,
+DROP COLUMN network_token_exp_month,
+DROP COLUMN network_token_exp_year,
+DROP COLUMN network_token_cryptogram_capable,
+DROP COLUMN network_token_status;
@@ -0,0 +1,9 @@
+ALTER TABLE payment_methods
+ADD COLUMN network_token_reference_id VARCHAR(128),
+ADD COLUMN network_token VARCHAR(255),
+ADD COLUMN network_token_exp_month VARCHAR(2),
+ADD COLUMN network_token_exp_year VARCHAR(4),
+ADD COLUMN network_token_cryptogram_capable BOOLEAN,
+ADD COLUMN network_token_status VARCHAR(32);
+
+CREATE UNIQUE INDEX payment_methods_network_token_reference_id_index ON payment_methods (network_token_reference_id);
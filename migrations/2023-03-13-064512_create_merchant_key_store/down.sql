@@ -0,0 +1,18 @@
+ALTER TABLE address
+    ALTER COLUMN line1 TYPE VARCHAR(255),
+    ALTER COLUMN line2 TYPE VARCHAR(255),
+    ALTER COLUMN line3 TYPE VARCHAR(255),
+    ALTER COLUMN state TYPE VARCHAR(128),
+    ALTER COLUMN zip TYPE VARCHAR(16),
+    ALTER COLUMN first_name TYPE VARCHAR(255),
+    ALTER COLUMN last_name TYPE VARCHAR(255),
+    ALTER COLUMN phone_number TYPE VARCHAR(32);
+
+ALTER TABLE customers
+    ALTER COLUMN name TYPE VARCHAR(255),
+    ALTER COLUMN email TYPE VARCHAR(255),
+    ALTER COLUMN phone TYPE VARCHAR(32);
+
+DROP INDEX merchant_key_store_master_key_identifier_index;
+
+DROP TABLE merchant_key_store;
@@ -0,0 +1,25 @@
+CREATE TABLE merchant_key_store (
+    merchant_id VARCHAR(64) PRIMARY KEY,
+    key BYTEA NOT NULL,
+    master_key_identifier VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX merchant_key_store_master_key_identifier_index ON merchant_key_store (master_key_identifier);
+
+-- Encrypted values are stored as base64 text, which is longer than the plaintext
+ALTER TABLE customers
+    ALTER COLUMN name TYPE TEXT,
+    ALTER COLUMN email TYPE TEXT,
+    ALTER COLUMN phone TYPE TEXT;
+
+ALTER TABLE address
+    ALTER COLUMN line1 TYPE TEXT,
+    ALTER COLUMN line2 TYPE TEXT,
+    ALTER COLUMN line3 TYPE TEXT,
+    ALTER COLUMN state TYPE TEXT,
+    ALTER COLUMN zip TYPE TEXT,
+    ALTER COLUMN first_name TYPE TEXT,
+    ALTER COLUMN last_name TYPE TEXT,
+    ALTER COLUMN phone_number TYPE TEXT;
@@ -0,0 +1,4 @@
+DROP TABLE vault.access_events;
+DROP TABLE vault.tokens;
+DROP TABLE vault.cards;
+DROP SCHEMA vault;
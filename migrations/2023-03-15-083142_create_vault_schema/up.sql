@@ -0,0 +1,40 @@
+-- The card vault lives in its own schema, so that access to it can be granted separately from
+-- the rest of the application tables.
+CREATE SCHEMA IF NOT EXISTS vault;
+
+CREATE TABLE vault.cards (
+    card_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    card_number TEXT NOT NULL,
+    card_exp_month TEXT NOT NULL,
+    card_exp_year TEXT NOT NULL,
+    name_on_card TEXT,
+    nickname VARCHAR(255),
+    card_fingerprint VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX cards_merchant_id_customer_id_index ON vault.cards (merchant_id, customer_id);
+
+CREATE TABLE vault.tokens (
+    token VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    value1 TEXT NOT NULL,
+    value2 TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX tokens_expires_at_index ON vault.tokens (expires_at);
+
+CREATE TABLE vault.access_events (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    resource_type VARCHAR(16) NOT NULL,
+    resource_id VARCHAR(64) NOT NULL,
+    action VARCHAR(16) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX access_events_merchant_id_resource_id_index ON vault.access_events (merchant_id, resource_id);
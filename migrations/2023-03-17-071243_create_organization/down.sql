@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account DROP COLUMN shared_with_sub_merchants;
+
+DROP INDEX merchant_account_organization_id_index;
+
+ALTER TABLE merchant_account DROP COLUMN organization_id;
+
+DROP TABLE organization;
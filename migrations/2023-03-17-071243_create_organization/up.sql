@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE organization (
+    org_id VARCHAR(64) PRIMARY KEY,
+    org_name VARCHAR(255),
+    hash_key VARCHAR(64) NOT NULL,
+    hashed_api_key VARCHAR(128) NOT NULL,
+    prefix VARCHAR(16) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+ALTER TABLE merchant_account ADD COLUMN organization_id VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX merchant_account_organization_id_index ON merchant_account (organization_id);
+
+ALTER TABLE merchant_connector_account ADD COLUMN shared_with_sub_merchants BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE admin_principal (
+    principal_id VARCHAR(64) PRIMARY KEY,
+    name VARCHAR(64) NOT NULL,
+    description VARCHAR(256),
+    hash_key VARCHAR(64) NOT NULL,
+    hashed_api_key VARCHAR(128) NOT NULL,
+    prefix VARCHAR(16) NOT NULL,
+    permissions TEXT[] NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX admin_principal_name_index ON admin_principal (name);
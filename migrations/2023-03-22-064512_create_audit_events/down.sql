@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER audit_events_append_only ON audit_events;
+
+DROP FUNCTION reject_audit_events_modification;
+
+DROP TABLE audit_events;
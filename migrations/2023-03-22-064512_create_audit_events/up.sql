@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE audit_events (
+    event_id VARCHAR(64) PRIMARY KEY,
+    actor_type VARCHAR(32) NOT NULL,
+    actor_id VARCHAR(64) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    resource_type VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(64),
+    before_state JSONB,
+    after_state JSONB,
+    request_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX audit_events_merchant_id_created_at_index ON audit_events (merchant_id, created_at);
+
+CREATE INDEX audit_events_resource_index ON audit_events (resource_type, resource_id);
+
+-- Audit events are append-only, they can never be updated or deleted
+CREATE FUNCTION reject_audit_events_modification() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_events_append_only
+    BEFORE UPDATE OR DELETE ON audit_events
+    FOR EACH ROW EXECUTE FUNCTION reject_audit_events_modification();
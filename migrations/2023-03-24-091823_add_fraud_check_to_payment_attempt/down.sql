@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN fraud_check_decision,
+DROP COLUMN fraud_check_connector,
+DROP COLUMN fraud_check_reason;
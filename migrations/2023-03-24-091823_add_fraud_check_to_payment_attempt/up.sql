@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN fraud_check_decision VARCHAR(32),
+ADD COLUMN fraud_check_connector VARCHAR(64),
+ADD COLUMN fraud_check_reason VARCHAR(255);